@@ -203,6 +203,60 @@ benchmarks! {
 		assert!(Pallet::<T>::asset_type_units_per_second(&asset_type_to_be_removed).is_none());
 		assert!(!Pallet::<T>::supported_fee_payment_assets().contains(&asset_type_to_be_removed));
 	}
+
+	update_foreign_asset_metadata {
+		let asset_type = T::ForeignAssetType::default();
+		let metadata = T::AssetRegistrarMetadata::default();
+		let amount = 1u32.into();
+		let asset_id: T::AssetId = asset_type.clone().into();
+		Pallet::<T>::register_foreign_asset(
+			RawOrigin::Root.into(),
+			asset_type.clone(),
+			metadata.clone(),
+			amount,
+			true
+		)?;
+	}: _(RawOrigin::Root, asset_id, metadata)
+	verify {
+		assert_eq!(Pallet::<T>::asset_id_type(asset_id), Some(asset_type));
+	}
+
+	add_asset_type_alias {
+		let asset_type = T::ForeignAssetType::default();
+		let metadata = T::AssetRegistrarMetadata::default();
+		let amount = 1u32.into();
+		let asset_id: T::AssetId = asset_type.clone().into();
+		Pallet::<T>::register_foreign_asset(
+			RawOrigin::Root.into(),
+			asset_type.clone(),
+			metadata,
+			amount,
+			true
+		)?;
+		let alias: T::ForeignAssetType = MultiLocation::new(0, X1(GeneralIndex(1))).into();
+	}: _(RawOrigin::Root, asset_id, alias.clone())
+	verify {
+		assert_eq!(Pallet::<T>::asset_type_alias(&alias), Some(asset_id));
+	}
+
+	remove_asset_type_alias {
+		let asset_type = T::ForeignAssetType::default();
+		let metadata = T::AssetRegistrarMetadata::default();
+		let amount = 1u32.into();
+		let asset_id: T::AssetId = asset_type.clone().into();
+		Pallet::<T>::register_foreign_asset(
+			RawOrigin::Root.into(),
+			asset_type.clone(),
+			metadata,
+			amount,
+			true
+		)?;
+		let alias: T::ForeignAssetType = MultiLocation::new(0, X1(GeneralIndex(1))).into();
+		Pallet::<T>::add_asset_type_alias(RawOrigin::Root.into(), asset_id, alias.clone())?;
+	}: _(RawOrigin::Root, alias.clone())
+	verify {
+		assert!(Pallet::<T>::asset_type_alias(&alias).is_none());
+	}
 }
 
 #[cfg(test)]
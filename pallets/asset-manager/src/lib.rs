@@ -32,7 +32,8 @@
 //! This pallet has eight extrinsics: register_foreign_asset, which registers a foreign
 //! asset in this pallet and creates the asset as dictated by the AssetRegistrar trait.
 //! set_asset_units_per_second: which sets the unit per second that should be charged for
-//! a particular asset.
+//! a particular asset. Asset types accepted by `UnregisteredFeeAssets` (e.g. bridged erc20
+//! tokens) can be given a unit per second without being registered.
 //! change_existing_asset_type: which allows to update the correspondence between AssetId and
 //! AssetType
 //! remove_supported_asset: which removes an asset from the supported assets for fee payment
@@ -40,6 +41,10 @@
 //! register_local_asset: which creates a local asset with a specific owner
 //! destroy_foreign_asset: which destroys a foreign asset and all its associated data
 //! destroy_local_asset: which destroys a local asset and all its associated data
+//! update_foreign_asset_metadata: which updates the metadata of a registered foreign asset
+//! add_asset_type_alias: which makes an additional asset type resolve to a registered foreign
+//! asset, e.g. its previous location after a change_existing_asset_type
+//! remove_asset_type_alias: which removes an asset type alias
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -61,7 +66,7 @@ pub mod pallet {
 	use super::*;
 	use frame_support::{
 		pallet_prelude::*,
-		traits::{Currency, ReservableCurrency},
+		traits::{Contains, Currency, ReservableCurrency},
 		PalletId,
 	};
 	use frame_system::pallet_prelude::*;
@@ -112,6 +117,14 @@ pub mod pallet {
 			unimplemented!()
 		}
 
+		// How to update the metadata of a foreign asset
+		fn update_foreign_asset_metadata(
+			_asset: T::AssetId,
+			_metadata: T::AssetRegistrarMetadata,
+		) -> DispatchResult {
+			unimplemented!()
+		}
+
 		// How to destroy a foreign asset
 		fn destroy_foreign_asset(_asset: T::AssetId) -> DispatchResult {
 			unimplemented!()
@@ -140,7 +153,7 @@ pub mod pallet {
 		}
 
 		fn get_asset_id(asset_type: T::ForeignAssetType) -> Option<T::AssetId> {
-			AssetTypeId::<T>::get(asset_type)
+			AssetTypeId::<T>::get(&asset_type).or_else(|| Self::resolve_alias(&asset_type))
 		}
 		#[cfg(feature = "runtime-benchmarks")]
 		fn set_asset_type_asset_id(asset_type: T::ForeignAssetType, asset_id: T::AssetId) {
@@ -151,12 +164,13 @@ pub mod pallet {
 
 	impl<T: Config> xcm_primitives::UnitsToWeightRatio<T::ForeignAssetType> for Pallet<T> {
 		fn payment_is_supported(asset_type: T::ForeignAssetType) -> bool {
+			let asset_type = Self::unaliased_asset_type(asset_type);
 			SupportedFeePaymentAssets::<T>::get()
 				.binary_search(&asset_type)
 				.is_ok()
 		}
 		fn get_units_per_second(asset_type: T::ForeignAssetType) -> Option<u128> {
-			AssetTypeUnitsPerSecond::<T>::get(asset_type)
+			AssetTypeUnitsPerSecond::<T>::get(Self::unaliased_asset_type(asset_type))
 		}
 		#[cfg(feature = "runtime-benchmarks")]
 		fn set_units_per_second(asset_type: T::ForeignAssetType, fee_per_second: u128) {
@@ -198,6 +212,10 @@ pub mod pallet {
 		/// Origin that is allowed to create and modify asset information for local assets
 		type LocalAssetModifierOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// Asset types that can be used for xcm fee payment without being registered as
+		/// foreign assets, e.g. erc20 tokens bridged through pallet-erc20-xcm-bridge
+		type UnregisteredFeeAssets: Contains<Self::ForeignAssetType>;
+
 		/// Ways of creating local asset Ids
 		type LocalAssetIdCreator: LocalAssetIdCreator<Self>;
 
@@ -222,6 +240,9 @@ pub mod pallet {
 		ErrorDestroyingAsset,
 		NotSufficientDeposit,
 		NonExistentLocalAsset,
+		ErrorUpdatingMetadata,
+		AliasAlreadyExists,
+		AliasDoesNotExist,
 	}
 
 	#[pallet::event]
@@ -263,6 +284,21 @@ pub mod pallet {
 		},
 		/// Removed all information related to an assetId and destroyed asset
 		LocalAssetDestroyed { asset_id: T::AssetId },
+		/// Changed the metadata of a foreign asset
+		ForeignAssetMetadataUpdated {
+			asset_id: T::AssetId,
+			metadata: T::AssetRegistrarMetadata,
+		},
+		/// Added an asset type alias for a foreign asset
+		AssetTypeAliasAdded {
+			asset_id: T::AssetId,
+			alias: T::ForeignAssetType,
+		},
+		/// Removed an asset type alias of a foreign asset
+		AssetTypeAliasRemoved {
+			asset_id: T::AssetId,
+			alias: T::ForeignAssetType,
+		},
 	}
 
 	/// Mapping from an asset id to asset type.
@@ -314,6 +350,15 @@ pub mod pallet {
 	pub type SupportedFeePaymentAssets<T: Config> =
 		StorageValue<_, Vec<T::ForeignAssetType>, ValueQuery>;
 
+	/// Additional asset types resolving to a registered asset id, e.g. the previous
+	/// location of an asset during a location migration.
+	/// Incoming assets with an alias asset type are treated as the aliased asset, while
+	/// AssetIdType keeps pointing to the canonical asset type.
+	#[pallet::storage]
+	#[pallet::getter(fn asset_type_alias)]
+	pub type AssetTypeAliases<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::ForeignAssetType, T::AssetId>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Register new asset with the asset manager
@@ -336,6 +381,11 @@ pub mod pallet {
 				AssetIdType::<T>::get(&asset_id).is_none(),
 				Error::<T>::AssetAlreadyExists
 			);
+			// Ensure the asset type is not used as an alias
+			ensure!(
+				!AssetTypeAliases::<T>::contains_key(&asset),
+				Error::<T>::AliasAlreadyExists
+			);
 			T::AssetRegistrar::create_foreign_asset(
 				asset_id,
 				min_amount,
@@ -358,6 +408,7 @@ pub mod pallet {
 
 		/// Change the amount of units we are charging per execution second
 		/// for a given ForeignAssetType
+		/// The asset type must be registered, or accepted by `UnregisteredFeeAssets`
 		#[pallet::call_index(1)]
 		#[pallet::weight(T::WeightInfo::set_asset_units_per_second(*num_assets_weight_hint))]
 		pub fn set_asset_units_per_second(
@@ -368,9 +419,10 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::ForeignAssetModifierOrigin::ensure_origin(origin)?;
 
-			// Ensure such an assetId does not exist
+			// Ensure such an assetId exists, unless the asset type does not need to be registered
 			ensure!(
-				AssetTypeId::<T>::get(&asset_type).is_some(),
+				AssetTypeId::<T>::get(&asset_type).is_some()
+					|| T::UnregisteredFeeAssets::contains(&asset_type),
 				Error::<T>::AssetDoesNotExist
 			);
 
@@ -421,6 +473,13 @@ pub mod pallet {
 			let previous_asset_type =
 				AssetIdType::<T>::get(&asset_id).ok_or(Error::<T>::AssetDoesNotExist)?;
 
+			// The new asset type can only be an alias of this same asset, in which case it
+			// stops being an alias
+			if let Some(aliased_asset_id) = AssetTypeAliases::<T>::get(&new_asset_type) {
+				ensure!(aliased_asset_id == asset_id, Error::<T>::AliasAlreadyExists);
+				AssetTypeAliases::<T>::remove(&new_asset_type);
+			}
+
 			// Insert new asset type info
 			AssetIdType::<T>::insert(&asset_id, &new_asset_type);
 			AssetTypeId::<T>::insert(&new_asset_type, &asset_id);
@@ -683,6 +742,77 @@ pub mod pallet {
 			Self::deposit_event(Event::LocalAssetDestroyed { asset_id });
 			Ok(())
 		}
+
+		/// Update the metadata (name, symbol, decimals, is_frozen) of a registered foreign asset
+		/// The asset id, and therefore its erc20 precompile address, is kept
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::update_foreign_asset_metadata())]
+		pub fn update_foreign_asset_metadata(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			metadata: T::AssetRegistrarMetadata,
+		) -> DispatchResult {
+			T::ForeignAssetModifierOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				AssetIdType::<T>::contains_key(&asset_id),
+				Error::<T>::AssetDoesNotExist
+			);
+
+			T::AssetRegistrar::update_foreign_asset_metadata(asset_id, metadata.clone())
+				.map_err(|_| Error::<T>::ErrorUpdatingMetadata)?;
+
+			Self::deposit_event(Event::ForeignAssetMetadataUpdated { asset_id, metadata });
+			Ok(())
+		}
+
+		/// Make an additional asset type resolve to a registered foreign asset
+		/// Used to keep the previous location of an asset working while it is migrated to a
+		/// new location with change_existing_asset_type
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::add_asset_type_alias())]
+		pub fn add_asset_type_alias(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			alias: T::ForeignAssetType,
+		) -> DispatchResult {
+			T::ForeignAssetModifierOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				AssetIdType::<T>::contains_key(&asset_id),
+				Error::<T>::AssetDoesNotExist
+			);
+			// The alias can't be the asset type of a registered asset
+			ensure!(
+				!AssetTypeId::<T>::contains_key(&alias),
+				Error::<T>::AssetAlreadyExists
+			);
+			ensure!(
+				!AssetTypeAliases::<T>::contains_key(&alias),
+				Error::<T>::AliasAlreadyExists
+			);
+
+			AssetTypeAliases::<T>::insert(&alias, &asset_id);
+
+			Self::deposit_event(Event::AssetTypeAliasAdded { asset_id, alias });
+			Ok(())
+		}
+
+		/// Remove an asset type alias, e.g. once a location migration is over
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::remove_asset_type_alias())]
+		pub fn remove_asset_type_alias(
+			origin: OriginFor<T>,
+			alias: T::ForeignAssetType,
+		) -> DispatchResult {
+			T::ForeignAssetModifierOrigin::ensure_origin(origin)?;
+
+			let asset_id =
+				AssetTypeAliases::<T>::take(&alias).ok_or(Error::<T>::AliasDoesNotExist)?;
+
+			Self::deposit_event(Event::AssetTypeAliasRemoved { asset_id, alias });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -690,5 +820,18 @@ pub mod pallet {
 		pub fn account_id() -> T::AccountId {
 			PALLET_ID.into_account_truncating()
 		}
+
+		/// The asset id an alias resolves to, if the aliased asset is still registered
+		fn resolve_alias(alias: &T::ForeignAssetType) -> Option<T::AssetId> {
+			AssetTypeAliases::<T>::get(alias)
+				.filter(|asset_id| AssetIdType::<T>::contains_key(asset_id))
+		}
+
+		/// The canonical asset type of an alias, or the asset type itself if it is not an alias
+		fn unaliased_asset_type(asset_type: T::ForeignAssetType) -> T::ForeignAssetType {
+			Self::resolve_alias(&asset_type)
+				.and_then(|asset_id| AssetIdType::<T>::get(asset_id))
+				.unwrap_or(asset_type)
+		}
 	}
 }
@@ -130,6 +130,26 @@ impl From<MockAssetType> for AssetId {
 	}
 }
 
+// Mimics erc20 tokens bridged through pallet-erc20-xcm-bridge, identified by a
+// pallet instance prefix followed by the contract address
+pub const MOCK_ERC20_PALLET_INSTANCE: u8 = 48u8;
+
+pub struct MockUnregisteredFeeAssets;
+impl frame_support::traits::Contains<MockAssetType> for MockUnregisteredFeeAssets {
+	fn contains(asset_type: &MockAssetType) -> bool {
+		matches!(
+			asset_type,
+			MockAssetType::Xcm(MultiLocation {
+				parents: 0,
+				interior: X2(
+					PalletInstance(MOCK_ERC20_PALLET_INSTANCE),
+					AccountKey20 { .. }
+				),
+			})
+		)
+	}
+}
+
 impl From<MultiLocation> for MockAssetType {
 	fn from(location: MultiLocation) -> Self {
 		Self::Xcm(location)
@@ -167,6 +187,10 @@ impl AssetRegistrar<Test> for MockAssetPalletRegistrar {
 		Ok(())
 	}
 
+	fn update_foreign_asset_metadata(_asset: u128, _metadata: u32) -> Result<(), DispatchError> {
+		Ok(())
+	}
+
 	fn destroy_foreign_asset(_asset: u128) -> Result<(), DispatchError> {
 		Ok(())
 	}
@@ -202,6 +226,7 @@ impl Config for Test {
 	type AssetRegistrar = MockAssetPalletRegistrar;
 	type ForeignAssetModifierOrigin = EnsureRoot<u64>;
 	type LocalAssetModifierOrigin = EnsureRoot<u64>;
+	type UnregisteredFeeAssets = MockUnregisteredFeeAssets;
 	type LocalAssetIdCreator = MockLocalAssetIdCreator;
 	type Currency = Balances;
 	type LocalAssetDeposit = LocalAssetDeposit;
@@ -19,6 +19,11 @@ use crate::*;
 use mock::*;
 
 use frame_support::{assert_noop, assert_ok};
+use xcm::latest::{
+	Junction::{AccountKey20, PalletInstance},
+	Junctions::X2,
+	MultiLocation,
+};
 
 #[test]
 fn registering_foreign_works() {
@@ -406,6 +411,50 @@ fn test_asset_id_non_existent_error() {
 	});
 }
 
+#[test]
+fn test_unregistered_fee_asset_can_set_units_per_second() {
+	ExtBuilder::default().build().execute_with(|| {
+		let erc20_asset_type = MockAssetType::Xcm(MultiLocation {
+			parents: 0,
+			interior: X2(
+				PalletInstance(MOCK_ERC20_PALLET_INSTANCE),
+				AccountKey20 {
+					network: None,
+					key: [1u8; 20],
+				},
+			),
+		});
+
+		assert_ok!(AssetManager::set_asset_units_per_second(
+			RuntimeOrigin::root(),
+			erc20_asset_type.clone(),
+			200u128.into(),
+			0
+		));
+
+		assert_eq!(
+			AssetManager::asset_type_units_per_second(&erc20_asset_type).unwrap(),
+			200
+		);
+		assert!(AssetManager::supported_fee_payment_assets().contains(&erc20_asset_type));
+		// No asset is registered for it
+		assert!(AssetManager::asset_type_id(&erc20_asset_type).is_none());
+
+		expect_events(vec![crate::Event::UnitsPerSecondChanged {
+			asset_type: erc20_asset_type.clone(),
+			units_per_second: 200,
+		}]);
+
+		assert_ok!(AssetManager::remove_supported_asset(
+			RuntimeOrigin::root(),
+			erc20_asset_type.clone(),
+			1,
+		));
+		assert!(AssetManager::asset_type_units_per_second(&erc20_asset_type).is_none());
+		assert!(AssetManager::supported_fee_payment_assets().is_empty());
+	});
+}
+
 #[test]
 fn test_root_can_remove_asset_association() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -568,3 +617,236 @@ fn test_destroy_local_asset_works() {
 			]);
 		});
 }
+
+#[test]
+fn test_root_can_update_foreign_asset_metadata() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AssetManager::register_foreign_asset(
+			RuntimeOrigin::root(),
+			MockAssetType::MockAsset(1),
+			0u32.into(),
+			1u32.into(),
+			true
+		));
+
+		assert_noop!(
+			AssetManager::update_foreign_asset_metadata(RuntimeOrigin::signed(1), 1, 1u32),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			AssetManager::update_foreign_asset_metadata(RuntimeOrigin::root(), 2, 1u32),
+			Error::<Test>::AssetDoesNotExist
+		);
+
+		assert_ok!(AssetManager::update_foreign_asset_metadata(
+			RuntimeOrigin::root(),
+			1,
+			1u32
+		));
+
+		// The asset keeps its id and type
+		assert_eq!(
+			AssetManager::asset_id_type(1).unwrap(),
+			MockAssetType::MockAsset(1)
+		);
+
+		expect_events(vec![
+			crate::Event::ForeignAssetRegistered {
+				asset_id: 1,
+				asset: MockAssetType::MockAsset(1),
+				metadata: 0,
+			},
+			crate::Event::ForeignAssetMetadataUpdated {
+				asset_id: 1,
+				metadata: 1,
+			},
+		])
+	});
+}
+
+#[test]
+fn test_asset_type_alias_resolves_to_migrated_asset() {
+	use xcm_primitives::{AssetTypeGetter, UnitsToWeightRatio};
+
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AssetManager::register_foreign_asset(
+			RuntimeOrigin::root(),
+			MockAssetType::MockAsset(1),
+			0u32.into(),
+			1u32.into(),
+			true
+		));
+		assert_ok!(AssetManager::set_asset_units_per_second(
+			RuntimeOrigin::root(),
+			MockAssetType::MockAsset(1),
+			200u128.into(),
+			0
+		));
+
+		// Migrate the asset to a new type, keeping the old one as an alias
+		assert_ok!(AssetManager::change_existing_asset_type(
+			RuntimeOrigin::root(),
+			1,
+			MockAssetType::MockAsset(2),
+			1
+		));
+		assert_ok!(AssetManager::add_asset_type_alias(
+			RuntimeOrigin::root(),
+			1,
+			MockAssetType::MockAsset(1)
+		));
+
+		// Both types resolve to the same asset and fee rate
+		assert_eq!(
+			<AssetManager as AssetTypeGetter<_, _>>::get_asset_id(MockAssetType::MockAsset(1)),
+			Some(1)
+		);
+		assert_eq!(
+			<AssetManager as AssetTypeGetter<_, _>>::get_asset_id(MockAssetType::MockAsset(2)),
+			Some(1)
+		);
+		assert!(
+			<AssetManager as UnitsToWeightRatio<_>>::payment_is_supported(
+				MockAssetType::MockAsset(1)
+			)
+		);
+		assert_eq!(
+			<AssetManager as UnitsToWeightRatio<_>>::get_units_per_second(
+				MockAssetType::MockAsset(1)
+			),
+			Some(200)
+		);
+
+		// The canonical type is kept
+		assert_eq!(
+			<AssetManager as AssetTypeGetter<_, _>>::get_asset_type(1),
+			Some(MockAssetType::MockAsset(2))
+		);
+
+		// The alias can't be registered as a new asset
+		assert_noop!(
+			AssetManager::register_foreign_asset(
+				RuntimeOrigin::root(),
+				MockAssetType::MockAsset(1),
+				0u32.into(),
+				1u32.into(),
+				true
+			),
+			Error::<Test>::AliasAlreadyExists
+		);
+
+		assert_ok!(AssetManager::remove_asset_type_alias(
+			RuntimeOrigin::root(),
+			MockAssetType::MockAsset(1)
+		));
+		assert_eq!(
+			<AssetManager as AssetTypeGetter<_, _>>::get_asset_id(MockAssetType::MockAsset(1)),
+			None
+		);
+
+		expect_events(vec![
+			crate::Event::ForeignAssetRegistered {
+				asset_id: 1,
+				asset: MockAssetType::MockAsset(1),
+				metadata: 0,
+			},
+			crate::Event::UnitsPerSecondChanged {
+				asset_type: MockAssetType::MockAsset(1),
+				units_per_second: 200,
+			},
+			crate::Event::ForeignAssetTypeChanged {
+				asset_id: 1,
+				new_asset_type: MockAssetType::MockAsset(2),
+			},
+			crate::Event::AssetTypeAliasAdded {
+				asset_id: 1,
+				alias: MockAssetType::MockAsset(1),
+			},
+			crate::Event::AssetTypeAliasRemoved {
+				asset_id: 1,
+				alias: MockAssetType::MockAsset(1),
+			},
+		])
+	});
+}
+
+#[test]
+fn test_asset_type_alias_errors() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AssetManager::register_foreign_asset(
+			RuntimeOrigin::root(),
+			MockAssetType::MockAsset(1),
+			0u32.into(),
+			1u32.into(),
+			true
+		));
+		assert_ok!(AssetManager::register_foreign_asset(
+			RuntimeOrigin::root(),
+			MockAssetType::MockAsset(2),
+			0u32.into(),
+			1u32.into(),
+			true
+		));
+
+		// The asset must exist
+		assert_noop!(
+			AssetManager::add_asset_type_alias(
+				RuntimeOrigin::root(),
+				3,
+				MockAssetType::MockAsset(4)
+			),
+			Error::<Test>::AssetDoesNotExist
+		);
+		// The alias can't be a registered asset type
+		assert_noop!(
+			AssetManager::add_asset_type_alias(
+				RuntimeOrigin::root(),
+				1,
+				MockAssetType::MockAsset(2)
+			),
+			Error::<Test>::AssetAlreadyExists
+		);
+
+		assert_ok!(AssetManager::add_asset_type_alias(
+			RuntimeOrigin::root(),
+			1,
+			MockAssetType::MockAsset(4)
+		));
+		assert_noop!(
+			AssetManager::add_asset_type_alias(
+				RuntimeOrigin::root(),
+				2,
+				MockAssetType::MockAsset(4)
+			),
+			Error::<Test>::AliasAlreadyExists
+		);
+
+		// An asset can't be moved to a type aliased to another asset
+		assert_noop!(
+			AssetManager::change_existing_asset_type(
+				RuntimeOrigin::root(),
+				2,
+				MockAssetType::MockAsset(4),
+				1
+			),
+			Error::<Test>::AliasAlreadyExists
+		);
+
+		// But it can be moved to its own alias, which stops being an alias
+		assert_ok!(AssetManager::change_existing_asset_type(
+			RuntimeOrigin::root(),
+			1,
+			MockAssetType::MockAsset(4),
+			1
+		));
+		assert!(AssetManager::asset_type_alias(MockAssetType::MockAsset(4)).is_none());
+
+		assert_noop!(
+			AssetManager::remove_asset_type_alias(
+				RuntimeOrigin::root(),
+				MockAssetType::MockAsset(4)
+			),
+			Error::<Test>::AliasDoesNotExist
+		);
+	});
+}
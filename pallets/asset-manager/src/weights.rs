@@ -58,6 +58,9 @@ pub trait WeightInfo {
 	fn remove_supported_asset(x: u32, ) -> Weight;
 	fn register_local_asset() -> Weight;
 	fn remove_existing_asset_type(x: u32, ) -> Weight;
+	fn update_foreign_asset_metadata() -> Weight;
+	fn add_asset_type_alias() -> Weight;
+	fn remove_asset_type_alias() -> Weight;
 }
 
 /// Weights for pallet_asset_manager using the Substrate node and recommended hardware.
@@ -177,6 +180,47 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 			.saturating_add(Weight::from_parts(0, 40).saturating_mul(x.into()))
 	}
+	/// Storage: AssetManager AssetIdType (r:1 w:0)
+	/// Proof Skipped: AssetManager AssetIdType (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(174), added: 2649, mode: MaxEncodedLen)
+	/// Storage: Assets Metadata (r:1 w:1)
+	/// Proof: Assets Metadata (max_values: None, max_size: Some(152), added: 2627, mode: MaxEncodedLen)
+	fn update_foreign_asset_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `520`
+		//  Estimated: `3979`
+		// Minimum execution time: 29_314_000 picoseconds.
+		Weight::from_parts(29_314_000, 3979)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: AssetManager AssetIdType (r:1 w:0)
+	/// Proof Skipped: AssetManager AssetIdType (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetTypeId (r:1 w:0)
+	/// Proof Skipped: AssetManager AssetTypeId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetTypeAliases (r:1 w:1)
+	/// Proof Skipped: AssetManager AssetTypeAliases (max_values: None, max_size: None, mode: Measured)
+	fn add_asset_type_alias() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `335`
+		//  Estimated: `3800`
+		// Minimum execution time: 18_102_000 picoseconds.
+		Weight::from_parts(18_102_000, 3800)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: AssetManager AssetTypeAliases (r:1 w:1)
+	/// Proof Skipped: AssetManager AssetTypeAliases (max_values: None, max_size: None, mode: Measured)
+	fn remove_asset_type_alias() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `196`
+		//  Estimated: `3661`
+		// Minimum execution time: 12_871_000 picoseconds.
+		Weight::from_parts(12_871_000, 3661)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -295,4 +339,45 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 			.saturating_add(Weight::from_parts(0, 40).saturating_mul(x.into()))
 	}
+	/// Storage: AssetManager AssetIdType (r:1 w:0)
+	/// Proof Skipped: AssetManager AssetIdType (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(174), added: 2649, mode: MaxEncodedLen)
+	/// Storage: Assets Metadata (r:1 w:1)
+	/// Proof: Assets Metadata (max_values: None, max_size: Some(152), added: 2627, mode: MaxEncodedLen)
+	fn update_foreign_asset_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `520`
+		//  Estimated: `3979`
+		// Minimum execution time: 29_314_000 picoseconds.
+		Weight::from_parts(29_314_000, 3979)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: AssetManager AssetIdType (r:1 w:0)
+	/// Proof Skipped: AssetManager AssetIdType (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetTypeId (r:1 w:0)
+	/// Proof Skipped: AssetManager AssetTypeId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetTypeAliases (r:1 w:1)
+	/// Proof Skipped: AssetManager AssetTypeAliases (max_values: None, max_size: None, mode: Measured)
+	fn add_asset_type_alias() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `335`
+		//  Estimated: `3800`
+		// Minimum execution time: 18_102_000 picoseconds.
+		Weight::from_parts(18_102_000, 3800)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: AssetManager AssetTypeAliases (r:1 w:1)
+	/// Proof Skipped: AssetManager AssetTypeAliases (max_values: None, max_size: None, mode: Measured)
+	fn remove_asset_type_alias() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `196`
+		//  Estimated: `3661`
+		// Minimum execution time: 12_871_000 picoseconds.
+		Weight::from_parts(12_871_000, 3661)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
@@ -0,0 +1,51 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::{Call, Config, Pallet, TransferLog, TransferLogsEnabled};
+use ethereum_types::{H160, U256};
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_support::traits::EnsureOrigin;
+
+benchmarks! {
+	set_transfer_logs_enabled {
+		let origin = T::ControllerOrigin::try_successful_origin()
+			.map_err(|_| "origin not available")?;
+	}: _<T::RuntimeOrigin>(origin, true)
+	verify {
+		assert!(TransferLogsEnabled::<T>::get());
+	}
+
+	observe_events {
+		let x in 0 .. 1000;
+		// Events are not deposited in the genesis block
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+		TransferLogsEnabled::<T>::put(true);
+		for i in 0..x {
+			Pallet::<T>::note_transfer(TransferLog {
+				token: H160::repeat_byte(0x01),
+				from: H160::zero(),
+				to: H160::from_low_u64_be(i as u64),
+				value: U256::from(i),
+			});
+		}
+	}: {
+		Pallet::<T>::observed_transfers();
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
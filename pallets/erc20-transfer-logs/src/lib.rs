@@ -0,0 +1,516 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Erc20 Transfer Logs Pallet
+//!
+//! Synthesizes the ERC-20 `Transfer` logs of the balance and asset movements done outside of the
+//! EVM, so EVM indexers and wallets can follow every balance change from the Ethereum receipts.
+//!
+//! - The runtime classifies its events with `Config::EventClassifier`: substrate movements such
+//!   as balance transfers, staking rewards or asset transfers are turned into transfer logs.
+//!   A movement is ignored if the same phase already reports it, either with a `Transfer` log
+//!   emitted by the EVM (such as the logs of the ERC-20 precompiles) or with a noted transfer.
+//!   EVM native value transfers emit no log, so their movements are synthesized as well.
+//! - The deposits and withdrawals of xcm messages are noted by wrapping the asset transactor of
+//!   the xcm executor, as they are minted or burned without a transfer event.
+//! - In `on_idle`, the transfers of the block are emitted by synthetic Ethereum transactions sent
+//!   from `Config::LogsSource` to the `Config::LogsEmitter` precompile, which emits a `Transfer`
+//!   log per movement from the address of the ERC-20 token. Mints and burns use the zero address.
+//!
+//! Synthesizing the logs is best effort: transfers are dropped if the block doesn't have enough
+//! weight left. It is disabled by default and can be enabled by `Config::ControllerOrigin`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+mod benchmarks;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::weights::WeightInfo;
+	use ethereum::TransactionAction;
+	use ethereum_types::{H160, H256, U256};
+	use fp_ethereum::ValidatedTransaction;
+	use frame_support::pallet_prelude::*;
+	use frame_system::{pallet_prelude::*, Phase};
+	use pallet_evm::GasWeightMapping;
+	use sp_runtime::traits::{Convert, Saturating};
+	use sp_std::vec::Vec;
+	use xcm::latest::prelude::*;
+	use xcm_executor::{
+		traits::{ConvertLocation, TransactAsset},
+		Assets,
+	};
+	use xcm_primitives::{EthereumXcmTransaction, EthereumXcmTransactionV2, XcmToEthereum};
+
+	/// Selector of `emitTransfers((address,address,address,uint256)[])`, the function of the
+	/// emitter precompile.
+	pub const EMIT_TRANSFERS_SELECTOR: [u8; 4] = [0xeb, 0x2a, 0xc8, 0x98];
+	/// Maximum number of transfers emitted by a single transaction, so its input (selector, offset,
+	/// length and 4 words per transfer) fits in an ethereum-xcm transaction.
+	pub const MAX_TRANSFERS_PER_TRANSACTION: u32 =
+		(xcm_primitives::MAX_ETHEREUM_XCM_INPUT_SIZE - 68) / 128;
+	/// Gas of a transaction emitting transfer logs, excluding the cost of each transfer.
+	pub const TRANSACTION_BASE_GAS: u64 = 30_000;
+	/// Gas of each transfer log: calldata, log and parsing costs.
+	pub const TRANSFER_GAS: u64 = 5_000;
+	/// Topic of the ERC-20 `Transfer(address,address,uint256)` log.
+	pub const TRANSFER_TOPIC: H256 = H256([
+		0xdd, 0xf2, 0x52, 0xad, 0x1b, 0xe2, 0xc8, 0x9b, 0x69, 0xc2, 0xb0, 0x68, 0xfc, 0x37, 0x8d,
+		0xaa, 0x95, 0x2b, 0xa7, 0xf1, 0x63, 0xc4, 0xa1, 0x16, 0x28, 0xf5, 0x5a, 0x4d, 0xf5, 0x23,
+		0xb3, 0xef,
+	]);
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_evm::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>
+			+ TryInto<Event<Self>>;
+		/// Classify the runtime events as substrate transfers or transfers logged by the EVM
+		type EventClassifier: ClassifyEvent<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The wrapped asset transactor, whose deposits and withdrawals are noted
+		type AssetTransactor: TransactAsset;
+		/// Convert a location to the address of an account
+		type AccountIdConverter: ConvertLocation<H160>;
+		/// Address of the ERC-20 token representing an asset, if any
+		type Erc20OfAsset: Convert<MultiLocation, Option<H160>>;
+		/// Handler for applying the synthetic transactions
+		type ValidatedTransaction: ValidatedTransaction;
+		/// Sender of the synthetic transactions. It must be an address without known private key,
+		/// as it is the only caller allowed by the emitter precompile.
+		type LogsSource: Get<H160>;
+		/// Address of the precompile emitting the transfer logs
+		type LogsEmitter: Get<H160>;
+		/// Maximum number of transfers emitted by a single transaction
+		#[pallet::constant]
+		type MaxTransfersPerTransaction: Get<u32>;
+		/// Origin allowed to enable or disable the transfer logs
+		type ControllerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// A movement of an ERC-20 token, reported as a `Transfer` log
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct TransferLog {
+		/// Address of the ERC-20 token
+		pub token: H160,
+		/// Sender, or the zero address for a mint
+		pub from: H160,
+		/// Recipient, or the zero address for a burn
+		pub to: H160,
+		/// Amount transferred
+		pub value: U256,
+	}
+
+	impl TransferLog {
+		/// The transfer reported by an ERC-20 `Transfer` log, if it is one
+		pub fn from_evm_log(log: &ethereum::Log) -> Option<Self> {
+			match &log.topics[..] {
+				[topic, from, to] if *topic == TRANSFER_TOPIC && log.data.len() == 32 => {
+					Some(TransferLog {
+						token: log.address,
+						from: H160::from(*from),
+						to: H160::from(*to),
+						value: U256::from_big_endian(&log.data),
+					})
+				}
+				_ => None,
+			}
+		}
+	}
+
+	/// What a runtime event means for the transfer logs
+	#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+	pub enum ObservedEvent {
+		/// A movement done by substrate
+		Transfer(TransferLog),
+		/// A movement already reported by a `Transfer` log emitted by the EVM
+		EvmTransfer(TransferLog),
+		/// Any other event
+		Other,
+	}
+
+	/// Classify the runtime events
+	pub trait ClassifyEvent<RuntimeEvent> {
+		fn classify(event: &RuntimeEvent) -> ObservedEvent;
+	}
+
+	impl<RuntimeEvent> ClassifyEvent<RuntimeEvent> for () {
+		fn classify(_event: &RuntimeEvent) -> ObservedEvent {
+			ObservedEvent::Other
+		}
+	}
+
+	/// Whether the transfer logs are synthesized
+	#[pallet::storage]
+	#[pallet::getter(fn transfer_logs_enabled)]
+	pub type TransferLogsEnabled<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// Global nonce of the synthetic transactions, making their hashes unique
+	#[pallet::storage]
+	#[pallet::getter(fn nonce)]
+	pub type Nonce<T: Config> = StorageValue<_, U256, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The transfer logs have been enabled or disabled
+		TransferLogsEnabledSet { enabled: bool },
+		/// A movement without transfer event has been noted, e.g. an xcm deposit or withdrawal
+		TransferNoted {
+			token: H160,
+			from: H160,
+			to: H160,
+			value: U256,
+		},
+		/// A synthetic transaction emitted the logs of `count` transfers
+		TransferLogsEmitted { transaction_hash: H256, count: u32 },
+		/// The logs of `count` transfers could not be emitted
+		TransferLogsDropped { count: u32 },
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut used_weight = T::DbWeight::get().reads(1);
+			if !TransferLogsEnabled::<T>::get() {
+				return used_weight;
+			}
+
+			let observe_weight =
+				T::WeightInfo::observe_events(frame_system::Pallet::<T>::event_count());
+			if used_weight
+				.saturating_add(observe_weight)
+				.any_gt(remaining_weight)
+			{
+				return used_weight;
+			}
+			used_weight.saturating_accrue(observe_weight);
+
+			let transfers = Self::observed_transfers();
+			let max_transfers = T::MaxTransfersPerTransaction::get() as usize;
+			for (index, chunk) in transfers.chunks(max_transfers).enumerate() {
+				let gas_limit = Self::gas_limit(chunk.len());
+				let weight_limit =
+					<T as pallet_evm::Config>::GasWeightMapping::gas_to_weight(gas_limit, true)
+						.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+				if used_weight
+					.saturating_add(weight_limit)
+					.any_gt(remaining_weight)
+				{
+					let dropped = transfers.len().saturating_sub(index * max_transfers);
+					Self::deposit_event(Event::TransferLogsDropped {
+						count: dropped as u32,
+					});
+					break;
+				}
+				used_weight.saturating_accrue(Self::emit_transfer_logs(chunk, gas_limit));
+			}
+
+			used_weight
+		}
+
+		fn integrity_test() {
+			assert!(
+				T::MaxTransfersPerTransaction::get() <= MAX_TRANSFERS_PER_TRANSACTION,
+				"MaxTransfersPerTransaction is too large for the input of a transaction"
+			);
+			assert!(T::MaxTransfersPerTransaction::get() > 0);
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Enable or disable the synthesis of the transfer logs
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_transfer_logs_enabled())]
+		pub fn set_transfer_logs_enabled(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+			T::ControllerOrigin::ensure_origin(origin)?;
+
+			TransferLogsEnabled::<T>::put(enabled);
+
+			Self::deposit_event(Event::TransferLogsEnabledSet { enabled });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Note a movement that doesn't emit a transfer event, so its log is synthesized at the
+		/// end of the block.
+		pub fn note_transfer(transfer: TransferLog) {
+			if TransferLogsEnabled::<T>::get() {
+				let TransferLog {
+					token,
+					from,
+					to,
+					value,
+				} = transfer;
+				Self::deposit_event(Event::TransferNoted {
+					token,
+					from,
+					to,
+					value,
+				});
+			}
+		}
+
+		/// Transfers observed in the events of the block, in order. A substrate transfer is
+		/// ignored if the same phase reports it with an EVM `Transfer` log or a noted transfer.
+		pub(crate) fn observed_transfers() -> Vec<TransferLog> {
+			let mut transfers = Vec::new();
+			// Transfers of the current phase, with whether they were noted
+			let mut phase_transfers: Vec<(TransferLog, bool)> = Vec::new();
+			// Transfers reported in the current phase by the EVM or by being noted
+			let mut reported: Vec<TransferLog> = Vec::new();
+			let mut current_phase = None;
+
+			let flush = |transfers: &mut Vec<TransferLog>,
+			             phase_transfers: &mut Vec<(TransferLog, bool)>,
+			             reported: &mut Vec<TransferLog>| {
+				for (transfer, noted) in phase_transfers.drain(..) {
+					// Each report only matches a single substrate transfer
+					if !noted {
+						if let Some(index) = reported.iter().position(|r| *r == transfer) {
+							reported.swap_remove(index);
+							continue;
+						}
+					}
+					transfers.push(transfer);
+				}
+				reported.clear();
+			};
+
+			for record in frame_system::Pallet::<T>::read_events_no_consensus() {
+				let phase = Some(Self::phase_key(&record.phase));
+				if phase != current_phase {
+					flush(&mut transfers, &mut phase_transfers, &mut reported);
+					current_phase = phase;
+				}
+
+				match T::EventClassifier::classify(&record.event) {
+					ObservedEvent::Transfer(transfer) => phase_transfers.push((transfer, false)),
+					ObservedEvent::EvmTransfer(transfer) => reported.push(transfer),
+					ObservedEvent::Other => {
+						let event = <T as Config>::RuntimeEvent::from(record.event);
+						if let Ok(Event::TransferNoted {
+							token,
+							from,
+							to,
+							value,
+						}) = event.try_into()
+						{
+							let transfer = TransferLog {
+								token,
+								from,
+								to,
+								value,
+							};
+							phase_transfers.push((transfer, true));
+							reported.push(transfer);
+						}
+					}
+				}
+			}
+			flush(&mut transfers, &mut phase_transfers, &mut reported);
+
+			transfers
+		}
+
+		/// Apply a synthetic transaction emitting the logs of the transfers, and return its weight.
+		fn emit_transfer_logs(transfers: &[TransferLog], gas_limit: u64) -> Weight {
+			let count = transfers.len() as u32;
+			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+			let transaction = BoundedVec::try_from(Self::emit_transfers_input(transfers))
+				.ok()
+				.and_then(|input| {
+					EthereumXcmTransaction::V2(EthereumXcmTransactionV2 {
+						gas_limit: gas_limit.into(),
+						action: TransactionAction::Call(T::LogsEmitter::get()),
+						value: U256::zero(),
+						input,
+						access_list: None,
+					})
+					.into_transaction_v2(Nonce::<T>::get(), T::ChainId::get())
+				});
+			let Some(transaction) = transaction else {
+				Self::deposit_event(Event::TransferLogsDropped { count });
+				return weight;
+			};
+
+			Nonce::<T>::mutate(|nonce| *nonce = nonce.saturating_add(U256::one()));
+			let transaction_hash = transaction.hash();
+
+			let (post_info, succeeded) =
+				match T::ValidatedTransaction::apply(T::LogsSource::get(), transaction) {
+					Ok(post_info) => (post_info, true),
+					Err(e) => (e.post_info, false),
+				};
+			weight.saturating_accrue(post_info.actual_weight.unwrap_or_else(|| {
+				<T as pallet_evm::Config>::GasWeightMapping::gas_to_weight(gas_limit, true)
+			}));
+
+			if succeeded {
+				Self::deposit_event(Event::TransferLogsEmitted {
+					transaction_hash,
+					count,
+				});
+			} else {
+				Self::deposit_event(Event::TransferLogsDropped { count });
+			}
+			weight
+		}
+
+		/// Solidity encoded call to `emitTransfers((address,address,address,uint256)[])`
+		pub(crate) fn emit_transfers_input(transfers: &[TransferLog]) -> Vec<u8> {
+			let mut input = Vec::with_capacity(68 + 128 * transfers.len());
+			input.extend_from_slice(&EMIT_TRANSFERS_SELECTOR);
+			// offset of the array
+			input.extend_from_slice(H256::from_low_u64_be(32).as_bytes());
+			// length of the array
+			input.extend_from_slice(H256::from_low_u64_be(transfers.len() as u64).as_bytes());
+			for transfer in transfers {
+				input.extend_from_slice(H256::from(transfer.token).as_bytes());
+				input.extend_from_slice(H256::from(transfer.from).as_bytes());
+				input.extend_from_slice(H256::from(transfer.to).as_bytes());
+				input.extend_from_slice(H256::from_uint(&transfer.value).as_bytes());
+			}
+			input
+		}
+
+		pub(crate) fn gas_limit(transfers: usize) -> u64 {
+			TRANSACTION_BASE_GAS.saturating_add(TRANSFER_GAS.saturating_mul(transfers as u64))
+		}
+
+		// Phase is only comparable in std
+		fn phase_key(phase: &Phase) -> (u8, u32) {
+			match phase {
+				Phase::Initialization => (0, 0),
+				Phase::ApplyExtrinsic(index) => (1, *index),
+				Phase::Finalization => (2, 0),
+			}
+		}
+
+		/// Note the movement of a fungible asset represented by an ERC-20 token
+		fn note_asset_transfer(
+			what: &MultiAsset,
+			from: Option<&MultiLocation>,
+			to: Option<&MultiLocation>,
+		) {
+			let MultiAsset {
+				id: Concrete(asset),
+				fun: Fungible(amount),
+			} = what
+			else {
+				return;
+			};
+			let Some(token) = T::Erc20OfAsset::convert(*asset) else {
+				return;
+			};
+			let account = |location: Option<&MultiLocation>| match location {
+				Some(location) => T::AccountIdConverter::convert_location(location),
+				None => Some(H160::zero()),
+			};
+			if let (Some(from), Some(to)) = (account(from), account(to)) {
+				Self::note_transfer(TransferLog {
+					token,
+					from,
+					to,
+					value: (*amount).into(),
+				});
+			}
+		}
+	}
+
+	// Deposits and withdrawals are noted as mints and burns. Internal transfers are not noted, as
+	// the wrapped transactors emit transfer events for them.
+	impl<T: Config> TransactAsset for Pallet<T> {
+		fn can_check_in(
+			origin: &MultiLocation,
+			what: &MultiAsset,
+			context: &XcmContext,
+		) -> XcmResult {
+			T::AssetTransactor::can_check_in(origin, what, context)
+		}
+
+		fn check_in(origin: &MultiLocation, what: &MultiAsset, context: &XcmContext) {
+			T::AssetTransactor::check_in(origin, what, context)
+		}
+
+		fn can_check_out(
+			dest: &MultiLocation,
+			what: &MultiAsset,
+			context: &XcmContext,
+		) -> XcmResult {
+			T::AssetTransactor::can_check_out(dest, what, context)
+		}
+
+		fn check_out(dest: &MultiLocation, what: &MultiAsset, context: &XcmContext) {
+			T::AssetTransactor::check_out(dest, what, context)
+		}
+
+		fn deposit_asset(
+			what: &MultiAsset,
+			who: &MultiLocation,
+			context: &XcmContext,
+		) -> XcmResult {
+			T::AssetTransactor::deposit_asset(what, who, context)?;
+			Self::note_asset_transfer(what, None, Some(who));
+			Ok(())
+		}
+
+		fn withdraw_asset(
+			what: &MultiAsset,
+			who: &MultiLocation,
+			maybe_context: Option<&XcmContext>,
+		) -> Result<Assets, XcmError> {
+			let assets = T::AssetTransactor::withdraw_asset(what, who, maybe_context)?;
+			Self::note_asset_transfer(what, Some(who), None);
+			Ok(assets)
+		}
+
+		fn internal_transfer_asset(
+			asset: &MultiAsset,
+			from: &MultiLocation,
+			to: &MultiLocation,
+			context: &XcmContext,
+		) -> Result<Assets, XcmError> {
+			T::AssetTransactor::internal_transfer_asset(asset, from, to, context)
+		}
+
+		fn transfer_asset(
+			asset: &MultiAsset,
+			from: &MultiLocation,
+			to: &MultiLocation,
+			context: &XcmContext,
+		) -> Result<Assets, XcmError> {
+			T::AssetTransactor::transfer_asset(asset, from, to, context)
+		}
+	}
+}
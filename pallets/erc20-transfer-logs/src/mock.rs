@@ -0,0 +1,298 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Test utilities
+use crate as erc20_transfer_logs;
+use crate::{ClassifyEvent, ObservedEvent, TransferLog};
+
+use ethereum::TransactionV2 as Transaction;
+use fp_ethereum::ValidatedTransaction;
+use frame_support::dispatch::{DispatchResultWithPostInfo, Pays, PostDispatchInfo};
+use frame_support::traits::Everything;
+use frame_support::{construct_runtime, pallet_prelude::*, parameter_types};
+use frame_system::EnsureRoot;
+use pallet_evm::{AddressMapping, EnsureAddressTruncated, SubstrateBlockHashMapping};
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::{BlakeTwo256, Convert, IdentityLookup};
+use sp_runtime::AccountId32;
+use sp_runtime::BuildStorage;
+use std::cell::RefCell;
+use xcm::latest::prelude::*;
+use xcm_executor::{
+	traits::{ConvertLocation, TransactAsset},
+	Assets,
+};
+
+pub type Balance = u128;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Timestamp: pallet_timestamp,
+		EVM: pallet_evm,
+		Erc20TransferLogs: erc20_transfer_logs,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u32 = 250;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type Nonce = u64;
+	type Block = Block;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId32;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u128 = 0;
+}
+impl pallet_balances::Config for Test {
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 4];
+	type MaxLocks = ();
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type RuntimeHoldReason = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
+}
+
+parameter_types! {
+	pub const MinimumPeriod: u64 = 6000 / 2;
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
+}
+
+const MAX_POV_SIZE: u64 = 5 * 1024 * 1024;
+/// Block storage limit in bytes. Set to 40 KB.
+const BLOCK_STORAGE_LIMIT: u64 = 40 * 1024;
+
+parameter_types! {
+	pub BlockGasLimit: U256 = U256::from(u64::MAX);
+	pub const WeightPerGas: Weight = Weight::from_parts(20_000, 0);
+	pub GasLimitPovSizeRatio: u64 = {
+		let block_gas_limit = BlockGasLimit::get().min(u64::MAX.into()).low_u64();
+		block_gas_limit.saturating_div(MAX_POV_SIZE)
+	};
+	pub GasLimitStorageGrowthRatio: u64 = {
+		let block_gas_limit = BlockGasLimit::get().min(u64::MAX.into()).low_u64();
+		block_gas_limit.saturating_div(BLOCK_STORAGE_LIMIT)
+	};
+}
+
+pub struct HashedAddressMapping;
+
+impl AddressMapping<AccountId32> for HashedAddressMapping {
+	fn into_account_id(address: H160) -> AccountId32 {
+		let mut data = [0u8; 32];
+		data[0..20].copy_from_slice(&address[..]);
+		AccountId32::from(Into::<[u8; 32]>::into(data))
+	}
+}
+
+pub fn account(address: H160) -> AccountId32 {
+	HashedAddressMapping::into_account_id(address)
+}
+
+fn address(account: &AccountId32) -> H160 {
+	H160::from_slice(&AsRef::<[u8; 32]>::as_ref(account)[0..20])
+}
+
+impl pallet_evm::Config for Test {
+	type FeeCalculator = ();
+	type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+	type WeightPerGas = WeightPerGas;
+	type CallOrigin = EnsureAddressTruncated;
+	type WithdrawOrigin = EnsureAddressTruncated;
+	type AddressMapping = HashedAddressMapping;
+	type Currency = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type PrecompilesType = ();
+	type PrecompilesValue = ();
+	type Runner = pallet_evm::runner::stack::Runner<Self>;
+	type ChainId = ();
+	type BlockGasLimit = BlockGasLimit;
+	type OnChargeTransaction = ();
+	type FindAuthor = ();
+	type BlockHashMapping = SubstrateBlockHashMapping<Self>;
+	type OnCreate = ();
+	type GasLimitPovSizeRatio = GasLimitPovSizeRatio;
+	type GasLimitStorageGrowthRatio = GasLimitStorageGrowthRatio;
+	type Timestamp = Timestamp;
+	type WeightInfo = pallet_evm::weights::SubstrateWeight<Test>;
+}
+
+pub const NATIVE_TOKEN: H160 = H160([0x08; 20]);
+pub const RELAY_TOKEN: H160 = H160([0xff; 20]);
+
+/// Balance transfers are reported as transfers of the native token, and EVM events as EVM
+/// executions.
+pub struct MockEventClassifier;
+impl ClassifyEvent<RuntimeEvent> for MockEventClassifier {
+	fn classify(event: &RuntimeEvent) -> ObservedEvent {
+		match event {
+			RuntimeEvent::Balances(pallet_balances::Event::Transfer { from, to, amount }) => {
+				ObservedEvent::Transfer(TransferLog {
+					token: NATIVE_TOKEN,
+					from: address(from),
+					to: address(to),
+					value: (*amount).into(),
+				})
+			}
+			RuntimeEvent::EVM(pallet_evm::Event::Log { log }) => TransferLog::from_evm_log(log)
+				.map_or(ObservedEvent::Other, ObservedEvent::EvmTransfer),
+			_ => ObservedEvent::Other,
+		}
+	}
+}
+
+pub struct MockAssetTransactor;
+impl TransactAsset for MockAssetTransactor {
+	fn deposit_asset(_what: &MultiAsset, _who: &MultiLocation, _context: &XcmContext) -> XcmResult {
+		Ok(())
+	}
+
+	fn withdraw_asset(
+		what: &MultiAsset,
+		_who: &MultiLocation,
+		_maybe_context: Option<&XcmContext>,
+	) -> Result<Assets, XcmError> {
+		Ok(what.clone().into())
+	}
+}
+
+pub struct AccountKey20ToH160;
+impl ConvertLocation<H160> for AccountKey20ToH160 {
+	fn convert_location(location: &MultiLocation) -> Option<H160> {
+		match location {
+			MultiLocation {
+				parents: 0,
+				interior: X1(AccountKey20 { key, .. }),
+			} => Some(H160(*key)),
+			_ => None,
+		}
+	}
+}
+
+/// Only the relay chain token is represented by an ERC-20 token
+pub struct MockErc20OfAsset;
+impl Convert<MultiLocation, Option<H160>> for MockErc20OfAsset {
+	fn convert(asset: MultiLocation) -> Option<H160> {
+		(asset == MultiLocation::parent()).then_some(RELAY_TOKEN)
+	}
+}
+
+thread_local! {
+	pub static APPLIED_TRANSACTIONS: RefCell<Vec<(H160, Transaction)>> = RefCell::new(Vec::new());
+}
+
+/// Transactions applied by the pallet, with their source
+pub(crate) fn applied_transactions() -> Vec<(H160, Transaction)> {
+	APPLIED_TRANSACTIONS.with(|transactions| transactions.borrow().clone())
+}
+
+pub const TRANSACTION_WEIGHT: Weight = Weight::from_parts(1_000_000, 0);
+
+pub struct MockValidatedTransaction;
+impl ValidatedTransaction for MockValidatedTransaction {
+	fn apply(source: H160, transaction: Transaction) -> DispatchResultWithPostInfo {
+		APPLIED_TRANSACTIONS
+			.with(|transactions| transactions.borrow_mut().push((source, transaction)));
+		Ok(PostDispatchInfo {
+			actual_weight: Some(TRANSACTION_WEIGHT),
+			pays_fee: Pays::No,
+		})
+	}
+}
+
+parameter_types! {
+	pub LogsSource: H160 = H160::repeat_byte(0xaa);
+	pub LogsEmitter: H160 = H160::from_low_u64_be(2076);
+	pub const MaxTransfersPerTransaction: u32 = 2;
+}
+
+impl crate::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type EventClassifier = MockEventClassifier;
+	type AssetTransactor = MockAssetTransactor;
+	type AccountIdConverter = AccountKey20ToH160;
+	type Erc20OfAsset = MockErc20OfAsset;
+	type ValidatedTransaction = MockValidatedTransaction;
+	type LogsSource = LogsSource;
+	type LogsEmitter = LogsEmitter;
+	type MaxTransfersPerTransaction = MaxTransfersPerTransaction;
+	type ControllerOrigin = EnsureRoot<AccountId32>;
+	type WeightInfo = ();
+}
+
+pub(crate) fn events() -> Vec<erc20_transfer_logs::Event<Test>> {
+	System::events()
+		.into_iter()
+		.map(|r| r.event)
+		.filter_map(|e| {
+			if let RuntimeEvent::Erc20TransferLogs(inner) = e {
+				Some(inner)
+			} else {
+				None
+			}
+		})
+		.collect::<Vec<_>>()
+}
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default()
+		.build_storage()
+		.expect("Frame system builds valid default genesis config");
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,331 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::{
+	Event, Nonce, TransferLog, TransferLogsEnabled, EMIT_TRANSFERS_SELECTOR, TRANSFER_TOPIC,
+};
+use ethereum::{Log, TransactionAction, TransactionV2};
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight};
+use sp_core::{H160, H256, U256};
+use sp_runtime::DispatchError;
+use xcm::latest::prelude::*;
+use xcm_executor::traits::TransactAsset;
+
+const ALICE: H160 = H160([0x01; 20]);
+const BOB: H160 = H160([0x02; 20]);
+
+fn balances_transfer(from: H160, to: H160, amount: u128) {
+	System::deposit_event(pallet_balances::Event::<Test>::Transfer {
+		from: account(from),
+		to: account(to),
+		amount,
+	});
+}
+
+fn native_transfer(from: H160, to: H160, amount: u128) -> TransferLog {
+	TransferLog {
+		token: NATIVE_TOKEN,
+		from,
+		to,
+		value: amount.into(),
+	}
+}
+
+fn transfer_log(transfer: TransferLog) -> Log {
+	Log {
+		address: transfer.token,
+		topics: vec![TRANSFER_TOPIC, transfer.from.into(), transfer.to.into()],
+		data: H256::from_uint(&transfer.value).as_bytes().to_vec(),
+	}
+}
+
+fn evm_transfer_log(transfer: TransferLog) {
+	System::deposit_event(pallet_evm::Event::<Test>::Log {
+		log: transfer_log(transfer),
+	});
+}
+
+fn next_extrinsic() {
+	System::note_applied_extrinsic(&Ok(().into()), Default::default());
+}
+
+fn enable() {
+	assert_ok!(Erc20TransferLogs::set_transfer_logs_enabled(
+		RuntimeOrigin::root(),
+		true
+	));
+}
+
+fn account_location(address: H160) -> MultiLocation {
+	MultiLocation::new(
+		0,
+		X1(AccountKey20 {
+			network: None,
+			key: address.0,
+		}),
+	)
+}
+
+#[test]
+fn set_transfer_logs_enabled_requires_controller_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Erc20TransferLogs::set_transfer_logs_enabled(
+				RuntimeOrigin::signed(account(ALICE)),
+				true
+			),
+			DispatchError::BadOrigin
+		);
+
+		enable();
+		assert!(TransferLogsEnabled::<Test>::get());
+		assert_eq!(
+			events(),
+			vec![Event::TransferLogsEnabledSet { enabled: true }]
+		);
+	});
+}
+
+#[test]
+fn nothing_is_emitted_when_disabled() {
+	new_test_ext().execute_with(|| {
+		balances_transfer(ALICE, BOB, 100);
+		Erc20TransferLogs::note_transfer(native_transfer(ALICE, BOB, 50));
+
+		Erc20TransferLogs::on_idle(1, Weight::MAX);
+
+		assert!(events().is_empty());
+		assert!(applied_transactions().is_empty());
+	});
+}
+
+#[test]
+fn transfers_reported_by_evm_logs_are_ignored() {
+	new_test_ext().execute_with(|| {
+		enable();
+
+		// substrate extrinsic
+		balances_transfer(ALICE, BOB, 100);
+		next_extrinsic();
+		// ethereum transaction: a transfer of the ERC-20 precompile, reported by its log
+		balances_transfer(BOB, ALICE, 10);
+		evm_transfer_log(native_transfer(BOB, ALICE, 10));
+		// and a native value transfer, which emits no log
+		balances_transfer(BOB, ALICE, 10);
+		System::deposit_event(pallet_evm::Event::<Test>::Executed {
+			address: H160::repeat_byte(0x0c),
+		});
+		next_extrinsic();
+		// substrate extrinsic, whose transfer isn't reported by the log of another phase
+		balances_transfer(ALICE, BOB, 1);
+		next_extrinsic();
+		evm_transfer_log(native_transfer(ALICE, BOB, 1));
+
+		assert_eq!(
+			Erc20TransferLogs::observed_transfers(),
+			vec![
+				native_transfer(ALICE, BOB, 100),
+				native_transfer(BOB, ALICE, 10),
+				native_transfer(ALICE, BOB, 1),
+			]
+		);
+	});
+}
+
+#[test]
+fn noted_transfers_replace_matching_substrate_transfers() {
+	new_test_ext().execute_with(|| {
+		enable();
+
+		balances_transfer(ALICE, BOB, 5);
+		Erc20TransferLogs::note_transfer(native_transfer(ALICE, BOB, 5));
+		Erc20TransferLogs::note_transfer(native_transfer(ALICE, H160::zero(), 3));
+		balances_transfer(ALICE, BOB, 5);
+		balances_transfer(BOB, ALICE, 2);
+
+		assert_eq!(
+			Erc20TransferLogs::observed_transfers(),
+			vec![
+				native_transfer(ALICE, BOB, 5),
+				native_transfer(ALICE, H160::zero(), 3),
+				native_transfer(ALICE, BOB, 5),
+				native_transfer(BOB, ALICE, 2),
+			]
+		);
+	});
+}
+
+#[test]
+fn only_erc20_transfer_logs_are_parsed() {
+	let transfer = native_transfer(ALICE, BOB, 42);
+	let log = transfer_log(transfer);
+	assert_eq!(TransferLog::from_evm_log(&log), Some(transfer));
+
+	let mut approval = log.clone();
+	approval.topics[0] = H256::repeat_byte(0x01);
+	assert_eq!(TransferLog::from_evm_log(&approval), None);
+
+	let mut erc721 = log.clone();
+	erc721.topics.push(H256::from_low_u64_be(42));
+	erc721.data = Vec::new();
+	assert_eq!(TransferLog::from_evm_log(&erc721), None);
+}
+
+#[test]
+fn on_idle_emits_transfer_logs_by_transaction() {
+	new_test_ext().execute_with(|| {
+		enable();
+		balances_transfer(ALICE, BOB, 100);
+		balances_transfer(BOB, ALICE, 10);
+		balances_transfer(ALICE, BOB, 1);
+
+		Erc20TransferLogs::on_idle(1, Weight::MAX);
+
+		// At most 2 transfers by transaction
+		let transactions = applied_transactions();
+		assert_eq!(transactions.len(), 2);
+		for (index, (source, transaction)) in transactions.iter().enumerate() {
+			assert_eq!(*source, LogsSource::get());
+			let TransactionV2::EIP1559(transaction) = transaction else {
+				panic!("expected an EIP-1559 transaction");
+			};
+			assert_eq!(transaction.nonce, U256::from(index));
+			assert_eq!(
+				transaction.action,
+				TransactionAction::Call(LogsEmitter::get())
+			);
+			assert_eq!(transaction.max_fee_per_gas, U256::zero());
+		}
+
+		let TransactionV2::EIP1559(first) = &transactions[0].1 else {
+			unreachable!()
+		};
+		assert_eq!(&first.input[0..4], &EMIT_TRANSFERS_SELECTOR);
+		assert_eq!(
+			first.input,
+			Erc20TransferLogs::emit_transfers_input(&[
+				native_transfer(ALICE, BOB, 100),
+				native_transfer(BOB, ALICE, 10),
+			])
+		);
+		assert_eq!(first.gas_limit, Erc20TransferLogs::gas_limit(2).into());
+		assert_eq!(Nonce::<Test>::get(), U256::from(2));
+
+		assert_eq!(
+			events()[1..],
+			[
+				Event::TransferLogsEmitted {
+					transaction_hash: transactions[0].1.hash(),
+					count: 2,
+				},
+				Event::TransferLogsEmitted {
+					transaction_hash: transactions[1].1.hash(),
+					count: 1,
+				},
+			]
+		);
+	});
+}
+
+#[test]
+fn emit_transfers_input_is_solidity_encoded() {
+	new_test_ext().execute_with(|| {
+		let input = Erc20TransferLogs::emit_transfers_input(&[native_transfer(ALICE, BOB, 100)]);
+
+		let mut expected = EMIT_TRANSFERS_SELECTOR.to_vec();
+		let word = |value: u8| {
+			let mut word = [0u8; 32];
+			word[31] = value;
+			word
+		};
+		let address = |address: H160| {
+			let mut word = [0u8; 32];
+			word[12..].copy_from_slice(address.as_bytes());
+			word
+		};
+		expected.extend_from_slice(&word(32));
+		expected.extend_from_slice(&word(1));
+		expected.extend_from_slice(&address(NATIVE_TOKEN));
+		expected.extend_from_slice(&address(ALICE));
+		expected.extend_from_slice(&address(BOB));
+		expected.extend_from_slice(&word(100));
+
+		assert_eq!(input, expected);
+	});
+}
+
+#[test]
+fn transfers_are_dropped_without_enough_weight() {
+	new_test_ext().execute_with(|| {
+		enable();
+		balances_transfer(ALICE, BOB, 100);
+		balances_transfer(BOB, ALICE, 10);
+		balances_transfer(ALICE, BOB, 1);
+
+		// Enough to observe the events, not to apply a transaction
+		Erc20TransferLogs::on_idle(1, Weight::from_parts(100_000_000, u64::MAX));
+
+		assert!(applied_transactions().is_empty());
+		assert_eq!(
+			events().last(),
+			Some(&Event::TransferLogsDropped { count: 3 })
+		);
+	});
+}
+
+#[test]
+fn xcm_deposits_and_withdrawals_are_noted() {
+	new_test_ext().execute_with(|| {
+		enable();
+		let context = XcmContext::with_message_id([0; 32]);
+
+		assert_ok!(Erc20TransferLogs::deposit_asset(
+			&(MultiLocation::parent(), 100u128).into(),
+			&account_location(ALICE),
+			&context,
+		));
+		assert_ok!(Erc20TransferLogs::withdraw_asset(
+			&(MultiLocation::parent(), 40u128).into(),
+			&account_location(ALICE),
+			Some(&context),
+		));
+		// Assets without ERC-20 token are not noted
+		assert_ok!(Erc20TransferLogs::deposit_asset(
+			&(MultiLocation::here(), 100u128).into(),
+			&account_location(ALICE),
+			&context,
+		));
+
+		assert_eq!(
+			events()[1..],
+			[
+				Event::TransferNoted {
+					token: RELAY_TOKEN,
+					from: H160::zero(),
+					to: ALICE,
+					value: 100.into(),
+				},
+				Event::TransferNoted {
+					token: RELAY_TOKEN,
+					from: ALICE,
+					to: H160::zero(),
+					value: 40.into(),
+				},
+			]
+		);
+	});
+}
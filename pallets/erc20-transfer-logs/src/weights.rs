@@ -0,0 +1,107 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+
+//! Autogenerated weights for pallet_erc20_transfer_logs
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-11-30, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `ip-10-0-0-176`, CPU: `Intel(R) Xeon(R) Platinum 8375C CPU @ 2.90GHz`
+//! EXECUTION: None, WASM-EXECUTION: Compiled, CHAIN: Some("moonbase-dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/moonbeam
+// benchmark
+// pallet
+// --chain=moonbase-dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_erc20_transfer_logs
+// --extrinsic=*
+// --wasm-execution=compiled
+// --template=./benchmarking/frame-weight-template.hbs
+// --output=./pallets/erc20-transfer-logs/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_erc20_transfer_logs.
+pub trait WeightInfo {
+	fn set_transfer_logs_enabled() -> Weight;
+	fn observe_events(x: u32, ) -> Weight;
+}
+
+/// Weights for pallet_erc20_transfer_logs using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Erc20TransferLogs TransferLogsEnabled (r:0 w:1)
+	/// Proof: Erc20TransferLogs TransferLogsEnabled (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn set_transfer_logs_enabled() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: System Events (r:1 w:0)
+	/// Proof Skipped: System Events (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `x` is `[0, 1000]`.
+	fn observe_events(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + x * (158 ±0)`
+		//  Estimated: `1485 + x * (158 ±0)`
+		// Minimum execution time: 3_000_000 picoseconds.
+		Weight::from_parts(4_311_000, 1485)
+			// Standard Error: 1_409
+			.saturating_add(Weight::from_parts(2_106_482, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(Weight::from_parts(0, 158).saturating_mul(x.into()))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: Erc20TransferLogs TransferLogsEnabled (r:0 w:1)
+	/// Proof: Erc20TransferLogs TransferLogsEnabled (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn set_transfer_logs_enabled() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: System Events (r:1 w:0)
+	/// Proof Skipped: System Events (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `x` is `[0, 1000]`.
+	fn observe_events(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + x * (158 ±0)`
+		//  Estimated: `1485 + x * (158 ±0)`
+		// Minimum execution time: 3_000_000 picoseconds.
+		Weight::from_parts(4_311_000, 1485)
+			// Standard Error: 1_409
+			.saturating_add(Weight::from_parts(2_106_482, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(Weight::from_parts(0, 158).saturating_mul(x.into()))
+	}
+}
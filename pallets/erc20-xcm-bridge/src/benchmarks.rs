@@ -0,0 +1,94 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::{Call, Config, Erc20TokenInfo, Erc20TokenStatus, Pallet};
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_system::RawOrigin;
+use sp_core::H160;
+
+fn register<T: Config>(contract_address: H160) {
+	Pallet::<T>::register_token(RawOrigin::Root.into(), contract_address, None)
+		.expect("token should register");
+}
+
+benchmarks! {
+	register_token {
+		let contract_address = H160::repeat_byte(1);
+	}: _(RawOrigin::Root, contract_address, Some(100_000))
+	verify {
+		assert_eq!(
+			Pallet::<T>::erc20_token(contract_address),
+			Some(Erc20TokenInfo { status: Erc20TokenStatus::Allowed, gas_limit: Some(100_000) })
+		);
+	}
+
+	set_token_gas_limit {
+		let contract_address = H160::repeat_byte(1);
+		register::<T>(contract_address);
+	}: _(RawOrigin::Root, contract_address, Some(100_000))
+	verify {
+		assert_eq!(
+			Pallet::<T>::erc20_token(contract_address).and_then(|info| info.gas_limit),
+			Some(100_000)
+		);
+	}
+
+	pause_token {
+		let contract_address = H160::repeat_byte(1);
+		register::<T>(contract_address);
+	}: _(RawOrigin::Root, contract_address)
+	verify {
+		assert_eq!(
+			Pallet::<T>::erc20_token(contract_address).map(|info| info.status),
+			Some(Erc20TokenStatus::Paused)
+		);
+	}
+
+	resume_token {
+		let contract_address = H160::repeat_byte(1);
+		register::<T>(contract_address);
+		Pallet::<T>::pause_token(RawOrigin::Root.into(), contract_address)?;
+	}: _(RawOrigin::Root, contract_address)
+	verify {
+		assert_eq!(
+			Pallet::<T>::erc20_token(contract_address).map(|info| info.status),
+			Some(Erc20TokenStatus::Allowed)
+		);
+	}
+
+	deny_token {
+		let contract_address = H160::repeat_byte(1);
+		register::<T>(contract_address);
+	}: _(RawOrigin::Root, contract_address)
+	verify {
+		assert_eq!(
+			Pallet::<T>::erc20_token(contract_address).map(|info| info.status),
+			Some(Erc20TokenStatus::Denied)
+		);
+	}
+
+	unregister_token {
+		let contract_address = H160::repeat_byte(1);
+		register::<T>(contract_address);
+	}: _(RawOrigin::Root, contract_address)
+	verify {
+		assert!(Pallet::<T>::erc20_token(contract_address).is_none());
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
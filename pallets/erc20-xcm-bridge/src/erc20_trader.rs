@@ -0,0 +1,149 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Module that provides a weight trader to pay xcm fees with erc20 tokens.
+
+use frame_support::weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight};
+use pallet_evm::GasWeightMapping;
+use sp_core::{Get, H160};
+use sp_runtime::traits::Zero;
+use xcm::latest::prelude::*;
+use xcm_executor::traits::WeightTrader;
+use xcm_primitives::UnitsToWeightRatio;
+
+/// Weight trader that buys execution with the erc20 token found first in the xcm holding,
+/// at the units per second registered for its multilocation.
+/// Fees are transferred to the fees account right away, so that refunds can be transferred
+/// back from it when the xcm holding is deposited.
+pub struct Erc20Trader<T, AssetType, AssetIdInfoGetter, FeesAccount>(
+	Weight,
+	Option<(MultiLocation, u128, u128)>, // id, amount, units_per_second
+	core::marker::PhantomData<(T, AssetType, AssetIdInfoGetter, FeesAccount)>,
+);
+
+impl<T, AssetType, AssetIdInfoGetter, FeesAccount>
+	Erc20Trader<T, AssetType, AssetIdInfoGetter, FeesAccount>
+where
+	T: crate::Config,
+{
+	/// Fee of `weight` at `units_per_second`. The units per second price the ref time, so the
+	/// proof size is converted to the ref time of the gas the evm charges for it, and the
+	/// largest of both components is charged.
+	fn fee(weight: Weight, units_per_second: u128) -> u128 {
+		let proof_size_gas = weight
+			.proof_size()
+			.saturating_mul(<T as pallet_evm::Config>::GasLimitPovSizeRatio::get());
+		let proof_size_ref_time =
+			<T as pallet_evm::Config>::GasWeightMapping::gas_to_weight(proof_size_gas, false)
+				.ref_time();
+		let ref_time = weight.ref_time().max(proof_size_ref_time);
+
+		units_per_second.saturating_mul(ref_time as u128) / (WEIGHT_REF_TIME_PER_SECOND as u128)
+	}
+}
+
+impl<T, AssetType, AssetIdInfoGetter, FeesAccount> WeightTrader
+	for Erc20Trader<T, AssetType, AssetIdInfoGetter, FeesAccount>
+where
+	T: crate::Config,
+	AssetType: From<MultiLocation> + Clone,
+	AssetIdInfoGetter: UnitsToWeightRatio<AssetType>,
+	FeesAccount: Get<H160>,
+{
+	fn new() -> Self {
+		Erc20Trader(Weight::zero(), None, core::marker::PhantomData)
+	}
+
+	fn buy_weight(
+		&mut self,
+		weight: Weight,
+		payment: xcm_executor::Assets,
+		_context: &XcmContext,
+	) -> Result<xcm_executor::Assets, XcmError> {
+		// can only call one time
+		if self.1.is_some() {
+			return Err(XcmError::NotWithdrawable);
+		}
+
+		let first_asset = payment
+			.fungible_assets_iter()
+			.next()
+			.ok_or(XcmError::TooExpensive)?;
+
+		// Other assets are left to the next traders
+		if !crate::Pallet::<T>::is_erc20_asset(&first_asset) {
+			return Err(XcmError::TooExpensive);
+		}
+		let id = match first_asset.id {
+			Concrete(id) => id,
+			_ => return Err(XcmError::TooExpensive),
+		};
+
+		let asset_type: AssetType = id.into();
+		if !AssetIdInfoGetter::payment_is_supported(asset_type.clone()) {
+			return Err(XcmError::TooExpensive);
+		}
+		let units_per_second =
+			AssetIdInfoGetter::get_units_per_second(asset_type).ok_or(XcmError::TooExpensive)?;
+
+		let amount = Self::fee(weight, units_per_second);
+
+		// We dont need to proceed if the amount is 0
+		if amount.is_zero() {
+			return Ok(payment);
+		}
+
+		let required = MultiAsset {
+			fun: Fungible(amount),
+			id: Concrete(id),
+		};
+		let unused = payment
+			.checked_sub(required.clone())
+			.map_err(|_| XcmError::TooExpensive)?;
+
+		crate::Pallet::<T>::pay_erc20_fee(&required, FeesAccount::get())?;
+
+		self.0 = weight;
+		self.1 = Some((id, amount, units_per_second));
+
+		Ok(unused)
+	}
+
+	// Refund weight. The refunded tokens will be transferred back from the fees account.
+	fn refund_weight(&mut self, weight: Weight, _context: &XcmContext) -> Option<MultiAsset> {
+		let (id, prev_amount, units_per_second) = self.1?;
+
+		let weight = weight.min(self.0);
+		// Refund the difference with the fee of the weight left, since the fee is not linear
+		// in both weight components
+		let amount =
+			prev_amount.saturating_sub(Self::fee(self.0.saturating_sub(weight), units_per_second));
+		if amount.is_zero() {
+			return None;
+		}
+
+		let refund = MultiAsset {
+			fun: Fungible(amount),
+			id: Concrete(id),
+		};
+		crate::Pallet::<T>::refund_erc20_fee(&refund, FeesAccount::get()).ok()?;
+
+		self.0 -= weight;
+		self.1 = Some((id, prev_amount.saturating_sub(amount), units_per_second));
+
+		Some(refund)
+	}
+}
@@ -15,23 +15,33 @@
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 
 //! Pallet that allow to transact erc20 tokens trought xcm directly.
+//!
+//! Only the erc20 tokens registered by governance can be transacted. Governance can also
+//! override the gas limit used for the transfers of a given token, pause its transfers, or
+//! explicitly deny it.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+mod benchmarks;
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
 mod tests;
+pub mod weights;
 
 mod erc20_matcher;
+mod erc20_trader;
 mod erc20_trap;
 mod errors;
 mod xcm_holding_ext;
 
 use frame_support::pallet;
 
+pub use erc20_trader::Erc20Trader;
 pub use erc20_trap::AssetTrapWrapper;
 pub use pallet::*;
+pub use weights::WeightInfo;
 pub use xcm_holding_ext::XcmExecutorWrapper;
 
 #[pallet]
@@ -39,10 +49,12 @@ pub mod pallet {
 
 	use crate::erc20_matcher::*;
 	use crate::errors::*;
+	use crate::weights::WeightInfo;
 	use crate::xcm_holding_ext::*;
 	use ethereum_types::BigEndianHash;
 	use fp_evm::{ExitReason, ExitSucceed};
 	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
 	use pallet_evm::{GasWeightMapping, Runner};
 	use sp_core::{H160, H256, U256};
 	use sp_std::vec::Vec;
@@ -62,18 +74,266 @@ pub mod pallet {
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config + pallet_evm::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		type AccountIdConverter: ConvertLocation<H160>;
 		type Erc20MultilocationPrefix: Get<MultiLocation>;
+		/// Gas limit of the erc20 transfers, for tokens without a registered gas limit
 		type Erc20TransferGasLimit: Get<u64>;
+		/// Maximum gas limit that can be requested for an erc20 transfer through the
+		/// multilocation of the token
+		#[pallet::constant]
+		type Erc20MaxTransferGasLimit: Get<u64>;
+		/// Origin allowed to manage the registry of erc20 tokens
+		type Erc20RegistryOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		type EvmRunner: Runner<Self>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Status of a registered erc20 token
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum Erc20TokenStatus {
+		/// The token can be transacted through xcm
+		Allowed,
+		/// The transfers of the token are temporarily suspended
+		Paused,
+		/// The token can't be transacted through xcm
+		Denied,
+	}
+
+	/// Registry information of an erc20 token
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Erc20TokenInfo {
+		pub status: Erc20TokenStatus,
+		/// Gas limit of the transfers of this token, `Erc20TransferGasLimit` is used if None
+		pub gas_limit: Option<u64>,
+	}
+
+	/// Erc20 tokens registered by governance, by contract address. Tokens without an entry
+	/// can be transacted through xcm with the default gas limit.
+	#[pallet::storage]
+	#[pallet::getter(fn erc20_token)]
+	pub type Erc20Tokens<T: Config> =
+		StorageMap<_, Blake2_128Concat, H160, Erc20TokenInfo, OptionQuery>;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The erc20 token is already registered
+		TokenAlreadyRegistered,
+		/// The erc20 token is not registered
+		TokenNotRegistered,
+		/// The erc20 token is denied
+		TokenDenied,
+		/// The erc20 token is not paused
+		TokenNotPaused,
+		/// The erc20 token is not allowed
+		TokenNotAllowed,
+		/// The gas limit can't be zero
+		InvalidGasLimit,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An erc20 token has been allowed to be transacted through xcm
+		TokenRegistered {
+			contract_address: H160,
+			gas_limit: Option<u64>,
+		},
+		/// The gas limit of the transfers of an erc20 token has changed
+		TokenGasLimitChanged {
+			contract_address: H160,
+			gas_limit: Option<u64>,
+		},
+		/// The transfers of an erc20 token have been paused
+		TokenPaused { contract_address: H160 },
+		/// The transfers of an erc20 token have been resumed
+		TokenResumed { contract_address: H160 },
+		/// An erc20 token has been denied
+		TokenDenied { contract_address: H160 },
+		/// An erc20 token has been removed from the registry
+		TokenUnregistered { contract_address: H160 },
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Allow an erc20 token to be transacted through xcm, optionally with a specific gas
+		/// limit for its transfers.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::register_token())]
+		pub fn register_token(
+			origin: OriginFor<T>,
+			contract_address: H160,
+			gas_limit: Option<u64>,
+		) -> DispatchResult {
+			T::Erc20RegistryOrigin::ensure_origin(origin)?;
+			ensure!(gas_limit != Some(0), Error::<T>::InvalidGasLimit);
+
+			match Erc20Tokens::<T>::get(contract_address) {
+				Some(Erc20TokenInfo {
+					status: Erc20TokenStatus::Denied,
+					..
+				}) => return Err(Error::<T>::TokenDenied.into()),
+				Some(_) => return Err(Error::<T>::TokenAlreadyRegistered.into()),
+				None => (),
+			}
+
+			Erc20Tokens::<T>::insert(
+				contract_address,
+				Erc20TokenInfo {
+					status: Erc20TokenStatus::Allowed,
+					gas_limit,
+				},
+			);
+
+			Self::deposit_event(Event::TokenRegistered {
+				contract_address,
+				gas_limit,
+			});
+			Ok(())
+		}
+
+		/// Change the gas limit of the transfers of a registered erc20 token.
+		/// None means that `Erc20TransferGasLimit` is used.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_token_gas_limit())]
+		pub fn set_token_gas_limit(
+			origin: OriginFor<T>,
+			contract_address: H160,
+			gas_limit: Option<u64>,
+		) -> DispatchResult {
+			T::Erc20RegistryOrigin::ensure_origin(origin)?;
+			ensure!(gas_limit != Some(0), Error::<T>::InvalidGasLimit);
+
+			Erc20Tokens::<T>::try_mutate(contract_address, |maybe_info| -> DispatchResult {
+				let info = maybe_info.as_mut().ok_or(Error::<T>::TokenNotRegistered)?;
+				info.gas_limit = gas_limit;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::TokenGasLimitChanged {
+				contract_address,
+				gas_limit,
+			});
+			Ok(())
+		}
+
+		/// Pause the transfers of an allowed erc20 token, whether it is registered or not.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::pause_token())]
+		pub fn pause_token(origin: OriginFor<T>, contract_address: H160) -> DispatchResult {
+			T::Erc20RegistryOrigin::ensure_origin(origin)?;
+
+			Erc20Tokens::<T>::try_mutate(contract_address, |maybe_info| -> DispatchResult {
+				let info = maybe_info.get_or_insert(Erc20TokenInfo {
+					status: Erc20TokenStatus::Allowed,
+					gas_limit: None,
+				});
+				ensure!(
+					info.status == Erc20TokenStatus::Allowed,
+					Error::<T>::TokenNotAllowed
+				);
+				info.status = Erc20TokenStatus::Paused;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::TokenPaused { contract_address });
+			Ok(())
+		}
+
+		/// Resume the transfers of a paused erc20 token.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::resume_token())]
+		pub fn resume_token(origin: OriginFor<T>, contract_address: H160) -> DispatchResult {
+			T::Erc20RegistryOrigin::ensure_origin(origin)?;
+
+			Erc20Tokens::<T>::try_mutate(contract_address, |maybe_info| -> DispatchResult {
+				let info = maybe_info.as_mut().ok_or(Error::<T>::TokenNotRegistered)?;
+				ensure!(
+					info.status == Erc20TokenStatus::Paused,
+					Error::<T>::TokenNotPaused
+				);
+				info.status = Erc20TokenStatus::Allowed;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::TokenResumed { contract_address });
+			Ok(())
+		}
+
+		/// Deny an erc20 token, whether it is registered or not. A denied token can only be
+		/// registered again after being unregistered.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::deny_token())]
+		pub fn deny_token(origin: OriginFor<T>, contract_address: H160) -> DispatchResult {
+			T::Erc20RegistryOrigin::ensure_origin(origin)?;
+
+			Erc20Tokens::<T>::mutate(contract_address, |maybe_info| {
+				let gas_limit = maybe_info.as_ref().and_then(|info| info.gas_limit);
+				*maybe_info = Some(Erc20TokenInfo {
+					status: Erc20TokenStatus::Denied,
+					gas_limit,
+				});
+			});
+
+			Self::deposit_event(Event::TokenDenied { contract_address });
+			Ok(())
+		}
+
+		/// Remove an erc20 token from the registry.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::unregister_token())]
+		pub fn unregister_token(origin: OriginFor<T>, contract_address: H160) -> DispatchResult {
+			T::Erc20RegistryOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				Erc20Tokens::<T>::contains_key(contract_address),
+				Error::<T>::TokenNotRegistered
+			);
+			Erc20Tokens::<T>::remove(contract_address);
+
+			Self::deposit_event(Event::TokenUnregistered { contract_address });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
 		pub fn is_erc20_asset(asset: &MultiAsset) -> bool {
 			Erc20Matcher::<T::Erc20MultilocationPrefix>::is_erc20_asset(asset)
 		}
+		pub fn is_erc20_location(location: &MultiLocation) -> bool {
+			Erc20Matcher::<T::Erc20MultilocationPrefix>::matches_erc20_multilocation(location)
+				.is_ok()
+		}
+		/// Ensure that the erc20 token can be transacted through xcm. Tokens that are not
+		/// registered are allowed, governance opts them out by pausing or denying them.
+		pub fn ensure_erc20_token_allowed(contract_address: H160) -> XcmResult {
+			match Erc20Tokens::<T>::get(contract_address).map(|info| info.status) {
+				Some(Erc20TokenStatus::Allowed) => Ok(()),
+				Some(Erc20TokenStatus::Paused) => Err(XcmError::FailedToTransactAsset(
+					"Erc20 token transfers are paused",
+				)),
+				Some(Erc20TokenStatus::Denied) => {
+					Err(XcmError::FailedToTransactAsset("Erc20 token is denied"))
+				}
+				None => Ok(()),
+			}
+		}
+		/// The gas limit registered by governance for the token takes precedence over the one
+		/// found in the multilocation, which is capped to `Erc20MaxTransferGasLimit`.
 		pub fn gas_limit_of_erc20_transfer(asset_id: &AssetId) -> u64 {
 			if let AssetId::Concrete(multilocation) = asset_id {
+				if let Some(gas_limit) =
+					Erc20Matcher::<T::Erc20MultilocationPrefix>::matches_erc20_multilocation(
+						multilocation,
+					)
+					.ok()
+					.and_then(|contract_address| Erc20Tokens::<T>::get(contract_address))
+					.and_then(|info| info.gas_limit)
+				{
+					return gas_limit;
+				}
 				if let Some(Junction::GeneralKey {
 					length: _,
 					ref data,
@@ -88,7 +348,8 @@ pub mod pallet {
 						if content == "gas_limit:" {
 							let mut bytes: [u8; 8] = Default::default();
 							bytes.copy_from_slice(&data[10..18]);
-							return u64::from_le_bytes(bytes);
+							return u64::from_le_bytes(bytes)
+								.min(T::Erc20MaxTransferGasLimit::get());
 						}
 					}
 				}
@@ -98,6 +359,62 @@ pub mod pallet {
 		pub fn weight_of_erc20_transfer(asset_id: &AssetId) -> Weight {
 			T::GasWeightMapping::gas_to_weight(Self::gas_limit_of_erc20_transfer(asset_id), true)
 		}
+		/// Pay xcm fees with erc20 tokens of the xcm holding, by transferring them from the
+		/// accounts they were withdrawn from to the fees account.
+		pub(crate) fn pay_erc20_fee(fee: &MultiAsset, fees_account: H160) -> XcmResult {
+			let (contract_address, amount) =
+				Erc20Matcher::<T::Erc20MultilocationPrefix>::matches_fungibles(fee)?;
+			Self::ensure_erc20_token_allowed(contract_address)?;
+
+			let gas_limit = Self::gas_limit_of_erc20_transfer(&fee.id);
+
+			XcmHoldingErc20sOrigins::with(|erc20s_origins| {
+				let tokens_to_transfer = match erc20s_origins.take(contract_address, amount) {
+					Ok(tokens_to_transfer) => tokens_to_transfer,
+					Err(DrainError::AssetNotFound) => return Err(XcmError::AssetNotFound),
+					Err(_) => {
+						return Err(XcmError::FailedToTransactAsset(
+							"not enough founds in xcm holding",
+						))
+					}
+				};
+
+				frame_support::storage::with_storage_layer(|| {
+					tokens_to_transfer.iter().try_for_each(|(from, subamount)| {
+						Self::erc20_transfer(
+							contract_address,
+							*from,
+							fees_account,
+							*subamount,
+							gas_limit,
+						)
+					})
+				})
+				.map_err(|e| {
+					// Give the tokens back to the xcm holding, as nothing was transferred
+					for (from, subamount) in tokens_to_transfer {
+						erc20s_origins.insert(contract_address, from, subamount);
+					}
+					e.into()
+				})
+			})
+			.ok_or(XcmError::FailedToTransactAsset(
+				"missing erc20 executor context",
+			))?
+		}
+		/// Give back to the xcm holding some erc20 tokens previously paid as xcm fees.
+		/// They will be transferred from the fees account when deposited.
+		pub(crate) fn refund_erc20_fee(refund: &MultiAsset, fees_account: H160) -> XcmResult {
+			let (contract_address, amount) =
+				Erc20Matcher::<T::Erc20MultilocationPrefix>::matches_fungibles(refund)?;
+
+			XcmHoldingErc20sOrigins::with(|erc20s_origins| {
+				erc20s_origins.insert(contract_address, fees_account, amount)
+			})
+			.ok_or(XcmError::FailedToTransactAsset(
+				"missing erc20 executor context",
+			))
+		}
 		fn erc20_transfer(
 			erc20_contract_address: H160,
 			from: H160,
@@ -166,6 +483,7 @@ pub mod pallet {
 		) -> XcmResult {
 			let (contract_address, amount) =
 				Erc20Matcher::<T::Erc20MultilocationPrefix>::matches_fungibles(what)?;
+			Self::ensure_erc20_token_allowed(contract_address)?;
 
 			let beneficiary = T::AccountIdConverter::convert_location(who)
 				.ok_or(MatchError::AccountIdConversionFailed)?;
@@ -213,6 +531,7 @@ pub mod pallet {
 		) -> Result<Assets, XcmError> {
 			let (contract_address, amount) =
 				Erc20Matcher::<T::Erc20MultilocationPrefix>::matches_fungibles(asset)?;
+			Self::ensure_erc20_token_allowed(contract_address)?;
 
 			let from = T::AccountIdConverter::convert_location(from)
 				.ok_or(MatchError::AccountIdConversionFailed)?;
@@ -245,6 +564,7 @@ pub mod pallet {
 		) -> Result<Assets, XcmError> {
 			let (contract_address, amount) =
 				Erc20Matcher::<T::Erc20MultilocationPrefix>::matches_fungibles(what)?;
+			Self::ensure_erc20_token_allowed(contract_address)?;
 			let who = T::AccountIdConverter::convert_location(who)
 				.ok_or(MatchError::AccountIdConversionFailed)?;
 
@@ -18,11 +18,16 @@
 use crate as erc20_xcm_bridge;
 
 use frame_support::traits::Everything;
+use frame_support::weights::constants::WEIGHT_REF_TIME_PER_SECOND;
 use frame_support::{construct_runtime, pallet_prelude::*, parameter_types};
+use frame_system::EnsureRoot;
 use pallet_evm::{AddressMapping, EnsureAddressTruncated, SubstrateBlockHashMapping};
 use sp_core::{H160, H256, U256};
 use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
 use sp_runtime::AccountId32;
+use sp_runtime::BuildStorage;
+use xcm::latest::MultiLocation;
+use xcm_primitives::UnitsToWeightRatio;
 
 pub type Balance = u128;
 
@@ -99,17 +104,14 @@ impl pallet_timestamp::Config for Test {
 	type WeightInfo = ();
 }
 
-const MAX_POV_SIZE: u64 = 5 * 1024 * 1024;
 /// Block storage limit in bytes. Set to 40 KB.
 const BLOCK_STORAGE_LIMIT: u64 = 40 * 1024;
 
 parameter_types! {
 	pub BlockGasLimit: U256 = U256::from(u64::MAX);
 	pub const WeightPerGas: Weight = Weight::from_parts(1, 0);
-	pub GasLimitPovSizeRatio: u64 = {
-		let block_gas_limit = BlockGasLimit::get().min(u64::MAX.into()).low_u64();
-		block_gas_limit.saturating_div(MAX_POV_SIZE)
-	};
+	// one byte of proof size is worth 4 gas, like in the runtimes
+	pub const GasLimitPovSizeRatio: u64 = 4;
 	pub GasLimitStorageGrowthRatio: u64 = {
 		let block_gas_limit = BlockGasLimit::get().min(u64::MAX.into()).low_u64();
 		block_gas_limit.saturating_div(BLOCK_STORAGE_LIMIT)
@@ -152,10 +154,68 @@ impl pallet_evm::Config for Test {
 
 parameter_types! {
 	pub Erc20XcmBridgeTransferGasLimit: u64 = 200_000;
+	pub const Erc20XcmBridgeMaxTransferGasLimit: u64 = 400_000;
 }
 impl crate::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
 	type AccountIdConverter = ();
 	type Erc20MultilocationPrefix = ();
 	type Erc20TransferGasLimit = Erc20XcmBridgeTransferGasLimit;
+	type Erc20MaxTransferGasLimit = Erc20XcmBridgeMaxTransferGasLimit;
+	type Erc20RegistryOrigin = EnsureRoot<AccountId32>;
 	type EvmRunner = pallet_evm::runner::stack::Runner<Self>;
+	type WeightInfo = ();
+}
+
+/// Units per second of every erc20 token used to pay xcm fees
+pub const ERC20_UNITS_PER_SECOND: u128 = 2 * WEIGHT_REF_TIME_PER_SECOND as u128;
+
+pub struct Erc20UnitsPerSecond;
+impl UnitsToWeightRatio<MultiLocation> for Erc20UnitsPerSecond {
+	fn payment_is_supported(asset_type: MultiLocation) -> bool {
+		Erc20XcmBridge::is_erc20_location(&asset_type)
+	}
+	fn get_units_per_second(asset_type: MultiLocation) -> Option<u128> {
+		Self::payment_is_supported(asset_type).then_some(ERC20_UNITS_PER_SECOND)
+	}
+}
+
+parameter_types! {
+	pub const Erc20FeesAccount: H160 = H160([0xff; 20]);
+}
+
+pub(crate) type Trader =
+	crate::Erc20Trader<Test, MultiLocation, Erc20UnitsPerSecond, Erc20FeesAccount>;
+
+/// Deploy at `address` a contract that accepts any erc20 transfer, returning true
+pub(crate) fn deploy_erc20_accepting_transfers(address: H160) {
+	// PUSH1 0x01 PUSH1 0x00 MSTORE PUSH1 0x20 PUSH1 0x00 RETURN
+	pallet_evm::AccountCodes::<Test>::insert(
+		address,
+		vec![0x60, 0x01, 0x60, 0x00, 0x52, 0x60, 0x20, 0x60, 0x00, 0xf3],
+	);
+}
+
+pub(crate) fn events() -> Vec<erc20_xcm_bridge::Event<Test>> {
+	System::events()
+		.into_iter()
+		.map(|r| r.event)
+		.filter_map(|e| {
+			if let RuntimeEvent::Erc20XcmBridge(inner) = e {
+				Some(inner)
+			} else {
+				None
+			}
+		})
+		.collect::<Vec<_>>()
+}
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default()
+		.build_storage()
+		.expect("Frame system builds valid default genesis config");
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
 }
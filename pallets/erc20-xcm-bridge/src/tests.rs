@@ -15,10 +15,30 @@
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 
 //! Unit testing
-use sp_runtime::BoundedVec;
-use xcm::latest::Junction;
+use frame_support::{assert_noop, assert_ok, weights::Weight};
+use sp_core::{H160, U256};
+use sp_runtime::{BoundedVec, DispatchError};
+use xcm::latest::{Error as XcmError, Junction, MultiAsset, MultiLocation, XcmContext};
+use xcm_executor::traits::WeightTrader;
 
-use crate::mock::{Erc20XcmBridge, Erc20XcmBridgeTransferGasLimit};
+use crate::mock::{
+	deploy_erc20_accepting_transfers, events, new_test_ext, Erc20FeesAccount, Erc20XcmBridge,
+	Erc20XcmBridgeMaxTransferGasLimit, Erc20XcmBridgeTransferGasLimit, RuntimeOrigin, Test, Trader,
+};
+use crate::xcm_holding_ext::XcmHoldingErc20sOrigins;
+use crate::{Erc20TokenInfo, Erc20TokenStatus, Error, Event};
+
+const CONTRACT: H160 = H160([1; 20]);
+
+fn contract_location() -> MultiLocation {
+	MultiLocation::new(
+		0,
+		Junction::AccountKey20 {
+			network: None,
+			key: CONTRACT.0,
+		},
+	)
+}
 
 #[test]
 fn general_key_data_size_32() {
@@ -51,6 +71,18 @@ fn gas_limit_override() {
 	)
 }
 
+#[test]
+fn gas_limit_override_is_capped() {
+	let text = "gas_limit:".as_bytes();
+	let data = [text, &u64::MAX.to_le_bytes()].concat();
+	let vec = BoundedVec::try_from(data).expect("vec should convert");
+	let junction: Junction = (vec).into();
+	assert_eq!(
+		Erc20XcmBridge::gas_limit_of_erc20_transfer(&junction.into()),
+		Erc20XcmBridgeMaxTransferGasLimit::get()
+	)
+}
+
 #[test]
 fn gas_limit_override_typo() {
 	let text = "gaslimit:".as_bytes();
@@ -63,3 +95,387 @@ fn gas_limit_override_typo() {
 		Erc20XcmBridgeTransferGasLimit::get()
 	)
 }
+
+#[test]
+fn register_token_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Erc20XcmBridge::register_token(RuntimeOrigin::signed([0u8; 32].into()), CONTRACT, None),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Erc20XcmBridge::register_token(RuntimeOrigin::root(), CONTRACT, Some(0)),
+			Error::<crate::mock::Test>::InvalidGasLimit
+		);
+
+		assert_ok!(Erc20XcmBridge::register_token(
+			RuntimeOrigin::root(),
+			CONTRACT,
+			Some(100_000)
+		));
+		assert_eq!(
+			Erc20XcmBridge::erc20_token(CONTRACT),
+			Some(Erc20TokenInfo {
+				status: Erc20TokenStatus::Allowed,
+				gas_limit: Some(100_000),
+			})
+		);
+		assert_noop!(
+			Erc20XcmBridge::register_token(RuntimeOrigin::root(), CONTRACT, None),
+			Error::<crate::mock::Test>::TokenAlreadyRegistered
+		);
+		assert_eq!(
+			events(),
+			vec![Event::TokenRegistered {
+				contract_address: CONTRACT,
+				gas_limit: Some(100_000),
+			}]
+		);
+	})
+}
+
+#[test]
+fn unregistered_token_is_allowed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Erc20XcmBridge::ensure_erc20_token_allowed(CONTRACT));
+
+		assert_ok!(Erc20XcmBridge::deny_token(RuntimeOrigin::root(), CONTRACT));
+		assert!(Erc20XcmBridge::ensure_erc20_token_allowed(CONTRACT).is_err());
+
+		assert_ok!(Erc20XcmBridge::unregister_token(
+			RuntimeOrigin::root(),
+			CONTRACT
+		));
+		assert_ok!(Erc20XcmBridge::ensure_erc20_token_allowed(CONTRACT));
+		assert_noop!(
+			Erc20XcmBridge::unregister_token(RuntimeOrigin::root(), CONTRACT),
+			Error::<crate::mock::Test>::TokenNotRegistered
+		);
+	})
+}
+
+#[test]
+fn pause_and_resume_token() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Erc20XcmBridge::resume_token(RuntimeOrigin::root(), CONTRACT),
+			Error::<crate::mock::Test>::TokenNotRegistered
+		);
+
+		// tokens that are not registered can be paused too
+		assert_ok!(Erc20XcmBridge::pause_token(RuntimeOrigin::root(), CONTRACT));
+		assert_eq!(
+			Erc20XcmBridge::erc20_token(CONTRACT),
+			Some(Erc20TokenInfo {
+				status: Erc20TokenStatus::Paused,
+				gas_limit: None,
+			})
+		);
+		assert_eq!(
+			Erc20XcmBridge::ensure_erc20_token_allowed(CONTRACT),
+			Err(XcmError::FailedToTransactAsset(
+				"Erc20 token transfers are paused"
+			))
+		);
+		assert_noop!(
+			Erc20XcmBridge::pause_token(RuntimeOrigin::root(), CONTRACT),
+			Error::<crate::mock::Test>::TokenNotAllowed
+		);
+
+		assert_ok!(Erc20XcmBridge::resume_token(
+			RuntimeOrigin::root(),
+			CONTRACT
+		));
+		assert_ok!(Erc20XcmBridge::ensure_erc20_token_allowed(CONTRACT));
+		assert_noop!(
+			Erc20XcmBridge::resume_token(RuntimeOrigin::root(), CONTRACT),
+			Error::<crate::mock::Test>::TokenNotPaused
+		);
+		assert_eq!(
+			events(),
+			vec![
+				Event::TokenPaused {
+					contract_address: CONTRACT
+				},
+				Event::TokenResumed {
+					contract_address: CONTRACT
+				},
+			]
+		);
+	})
+}
+
+#[test]
+fn denied_token_cannot_be_registered() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Erc20XcmBridge::deny_token(RuntimeOrigin::root(), CONTRACT));
+		assert_eq!(
+			Erc20XcmBridge::ensure_erc20_token_allowed(CONTRACT),
+			Err(XcmError::FailedToTransactAsset("Erc20 token is denied"))
+		);
+		assert_noop!(
+			Erc20XcmBridge::register_token(RuntimeOrigin::root(), CONTRACT, None),
+			Error::<crate::mock::Test>::TokenDenied
+		);
+		assert_noop!(
+			Erc20XcmBridge::pause_token(RuntimeOrigin::root(), CONTRACT),
+			Error::<crate::mock::Test>::TokenNotAllowed
+		);
+
+		// The token must be unregistered before being registered again
+		assert_ok!(Erc20XcmBridge::unregister_token(
+			RuntimeOrigin::root(),
+			CONTRACT
+		));
+		assert_ok!(Erc20XcmBridge::register_token(
+			RuntimeOrigin::root(),
+			CONTRACT,
+			None
+		));
+	})
+}
+
+#[test]
+fn registered_gas_limit_takes_precedence() {
+	new_test_ext().execute_with(|| {
+		let text = "gas_limit:".as_bytes();
+		let data = [text, &300_000u64.to_le_bytes()].concat();
+		let vec = BoundedVec::try_from(data).expect("vec should convert");
+		let mut location = contract_location();
+		location
+			.push_interior(vec.into())
+			.expect("location should not be full");
+
+		assert_eq!(
+			Erc20XcmBridge::gas_limit_of_erc20_transfer(&location.into()),
+			300_000
+		);
+
+		assert_ok!(Erc20XcmBridge::register_token(
+			RuntimeOrigin::root(),
+			CONTRACT,
+			None
+		));
+		assert_eq!(
+			Erc20XcmBridge::gas_limit_of_erc20_transfer(&location.into()),
+			300_000
+		);
+
+		assert_noop!(
+			Erc20XcmBridge::set_token_gas_limit(RuntimeOrigin::root(), H160([2; 20]), None),
+			Error::<crate::mock::Test>::TokenNotRegistered
+		);
+		assert_ok!(Erc20XcmBridge::set_token_gas_limit(
+			RuntimeOrigin::root(),
+			CONTRACT,
+			Some(50_000)
+		));
+		assert_eq!(
+			Erc20XcmBridge::gas_limit_of_erc20_transfer(&location.into()),
+			50_000
+		);
+		assert_eq!(
+			Erc20XcmBridge::gas_limit_of_erc20_transfer(&contract_location().into()),
+			50_000
+		);
+		assert!(events().contains(&Event::TokenGasLimitChanged {
+			contract_address: CONTRACT,
+			gas_limit: Some(50_000),
+		}));
+	})
+}
+
+#[test]
+fn default_gas_limit_of_registered_token() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Erc20XcmBridge::register_token(
+			RuntimeOrigin::root(),
+			CONTRACT,
+			None
+		));
+		assert_eq!(
+			Erc20XcmBridge::gas_limit_of_erc20_transfer(&contract_location().into()),
+			Erc20XcmBridgeTransferGasLimit::get()
+		);
+	})
+}
+
+const USER: H160 = H160([3; 20]);
+
+fn xcm_context() -> XcmContext {
+	XcmContext {
+		origin: None,
+		message_id: [0; 32],
+		topic: None,
+	}
+}
+
+/// Register and deploy the erc20 token, and put `amount` of it in the xcm holding
+fn setup_erc20_in_holding(amount: u128) -> xcm_executor::Assets {
+	assert_ok!(Erc20XcmBridge::register_token(
+		RuntimeOrigin::root(),
+		CONTRACT,
+		None
+	));
+	deploy_erc20_accepting_transfers(CONTRACT);
+	XcmHoldingErc20sOrigins::with(|erc20s_origins| {
+		erc20s_origins.insert(CONTRACT, USER, U256::from(amount))
+	})
+	.expect("xcm holding extension should be set");
+
+	MultiAsset::from((contract_location(), amount)).into()
+}
+
+#[test]
+fn trader_buys_weight_with_erc20() {
+	new_test_ext().execute_with(|| {
+		XcmHoldingErc20sOrigins::using(|| {
+			let payment = setup_erc20_in_holding(1_000);
+
+			// 2 units per unit of ref time
+			let mut trader = Trader::new();
+			let unused = trader
+				.buy_weight(Weight::from_parts(100, 0), payment, &xcm_context())
+				.expect("weight should be bought");
+			assert_eq!(
+				unused,
+				xcm_executor::Assets::from(MultiAsset::from((contract_location(), 800u128)))
+			);
+
+			// the fee was transferred out of the xcm holding
+			XcmHoldingErc20sOrigins::with(|erc20s_origins| {
+				assert_eq!(
+					erc20s_origins.drain(CONTRACT, U256::from(800)),
+					Ok(vec![(USER, U256::from(800))])
+				);
+			});
+
+			// can only buy weight once
+			assert_eq!(
+				trader
+					.buy_weight(
+						Weight::from_parts(100, 0),
+						MultiAsset::from((contract_location(), 200u128)).into(),
+						&xcm_context()
+					)
+					.map(|_| ()),
+				Err(XcmError::NotWithdrawable)
+			);
+		});
+	})
+}
+
+#[test]
+fn trader_refunds_unused_weight_from_fees_account() {
+	new_test_ext().execute_with(|| {
+		XcmHoldingErc20sOrigins::using(|| {
+			let payment = setup_erc20_in_holding(1_000);
+
+			let mut trader = Trader::new();
+			assert_ok!(trader
+				.buy_weight(Weight::from_parts(100, 0), payment, &xcm_context())
+				.map(|_| ()));
+
+			assert_eq!(
+				trader.refund_weight(Weight::from_parts(40, 0), &xcm_context()),
+				Some(MultiAsset::from((contract_location(), 80u128)))
+			);
+			// cannot refund more than the weight that was bought
+			assert_eq!(
+				trader.refund_weight(Weight::from_parts(100, 0), &xcm_context()),
+				Some(MultiAsset::from((contract_location(), 120u128)))
+			);
+			assert_eq!(
+				trader.refund_weight(Weight::from_parts(1, 0), &xcm_context()),
+				None
+			);
+
+			// the refunds are transferred back from the fees account
+			XcmHoldingErc20sOrigins::with(|erc20s_origins| {
+				assert_eq!(
+					erc20s_origins.drain(CONTRACT, U256::from(1_000)),
+					Ok(vec![
+						(USER, U256::from(800)),
+						(Erc20FeesAccount::get(), U256::from(80)),
+						(Erc20FeesAccount::get(), U256::from(120)),
+					])
+				);
+			});
+		});
+	})
+}
+
+#[test]
+fn trader_charges_the_proof_size_like_the_evm() {
+	new_test_ext().execute_with(|| {
+		XcmHoldingErc20sOrigins::using(|| {
+			let payment = setup_erc20_in_holding(1_000);
+
+			// 50 bytes of proof size are worth 200 gas, so 200 units of ref time
+			let mut trader = Trader::new();
+			let unused = trader
+				.buy_weight(Weight::from_parts(100, 50), payment, &xcm_context())
+				.expect("weight should be bought");
+			assert_eq!(
+				unused,
+				xcm_executor::Assets::from(MultiAsset::from((contract_location(), 600u128)))
+			);
+
+			// the weight left is priced by its ref time: (100, 20) costs 200
+			assert_eq!(
+				trader.refund_weight(Weight::from_parts(0, 30), &xcm_context()),
+				Some(MultiAsset::from((contract_location(), 200u128)))
+			);
+			// the weight left is priced by its proof size: (0, 20) costs 160
+			assert_eq!(
+				trader.refund_weight(Weight::from_parts(100, 0), &xcm_context()),
+				Some(MultiAsset::from((contract_location(), 40u128)))
+			);
+			assert_eq!(
+				trader.refund_weight(Weight::from_parts(0, 20), &xcm_context()),
+				Some(MultiAsset::from((contract_location(), 160u128)))
+			);
+
+			XcmHoldingErc20sOrigins::with(|erc20s_origins| {
+				assert_eq!(
+					erc20s_origins.drain(CONTRACT, U256::from(1_000)),
+					Ok(vec![
+						(USER, U256::from(600)),
+						(Erc20FeesAccount::get(), U256::from(200)),
+						(Erc20FeesAccount::get(), U256::from(40)),
+						(Erc20FeesAccount::get(), U256::from(160)),
+					])
+				);
+			});
+		});
+	})
+}
+
+#[test]
+fn trader_fails_with_insufficient_erc20_balance() {
+	new_test_ext().execute_with(|| {
+		XcmHoldingErc20sOrigins::using(|| {
+			let payment = setup_erc20_in_holding(100);
+
+			let mut trader = Trader::new();
+			assert_eq!(
+				trader
+					.buy_weight(Weight::from_parts(100, 0), payment, &xcm_context())
+					.map(|_| ()),
+				Err(XcmError::TooExpensive)
+			);
+			assert_eq!(
+				trader.refund_weight(Weight::from_parts(100, 0), &xcm_context()),
+				None
+			);
+
+			// nothing was taken from the xcm holding
+			XcmHoldingErc20sOrigins::with(|erc20s_origins| {
+				assert_eq!(
+					erc20s_origins.drain(CONTRACT, U256::from(100)),
+					Ok(vec![(USER, U256::from(100))])
+				);
+			});
+		});
+	})
+}
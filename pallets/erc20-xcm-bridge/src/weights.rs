@@ -0,0 +1,195 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+
+//! Autogenerated weights for pallet_erc20_xcm_bridge
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-11-01, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `ip-10-0-0-176`, CPU: `Intel(R) Xeon(R) Platinum 8375C CPU @ 2.90GHz`
+//! EXECUTION: None, WASM-EXECUTION: Compiled, CHAIN: Some("moonbase-dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/moonbeam
+// benchmark
+// pallet
+// --chain=moonbase-dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_erc20_xcm_bridge
+// --extrinsic=*
+// --wasm-execution=compiled
+// --template=./benchmarking/frame-weight-template.hbs
+// --output=./pallets/erc20-xcm-bridge/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_erc20_xcm_bridge.
+pub trait WeightInfo {
+	fn register_token() -> Weight;
+	fn set_token_gas_limit() -> Weight;
+	fn pause_token() -> Weight;
+	fn resume_token() -> Weight;
+	fn deny_token() -> Weight;
+	fn unregister_token() -> Weight;
+}
+
+/// Weights for pallet_erc20_xcm_bridge using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Erc20XcmBridge Erc20Tokens (r:1 w:1)
+	/// Proof: Erc20XcmBridge Erc20Tokens (max_values: None, max_size: Some(46), added: 2521, mode: MaxEncodedLen)
+	fn register_token() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3511`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 3511)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Erc20XcmBridge Erc20Tokens (r:1 w:1)
+	/// Proof: Erc20XcmBridge Erc20Tokens (max_values: None, max_size: Some(46), added: 2521, mode: MaxEncodedLen)
+	fn set_token_gas_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `118`
+		//  Estimated: `3511`
+		// Minimum execution time: 16_500_000 picoseconds.
+		Weight::from_parts(17_500_000, 3511)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Erc20XcmBridge Erc20Tokens (r:1 w:1)
+	/// Proof: Erc20XcmBridge Erc20Tokens (max_values: None, max_size: Some(46), added: 2521, mode: MaxEncodedLen)
+	fn pause_token() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `118`
+		//  Estimated: `3511`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 3511)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Erc20XcmBridge Erc20Tokens (r:1 w:1)
+	/// Proof: Erc20XcmBridge Erc20Tokens (max_values: None, max_size: Some(46), added: 2521, mode: MaxEncodedLen)
+	fn resume_token() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `118`
+		//  Estimated: `3511`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 3511)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Erc20XcmBridge Erc20Tokens (r:1 w:1)
+	/// Proof: Erc20XcmBridge Erc20Tokens (max_values: None, max_size: Some(46), added: 2521, mode: MaxEncodedLen)
+	fn deny_token() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3511`
+		// Minimum execution time: 16_500_000 picoseconds.
+		Weight::from_parts(17_500_000, 3511)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Erc20XcmBridge Erc20Tokens (r:1 w:1)
+	/// Proof: Erc20XcmBridge Erc20Tokens (max_values: None, max_size: Some(46), added: 2521, mode: MaxEncodedLen)
+	fn unregister_token() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `118`
+		//  Estimated: `3511`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 3511)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: Erc20XcmBridge Erc20Tokens (r:1 w:1)
+	/// Proof: Erc20XcmBridge Erc20Tokens (max_values: None, max_size: Some(46), added: 2521, mode: MaxEncodedLen)
+	fn register_token() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3511`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 3511)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Erc20XcmBridge Erc20Tokens (r:1 w:1)
+	/// Proof: Erc20XcmBridge Erc20Tokens (max_values: None, max_size: Some(46), added: 2521, mode: MaxEncodedLen)
+	fn set_token_gas_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `118`
+		//  Estimated: `3511`
+		// Minimum execution time: 16_500_000 picoseconds.
+		Weight::from_parts(17_500_000, 3511)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Erc20XcmBridge Erc20Tokens (r:1 w:1)
+	/// Proof: Erc20XcmBridge Erc20Tokens (max_values: None, max_size: Some(46), added: 2521, mode: MaxEncodedLen)
+	fn pause_token() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `118`
+		//  Estimated: `3511`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 3511)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Erc20XcmBridge Erc20Tokens (r:1 w:1)
+	/// Proof: Erc20XcmBridge Erc20Tokens (max_values: None, max_size: Some(46), added: 2521, mode: MaxEncodedLen)
+	fn resume_token() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `118`
+		//  Estimated: `3511`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 3511)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Erc20XcmBridge Erc20Tokens (r:1 w:1)
+	/// Proof: Erc20XcmBridge Erc20Tokens (max_values: None, max_size: Some(46), added: 2521, mode: MaxEncodedLen)
+	fn deny_token() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3511`
+		// Minimum execution time: 16_500_000 picoseconds.
+		Weight::from_parts(17_500_000, 3511)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Erc20XcmBridge Erc20Tokens (r:1 w:1)
+	/// Proof: Erc20XcmBridge Erc20Tokens (max_values: None, max_size: Some(46), added: 2521, mode: MaxEncodedLen)
+	fn unregister_token() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `118`
+		//  Estimated: `3511`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 3511)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -80,6 +80,45 @@ impl XcmHoldingErc20sOrigins {
 			Err(DrainError::AssetNotFound)
 		}
 	}
+	/// Take and remove a given amount of erc20 tokens from the XCM holding, splitting the
+	/// tokens of a holder if needed.
+	/// This is meant for xcm fees payment, whose amount generally does not match any
+	/// withdrawal.
+	pub(crate) fn take(
+		&mut self,
+		contract_address: H160,
+		mut amount: U256,
+	) -> Result<Vec<(H160, U256)>, DrainError> {
+		let erc20_origins = self
+			.map
+			.get_mut(&contract_address)
+			.ok_or(DrainError::AssetNotFound)?;
+
+		let available = erc20_origins
+			.iter()
+			.fold(U256::zero(), |acc, (_, subamount)| {
+				acc.saturating_add(*subamount)
+			});
+		if available < amount {
+			return Err(DrainError::NotEnoughFounds);
+		}
+
+		let mut tokens_to_transfer = Vec::new();
+		while !amount.is_zero() {
+			let (from, subamount) = erc20_origins[0];
+			if subamount > amount {
+				tokens_to_transfer.push((from, amount));
+				erc20_origins[0].1 = subamount - amount;
+				amount = U256::zero();
+			} else {
+				tokens_to_transfer.push((from, subamount));
+				erc20_origins.remove(0);
+				amount -= subamount;
+			}
+		}
+
+		Ok(tokens_to_transfer)
+	}
 	pub(crate) fn insert(&mut self, contract_address: H160, who: H160, amount: U256) {
 		self.map
 			.entry(contract_address)
@@ -92,6 +131,15 @@ impl XcmHoldingErc20sOrigins {
 	{
 		XCM_HOLDING_ERC20_ORIGINS::with(|erc20s_origins| f(erc20s_origins))
 	}
+	/// Run `f` with an empty xcm holding extension, as the executor wrapper does
+	#[cfg(test)]
+	pub(crate) fn using<R, F>(f: F) -> R
+	where
+		F: FnOnce() -> R,
+	{
+		let mut erc20s_origins = Default::default();
+		XCM_HOLDING_ERC20_ORIGINS::using(&mut erc20s_origins, f)
+	}
 }
 
 /// Xcm executor wrapper that inject xcm holding extension "XcmHoldingErc20sOrigins"
@@ -182,5 +230,31 @@ mod tests {
 				);
 			})
 		});
+
+		// Take with split
+		let mut erc20s_origins_ = Default::default();
+		XCM_HOLDING_ERC20_ORIGINS::using(&mut erc20s_origins_, || {
+			XcmHoldingErc20sOrigins::with(|erc20s_origins| {
+				erc20s_origins.insert(TOKEN1, USER1, U256::from(100));
+				erc20s_origins.insert(TOKEN1, USER2, U256::from(200));
+				assert_eq!(
+					erc20s_origins.take(TOKEN2, U256::from(1)),
+					Err(DrainError::AssetNotFound)
+				);
+				assert_eq!(
+					erc20s_origins.take(TOKEN1, U256::from(301)),
+					Err(DrainError::NotEnoughFounds)
+				);
+				assert_eq!(
+					erc20s_origins.take(TOKEN1, U256::from(150)),
+					Ok(vec![(USER1, U256::from(100)), (USER2, U256::from(50))])
+				);
+				// The remaining tokens can be drained at once
+				assert_eq!(
+					erc20s_origins.drain(TOKEN1, U256::from(150)),
+					Ok(vec![(USER2, U256::from(150))])
+				);
+			})
+		});
 	}
 }
@@ -165,8 +165,15 @@ where
 	fn report_holding(_response_info: &QueryResponseInfo, _assets: &MultiAssetFilter) -> Weight {
 		XcmGeneric::<Runtime>::report_holding()
 	}
-	fn buy_execution(_fees: &MultiAsset, _weight_limit: &WeightLimit) -> XCMWeight {
-		XcmGeneric::<Runtime>::buy_execution()
+	fn buy_execution(fees: &MultiAsset, _weight_limit: &WeightLimit) -> XCMWeight {
+		// Fees paid with erc20 tokens are transferred through the evm
+		if pallet_erc20_xcm_bridge::Pallet::<Runtime>::is_erc20_asset(fees) {
+			XcmGeneric::<Runtime>::buy_execution().saturating_add(
+				pallet_erc20_xcm_bridge::Pallet::<Runtime>::weight_of_erc20_transfer(&fees.id),
+			)
+		} else {
+			XcmGeneric::<Runtime>::buy_execution()
+		}
 	}
 	fn refund_surplus() -> XCMWeight {
 		XcmGeneric::<Runtime>::refund_surplus()
@@ -19,13 +19,15 @@
 //! Benchmarking
 use crate::{
 	AwardedPts, BalanceOf, BottomDelegations, Call, CandidateBondLessRequest, Config,
-	DelegationAction, EnableMarkingOffline, Pallet, ParachainBondConfig, ParachainBondInfo, Points,
-	Range, RewardPayment, Round, ScheduledRequest, Staked, TopDelegations,
+	DelegationAction, EnableMarkingOffline, InflationDistributionAccount,
+	InflationDistributionConfig, InflationDistributionInfo, Pallet, Points, Range, RewardPayment,
+	Round, ScheduledRequest, Staked, TopDelegations, MAX_INFLATION_DISTRIBUTION_ACCOUNTS,
 };
+use crate::{Bond, DelegatorRewardPool, DelegatorRewardPools, RewardMode, RewardPaymentMode};
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
 use frame_support::traits::{Currency, Get, OnFinalize, OnInitialize};
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
-use sp_runtime::{traits::Zero, Perbill, Percent};
+use sp_runtime::{traits::Zero, BoundedVec, Perbill, Percent};
 use sp_std::vec::Vec;
 
 /// Minimum collator candidate stake
@@ -279,13 +281,135 @@ benchmarks! {
 		let parachain_bond_account: T::AccountId = account("TEST", 0u32, USER_SEED);
 	}: _(RawOrigin::Root, parachain_bond_account.clone())
 	verify {
-		assert_eq!(Pallet::<T>::parachain_bond_info().account, parachain_bond_account);
+		assert_eq!(
+			Pallet::<T>::inflation_distribution_info()[0].account,
+			parachain_bond_account
+		);
 	}
 
 	set_parachain_bond_reserve_percent {
 	}: _(RawOrigin::Root, Percent::from_percent(33))
 	verify {
-		assert_eq!(Pallet::<T>::parachain_bond_info().percent, Percent::from_percent(33));
+		assert_eq!(
+			Pallet::<T>::inflation_distribution_info()[0].percent,
+			Percent::from_percent(33)
+		);
+	}
+
+	set_inflation_distribution_config {
+		let config: Vec<_> = (0..MAX_INFLATION_DISTRIBUTION_ACCOUNTS)
+			.map(|i| InflationDistributionAccount {
+				account: account("TEST", i, USER_SEED),
+				percent: Percent::from_percent(5),
+			})
+			.collect();
+		let config = InflationDistributionConfig::<T::AccountId>::try_from(config)
+			.expect("bounded by MAX_INFLATION_DISTRIBUTION_ACCOUNTS");
+	}: _(RawOrigin::Root, config.clone())
+	verify {
+		assert_eq!(Pallet::<T>::inflation_distribution_info(), config);
+	}
+
+	set_inflation_distribution_account {
+		let config: Vec<_> = (0..MAX_INFLATION_DISTRIBUTION_ACCOUNTS)
+			.map(|i| InflationDistributionAccount {
+				account: account("TEST", i, USER_SEED),
+				percent: Percent::from_percent(5),
+			})
+			.collect();
+		<InflationDistributionInfo<T>>::put(
+			InflationDistributionConfig::<T::AccountId>::try_from(config)
+				.expect("bounded by MAX_INFLATION_DISTRIBUTION_ACCOUNTS")
+		);
+		let index = MAX_INFLATION_DISTRIBUTION_ACCOUNTS - 1;
+		let new = InflationDistributionAccount {
+			account: account("TEST", MAX_INFLATION_DISTRIBUTION_ACCOUNTS, USER_SEED),
+			percent: Percent::from_percent(10),
+		};
+	}: _(RawOrigin::Root, index, new.clone())
+	verify {
+		assert_eq!(Pallet::<T>::inflation_distribution_info()[index as usize], new);
+	}
+
+	set_reward_payment_mode {
+	}: _(RawOrigin::Root, RewardPaymentMode::Claim)
+	verify {
+		assert_eq!(<RewardMode<T>>::get(), RewardPaymentMode::Claim);
+	}
+
+	claim_rewards {
+		// x controls the number of reward pools of the round the delegator is not part of
+		// y controls the number of delegations left to claim in each pool
+		// z controls the number of reward pools claimed, each of a distinct candidate
+		let x in 0..<<T as Config>::MaxCandidates as Get<u32>>::get();
+		let y in 1..<<T as Config>::MaxTopDelegationsPerCandidate as Get<u32>>::get();
+		let z in 1..<<T as Config>::MaxDelegationsPerDelegator as Get<u32>>::get();
+
+		let min_candidate_stake = min_candidate_stk::<T>();
+		let min_delegator_stake = min_delegator_stk::<T>();
+		let mut seed = Seed::new();
+
+		let delegator = create_funded_user::<T>(
+			"delegator",
+			seed.take(),
+			min_delegator_stake * z.into(),
+		).0;
+		let mut candidates = Vec::new();
+		for i in 0..z {
+			let candidate = create_funded_collator::<T>(
+				"collator",
+				seed.take(),
+				min_candidate_stake,
+				true,
+				i,
+			)?;
+			// claimed rewards are compounded into the delegation
+			Pallet::<T>::delegate_with_auto_compound(
+				RawOrigin::Signed(delegator.clone()).into(),
+				candidate.clone(),
+				min_delegator_stake,
+				Percent::from_percent(100),
+				0,
+				0,
+				i,
+			)?;
+			candidates.push(candidate);
+		}
+
+		let others: Vec<_> = (1..=y)
+			.map(|i| Bond { owner: account("other", i, 0u32), amount: min_delegator_stake })
+			.collect();
+		for i in 0..x {
+			<DelegatorRewardPools<T>>::insert(1, account::<T::AccountId>("other_collator", i, 0u32), DelegatorRewardPool {
+				rewards: min_delegator_stake * y.into(),
+				total: min_delegator_stake * y.into(),
+				unclaimed: BoundedVec::try_from(others.clone())
+					.expect("bounded by MaxTopDelegationsPerCandidate"),
+			});
+		}
+		// the delegator is the last delegation of each pool it is part of, shared with other
+		// delegators so that the pools are mutated rather than removed
+		let mut unclaimed = others;
+		unclaimed.pop();
+		unclaimed.push(Bond { owner: delegator.clone(), amount: min_delegator_stake });
+		let unclaimed = BoundedVec::try_from(unclaimed)
+			.expect("bounded by MaxTopDelegationsPerCandidate");
+		for candidate in candidates.iter() {
+			<DelegatorRewardPools<T>>::insert(1, candidate, DelegatorRewardPool {
+				rewards: min_delegator_stake * y.into(),
+				total: min_delegator_stake * y.into(),
+				unclaimed: unclaimed.clone(),
+			});
+		}
+		let caller: T::AccountId = create_funded_user::<T>("caller", seed.take(), 0u32.into()).0;
+	}: _(RawOrigin::Signed(caller), delegator.clone(), vec![1])
+	verify {
+		for candidate in candidates.iter() {
+			assert_eq!(
+				Pallet::<T>::pending_delegator_reward(1, candidate, &delegator),
+				None,
+			);
+		}
 	}
 
 	// ROOT DISPATCHABLES
@@ -1546,21 +1670,27 @@ benchmarks! {
 		// may need:
 		//  <Points<T>>
 		//  <Staked<T>>
-		//  <ParachainBondInfo<T>>
-		//  ensure parachain bond account exists so that deposit_into_existing succeeds
+		//  <InflationDistributionInfo<T>>
+		//  ensure inflation distribution accounts exist so that deposit_into_existing succeeds
 		<Points<T>>::insert(payout_round, 100);
 		<Staked<T>>::insert(payout_round, min_candidate_stk::<T>());
 
-		// set an account in the bond config so that we will measure the payout to it
-		let account = create_funded_user::<T>(
-			"parachain_bond",
-			0,
-			min_candidate_stk::<T>(),
-		).0;
-		<ParachainBondInfo<T>>::put(ParachainBondConfig {
-			account,
-			percent: Percent::from_percent(50),
-		});
+		// fill the inflation distribution config with funded accounts so that we will measure
+		// the worst case payout to all of them
+		let config: Vec<_> = (0..MAX_INFLATION_DISTRIBUTION_ACCOUNTS)
+			.map(|i| InflationDistributionAccount {
+				account: create_funded_user::<T>(
+					"inflation_distribution",
+					i,
+					min_candidate_stk::<T>(),
+				).0,
+				percent: Percent::from_percent(10),
+			})
+			.collect();
+		<InflationDistributionInfo<T>>::put(
+			InflationDistributionConfig::<T::AccountId>::try_from(config)
+				.expect("bounded by MAX_INFLATION_DISTRIBUTION_ACCOUNTS")
+		);
 
 	}: { Pallet::<T>::prepare_staking_payouts(round); }
 	verify {
@@ -1857,6 +1987,93 @@ benchmarks! {
 		}
 	}
 
+	pay_one_collator_reward_claim {
+		// y controls number of delegations, its maximum per collator is the max top delegations
+		let y in 0..<<T as Config>::MaxTopDelegationsPerCandidate as Get<u32>>::get();
+
+		// must come after 'let foo in 0..` statements for macro
+		use crate::{
+			DelayedPayout, DelayedPayouts, AtStake, CollatorSnapshot, BondWithAutoCompound, Points,
+			AwardedPts,
+		};
+
+		let initial_stake_amount = min_candidate_stk::<T>() * 1_000_000u32.into();
+		let mut total_staked = 0u32.into();
+
+		let sole_collator = create_funded_collator::<T>(
+			"collator",
+			0,
+			initial_stake_amount,
+			true,
+			1u32,
+		)?;
+		total_staked += initial_stake_amount;
+
+		let mut delegators: Vec<T::AccountId> = Vec::new();
+		for i in 0..y {
+			let seed = USER_SEED + i;
+			let delegator = create_funded_delegator::<T>(
+				"delegator",
+				seed,
+				initial_stake_amount,
+				sole_collator.clone(),
+				true,
+				delegators.len() as u32,
+			)?;
+			delegators.push(delegator);
+			total_staked += initial_stake_amount;
+		}
+		let initial_delegator_balance = min_candidate_stk::<T>() + initial_stake_amount;
+
+		// delegator rewards accrue into a reward pool instead of being paid out
+		<RewardMode<T>>::put(RewardPaymentMode::Claim);
+
+		let round_for_payout = 5;
+		<DelayedPayouts<T>>::insert(&round_for_payout, DelayedPayout {
+			round_issuance: 1000u32.into(),
+			total_staking_reward: total_staked,
+			collator_commission: Perbill::from_rational(1u32, 100u32),
+		});
+
+		let mut delegations: Vec<BondWithAutoCompound<T::AccountId, BalanceOf<T>>> = Vec::new();
+		for delegator in &delegators {
+			delegations.push(BondWithAutoCompound {
+				owner: delegator.clone(),
+				amount: 100u32.into(),
+				auto_compound: Percent::zero(),
+			});
+		}
+
+		<AtStake<T>>::insert(round_for_payout, &sole_collator, CollatorSnapshot {
+			bond: 1_000u32.into(),
+			delegations,
+			total: 1_000_000u32.into(),
+		});
+
+		<Points<T>>::insert(round_for_payout, 100);
+		<AwardedPts<T>>::insert(round_for_payout, &sole_collator, 20);
+
+	}: {
+		let round_for_payout = 5;
+		let payout_info = Pallet::<T>::delayed_payouts(round_for_payout).expect("payout expected");
+		let result = Pallet::<T>::pay_one_collator_reward(round_for_payout, payout_info);
+		assert!(matches!(result.0, RewardPayment::Paid));
+	}
+	verify {
+		// collator should have been paid
+		assert!(
+			T::Currency::free_balance(&sole_collator) > initial_stake_amount,
+			"collator should have been paid in pay_one_collator_reward"
+		);
+		// delegator rewards should have been accrued rather than paid
+		if y > 0 {
+			assert!(<DelegatorRewardPools<T>>::contains_key(5, &sole_collator));
+		}
+		for delegator in &delegators {
+			assert_eq!(T::Currency::free_balance(&delegator), initial_delegator_balance);
+		}
+	}
+
 	base_on_initialize {
 		let collator: T::AccountId = create_funded_collator::<T>(
 			"collator",
@@ -31,6 +31,8 @@
 //! Immediately following a round change, payments are made once-per-block until all payments have
 //! been made. In each such block, one collator is chosen for a rewards payment and is paid along
 //! with each of its top `T::MaxTopDelegationsPerCandidate` delegators.
+//! If the reward payment mode is set to `Claim`, only the collator is paid and the delegators
+//! share accrues until it is claimed with `claim_rewards`.
 //!
 //! To join the set of candidates, call `join_candidates` with `bond >= MinCandidateStk`.
 //! To leave the set of candidates, call `schedule_leave_candidates`. If the call succeeds,
@@ -245,6 +247,10 @@ pub mod pallet {
 		RemovedCall,
 		MarkingOfflineNotEnabled,
 		CurrentRoundTooLow,
+		TotalInflationDistributionPercentExceeds100,
+		InflationDistributionIndexOutOfBounds,
+		TooManyRoundsToClaim,
+		NoRewardsToClaim,
 	}
 
 	#[pallet::event]
@@ -390,6 +396,7 @@ pub mod pallet {
 			account: T::AccountId,
 			rewards: BalanceOf<T>,
 		},
+		/// DEPRECATED: replaced by `InflationDistributed`, no longer emitted.
 		/// Transferred to account which holds funds reserved for parachain bond.
 		ReservedForParachainBond {
 			account: T::AccountId,
@@ -443,6 +450,42 @@ pub mod pallet {
 			delegator: T::AccountId,
 			amount: BalanceOf<T>,
 		},
+		/// Transferred to the account at `index` of the inflation distribution config, which
+		/// holds funds reserved from the round inflation.
+		InflationDistributed {
+			index: u32,
+			account: T::AccountId,
+			value: BalanceOf<T>,
+		},
+		/// Inflation distribution config (re)set.
+		InflationDistributionConfigUpdated {
+			old: InflationDistributionConfig<T::AccountId>,
+			new: InflationDistributionConfig<T::AccountId>,
+		},
+		/// Account at `index` of the inflation distribution config (re)set, `old` is the default
+		/// account if the entry was appended to the config.
+		InflationDistributionAccountSet {
+			index: u32,
+			old: InflationDistributionAccount<T::AccountId>,
+			new: InflationDistributionAccount<T::AccountId>,
+		},
+		/// Reward payment mode (re)set.
+		RewardPaymentModeSet {
+			old: RewardPaymentMode,
+			new: RewardPaymentMode,
+		},
+		/// Delegator rewards accrued to be claimed for the collator and round.
+		DelegatorRewardsAccrued {
+			round: RoundIndex,
+			collator: T::AccountId,
+			rewards: BalanceOf<T>,
+		},
+		/// Delegator claimed its accrued rewards for the given rounds.
+		RewardsClaimed {
+			delegator: T::AccountId,
+			rounds: Vec<RoundIndex>,
+			rewards: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::hooks]
@@ -500,10 +543,11 @@ pub mod pallet {
 	pub(crate) type TotalSelected<T: Config> = StorageValue<_, u32, ValueQuery>;
 
 	#[pallet::storage]
-	#[pallet::getter(fn parachain_bond_info)]
-	/// Parachain bond config info { account, percent_of_inflation }
-	pub(crate) type ParachainBondInfo<T: Config> =
-		StorageValue<_, ParachainBondConfig<T::AccountId>, ValueQuery>;
+	#[pallet::getter(fn inflation_distribution_info)]
+	/// Inflation distribution config, a list of { account, percent_of_inflation }.
+	/// The first entry is the parachain bond reserve.
+	pub(crate) type InflationDistributionInfo<T: Config> =
+		StorageValue<_, InflationDistributionConfig<T::AccountId>, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn round)]
@@ -657,6 +701,24 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn reward_payment_mode)]
+	/// Whether delegator rewards are paid automatically or accrue until claimed
+	pub type RewardMode<T: Config> = StorageValue<_, RewardPaymentMode, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn delegator_reward_pool)]
+	/// Delegator rewards of a collator for a round that have not been fully claimed yet
+	pub type DelegatorRewardPools<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		RoundIndex,
+		Twox64Concat,
+		T::AccountId,
+		DelegatorRewardPool<T::AccountId, BalanceOf<T>, T::MaxTopDelegationsPerCandidate>,
+		OptionQuery,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn marking_offline)]
 	/// Killswitch to enable/disable marking offline feature.
@@ -774,12 +836,16 @@ pub mod pallet {
 			// Set collator commission to default config
 			<CollatorCommission<T>>::put(self.collator_commission);
 			// Set parachain bond config to default config
-			<ParachainBondInfo<T>>::put(ParachainBondConfig {
+			let parachain_bond_reserve = InflationDistributionAccount {
 				// must be set soon; if not => due inflation will be sent to collators/delegators
 				account: T::AccountId::decode(&mut sp_runtime::traits::TrailingZeroInput::zeroes())
 					.expect("infinite length input; no invalid inputs for type; qed"),
 				percent: self.parachain_bond_reserve_percent,
-			});
+			};
+			<InflationDistributionInfo<T>>::put(
+				InflationDistributionConfig::<T::AccountId>::try_from(vec![parachain_bond_reserve])
+					.expect("MAX_INFLATION_DISTRIBUTION_ACCOUNTS is not zero; qed"),
+			);
 			// Set total selected candidates to value from config
 			assert!(
 				self.num_selected_candidates >= T::MinSelectedCandidates::get(),
@@ -862,6 +928,7 @@ pub mod pallet {
 		}
 
 		/// Set the account that will hold funds set aside for parachain bond
+		/// - updates the first entry of the inflation distribution config
 		#[pallet::call_index(2)]
 		#[pallet::weight(<T as Config>::WeightInfo::set_parachain_bond_account())]
 		pub fn set_parachain_bond_account(
@@ -869,20 +936,16 @@ pub mod pallet {
 			new: T::AccountId,
 		) -> DispatchResultWithPostInfo {
 			T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
-			let ParachainBondConfig {
-				account: old,
-				percent,
-			} = <ParachainBondInfo<T>>::get();
-			ensure!(old != new, Error::<T>::NoWritingSameValue);
-			<ParachainBondInfo<T>>::put(ParachainBondConfig {
-				account: new.clone(),
-				percent,
-			});
+			let old = Self::mutate_inflation_distribution_account(0, |reserve| {
+				ensure!(reserve.account != new, Error::<T>::NoWritingSameValue);
+				Ok(sp_std::mem::replace(&mut reserve.account, new.clone()))
+			})?;
 			Self::deposit_event(Event::ParachainBondAccountSet { old, new });
 			Ok(().into())
 		}
 
 		/// Set the percent of inflation set aside for parachain bond
+		/// - updates the first entry of the inflation distribution config
 		#[pallet::call_index(3)]
 		#[pallet::weight(<T as Config>::WeightInfo::set_parachain_bond_reserve_percent())]
 		pub fn set_parachain_bond_reserve_percent(
@@ -890,15 +953,10 @@ pub mod pallet {
 			new: Percent,
 		) -> DispatchResultWithPostInfo {
 			T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
-			let ParachainBondConfig {
-				account,
-				percent: old,
-			} = <ParachainBondInfo<T>>::get();
-			ensure!(old != new, Error::<T>::NoWritingSameValue);
-			<ParachainBondInfo<T>>::put(ParachainBondConfig {
-				account,
-				percent: new,
-			});
+			let old = Self::mutate_inflation_distribution_account(0, |reserve| {
+				ensure!(reserve.percent != new, Error::<T>::NoWritingSameValue);
+				Ok(sp_std::mem::replace(&mut reserve.percent, new))
+			})?;
 			Self::deposit_event(Event::ParachainBondReservePercentSet { old, new });
 			Ok(().into())
 		}
@@ -1454,6 +1512,139 @@ pub mod pallet {
 			T::MonetaryGovernanceOrigin::ensure_origin(origin.clone())?;
 			Self::join_candidates_inner(account, bond, candidate_count)
 		}
+
+		/// Set the inflation distribution config, the list of accounts that receive a
+		/// percent of the round inflation before it is paid to collators and delegators.
+		/// The first entry is the parachain bond reserve.
+		/// - the sum of all percents must not exceed 100%
+		#[pallet::call_index(32)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_inflation_distribution_config())]
+		pub fn set_inflation_distribution_config(
+			origin: OriginFor<T>,
+			new: InflationDistributionConfig<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+			ensure!(
+				total_inflation_distribution_percent(&new).is_some(),
+				Error::<T>::TotalInflationDistributionPercentExceeds100
+			);
+			let old = <InflationDistributionInfo<T>>::get();
+			ensure!(old != new, Error::<T>::NoWritingSameValue);
+			<InflationDistributionInfo<T>>::put(new.clone());
+			Self::deposit_event(Event::InflationDistributionConfigUpdated { old, new });
+			Ok(().into())
+		}
+
+		/// Claim the delegator rewards accrued for the given rounds, from the reward pool of
+		/// every collator it was delegating to in those rounds. Any account may claim on behalf
+		/// of a delegator, including one that has since revoked the delegation or left the
+		/// delegator set.
+		/// - the delegator's current auto-compound preferences are applied to the claimed rewards
+		/// - rewards accrue only while the reward payment mode is `Claim`
+		#[pallet::call_index(33)]
+		#[pallet::weight(Pallet::<T>::claim_rewards_weight(rounds.len() as u32))]
+		pub fn claim_rewards(
+			origin: OriginFor<T>,
+			delegator: T::AccountId,
+			rounds: Vec<RoundIndex>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			ensure!(
+				rounds.len() as u32 <= MAX_CLAIM_ROUNDS,
+				Error::<T>::TooManyRoundsToClaim
+			);
+
+			let mut unique_rounds = rounds.clone();
+			unique_rounds.sort_unstable();
+			unique_rounds.dedup();
+
+			let mut due_per_candidate: BTreeMap<T::AccountId, BalanceOf<T>> = BTreeMap::new();
+			let mut other_pools = 0u32;
+			let mut claimed_pools = 0u32;
+			for round in unique_rounds {
+				// there is at most one pool per collator selected in the round
+				let pools: Vec<_> = <DelegatorRewardPools<T>>::iter_prefix(round).collect();
+				for (candidate, mut pool) in pools {
+					let Some(amount) = pool.take_unclaimed(&delegator) else {
+						other_pools = other_pools.saturating_add(1);
+						continue;
+					};
+					claimed_pools = claimed_pools.saturating_add(1);
+					let due = Self::delegator_reward_share(&pool, amount);
+					if pool.unclaimed.is_empty() {
+						<DelegatorRewardPools<T>>::remove(round, &candidate);
+					} else {
+						<DelegatorRewardPools<T>>::insert(round, &candidate, pool);
+					}
+					let total_due = due_per_candidate.entry(candidate).or_default();
+					*total_due = total_due.saturating_add(due);
+				}
+			}
+			ensure!(!due_per_candidate.is_empty(), Error::<T>::NoRewardsToClaim);
+
+			// the claim fails as a whole if any reward cannot be minted, so that the pools keep
+			// the delegator's share
+			let mut rewards = BalanceOf::<T>::zero();
+			for (candidate, due) in due_per_candidate {
+				let minted = Self::try_mint_and_compound(
+					due,
+					Self::delegation_auto_compound(&candidate, &delegator),
+					candidate,
+					delegator.clone(),
+				)?;
+				rewards = rewards.saturating_add(minted);
+			}
+			let actual_weight = <T as Config>::WeightInfo::claim_rewards(
+				other_pools,
+				T::MaxTopDelegationsPerCandidate::get(),
+				claimed_pools,
+			);
+			Self::deposit_event(Event::RewardsClaimed {
+				delegator,
+				rounds,
+				rewards,
+			});
+
+			Ok(Some(actual_weight).into())
+		}
+
+		/// Set whether delegator rewards are paid automatically (`Push`) or accrue until claimed
+		/// (`Claim`).
+		/// - the mode applies to collator payouts made after the change, rewards that accrued
+		/// under `Claim` remain claimable after switching back to `Push`
+		#[pallet::call_index(34)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_reward_payment_mode())]
+		pub fn set_reward_payment_mode(
+			origin: OriginFor<T>,
+			new: RewardPaymentMode,
+		) -> DispatchResultWithPostInfo {
+			T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+			let old = <RewardMode<T>>::get();
+			ensure!(old != new, Error::<T>::NoWritingSameValue);
+			<RewardMode<T>>::put(new);
+			Self::deposit_event(Event::RewardPaymentModeSet { old, new });
+			Ok(().into())
+		}
+
+		/// Set the account and percent of inflation at `index` of the inflation distribution
+		/// config, leaving the other entries unchanged.
+		/// - `index` may be the length of the config to append a new entry
+		/// - the sum of all percents must not exceed 100%
+		#[pallet::call_index(35)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_inflation_distribution_account())]
+		pub fn set_inflation_distribution_account(
+			origin: OriginFor<T>,
+			index: u32,
+			new: InflationDistributionAccount<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+			let old = Self::mutate_inflation_distribution_account(index, |entry| {
+				ensure!(*entry != new, Error::<T>::NoWritingSameValue);
+				Ok(sp_std::mem::replace(entry, new.clone()))
+			})?;
+			Self::deposit_event(Event::InflationDistributionAccountSet { index, old, new });
+			Ok(().into())
+		}
 	}
 
 	/// Represents a payout made via `pay_one_collator_reward`.
@@ -1762,6 +1953,30 @@ pub mod pallet {
 			<CandidatePool<T>>::put(candidates);
 		}
 
+		/// Apply `f` to the entry at `index` of the inflation distribution config, appending a
+		/// default entry if `index` is the length of the config.
+		fn mutate_inflation_distribution_account<R>(
+			index: u32,
+			f: impl FnOnce(&mut InflationDistributionAccount<T::AccountId>) -> Result<R, DispatchError>,
+		) -> Result<R, DispatchError> {
+			let mut config = <InflationDistributionInfo<T>>::get();
+			let index = index as usize;
+			if index == config.len() {
+				config
+					.try_push(InflationDistributionAccount::default())
+					.map_err(|_| Error::<T>::InflationDistributionIndexOutOfBounds)?;
+			}
+			let result = f(config
+				.get_mut(index)
+				.ok_or(Error::<T>::InflationDistributionIndexOutOfBounds)?)?;
+			ensure!(
+				total_inflation_distribution_percent(&config).is_some(),
+				Error::<T>::TotalInflationDistributionPercentExceeds100
+			);
+			<InflationDistributionInfo<T>>::put(config);
+			Ok(result)
+		}
+
 		/// Compute round issuance based on total staked for the given round
 		fn compute_issuance(staked: BalanceOf<T>) -> BalanceOf<T> {
 			let config = <InflationConfig<T>>::get();
@@ -1812,18 +2027,21 @@ pub mod pallet {
 			let total_staked = <Staked<T>>::take(round_to_payout);
 			let total_issuance = Self::compute_issuance(total_staked);
 			let mut left_issuance = total_issuance;
-			// reserve portion of issuance for parachain bond account
-			let bond_config = <ParachainBondInfo<T>>::get();
-			let parachain_bond_reserve = bond_config.percent * total_issuance;
-			if let Ok(imb) =
-				T::Currency::deposit_into_existing(&bond_config.account, parachain_bond_reserve)
+			// reserve portions of issuance for each inflation distribution account
+			let distribution_config = <InflationDistributionInfo<T>>::get();
+			for (index, InflationDistributionAccount { account, percent }) in
+				distribution_config.into_iter().enumerate()
 			{
-				// update round issuance iff transfer succeeds
-				left_issuance = left_issuance.saturating_sub(imb.peek());
-				Self::deposit_event(Event::ReservedForParachainBond {
-					account: bond_config.account,
-					value: imb.peek(),
-				});
+				let reserve = percent * total_issuance;
+				if let Ok(imb) = T::Currency::deposit_into_existing(&account, reserve) {
+					// update round issuance iff transfer succeeds
+					left_issuance = left_issuance.saturating_sub(imb.peek());
+					Self::deposit_event(Event::InflationDistributed {
+						index: index as u32,
+						account,
+						value: imb.peek(),
+					});
+				}
 			}
 
 			let payout = DelayedPayout {
@@ -1915,6 +2133,7 @@ pub mod pallet {
 				let mut amt_due = total_paid;
 
 				let num_delegators = state.delegations.len();
+				let claim_mode = matches!(<RewardMode<T>>::get(), RewardPaymentMode::Claim);
 				let mut num_paid_delegations = 0u32;
 				let mut num_auto_compounding = 0u32;
 				let num_scheduled_requests =
@@ -1950,24 +2169,34 @@ pub mod pallet {
 							collator_reward,
 						));
 
-					// pay delegators due portion
-					for BondWithAutoCompound {
-						owner,
-						amount,
-						auto_compound,
-					} in state.delegations
-					{
-						let percent = Perbill::from_rational(amount, state.total);
-						let due = percent * amt_due;
-						if !due.is_zero() {
-							num_auto_compounding += if auto_compound.is_zero() { 0 } else { 1 };
-							num_paid_delegations += 1u32;
-							Self::mint_and_compound(
-								due,
-								auto_compound.clone(),
-								collator.clone(),
-								owner.clone(),
-							);
+					if claim_mode {
+						// accrue delegators due portion, to be claimed later
+						Self::accrue_delegator_rewards(
+							paid_for_round,
+							collator.clone(),
+							amt_due,
+							state,
+						);
+					} else {
+						// pay delegators due portion
+						for BondWithAutoCompound {
+							owner,
+							amount,
+							auto_compound,
+						} in state.delegations
+						{
+							let percent = Perbill::from_rational(amount, state.total);
+							let due = percent * amt_due;
+							if !due.is_zero() {
+								num_auto_compounding += if auto_compound.is_zero() { 0 } else { 1 };
+								num_paid_delegations += 1u32;
+								Self::mint_and_compound(
+									due,
+									auto_compound.clone(),
+									collator.clone(),
+									owner.clone(),
+								);
+							}
 						}
 					}
 				}
@@ -1979,10 +2208,14 @@ pub mod pallet {
 						num_scheduled_requests as u32,
 					));
 
+				let payout_weight = if claim_mode {
+					T::WeightInfo::pay_one_collator_reward_claim(num_delegators as u32)
+				} else {
+					T::WeightInfo::pay_one_collator_reward(num_delegators as u32)
+				};
 				(
 					RewardPayment::Paid,
-					T::WeightInfo::pay_one_collator_reward(num_delegators as u32)
-						.saturating_add(extra_weight),
+					payout_weight.saturating_add(extra_weight),
 				)
 			} else {
 				// Note that we don't clean up storage here; it is cleaned up in
@@ -1991,6 +2224,102 @@ pub mod pallet {
 			}
 		}
 
+		/// Store the delegators due portion of a collator's round rewards so that each delegator
+		/// can claim its share later with `claim_rewards`.
+		fn accrue_delegator_rewards(
+			round: RoundIndex,
+			collator: T::AccountId,
+			rewards: BalanceOf<T>,
+			snapshot: CollatorSnapshot<T::AccountId, BalanceOf<T>>,
+		) {
+			// the snapshot only holds the rewardable (top) delegations of the collator
+			let unclaimed = BoundedVec::truncate_from(
+				snapshot
+					.delegations
+					.into_iter()
+					.filter(|bond| !bond.amount.is_zero())
+					.map(|bond| Bond {
+						owner: bond.owner,
+						amount: bond.amount,
+					})
+					.collect(),
+			);
+			if unclaimed.is_empty() || rewards.is_zero() {
+				return;
+			}
+			<DelegatorRewardPools<T>>::insert(
+				round,
+				&collator,
+				DelegatorRewardPool {
+					rewards,
+					total: snapshot.total,
+					unclaimed,
+				},
+			);
+			Self::deposit_event(Event::DelegatorRewardsAccrued {
+				round,
+				collator,
+				rewards,
+			});
+		}
+
+		/// The delegator's share of the collator's reward pool for the round that it has not
+		/// claimed yet, if any. This is the amount `claim_rewards` would pay for that pool.
+		pub fn pending_delegator_reward(
+			round: RoundIndex,
+			collator: &T::AccountId,
+			delegator: &T::AccountId,
+		) -> Option<BalanceOf<T>> {
+			let pool = <DelegatorRewardPools<T>>::get(round, collator)?;
+			let amount = *pool.unclaimed_of(delegator)?;
+			Some(Self::delegator_reward_share(&pool, amount))
+		}
+
+		/// Share of the pool rewards due to a delegation counted with `amount`
+		pub fn delegator_reward_share(
+			pool: &DelegatorRewardPool<
+				T::AccountId,
+				BalanceOf<T>,
+				T::MaxTopDelegationsPerCandidate,
+			>,
+			amount: BalanceOf<T>,
+		) -> BalanceOf<T> {
+			Perbill::from_rational(amount, pool.total) * pool.rewards
+		}
+
+		/// Estimated rewards of a delegation counted with `amount` in the snapshot of a collator
+		/// awarded `collator_points` in a round that has not been paid out yet. This is the
+		/// amount `pay_one_collator_reward` will pay (or accrue) for the delegation.
+		pub fn estimated_delegator_reward(
+			payout: &DelayedPayout<BalanceOf<T>>,
+			total_points: RewardPoint,
+			collator_points: RewardPoint,
+			snapshot_total: BalanceOf<T>,
+			amount: BalanceOf<T>,
+		) -> BalanceOf<T> {
+			if total_points.is_zero() {
+				return Zero::zero();
+			}
+			let pct_due = Perbill::from_rational(collator_points, total_points);
+			let commission = pct_due * (payout.collator_commission * payout.round_issuance);
+			let delegators_due = (pct_due * payout.total_staking_reward).saturating_sub(commission);
+			Perbill::from_rational(amount, snapshot_total) * delegators_due
+		}
+
+		/// Weight charged before claiming the delegator rewards of `rounds` rounds. Every
+		/// collator selected in a round may have a reward pool, the delegator being in at most
+		/// `MaxDelegationsPerDelegator` of them.
+		fn claim_rewards_weight(rounds: u32) -> Weight {
+			let pools = rounds.saturating_mul(<TotalSelected<T>>::get());
+			let claimed_pools =
+				pools.min(rounds.saturating_mul(T::MaxDelegationsPerDelegator::get()));
+			<T as Config>::WeightInfo::claim_rewards(
+				pools.saturating_sub(claimed_pools),
+				T::MaxTopDelegationsPerCandidate::get(),
+				claimed_pools,
+			)
+		}
+
 		/// Compute the top `TotalSelected` candidates in the CandidatePool and return
 		/// a vec of their AccountIds (sorted by AccountId).
 		///
@@ -2234,39 +2563,50 @@ pub mod pallet {
 			candidate: T::AccountId,
 			delegator: T::AccountId,
 		) {
-			if let Ok(amount_transferred) =
-				T::Currency::deposit_into_existing(&delegator, amt.clone())
-			{
-				Self::deposit_event(Event::Rewarded {
-					account: delegator.clone(),
-					rewards: amount_transferred.peek(),
-				});
+			let _ = Self::try_mint_and_compound(amt, compound_percent, candidate, delegator);
+		}
 
-				let compound_amount = compound_percent.mul_ceil(amount_transferred.peek());
-				if compound_amount.is_zero() {
-					return;
-				}
+		/// Mint and compound delegation rewards like `mint_and_compound`, returning the minted
+		/// amount or the error preventing the rewards from being minted. A failure to compound
+		/// does not prevent the rewards from being minted.
+		pub fn try_mint_and_compound(
+			amt: BalanceOf<T>,
+			compound_percent: Percent,
+			candidate: T::AccountId,
+			delegator: T::AccountId,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let amount_transferred = T::Currency::deposit_into_existing(&delegator, amt.clone())?;
+			let minted = amount_transferred.peek();
+			Self::deposit_event(Event::Rewarded {
+				account: delegator.clone(),
+				rewards: minted,
+			});
 
-				if let Err(err) = Self::delegation_bond_more_without_event(
-					delegator.clone(),
-					candidate.clone(),
-					compound_amount.clone(),
-				) {
-					log::debug!(
-						"skipped compounding staking reward towards candidate '{:?}' for delegator '{:?}': {:?}",
-						candidate,
-						delegator,
-						err
-					);
-					return;
-				};
+			let compound_amount = compound_percent.mul_ceil(minted);
+			if compound_amount.is_zero() {
+				return Ok(minted);
+			}
 
-				Pallet::<T>::deposit_event(Event::Compounded {
-					delegator,
+			if let Err(err) = Self::delegation_bond_more_without_event(
+				delegator.clone(),
+				candidate.clone(),
+				compound_amount.clone(),
+			) {
+				log::debug!(
+					"skipped compounding staking reward towards candidate '{:?}' for delegator '{:?}': {:?}",
 					candidate,
-					amount: compound_amount.clone(),
-				});
+					delegator,
+					err
+				);
+				return Ok(minted);
 			};
+
+			Pallet::<T>::deposit_event(Event::Compounded {
+				delegator,
+				candidate,
+				amount: compound_amount.clone(),
+			});
+			Ok(minted)
 		}
 	}
 
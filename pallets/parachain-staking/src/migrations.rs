@@ -15,3 +15,64 @@
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 
 //! # Migrations
+
+use crate::{
+	types::ParachainBondConfig, Config, InflationDistributionAccount, InflationDistributionConfig,
+	InflationDistributionInfo, Pallet,
+};
+use frame_support::{pallet_prelude::*, storage_alias, traits::OnRuntimeUpgrade};
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Deprecated storage item replaced by `InflationDistributionInfo`.
+#[storage_alias]
+pub type ParachainBondInfo<T: Config> = StorageValue<
+	Pallet<T>,
+	ParachainBondConfig<<T as frame_system::Config>::AccountId>,
+	OptionQuery,
+>;
+
+/// Migrates the single parachain bond reserve `ParachainBondInfo` to the first entry of the
+/// inflation distribution config `InflationDistributionInfo`.
+pub struct MigrateParachainBondConfig<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateParachainBondConfig<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let Some(ParachainBondConfig { account, percent }) = ParachainBondInfo::<T>::take() else {
+			log::info!("ParachainBondInfo already migrated, skipping");
+			return T::DbWeight::get().reads(1);
+		};
+		let config = InflationDistributionConfig::<T::AccountId>::try_from(sp_std::vec![
+			InflationDistributionAccount { account, percent },
+		])
+		.expect("MAX_INFLATION_DISTRIBUTION_ACCOUNTS is not zero; qed");
+		InflationDistributionInfo::<T>::put(config);
+		T::DbWeight::get().reads_writes(1, 2)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+		Ok(ParachainBondInfo::<T>::get().encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+		let old = Option::<ParachainBondConfig<T::AccountId>>::decode(&mut &state[..])
+			.map_err(|_| sp_runtime::DispatchError::Other("invalid pre_upgrade state"))?;
+		ensure!(
+			ParachainBondInfo::<T>::get().is_none(),
+			"ParachainBondInfo should be removed"
+		);
+		if let Some(ParachainBondConfig { account, percent }) = old {
+			let config = InflationDistributionInfo::<T>::get();
+			ensure!(
+				config.len() == 1,
+				"expected a single inflation distribution account"
+			);
+			ensure!(
+				config[0] == InflationDistributionAccount { account, percent },
+				"parachain bond config should be the first inflation distribution account"
+			);
+		}
+		Ok(())
+	}
+}
@@ -29,12 +29,14 @@ use crate::mock::{
 	Balances, BlockNumber, ExtBuilder, ParachainStaking, RuntimeOrigin, Test,
 };
 use crate::{
-	assert_events_emitted, assert_events_emitted_match, assert_events_eq, assert_no_events,
-	AtStake, Bond, CollatorStatus, DelegationScheduledRequests, DelegatorAdded,
-	EnableMarkingOffline, Error, Event, Range, DELEGATOR_LOCK_ID,
+	assert_events_emitted, assert_events_emitted_match, assert_events_eq,
+	assert_events_not_emitted_match, assert_no_events, AtStake, Bond, CollatorStatus,
+	DelegationScheduledRequests, DelegatorAdded, EnableMarkingOffline, Error, Event,
+	InflationDistributionAccount, InflationDistributionConfig, Range, RewardPaymentMode,
+	DELEGATOR_LOCK_ID, MAX_CLAIM_ROUNDS,
 };
 use frame_support::{assert_err, assert_noop, assert_ok, pallet_prelude::*, BoundedVec};
-use sp_runtime::{traits::Zero, DispatchError, ModuleError, Perbill, Percent};
+use sp_runtime::{traits::Zero, ArithmeticError, DispatchError, ModuleError, Perbill, Percent};
 
 // ~~ ROOT ~~
 
@@ -620,12 +622,18 @@ fn set_parachain_bond_account_event_emits_correctly() {
 #[test]
 fn set_parachain_bond_account_storage_updates_correctly() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_eq!(ParachainStaking::parachain_bond_info().account, 0);
+		assert_eq!(
+			ParachainStaking::inflation_distribution_info()[0].account,
+			0
+		);
 		assert_ok!(ParachainStaking::set_parachain_bond_account(
 			RuntimeOrigin::root(),
 			11
 		));
-		assert_eq!(ParachainStaking::parachain_bond_info().account, 11);
+		assert_eq!(
+			ParachainStaking::inflation_distribution_info()[0].account,
+			11
+		);
 	});
 }
 
@@ -649,7 +657,7 @@ fn set_parachain_bond_reserve_percent_event_emits_correctly() {
 fn set_parachain_bond_reserve_percent_storage_updates_correctly() {
 	ExtBuilder::default().build().execute_with(|| {
 		assert_eq!(
-			ParachainStaking::parachain_bond_info().percent,
+			ParachainStaking::inflation_distribution_info()[0].percent,
 			Percent::from_percent(30)
 		);
 		assert_ok!(ParachainStaking::set_parachain_bond_reserve_percent(
@@ -657,7 +665,7 @@ fn set_parachain_bond_reserve_percent_storage_updates_correctly() {
 			Percent::from_percent(50)
 		));
 		assert_eq!(
-			ParachainStaking::parachain_bond_info().percent,
+			ParachainStaking::inflation_distribution_info()[0].percent,
 			Percent::from_percent(50)
 		);
 	});
@@ -676,6 +684,271 @@ fn cannot_set_same_parachain_bond_reserve_percent() {
 	});
 }
 
+#[test]
+fn cannot_set_parachain_bond_reserve_percent_if_total_exceeds_100() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ParachainStaking::set_inflation_distribution_config(
+			RuntimeOrigin::root(),
+			inflation_distribution_config(vec![(0, 30), (12, 50)]),
+		));
+		assert_noop!(
+			ParachainStaking::set_parachain_bond_reserve_percent(
+				RuntimeOrigin::root(),
+				Percent::from_percent(51)
+			),
+			Error::<Test>::TotalInflationDistributionPercentExceeds100
+		);
+	});
+}
+
+// SET INFLATION DISTRIBUTION CONFIG
+
+fn inflation_distribution_config(accounts: Vec<(u64, u8)>) -> InflationDistributionConfig<u64> {
+	accounts
+		.into_iter()
+		.map(|(account, percent)| InflationDistributionAccount {
+			account,
+			percent: Percent::from_percent(percent),
+		})
+		.collect::<Vec<_>>()
+		.try_into()
+		.expect("within MAX_INFLATION_DISTRIBUTION_ACCOUNTS")
+}
+
+#[test]
+fn set_inflation_distribution_config_event_emits_correctly() {
+	ExtBuilder::default().build().execute_with(|| {
+		let new = inflation_distribution_config(vec![(11, 30), (12, 10), (13, 5)]);
+		assert_ok!(ParachainStaking::set_inflation_distribution_config(
+			RuntimeOrigin::root(),
+			new.clone(),
+		));
+		assert_events_eq!(Event::InflationDistributionConfigUpdated {
+			old: inflation_distribution_config(vec![(0, 30)]),
+			new,
+		});
+	});
+}
+
+#[test]
+fn set_inflation_distribution_config_storage_updates_correctly() {
+	ExtBuilder::default().build().execute_with(|| {
+		let new = inflation_distribution_config(vec![(11, 30), (12, 10)]);
+		assert_ok!(ParachainStaking::set_inflation_distribution_config(
+			RuntimeOrigin::root(),
+			new.clone(),
+		));
+		assert_eq!(ParachainStaking::inflation_distribution_info(), new);
+	});
+}
+
+#[test]
+fn cannot_set_same_inflation_distribution_config() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ParachainStaking::set_inflation_distribution_config(
+				RuntimeOrigin::root(),
+				inflation_distribution_config(vec![(0, 30)]),
+			),
+			Error::<Test>::NoWritingSameValue
+		);
+	});
+}
+
+#[test]
+fn cannot_set_inflation_distribution_config_above_100_percent() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ParachainStaking::set_inflation_distribution_config(
+				RuntimeOrigin::root(),
+				inflation_distribution_config(vec![(11, 60), (12, 41)]),
+			),
+			Error::<Test>::TotalInflationDistributionPercentExceeds100
+		);
+	});
+}
+
+#[test]
+fn set_inflation_distribution_config_requires_monetary_governance_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ParachainStaking::set_inflation_distribution_config(
+				RuntimeOrigin::signed(11),
+				inflation_distribution_config(vec![(11, 30)]),
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_inflation_distribution_account_updates_only_the_given_index() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ParachainStaking::set_inflation_distribution_config(
+			RuntimeOrigin::root(),
+			inflation_distribution_config(vec![(11, 30), (12, 10)]),
+		));
+		let new = InflationDistributionAccount {
+			account: 13,
+			percent: Percent::from_percent(20),
+		};
+		assert_ok!(ParachainStaking::set_inflation_distribution_account(
+			RuntimeOrigin::root(),
+			1,
+			new.clone(),
+		));
+		assert_events_emitted!(Event::InflationDistributionAccountSet {
+			index: 1,
+			old: InflationDistributionAccount {
+				account: 12,
+				percent: Percent::from_percent(10),
+			},
+			new,
+		});
+		assert_eq!(
+			ParachainStaking::inflation_distribution_info(),
+			inflation_distribution_config(vec![(11, 30), (13, 20)])
+		);
+	});
+}
+
+#[test]
+fn set_inflation_distribution_account_appends_at_the_config_length() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ParachainStaking::set_inflation_distribution_account(
+			RuntimeOrigin::root(),
+			1,
+			InflationDistributionAccount {
+				account: 12,
+				percent: Percent::from_percent(10),
+			},
+		));
+		assert_eq!(
+			ParachainStaking::inflation_distribution_info(),
+			inflation_distribution_config(vec![(0, 30), (12, 10)])
+		);
+	});
+}
+
+#[test]
+fn cannot_set_inflation_distribution_account_past_the_config_length() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ParachainStaking::set_inflation_distribution_account(
+				RuntimeOrigin::root(),
+				2,
+				InflationDistributionAccount {
+					account: 12,
+					percent: Percent::from_percent(10),
+				},
+			),
+			Error::<Test>::InflationDistributionIndexOutOfBounds
+		);
+	});
+}
+
+#[test]
+fn cannot_set_inflation_distribution_account_above_100_percent() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ParachainStaking::set_inflation_distribution_account(
+				RuntimeOrigin::root(),
+				1,
+				InflationDistributionAccount {
+					account: 12,
+					percent: Percent::from_percent(71),
+				},
+			),
+			Error::<Test>::TotalInflationDistributionPercentExceeds100
+		);
+	});
+}
+
+#[test]
+fn set_inflation_distribution_account_requires_monetary_governance_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ParachainStaking::set_inflation_distribution_account(
+				RuntimeOrigin::signed(11),
+				0,
+				InflationDistributionAccount {
+					account: 11,
+					percent: Percent::from_percent(30),
+				},
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn inflation_is_distributed_to_all_configured_accounts() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (11, 1), (12, 1), (13, 1)])
+		.with_candidates(vec![(1, 20), (2, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_inflation_distribution_config(
+				RuntimeOrigin::root(),
+				inflation_distribution_config(vec![(11, 30), (12, 20), (13, 0)]),
+			));
+			roll_to_round_begin(2);
+			set_author(2, 1, 100);
+			roll_to_round_begin(4);
+
+			let payout = ParachainStaking::delayed_payouts(2).expect("payout must be scheduled");
+			let reserve_11 = Percent::from_percent(30) * payout.round_issuance;
+			let reserve_12 = Percent::from_percent(20) * payout.round_issuance;
+			assert!(!reserve_11.is_zero());
+			assert!(!reserve_12.is_zero());
+			assert_events_emitted!(
+				Event::InflationDistributed {
+					index: 0,
+					account: 11,
+					value: reserve_11,
+				},
+				Event::InflationDistributed {
+					index: 1,
+					account: 12,
+					value: reserve_12,
+				},
+				Event::InflationDistributed {
+					index: 2,
+					account: 13,
+					value: 0,
+				},
+			);
+			assert_eq!(Balances::free_balance(&11), 1 + reserve_11);
+			assert_eq!(Balances::free_balance(&12), 1 + reserve_12);
+			assert_eq!(Balances::free_balance(&13), 1);
+			assert_eq!(
+				payout.total_staking_reward,
+				payout.round_issuance - reserve_11 - reserve_12
+			);
+		});
+}
+
+#[test]
+fn migrate_parachain_bond_config_to_inflation_distribution_config() {
+	use crate::migrations::{MigrateParachainBondConfig, ParachainBondInfo};
+	use crate::ParachainBondConfig;
+	use frame_support::traits::OnRuntimeUpgrade;
+
+	ExtBuilder::default().build().execute_with(|| {
+		crate::InflationDistributionInfo::<Test>::kill();
+		ParachainBondInfo::<Test>::put(ParachainBondConfig {
+			account: 11,
+			percent: Percent::from_percent(25),
+		});
+		MigrateParachainBondConfig::<Test>::on_runtime_upgrade();
+		assert_eq!(ParachainBondInfo::<Test>::get(), None);
+		assert_eq!(
+			ParachainStaking::inflation_distribution_info(),
+			inflation_distribution_config(vec![(11, 25)])
+		);
+	});
+}
+
 // ~~ PUBLIC ~~
 
 // JOIN CANDIDATES
@@ -4034,7 +4307,8 @@ fn parachain_bond_inflation_reserve_matches_config() {
 				1,
 			));
 			assert_events_eq!(
-				Event::ReservedForParachainBond {
+				Event::InflationDistributed {
+					index: 0,
 					account: 11,
 					value: 15,
 				},
@@ -4097,7 +4371,8 @@ fn parachain_bond_inflation_reserve_matches_config() {
 			// fast forward to block in which delegator 6 exit executes
 			roll_to_round_begin(5);
 			assert_events_eq!(
-				Event::ReservedForParachainBond {
+				Event::InflationDistributed {
+					index: 0,
 					account: 11,
 					value: 16,
 				},
@@ -4159,7 +4434,8 @@ fn parachain_bond_inflation_reserve_matches_config() {
 				10
 			));
 			assert_events_eq!(
-				Event::ReservedForParachainBond {
+				Event::InflationDistributed {
+					index: 0,
 					account: 11,
 					value: 16,
 				},
@@ -4226,7 +4502,8 @@ fn parachain_bond_inflation_reserve_matches_config() {
 			);
 			roll_to_round_begin(7);
 			assert_events_eq!(
-				Event::ReservedForParachainBond {
+				Event::InflationDistributed {
+					index: 0,
 					account: 11,
 					value: 17,
 				},
@@ -4292,7 +4569,8 @@ fn parachain_bond_inflation_reserve_matches_config() {
 			roll_to_round_begin(8);
 			// keep paying 6
 			assert_events_eq!(
-				Event::ReservedForParachainBond {
+				Event::InflationDistributed {
+					index: 0,
 					account: 11,
 					value: 30,
 				},
@@ -4348,7 +4626,8 @@ fn parachain_bond_inflation_reserve_matches_config() {
 			roll_to_round_begin(9);
 			// no more paying 6
 			assert_events_eq!(
-				Event::ReservedForParachainBond {
+				Event::InflationDistributed {
+					index: 0,
 					account: 11,
 					value: 32,
 				},
@@ -4419,7 +4698,8 @@ fn parachain_bond_inflation_reserve_matches_config() {
 			roll_to_round_begin(10);
 			// new delegation is not rewarded yet
 			assert_events_eq!(
-				Event::ReservedForParachainBond {
+				Event::InflationDistributed {
+					index: 0,
 					account: 11,
 					value: 33,
 				},
@@ -4476,7 +4756,8 @@ fn parachain_bond_inflation_reserve_matches_config() {
 			roll_to_round_begin(11);
 			// new delegation is still not rewarded yet
 			assert_events_eq!(
-				Event::ReservedForParachainBond {
+				Event::InflationDistributed {
+					index: 0,
 					account: 11,
 					value: 35,
 				},
@@ -4531,7 +4812,8 @@ fn parachain_bond_inflation_reserve_matches_config() {
 			roll_to_round_begin(12);
 			// new delegation is rewarded, 2 rounds after joining (`RewardPaymentDelay` is 2)
 			assert_events_eq!(
-				Event::ReservedForParachainBond {
+				Event::InflationDistributed {
+					index: 0,
 					account: 11,
 					value: 37,
 				},
@@ -8768,3 +9050,369 @@ fn test_removed_calls() {
 		);
 	});
 }
+
+#[test]
+fn set_reward_payment_mode_event_emits_correctly() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ParachainStaking::set_reward_payment_mode(
+			RuntimeOrigin::root(),
+			RewardPaymentMode::Claim,
+		));
+		assert_events_eq!(Event::RewardPaymentModeSet {
+			old: RewardPaymentMode::Push,
+			new: RewardPaymentMode::Claim,
+		});
+		assert_eq!(
+			ParachainStaking::reward_payment_mode(),
+			RewardPaymentMode::Claim
+		);
+	});
+}
+
+#[test]
+fn cannot_set_same_reward_payment_mode() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ParachainStaking::set_reward_payment_mode(
+				RuntimeOrigin::root(),
+				RewardPaymentMode::Push,
+			),
+			Error::<Test>::NoWritingSameValue
+		);
+	});
+}
+
+#[test]
+fn test_rewards_accrue_instead_of_being_paid_in_claim_mode() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 200), (3, 200)])
+		.with_candidates(vec![(1, 100)])
+		.with_delegations(vec![(2, 1, 200), (3, 1, 200)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_reward_payment_mode(
+				RuntimeOrigin::root(),
+				RewardPaymentMode::Claim,
+			));
+			(2..=6).for_each(|round| set_author(round, 1, 1));
+			roll_to_round_begin(4);
+			roll_blocks(1);
+
+			// only the collator is paid, delegators rewards accrue
+			assert_events_emitted_match!(Event::Rewarded { account: 1, .. });
+			assert_events_emitted_match!(Event::DelegatorRewardsAccrued {
+				round: 2,
+				collator: 1,
+				..
+			});
+			assert_events_not_emitted_match!(Event::Rewarded { account: 2, .. });
+			assert_events_not_emitted_match!(Event::Rewarded { account: 3, .. });
+			assert_eq!(Balances::free_balance(&2), 200);
+			assert_eq!(Balances::free_balance(&3), 200);
+
+			let pool = ParachainStaking::delegator_reward_pool(2, 1).expect("pool must exist");
+			assert_eq!(pool.unclaimed.len(), 2);
+			assert!(!pool.rewards.is_zero());
+		});
+}
+
+#[test]
+fn test_claim_rewards_pays_accrued_rewards_and_cleans_up() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 200), (3, 200), (9, 1)])
+		.with_candidates(vec![(1, 100)])
+		.with_delegations(vec![(2, 1, 200), (3, 1, 200)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_reward_payment_mode(
+				RuntimeOrigin::root(),
+				RewardPaymentMode::Claim,
+			));
+			(2..=6).for_each(|round| set_author(round, 1, 1));
+			roll_to_round_begin(4);
+			roll_blocks(1);
+
+			let pool = ParachainStaking::delegator_reward_pool(2, 1).expect("pool must exist");
+			let due = Perbill::from_rational(200u128, pool.total) * pool.rewards;
+			assert!(!due.is_zero());
+			assert_eq!(
+				ParachainStaking::pending_delegator_reward(2, &1, &2),
+				Some(due)
+			);
+
+			// anyone can claim on behalf of a delegator
+			assert_ok!(ParachainStaking::claim_rewards(
+				RuntimeOrigin::signed(9),
+				2,
+				vec![2],
+			));
+			assert_events_emitted!(
+				Event::Rewarded {
+					account: 2,
+					rewards: due,
+				},
+				Event::RewardsClaimed {
+					delegator: 2,
+					rounds: vec![2],
+					rewards: due,
+				},
+			);
+			assert_eq!(Balances::free_balance(&2), 200 + due);
+			assert_eq!(ParachainStaking::pending_delegator_reward(2, &1, &2), None);
+			assert_eq!(
+				ParachainStaking::delegator_reward_pool(2, 1)
+					.expect("pool must exist")
+					.unclaimed
+					.len(),
+				1
+			);
+
+			// cannot claim twice
+			assert_noop!(
+				ParachainStaking::claim_rewards(RuntimeOrigin::signed(2), 2, vec![2]),
+				Error::<Test>::NoRewardsToClaim
+			);
+
+			// the pool is removed once every delegator has claimed
+			assert_ok!(ParachainStaking::claim_rewards(
+				RuntimeOrigin::signed(3),
+				3,
+				vec![2],
+			));
+			assert!(ParachainStaking::delegator_reward_pool(2, 1).is_none());
+		});
+}
+
+#[test]
+fn test_claim_rewards_applies_auto_compound_config_at_claim_time() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 200), (3, 200)])
+		.with_candidates(vec![(1, 100)])
+		.with_delegations(vec![(2, 1, 200), (3, 1, 200)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_reward_payment_mode(
+				RuntimeOrigin::root(),
+				RewardPaymentMode::Claim,
+			));
+			(2..=6).for_each(|round| set_author(round, 1, 1));
+			roll_to_round_begin(5);
+			roll_blocks(1);
+
+			// auto-compound config set after the rewards accrued
+			assert_ok!(ParachainStaking::set_auto_compound(
+				RuntimeOrigin::signed(2),
+				1,
+				Percent::from_percent(100),
+				0,
+				1,
+			));
+
+			let due = [2, 3]
+				.iter()
+				.map(|round| {
+					let pool =
+						ParachainStaking::delegator_reward_pool(round, 1).expect("pool must exist");
+					Perbill::from_rational(200u128, pool.total) * pool.rewards
+				})
+				.fold(0, |acc, x| acc + x);
+			assert_ok!(ParachainStaking::claim_rewards(
+				RuntimeOrigin::signed(2),
+				2,
+				vec![2, 3],
+			));
+			// rewards of all rounds are paid and compounded at once
+			assert_events_emitted!(
+				Event::Rewarded {
+					account: 2,
+					rewards: due,
+				},
+				Event::Compounded {
+					candidate: 1,
+					delegator: 2,
+					amount: due,
+				},
+			);
+			assert_eq!(
+				ParachainStaking::delegator_state(2)
+					.expect("delegator must exist")
+					.total,
+				200 + due
+			);
+		});
+}
+
+#[test]
+fn test_claim_rewards_fails_with_too_many_rounds() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 200)])
+		.with_candidates(vec![(1, 100)])
+		.with_delegations(vec![(2, 1, 200)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ParachainStaking::claim_rewards(
+					RuntimeOrigin::signed(2),
+					2,
+					(1..=MAX_CLAIM_ROUNDS + 1).collect(),
+				),
+				Error::<Test>::TooManyRoundsToClaim
+			);
+		});
+}
+
+#[test]
+fn test_claim_rewards_after_revoking_delegation() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 200), (3, 200)])
+		.with_candidates(vec![(1, 100)])
+		.with_delegations(vec![(2, 1, 200), (3, 1, 200)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_reward_payment_mode(
+				RuntimeOrigin::root(),
+				RewardPaymentMode::Claim,
+			));
+			(2..=6).for_each(|round| set_author(round, 1, 1));
+			roll_to_round_begin(4);
+			roll_blocks(1);
+
+			assert_ok!(ParachainStaking::schedule_revoke_delegation(
+				RuntimeOrigin::signed(2),
+				1
+			));
+			roll_to_round_begin(7);
+			assert_ok!(ParachainStaking::execute_delegation_request(
+				RuntimeOrigin::signed(2),
+				2,
+				1
+			));
+			assert!(ParachainStaking::delegator_state(2).is_none());
+
+			// rewards accrued before leaving remain claimable
+			let pool = ParachainStaking::delegator_reward_pool(2, 1).expect("pool must exist");
+			let due = Perbill::from_rational(200u128, pool.total) * pool.rewards;
+			assert!(!due.is_zero());
+			assert_ok!(ParachainStaking::claim_rewards(
+				RuntimeOrigin::signed(2),
+				2,
+				vec![2],
+			));
+			assert_events_emitted!(Event::Rewarded {
+				account: 2,
+				rewards: due,
+			});
+			assert_eq!(Balances::free_balance(&2), 200 + due);
+		});
+}
+
+#[test]
+fn test_claim_rewards_from_the_pools_of_every_collator_of_the_rounds() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 400)])
+		.with_candidates(vec![(1, 100), (2, 100)])
+		.with_delegations(vec![(3, 1, 200), (3, 2, 200)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_reward_payment_mode(
+				RuntimeOrigin::root(),
+				RewardPaymentMode::Claim,
+			));
+			(2..=6).for_each(|round| {
+				set_author(round, 1, 1);
+				set_author(round, 2, 1);
+			});
+			roll_to_round_begin(5);
+			roll_blocks(3);
+
+			let due = [(2, 1), (2, 2), (3, 1), (3, 2)]
+				.iter()
+				.map(|(round, collator)| {
+					ParachainStaking::pending_delegator_reward(*round, collator, &3)
+						.expect("pool must exist")
+				})
+				.fold(0, |acc, x| acc + x);
+			assert_ok!(ParachainStaking::claim_rewards(
+				RuntimeOrigin::signed(3),
+				3,
+				vec![2, 3],
+			));
+			assert_events_emitted!(Event::RewardsClaimed {
+				delegator: 3,
+				rounds: vec![2, 3],
+				rewards: due,
+			});
+			assert_eq!(Balances::free_balance(&3), 400 + due);
+			for round in [2, 3] {
+				assert!(ParachainStaking::delegator_reward_pool(round, 1).is_none());
+				assert!(ParachainStaking::delegator_reward_pool(round, 2).is_none());
+			}
+		});
+}
+
+#[test]
+fn test_claim_rewards_keeps_the_pools_if_rewards_cannot_be_minted() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 200), (3, 200)])
+		.with_candidates(vec![(1, 100)])
+		.with_delegations(vec![(2, 1, 200), (3, 1, 200)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_reward_payment_mode(
+				RuntimeOrigin::root(),
+				RewardPaymentMode::Claim,
+			));
+			(2..=6).for_each(|round| set_author(round, 1, 1));
+			roll_to_round_begin(4);
+			roll_blocks(1);
+
+			let due =
+				ParachainStaking::pending_delegator_reward(2, &1, &2).expect("pool must exist");
+			// the rewards would overflow the delegator balance
+			assert_ok!(Balances::force_set_balance(
+				RuntimeOrigin::root(),
+				2,
+				u128::MAX
+			));
+			assert_noop!(
+				ParachainStaking::claim_rewards(RuntimeOrigin::signed(2), 2, vec![2]),
+				ArithmeticError::Overflow
+			);
+			assert_eq!(
+				ParachainStaking::pending_delegator_reward(2, &1, &2),
+				Some(due)
+			);
+		});
+}
+
+#[test]
+fn test_estimated_delegator_reward_matches_the_payout() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 200), (3, 200)])
+		.with_candidates(vec![(1, 100)])
+		.with_delegations(vec![(2, 1, 200), (3, 1, 200)])
+		.build()
+		.execute_with(|| {
+			(2..=6).for_each(|round| set_author(round, 1, 1));
+			roll_to_round_begin(4);
+
+			// the payout of round 2 is prepared but the collator is not paid yet
+			let payout = ParachainStaking::delayed_payouts(2).expect("payout must be prepared");
+			let snapshot = ParachainStaking::at_stake(2, 1).expect("snapshot must exist");
+			let estimate = ParachainStaking::estimated_delegator_reward(
+				&payout,
+				ParachainStaking::points(2),
+				ParachainStaking::awarded_pts(2, 1),
+				snapshot.total,
+				200,
+			);
+			assert!(!estimate.is_zero());
+
+			roll_blocks(1);
+			assert_events_emitted!(Event::Rewarded {
+				account: 2,
+				rewards: estimate,
+			});
+			assert_eq!(Balances::free_balance(&2), 200 + estimate);
+		});
+}
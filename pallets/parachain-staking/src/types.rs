@@ -175,7 +175,7 @@ impl<A, B: Default> Default for CollatorSnapshot<A, B> {
 pub struct DelayedPayout<Balance> {
 	/// Total round reward (result of compute_issuance() at round end)
 	pub round_issuance: Balance,
-	/// The total inflation paid this round to stakers (e.g. less parachain bond fund)
+	/// The total inflation paid this round to stakers (e.g. less inflation distribution reserves)
 	pub total_staking_reward: Balance,
 	/// Snapshot of collator commission rate at the end of the round
 	pub collator_commission: Perbill,
@@ -1745,6 +1745,7 @@ impl<
 }
 
 #[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+/// DEPRECATED in favour of InflationDistributionConfig, kept for the storage migration
 /// Reserve information { account, percent_of_inflation }
 pub struct ParachainBondConfig<AccountId> {
 	/// Account which receives funds intended for parachain bond
@@ -1762,6 +1763,95 @@ impl<A: Decode> Default for ParachainBondConfig<A> {
 	}
 }
 
+/// Maximum number of accounts that can receive a portion of the round inflation
+pub const MAX_INFLATION_DISTRIBUTION_ACCOUNTS: u32 = 8;
+
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+/// Reserve destination for a portion of the round inflation { account, percent_of_inflation }
+pub struct InflationDistributionAccount<AccountId> {
+	/// Account which receives funds reserved from inflation
+	pub account: AccountId,
+	/// Percent of inflation set aside for the account
+	pub percent: Percent,
+}
+impl<A: Decode> Default for InflationDistributionAccount<A> {
+	fn default() -> InflationDistributionAccount<A> {
+		InflationDistributionAccount {
+			account: A::decode(&mut sp_runtime::traits::TrailingZeroInput::zeroes())
+				.expect("infinite length input; no invalid inputs for type; qed"),
+			percent: Percent::zero(),
+		}
+	}
+}
+
+/// Ordered list of inflation reserve destinations. The destination at index 0 is the
+/// parachain bond reserve.
+pub type InflationDistributionConfig<AccountId> = BoundedVec<
+	InflationDistributionAccount<AccountId>,
+	ConstU32<MAX_INFLATION_DISTRIBUTION_ACCOUNTS>,
+>;
+
+/// Sum of all the percents of inflation reserved by the given config. Returns `None` if the
+/// sum exceeds 100%.
+pub fn total_inflation_distribution_percent<AccountId>(
+	config: &[InflationDistributionAccount<AccountId>],
+) -> Option<Percent> {
+	let total = config.iter().fold(0u32, |acc, x| {
+		acc.saturating_add(x.percent.deconstruct() as u32)
+	});
+	if total > 100 {
+		None
+	} else {
+		Some(Percent::from_percent(total as u8))
+	}
+}
+
+/// Maximum number of rounds that can be claimed at once with `claim_rewards`
+pub const MAX_CLAIM_ROUNDS: u32 = 32;
+
+#[derive(Copy, Clone, PartialEq, Eq, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+/// How delegator rewards are paid once a round is due for payout
+pub enum RewardPaymentMode {
+	/// Delegators are paid automatically, along with their collator, one collator per block
+	#[default]
+	Push,
+	/// Delegator rewards accrue into a `DelegatorRewardPool` per round and collator, and are paid
+	/// out when claimed with `claim_rewards`. Collators are still paid automatically.
+	Claim,
+}
+
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(MaxDelegations))]
+/// Unclaimed delegator rewards of a collator for a round
+pub struct DelegatorRewardPool<AccountId, Balance, MaxDelegations: Get<u32>> {
+	/// Rewards due to the collator and its delegators, less the collator commission
+	pub rewards: Balance,
+	/// Total counted stake of the collator snapshot, which each share is computed against
+	pub total: Balance,
+	/// Delegations of the collator snapshot that have not claimed their share yet
+	pub unclaimed: BoundedVec<Bond<AccountId, Balance>, MaxDelegations>,
+}
+
+impl<A: PartialEq, B, S: Get<u32>> DelegatorRewardPool<A, B, S> {
+	/// The counted bond of the delegator if it has not claimed its share yet
+	pub fn unclaimed_of(&self, delegator: &A) -> Option<&B> {
+		self.unclaimed
+			.iter()
+			.find(|bond| &bond.owner == delegator)
+			.map(|bond| &bond.amount)
+	}
+
+	/// Remove the delegator from the unclaimed delegations, returning its counted bond if it had
+	/// not claimed yet
+	pub fn take_unclaimed(&mut self, delegator: &A) -> Option<B> {
+		let position = self
+			.unclaimed
+			.iter()
+			.position(|bond| &bond.owner == delegator)?;
+		Some(self.unclaimed.swap_remove(position).amount)
+	}
+}
+
 pub enum BondAdjust<Balance> {
 	Increase(Balance),
 	Decrease,
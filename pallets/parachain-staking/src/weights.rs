@@ -84,12 +84,17 @@ pub trait WeightInfo {
 	fn select_top_candidates(x: u32, y: u32, ) -> Weight;
 	fn pay_one_collator_reward_best(x: u32, y: u32, z: u32, ) -> Weight;
 	fn pay_one_collator_reward(y: u32, ) -> Weight;
+	fn pay_one_collator_reward_claim(y: u32, ) -> Weight;
 	fn base_on_initialize() -> Weight;
 	fn set_auto_compound(x: u32, y: u32, ) -> Weight;
 	fn delegate_with_auto_compound(x: u32, y: u32, z: u32, ) -> Weight;
 	fn delegate_with_auto_compound_worst() -> Weight;
 	fn mint_collator_reward() -> Weight;
 	fn notify_inactive_collator() -> Weight;
+	fn set_inflation_distribution_config() -> Weight;
+	fn claim_rewards(x: u32, y: u32, z: u32, ) -> Weight;
+	fn set_reward_payment_mode() -> Weight;
+	fn set_inflation_distribution_account() -> Weight;
 }
 
 /// Weights for pallet_parachain_staking using the Substrate node and recommended hardware.
@@ -117,8 +122,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: ParachainStaking ParachainBondInfo (r:1 w:1)
-	/// Proof Skipped: ParachainStaking ParachainBondInfo (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking InflationDistributionInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking InflationDistributionInfo (max_values: Some(1), max_size: None, mode: Measured)
 	fn set_parachain_bond_account() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `6`
@@ -128,8 +133,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: ParachainStaking ParachainBondInfo (r:1 w:1)
-	/// Proof Skipped: ParachainStaking ParachainBondInfo (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking InflationDistributionInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking InflationDistributionInfo (max_values: Some(1), max_size: None, mode: Measured)
 	fn set_parachain_bond_reserve_percent() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `6`
@@ -638,9 +643,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: ParachainStaking Staked (max_values: None, max_size: None, mode: Measured)
 	/// Storage: ParachainStaking InflationConfig (r:1 w:0)
 	/// Proof Skipped: ParachainStaking InflationConfig (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: ParachainStaking ParachainBondInfo (r:1 w:0)
-	/// Proof Skipped: ParachainStaking ParachainBondInfo (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: System Account (r:1 w:1)
+	/// Storage: ParachainStaking InflationDistributionInfo (r:1 w:0)
+	/// Proof Skipped: ParachainStaking InflationDistributionInfo (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: System Account (r:8 w:8)
 	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
 	/// Storage: ParachainStaking CollatorCommission (r:1 w:0)
 	/// Proof Skipped: ParachainStaking CollatorCommission (max_values: Some(1), max_size: None, mode: Measured)
@@ -648,12 +653,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: ParachainStaking DelayedPayouts (max_values: None, max_size: None, mode: Measured)
 	fn prepare_staking_payouts() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `380`
-		//  Estimated: `3845`
-		// Minimum execution time: 48_260_000 picoseconds.
-		Weight::from_parts(49_856_000, 3845)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		//  Measured:  `1203`
+		//  Estimated: `21982`
+		// Minimum execution time: 131_395_000 picoseconds.
+		Weight::from_parts(134_712_000, 21982)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
 	/// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:0)
 	/// Proof Skipped: ParachainStaking DelegationScheduledRequests (max_values: None, max_size: None, mode: Measured)
@@ -755,6 +760,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: ParachainStaking AtStake (max_values: None, max_size: None, mode: Measured)
 	/// Storage: ParachainStaking AwardedPts (r:1 w:1)
 	/// Proof Skipped: ParachainStaking AwardedPts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking RewardMode (r:1 w:0)
+	/// Proof Skipped: ParachainStaking RewardMode (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:0)
 	/// Proof Skipped: ParachainStaking DelegationScheduledRequests (max_values: None, max_size: None, mode: Measured)
 	/// Storage: MoonbeamOrbiters OrbiterPerRound (r:1 w:0)
@@ -770,12 +777,43 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(68_535_135, 6978)
 			// Standard Error: 6_093
 			.saturating_add(Weight::from_parts(25_186_464, 0).saturating_mul(y.into()))
-			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(y.into())))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(y.into())))
 			.saturating_add(Weight::from_parts(0, 2591).saturating_mul(y.into()))
 	}
+	/// Storage: ParachainStaking DelayedPayouts (r:1 w:0)
+	/// Proof Skipped: ParachainStaking DelayedPayouts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Points (r:1 w:0)
+	/// Proof Skipped: ParachainStaking Points (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking AtStake (r:2 w:1)
+	/// Proof Skipped: ParachainStaking AtStake (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking AwardedPts (r:1 w:1)
+	/// Proof Skipped: ParachainStaking AwardedPts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking RewardMode (r:1 w:0)
+	/// Proof Skipped: ParachainStaking RewardMode (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:0)
+	/// Proof Skipped: ParachainStaking DelegationScheduledRequests (max_values: None, max_size: None, mode: Measured)
+	/// Storage: MoonbeamOrbiters OrbiterPerRound (r:1 w:0)
+	/// Proof Skipped: MoonbeamOrbiters OrbiterPerRound (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking DelegatorRewardPools (r:0 w:1)
+	/// Proof Skipped: ParachainStaking DelegatorRewardPools (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `y` is `[0, 300]`.
+	fn pay_one_collator_reward_claim(y: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1243 + y * (160 ±0)`
+		//  Estimated: `7013 + y * (160 ±0)`
+		// Minimum execution time: 68_114_000 picoseconds.
+		Weight::from_parts(70_930_742, 7013)
+			// Standard Error: 2_412
+			.saturating_add(Weight::from_parts(497_318, 0).saturating_mul(y.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 160).saturating_mul(y.into()))
+	}
 	fn base_on_initialize() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
@@ -911,6 +949,94 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(10_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: ParachainStaking InflationDistributionInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking InflationDistributionInfo (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_inflation_distribution_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `1491`
+		// Minimum execution time: 15_094_000 picoseconds.
+		Weight::from_parts(15_094_000, 1491)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: ParachainStaking DelegatorRewardPools (r:201 w:100)
+	/// Proof Skipped: ParachainStaking DelegatorRewardPools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking AutoCompoundingDelegations (r:100 w:0)
+	/// Proof Skipped: ParachainStaking AutoCompoundingDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:100 w:100)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking DelegatorState (r:100 w:100)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidateInfo (r:100 w:100)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking TopDelegations (r:100 w:100)
+	/// Proof Skipped: ParachainStaking TopDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidatePool (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1287), added: 3762, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:0)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking Total (r:1 w:1)
+	/// Proof Skipped: ParachainStaking Total (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `x` is `[0, 200]`.
+	/// The range of component `y` is `[1, 300]`.
+	/// The range of component `z` is `[1, 100]`.
+	fn claim_rewards(x: u32, y: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1862 + x * (96 ±0) + x * y * (36 ±0) + z * (1402 ±0) + y * z * (36 ±0)`
+		//  Estimated: `7802 + x * (2571 ±0) + x * y * (36 ±0) + z * (14318 ±0) + y * z * (36 ±0)`
+		// Minimum execution time: 98_710_000 picoseconds.
+		Weight::from_parts(52_381_000, 7802)
+			// Standard Error: 9_412
+			.saturating_add(Weight::from_parts(6_125_408, 0).saturating_mul(x.into()))
+			// Standard Error: 31
+			.saturating_add(Weight::from_parts(61_233, 0).saturating_mul(
+				(x as u64).saturating_mul(y as u64),
+			))
+			// Standard Error: 61_930
+			.saturating_add(Weight::from_parts(61_046_112, 0).saturating_mul(z.into()))
+			// Standard Error: 214
+			.saturating_add(Weight::from_parts(88_473, 0).saturating_mul(
+				(y as u64).saturating_mul(z as u64),
+			))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(z.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(z.into())))
+			.saturating_add(Weight::from_parts(0, 2571).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 36).saturating_mul(
+				(x as u64).saturating_mul(y as u64),
+			))
+			.saturating_add(Weight::from_parts(0, 14318).saturating_mul(z.into()))
+			.saturating_add(Weight::from_parts(0, 36).saturating_mul(
+				(y as u64).saturating_mul(z as u64),
+			))
+	}
+	/// Storage: ParachainStaking RewardMode (r:1 w:1)
+	/// Proof Skipped: ParachainStaking RewardMode (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_reward_payment_mode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `1491`
+		// Minimum execution time: 14_310_000 picoseconds.
+		Weight::from_parts(14_310_000, 1491)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: ParachainStaking InflationDistributionInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking InflationDistributionInfo (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_inflation_distribution_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `1491`
+		// Minimum execution time: 15_094_000 picoseconds.
+		Weight::from_parts(15_094_000, 1491)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -937,8 +1063,8 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: ParachainStaking ParachainBondInfo (r:1 w:1)
-	/// Proof Skipped: ParachainStaking ParachainBondInfo (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking InflationDistributionInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking InflationDistributionInfo (max_values: Some(1), max_size: None, mode: Measured)
 	fn set_parachain_bond_account() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `6`
@@ -948,8 +1074,8 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: ParachainStaking ParachainBondInfo (r:1 w:1)
-	/// Proof Skipped: ParachainStaking ParachainBondInfo (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking InflationDistributionInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking InflationDistributionInfo (max_values: Some(1), max_size: None, mode: Measured)
 	fn set_parachain_bond_reserve_percent() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `6`
@@ -1458,9 +1584,9 @@ impl WeightInfo for () {
 	/// Proof Skipped: ParachainStaking Staked (max_values: None, max_size: None, mode: Measured)
 	/// Storage: ParachainStaking InflationConfig (r:1 w:0)
 	/// Proof Skipped: ParachainStaking InflationConfig (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: ParachainStaking ParachainBondInfo (r:1 w:0)
-	/// Proof Skipped: ParachainStaking ParachainBondInfo (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: System Account (r:1 w:1)
+	/// Storage: ParachainStaking InflationDistributionInfo (r:1 w:0)
+	/// Proof Skipped: ParachainStaking InflationDistributionInfo (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: System Account (r:8 w:8)
 	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
 	/// Storage: ParachainStaking CollatorCommission (r:1 w:0)
 	/// Proof Skipped: ParachainStaking CollatorCommission (max_values: Some(1), max_size: None, mode: Measured)
@@ -1468,12 +1594,12 @@ impl WeightInfo for () {
 	/// Proof Skipped: ParachainStaking DelayedPayouts (max_values: None, max_size: None, mode: Measured)
 	fn prepare_staking_payouts() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `380`
-		//  Estimated: `3845`
-		// Minimum execution time: 48_260_000 picoseconds.
-		Weight::from_parts(49_856_000, 3845)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		//  Measured:  `1203`
+		//  Estimated: `21982`
+		// Minimum execution time: 131_395_000 picoseconds.
+		Weight::from_parts(134_712_000, 21982)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
 	/// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:0)
 	/// Proof Skipped: ParachainStaking DelegationScheduledRequests (max_values: None, max_size: None, mode: Measured)
@@ -1575,6 +1701,8 @@ impl WeightInfo for () {
 	/// Proof Skipped: ParachainStaking AtStake (max_values: None, max_size: None, mode: Measured)
 	/// Storage: ParachainStaking AwardedPts (r:1 w:1)
 	/// Proof Skipped: ParachainStaking AwardedPts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking RewardMode (r:1 w:0)
+	/// Proof Skipped: ParachainStaking RewardMode (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:0)
 	/// Proof Skipped: ParachainStaking DelegationScheduledRequests (max_values: None, max_size: None, mode: Measured)
 	/// Storage: MoonbeamOrbiters OrbiterPerRound (r:1 w:0)
@@ -1590,12 +1718,43 @@ impl WeightInfo for () {
 		Weight::from_parts(68_535_135, 6978)
 			// Standard Error: 6_093
 			.saturating_add(Weight::from_parts(25_186_464, 0).saturating_mul(y.into()))
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(y.into())))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(y.into())))
 			.saturating_add(Weight::from_parts(0, 2591).saturating_mul(y.into()))
 	}
+	/// Storage: ParachainStaking DelayedPayouts (r:1 w:0)
+	/// Proof Skipped: ParachainStaking DelayedPayouts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Points (r:1 w:0)
+	/// Proof Skipped: ParachainStaking Points (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking AtStake (r:2 w:1)
+	/// Proof Skipped: ParachainStaking AtStake (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking AwardedPts (r:1 w:1)
+	/// Proof Skipped: ParachainStaking AwardedPts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking RewardMode (r:1 w:0)
+	/// Proof Skipped: ParachainStaking RewardMode (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:0)
+	/// Proof Skipped: ParachainStaking DelegationScheduledRequests (max_values: None, max_size: None, mode: Measured)
+	/// Storage: MoonbeamOrbiters OrbiterPerRound (r:1 w:0)
+	/// Proof Skipped: MoonbeamOrbiters OrbiterPerRound (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking DelegatorRewardPools (r:0 w:1)
+	/// Proof Skipped: ParachainStaking DelegatorRewardPools (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `y` is `[0, 300]`.
+	fn pay_one_collator_reward_claim(y: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1243 + y * (160 ±0)`
+		//  Estimated: `7013 + y * (160 ±0)`
+		// Minimum execution time: 68_114_000 picoseconds.
+		Weight::from_parts(70_930_742, 7013)
+			// Standard Error: 2_412
+			.saturating_add(Weight::from_parts(497_318, 0).saturating_mul(y.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 160).saturating_mul(y.into()))
+	}
 	fn base_on_initialize() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
@@ -1731,4 +1890,92 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(10_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: ParachainStaking InflationDistributionInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking InflationDistributionInfo (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_inflation_distribution_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `1491`
+		// Minimum execution time: 15_094_000 picoseconds.
+		Weight::from_parts(15_094_000, 1491)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: ParachainStaking DelegatorRewardPools (r:201 w:100)
+	/// Proof Skipped: ParachainStaking DelegatorRewardPools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking AutoCompoundingDelegations (r:100 w:0)
+	/// Proof Skipped: ParachainStaking AutoCompoundingDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:100 w:100)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking DelegatorState (r:100 w:100)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidateInfo (r:100 w:100)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking TopDelegations (r:100 w:100)
+	/// Proof Skipped: ParachainStaking TopDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidatePool (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1287), added: 3762, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:0)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking Total (r:1 w:1)
+	/// Proof Skipped: ParachainStaking Total (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `x` is `[0, 200]`.
+	/// The range of component `y` is `[1, 300]`.
+	/// The range of component `z` is `[1, 100]`.
+	fn claim_rewards(x: u32, y: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1862 + x * (96 ±0) + x * y * (36 ±0) + z * (1402 ±0) + y * z * (36 ±0)`
+		//  Estimated: `7802 + x * (2571 ±0) + x * y * (36 ±0) + z * (14318 ±0) + y * z * (36 ±0)`
+		// Minimum execution time: 98_710_000 picoseconds.
+		Weight::from_parts(52_381_000, 7802)
+			// Standard Error: 9_412
+			.saturating_add(Weight::from_parts(6_125_408, 0).saturating_mul(x.into()))
+			// Standard Error: 31
+			.saturating_add(Weight::from_parts(61_233, 0).saturating_mul(
+				(x as u64).saturating_mul(y as u64),
+			))
+			// Standard Error: 61_930
+			.saturating_add(Weight::from_parts(61_046_112, 0).saturating_mul(z.into()))
+			// Standard Error: 214
+			.saturating_add(Weight::from_parts(88_473, 0).saturating_mul(
+				(y as u64).saturating_mul(z as u64),
+			))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(z.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(z.into())))
+			.saturating_add(Weight::from_parts(0, 2571).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 36).saturating_mul(
+				(x as u64).saturating_mul(y as u64),
+			))
+			.saturating_add(Weight::from_parts(0, 14318).saturating_mul(z.into()))
+			.saturating_add(Weight::from_parts(0, 36).saturating_mul(
+				(y as u64).saturating_mul(z as u64),
+			))
+	}
+	/// Storage: ParachainStaking RewardMode (r:1 w:1)
+	/// Proof Skipped: ParachainStaking RewardMode (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_reward_payment_mode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `1491`
+		// Minimum execution time: 14_310_000 picoseconds.
+		Weight::from_parts(14_310_000, 1491)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: ParachainStaking InflationDistributionInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking InflationDistributionInfo (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_inflation_distribution_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `1491`
+		// Minimum execution time: 15_094_000 picoseconds.
+		Weight::from_parts(15_094_000, 1491)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -0,0 +1,104 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::{Call, Config, Pallet, PriceFeeders, RelativePrice, RelativePrices};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_support::traits::EnsureOrigin;
+use frame_system::RawOrigin;
+use sp_runtime::FixedU128;
+use sp_std::boxed::Box;
+use xcm::latest::MultiLocation;
+
+fn add_feeder<T: Config>() -> T::AccountId {
+	let feeder: T::AccountId = account("feeder", 0, 0);
+	PriceFeeders::<T>::insert(&feeder, ());
+	feeder
+}
+
+benchmarks! {
+	add_feeder {
+		let origin = T::FeederManagerOrigin::try_successful_origin()
+			.map_err(|_| "origin not available")?;
+		let feeder: T::AccountId = account("feeder", 0, 0);
+	}: _<T::RuntimeOrigin>(origin, feeder.clone())
+	verify {
+		assert!(PriceFeeders::<T>::contains_key(&feeder));
+	}
+
+	remove_feeder {
+		let origin = T::FeederManagerOrigin::try_successful_origin()
+			.map_err(|_| "origin not available")?;
+		let feeder = add_feeder::<T>();
+	}: _<T::RuntimeOrigin>(origin, feeder.clone())
+	verify {
+		assert!(!PriceFeeders::<T>::contains_key(&feeder));
+	}
+
+	set_relative_price {
+		let feeder = add_feeder::<T>();
+		let location = MultiLocation::parent();
+		// The current price must be fresh and from a previous block
+		RelativePrices::<T>::insert(&location, RelativePrice {
+			price: FixedU128::from_inner(1_000),
+			updated_at: frame_system::Pallet::<T>::block_number(),
+			reference_price: FixedU128::from_inner(1_000),
+		});
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + 1u32.into()
+		);
+	}: _(
+		RawOrigin::Signed(feeder),
+		Box::new(xcm::VersionedMultiLocation::V3(location)),
+		FixedU128::from_inner(1_001)
+	)
+	verify {
+		assert_eq!(
+			RelativePrices::<T>::get(&location).map(|price| price.price),
+			Some(FixedU128::from_inner(1_001))
+		);
+	}
+
+	remove_relative_price {
+		let origin = T::FeederManagerOrigin::try_successful_origin()
+			.map_err(|_| "origin not available")?;
+		let location = MultiLocation::parent();
+		RelativePrices::<T>::insert(&location, RelativePrice {
+			price: FixedU128::from_inner(2),
+			updated_at: frame_system::Pallet::<T>::block_number(),
+			reference_price: FixedU128::from_inner(2),
+		});
+	}: _<T::RuntimeOrigin>(origin, Box::new(xcm::VersionedMultiLocation::V3(location)))
+	verify {
+		assert!(!RelativePrices::<T>::contains_key(&location));
+	}
+
+	force_set_relative_price {
+		let origin = T::FeederManagerOrigin::try_successful_origin()
+			.map_err(|_| "origin not available")?;
+		let location = MultiLocation::parent();
+	}: _<T::RuntimeOrigin>(
+		origin,
+		Box::new(xcm::VersionedMultiLocation::V3(location)),
+		FixedU128::from_inner(2)
+	)
+	verify {
+		assert!(RelativePrices::<T>::contains_key(&location));
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,301 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Xcm Fee Oracle Pallet
+//!
+//! On-chain feed of the prices of the assets used to pay xcm fees, relative to the native
+//! currency. The feed is updated by a set of price feeders managed by governance.
+//!
+//! The relative price of an asset is the amount of units of the asset that are worth one unit
+//! of the native currency. The units per second charged for an asset are derived from it and
+//! from the units per second charged in native currency. Prices older than `MaxPriceAge`
+//! blocks are considered stale and ignored, so that the static rates set by governance can be
+//! used as fallback (see `xcm_primitives::UnitsToWeightRatioWithFallback`).
+//!
+//! Price feeders can only update a fresh price, at most once per block, and can't move it by
+//! more than `MaxPriceDeviation` away from the last price set by `FeederManagerOrigin`, so that
+//! successive updates can't compound. The initial price of an asset, and the price of an asset
+//! whose price became stale, must be set by `FeederManagerOrigin`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+mod benchmarks;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::weights::WeightInfo;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::{
+		traits::{Saturating, Zero},
+		FixedPointNumber, FixedU128, Perbill,
+	};
+	use sp_std::boxed::Box;
+	use xcm::{latest::MultiLocation, VersionedMultiLocation};
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Origin allowed to add and remove price feeders, and to remove relative prices
+		type FeederManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Units of native currency charged per second of execution
+		type NativeUnitsPerSecond: Get<u128>;
+		/// Number of blocks after which a relative price is considered stale
+		#[pallet::constant]
+		type MaxPriceAge: Get<BlockNumberFor<Self>>;
+		/// Maximum deviation of the relative prices set by the price feeders from the last
+		/// relative price set by `FeederManagerOrigin`
+		#[pallet::constant]
+		type MaxPriceDeviation: Get<Perbill>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Price of an asset relative to the native currency
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct RelativePrice<BlockNumber> {
+		/// Units of the asset that are worth one unit of native currency
+		pub price: FixedU128,
+		/// Block at which the price was last updated
+		pub updated_at: BlockNumber,
+		/// Last price set by the feeder manager, that bounds the prices set by the feeders
+		pub reference_price: FixedU128,
+	}
+
+	/// Accounts allowed to update the relative prices
+	#[pallet::storage]
+	#[pallet::getter(fn price_feeders)]
+	pub type PriceFeeders<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+	/// Relative prices of the assets, by asset location
+	#[pallet::storage]
+	#[pallet::getter(fn relative_price)]
+	pub type RelativePrices<T: Config> =
+		StorageMap<_, Blake2_128Concat, MultiLocation, RelativePrice<BlockNumberFor<T>>>;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account is already a price feeder
+		AlreadyFeeder,
+		/// The account is not a price feeder
+		NotFeeder,
+		/// The location could not be converted to the latest xcm version
+		BadVersion,
+		/// The relative price can't be zero
+		ZeroPrice,
+		/// There is no relative price for this asset
+		PriceNotFound,
+		/// The relative price is stale and must be set again by the feeder manager
+		StalePrice,
+		/// The relative price was already updated in this block
+		PriceAlreadyUpdated,
+		/// The new relative price deviates too much from the reference price
+		PriceDeviationTooHigh,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A price feeder has been added
+		FeederAdded { account: T::AccountId },
+		/// A price feeder has been removed
+		FeederRemoved { account: T::AccountId },
+		/// The relative price of an asset has been updated
+		RelativePriceUpdated {
+			location: MultiLocation,
+			price: FixedU128,
+		},
+		/// The relative price of an asset has been removed
+		RelativePriceRemoved { location: MultiLocation },
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Allow an account to update the relative prices
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::add_feeder())]
+		pub fn add_feeder(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+			T::FeederManagerOrigin::ensure_origin(origin)?;
+			ensure!(
+				!PriceFeeders::<T>::contains_key(&account),
+				Error::<T>::AlreadyFeeder
+			);
+
+			PriceFeeders::<T>::insert(&account, ());
+
+			Self::deposit_event(Event::FeederAdded { account });
+			Ok(())
+		}
+
+		/// Revoke the permission of an account to update the relative prices
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::remove_feeder())]
+		pub fn remove_feeder(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+			T::FeederManagerOrigin::ensure_origin(origin)?;
+			ensure!(
+				PriceFeeders::<T>::contains_key(&account),
+				Error::<T>::NotFeeder
+			);
+
+			PriceFeeders::<T>::remove(&account);
+
+			Self::deposit_event(Event::FeederRemoved { account });
+			Ok(())
+		}
+
+		/// Update the price of an asset relative to the native currency.
+		/// The current price must be fresh, and the new price can't deviate by more than
+		/// MaxPriceDeviation from the reference price set by the feeder manager.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::set_relative_price())]
+		pub fn set_relative_price(
+			origin: OriginFor<T>,
+			location: Box<VersionedMultiLocation>,
+			price: FixedU128,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(PriceFeeders::<T>::contains_key(&who), Error::<T>::NotFeeder);
+			ensure!(!price.is_zero(), Error::<T>::ZeroPrice);
+			let location =
+				MultiLocation::try_from(*location).map_err(|()| Error::<T>::BadVersion)?;
+
+			let current = RelativePrices::<T>::get(&location).ok_or(Error::<T>::PriceNotFound)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(!Self::is_stale(&current, now), Error::<T>::StalePrice);
+			ensure!(current.updated_at < now, Error::<T>::PriceAlreadyUpdated);
+
+			let reference_price = current.reference_price;
+			let deviation = if price > reference_price {
+				price.saturating_sub(reference_price)
+			} else {
+				reference_price.saturating_sub(price)
+			};
+			let max_deviation = FixedU128::from_inner(
+				T::MaxPriceDeviation::get().mul_floor(reference_price.into_inner()),
+			);
+			ensure!(
+				deviation <= max_deviation,
+				Error::<T>::PriceDeviationTooHigh
+			);
+
+			Self::do_set_relative_price(location, price, reference_price);
+			Ok(())
+		}
+
+		/// Remove the relative price of an asset, so that the static rate is used instead
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::remove_relative_price())]
+		pub fn remove_relative_price(
+			origin: OriginFor<T>,
+			location: Box<VersionedMultiLocation>,
+		) -> DispatchResult {
+			T::FeederManagerOrigin::ensure_origin(origin)?;
+			let location =
+				MultiLocation::try_from(*location).map_err(|()| Error::<T>::BadVersion)?;
+			ensure!(
+				RelativePrices::<T>::contains_key(&location),
+				Error::<T>::PriceNotFound
+			);
+
+			RelativePrices::<T>::remove(&location);
+
+			Self::deposit_event(Event::RelativePriceRemoved { location });
+			Ok(())
+		}
+
+		/// Set the price of an asset relative to the native currency, without checking its
+		/// deviation from the current price. Used to set the initial price of an asset, or to
+		/// set again a price that became stale. The price becomes the reference price that
+		/// bounds the updates of the price feeders.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::force_set_relative_price())]
+		pub fn force_set_relative_price(
+			origin: OriginFor<T>,
+			location: Box<VersionedMultiLocation>,
+			price: FixedU128,
+		) -> DispatchResult {
+			T::FeederManagerOrigin::ensure_origin(origin)?;
+			ensure!(!price.is_zero(), Error::<T>::ZeroPrice);
+			let location =
+				MultiLocation::try_from(*location).map_err(|()| Error::<T>::BadVersion)?;
+
+			Self::do_set_relative_price(location, price, price);
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn do_set_relative_price(
+			location: MultiLocation,
+			price: FixedU128,
+			reference_price: FixedU128,
+		) {
+			RelativePrices::<T>::insert(
+				&location,
+				RelativePrice {
+					price,
+					updated_at: frame_system::Pallet::<T>::block_number(),
+					reference_price,
+				},
+			);
+
+			Self::deposit_event(Event::RelativePriceUpdated { location, price });
+		}
+
+		fn is_stale(price: &RelativePrice<BlockNumberFor<T>>, now: BlockNumberFor<T>) -> bool {
+			now.saturating_sub(price.updated_at) > T::MaxPriceAge::get()
+		}
+
+		/// Units per second charged for an asset, derived from its relative price.
+		/// Returns None if there is no price for the asset or if the price is stale.
+		pub fn units_per_second(location: &MultiLocation) -> Option<u128> {
+			let relative_price = RelativePrices::<T>::get(location)?;
+			if Self::is_stale(&relative_price, frame_system::Pallet::<T>::block_number()) {
+				return None;
+			}
+
+			let units_per_second = relative_price
+				.price
+				.saturating_mul_int(T::NativeUnitsPerSecond::get());
+			(!units_per_second.is_zero()).then_some(units_per_second)
+		}
+	}
+
+	impl<T: Config, AssetType: Into<Option<MultiLocation>>>
+		xcm_primitives::UnitsToWeightRatio<AssetType> for Pallet<T>
+	{
+		fn payment_is_supported(asset_type: AssetType) -> bool {
+			Self::get_units_per_second(asset_type).is_some()
+		}
+		fn get_units_per_second(asset_type: AssetType) -> Option<u128> {
+			let location: Option<MultiLocation> = asset_type.into();
+			location.and_then(|location| Self::units_per_second(&location))
+		}
+	}
+}
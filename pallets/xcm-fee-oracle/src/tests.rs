@@ -0,0 +1,340 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::{events, new_test_ext, RuntimeOrigin, System, Test, XcmFeeOracle};
+use crate::{Error, Event, RelativePrice};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{DispatchError, FixedPointNumber, FixedU128};
+use xcm::latest::{Junction, MultiLocation};
+use xcm::VersionedMultiLocation;
+use xcm_primitives::UnitsToWeightRatio;
+
+const FEEDER: u64 = 1;
+
+fn versioned(location: MultiLocation) -> Box<VersionedMultiLocation> {
+	Box::new(VersionedMultiLocation::V3(location))
+}
+
+#[test]
+fn add_and_remove_feeder() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XcmFeeOracle::add_feeder(RuntimeOrigin::signed(FEEDER), FEEDER),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(XcmFeeOracle::add_feeder(RuntimeOrigin::root(), FEEDER));
+		assert_noop!(
+			XcmFeeOracle::add_feeder(RuntimeOrigin::root(), FEEDER),
+			Error::<Test>::AlreadyFeeder
+		);
+		assert!(XcmFeeOracle::price_feeders(FEEDER).is_some());
+
+		assert_ok!(XcmFeeOracle::remove_feeder(RuntimeOrigin::root(), FEEDER));
+		assert_noop!(
+			XcmFeeOracle::remove_feeder(RuntimeOrigin::root(), FEEDER),
+			Error::<Test>::NotFeeder
+		);
+		assert!(XcmFeeOracle::price_feeders(FEEDER).is_none());
+
+		assert_eq!(
+			events(),
+			vec![
+				Event::FeederAdded { account: FEEDER },
+				Event::FeederRemoved { account: FEEDER },
+			]
+		);
+	});
+}
+
+#[test]
+fn only_feeders_can_set_relative_price() {
+	new_test_ext().execute_with(|| {
+		let location = MultiLocation::parent();
+		let price = FixedU128::from_rational(21, 20);
+		assert_ok!(XcmFeeOracle::force_set_relative_price(
+			RuntimeOrigin::root(),
+			versioned(location),
+			FixedU128::from_u32(1)
+		));
+		System::set_block_number(2);
+
+		assert_noop!(
+			XcmFeeOracle::set_relative_price(
+				RuntimeOrigin::signed(FEEDER),
+				versioned(location),
+				price
+			),
+			Error::<Test>::NotFeeder
+		);
+
+		assert_ok!(XcmFeeOracle::add_feeder(RuntimeOrigin::root(), FEEDER));
+		assert_noop!(
+			XcmFeeOracle::set_relative_price(
+				RuntimeOrigin::signed(FEEDER),
+				versioned(location),
+				FixedU128::from_inner(0)
+			),
+			Error::<Test>::ZeroPrice
+		);
+		assert_ok!(XcmFeeOracle::set_relative_price(
+			RuntimeOrigin::signed(FEEDER),
+			versioned(location),
+			price
+		));
+
+		assert_eq!(
+			XcmFeeOracle::relative_price(location),
+			Some(RelativePrice {
+				price,
+				updated_at: 2,
+				reference_price: FixedU128::from_u32(1),
+			})
+		);
+		assert_eq!(
+			events().last(),
+			Some(&Event::RelativePriceUpdated { location, price })
+		);
+	});
+}
+
+#[test]
+fn initial_price_is_set_by_feeder_manager() {
+	new_test_ext().execute_with(|| {
+		let location = MultiLocation::parent();
+		let price = FixedU128::from_rational(3, 2);
+		assert_ok!(XcmFeeOracle::add_feeder(RuntimeOrigin::root(), FEEDER));
+
+		assert_noop!(
+			XcmFeeOracle::set_relative_price(
+				RuntimeOrigin::signed(FEEDER),
+				versioned(location),
+				price
+			),
+			Error::<Test>::PriceNotFound
+		);
+		assert_noop!(
+			XcmFeeOracle::force_set_relative_price(
+				RuntimeOrigin::signed(FEEDER),
+				versioned(location),
+				price
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			XcmFeeOracle::force_set_relative_price(
+				RuntimeOrigin::root(),
+				versioned(location),
+				FixedU128::from_inner(0)
+			),
+			Error::<Test>::ZeroPrice
+		);
+		assert_ok!(XcmFeeOracle::force_set_relative_price(
+			RuntimeOrigin::root(),
+			versioned(location),
+			price
+		));
+
+		assert_eq!(
+			XcmFeeOracle::relative_price(location),
+			Some(RelativePrice {
+				price,
+				updated_at: 1,
+				reference_price: price,
+			})
+		);
+		assert_eq!(
+			events().last(),
+			Some(&Event::RelativePriceUpdated { location, price })
+		);
+	});
+}
+
+#[test]
+fn price_updates_are_bounded() {
+	new_test_ext().execute_with(|| {
+		let location = MultiLocation::parent();
+		assert_ok!(XcmFeeOracle::add_feeder(RuntimeOrigin::root(), FEEDER));
+		assert_ok!(XcmFeeOracle::force_set_relative_price(
+			RuntimeOrigin::root(),
+			versioned(location),
+			FixedU128::from_u32(2)
+		));
+
+		// Only one update per block
+		assert_noop!(
+			XcmFeeOracle::set_relative_price(
+				RuntimeOrigin::signed(FEEDER),
+				versioned(location),
+				FixedU128::from_u32(2)
+			),
+			Error::<Test>::PriceAlreadyUpdated
+		);
+
+		// MaxPriceDeviation is 10% of the reference price
+		System::set_block_number(2);
+		assert_noop!(
+			XcmFeeOracle::set_relative_price(
+				RuntimeOrigin::signed(FEEDER),
+				versioned(location),
+				FixedU128::from_rational(221, 100)
+			),
+			Error::<Test>::PriceDeviationTooHigh
+		);
+		assert_noop!(
+			XcmFeeOracle::set_relative_price(
+				RuntimeOrigin::signed(FEEDER),
+				versioned(location),
+				FixedU128::from_rational(179, 100)
+			),
+			Error::<Test>::PriceDeviationTooHigh
+		);
+		assert_ok!(XcmFeeOracle::set_relative_price(
+			RuntimeOrigin::signed(FEEDER),
+			versioned(location),
+			FixedU128::from_rational(22, 10)
+		));
+
+		// The deviation is bounded by the reference price, not by the current price, so that
+		// successive updates can't compound
+		System::set_block_number(3);
+		assert_noop!(
+			XcmFeeOracle::set_relative_price(
+				RuntimeOrigin::signed(FEEDER),
+				versioned(location),
+				FixedU128::from_rational(24, 10)
+			),
+			Error::<Test>::PriceDeviationTooHigh
+		);
+		assert_ok!(XcmFeeOracle::set_relative_price(
+			RuntimeOrigin::signed(FEEDER),
+			versioned(location),
+			FixedU128::from_rational(18, 10)
+		));
+
+		// The feeder manager moves the reference price
+		assert_ok!(XcmFeeOracle::force_set_relative_price(
+			RuntimeOrigin::root(),
+			versioned(location),
+			FixedU128::from_u32(3)
+		));
+		System::set_block_number(4);
+		assert_ok!(XcmFeeOracle::set_relative_price(
+			RuntimeOrigin::signed(FEEDER),
+			versioned(location),
+			FixedU128::from_rational(33, 10)
+		));
+		assert_eq!(
+			XcmFeeOracle::relative_price(location),
+			Some(RelativePrice {
+				price: FixedU128::from_rational(33, 10),
+				updated_at: 4,
+				reference_price: FixedU128::from_u32(3),
+			})
+		);
+	});
+}
+
+#[test]
+fn units_per_second_are_derived_from_relative_price() {
+	new_test_ext().execute_with(|| {
+		let location = MultiLocation::parent();
+		assert_ok!(XcmFeeOracle::force_set_relative_price(
+			RuntimeOrigin::root(),
+			versioned(location),
+			FixedU128::from_rational(3, 2)
+		));
+
+		// 1.5 * NativeUnitsPerSecond
+		assert_eq!(XcmFeeOracle::units_per_second(&location), Some(1_500_000));
+		assert!(
+			<XcmFeeOracle as UnitsToWeightRatio<MultiLocation>>::payment_is_supported(location)
+		);
+
+		// Unknown assets are not supported
+		let other = MultiLocation::new(1, Junction::Parachain(1000));
+		assert_eq!(XcmFeeOracle::units_per_second(&other), None);
+		assert!(!<XcmFeeOracle as UnitsToWeightRatio<MultiLocation>>::payment_is_supported(other));
+	});
+}
+
+#[test]
+fn stale_prices_expire() {
+	new_test_ext().execute_with(|| {
+		let location = MultiLocation::parent();
+		assert_ok!(XcmFeeOracle::add_feeder(RuntimeOrigin::root(), FEEDER));
+		assert_ok!(XcmFeeOracle::force_set_relative_price(
+			RuntimeOrigin::root(),
+			versioned(location),
+			FixedU128::from_rational(1, 2)
+		));
+
+		// MaxPriceAge is 10 blocks
+		System::set_block_number(11);
+		assert_eq!(XcmFeeOracle::units_per_second(&location), Some(500_000));
+		System::set_block_number(12);
+		assert_eq!(XcmFeeOracle::units_per_second(&location), None);
+
+		// Feeders can't update a stale price
+		assert_noop!(
+			XcmFeeOracle::set_relative_price(
+				RuntimeOrigin::signed(FEEDER),
+				versioned(location),
+				FixedU128::from_rational(1, 2)
+			),
+			Error::<Test>::StalePrice
+		);
+
+		// Setting the price again makes it fresh
+		assert_ok!(XcmFeeOracle::force_set_relative_price(
+			RuntimeOrigin::root(),
+			versioned(location),
+			FixedU128::from_rational(1, 2)
+		));
+		assert_eq!(XcmFeeOracle::units_per_second(&location), Some(500_000));
+	});
+}
+
+#[test]
+fn remove_relative_price() {
+	new_test_ext().execute_with(|| {
+		let location = MultiLocation::parent();
+		assert_noop!(
+			XcmFeeOracle::remove_relative_price(RuntimeOrigin::root(), versioned(location)),
+			Error::<Test>::PriceNotFound
+		);
+
+		assert_ok!(XcmFeeOracle::force_set_relative_price(
+			RuntimeOrigin::root(),
+			versioned(location),
+			FixedU128::from_rational(1, 2)
+		));
+
+		assert_noop!(
+			XcmFeeOracle::remove_relative_price(RuntimeOrigin::signed(FEEDER), versioned(location)),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(XcmFeeOracle::remove_relative_price(
+			RuntimeOrigin::root(),
+			versioned(location)
+		));
+
+		assert_eq!(XcmFeeOracle::units_per_second(&location), None);
+		assert_eq!(
+			events().last(),
+			Some(&Event::RelativePriceRemoved { location })
+		);
+	});
+}
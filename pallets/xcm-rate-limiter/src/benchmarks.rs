@@ -0,0 +1,113 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::{
+	Call, Config, NextQuarantineId, Pallet, PausedOrigins, QuarantinedDeposit, QuarantinedDeposits,
+	RateLimit, RateLimits,
+};
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_support::traits::{EnsureOrigin, Get};
+use sp_std::boxed::Box;
+use xcm::latest::prelude::*;
+
+fn quarantine<T: Config>(asset: MultiLocation) -> u64 {
+	let id = NextQuarantineId::<T>::get();
+	NextQuarantineId::<T>::put(id + 1);
+	QuarantinedDeposits::<T>::insert(
+		id,
+		QuarantinedDeposit {
+			origin: MultiLocation::new(1, X1(Parachain(1000))),
+			asset,
+			amount: 1_000,
+			beneficiary: MultiLocation::new(
+				0,
+				X1(AccountKey20 {
+					network: None,
+					key: [1u8; 20],
+				}),
+			),
+			quarantined_at: frame_system::Pallet::<T>::block_number(),
+		},
+	);
+	id
+}
+
+benchmarks! {
+	set_rate_limit {
+		let origin = T::ManagerOrigin::try_successful_origin()
+			.map_err(|_| "origin not available")?;
+		let asset = MultiLocation::parent();
+	}: _<T::RuntimeOrigin>(
+		origin,
+		Box::new(xcm::VersionedMultiLocation::V3(asset)),
+		1_000,
+		10u32.into()
+	)
+	verify {
+		assert!(RateLimits::<T>::contains_key(&asset));
+	}
+
+	remove_rate_limit {
+		let origin = T::ManagerOrigin::try_successful_origin()
+			.map_err(|_| "origin not available")?;
+		let asset = MultiLocation::parent();
+		RateLimits::<T>::insert(&asset, RateLimit { amount: 1_000, period: 10u32.into() });
+	}: _<T::RuntimeOrigin>(origin, Box::new(xcm::VersionedMultiLocation::V3(asset)))
+	verify {
+		assert!(!RateLimits::<T>::contains_key(&asset));
+	}
+
+	pause_origin {
+		let origin = T::PauseOrigin::try_successful_origin()
+			.map_err(|_| "origin not available")?;
+		let location = MultiLocation::new(1, X1(Parachain(1000)));
+	}: _<T::RuntimeOrigin>(origin, Box::new(xcm::VersionedMultiLocation::V3(location)))
+	verify {
+		assert!(PausedOrigins::<T>::contains_key(&location));
+	}
+
+	unpause_origin {
+		let origin = T::ManagerOrigin::try_successful_origin()
+			.map_err(|_| "origin not available")?;
+		let location = MultiLocation::new(1, X1(Parachain(1000)));
+		PausedOrigins::<T>::insert(&location, ());
+	}: _<T::RuntimeOrigin>(origin, Box::new(xcm::VersionedMultiLocation::V3(location)))
+	verify {
+		assert!(!PausedOrigins::<T>::contains_key(&location));
+	}
+
+	release_quarantined_deposit {
+		let origin = T::ManagerOrigin::try_successful_origin()
+			.map_err(|_| "origin not available")?;
+		let id = quarantine::<T>(T::BenchmarkReleasableAsset::get());
+	}: _<T::RuntimeOrigin>(origin, id)
+	verify {
+		assert!(!QuarantinedDeposits::<T>::contains_key(id));
+	}
+
+	reject_quarantined_deposit {
+		let origin = T::ManagerOrigin::try_successful_origin()
+			.map_err(|_| "origin not available")?;
+		let id = quarantine::<T>(MultiLocation::parent());
+	}: _<T::RuntimeOrigin>(origin, id)
+	verify {
+		assert!(!QuarantinedDeposits::<T>::contains_key(id));
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,536 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Xcm Rate Limiter Pallet
+//!
+//! Protects against the unbounded minting of derivative tokens by a compromised sibling chain.
+//!
+//! The pallet wraps the asset transactor of the xcm executor and inspects the assets deposited
+//! by xcm messages coming from other consensus systems:
+//! - Governance can configure, per asset, the maximum amount that can be deposited over a
+//!   rolling window of blocks. The inflow tracked for an asset decays linearly over the window.
+//! - Deposits exceeding the limit are not minted but held in a quarantine queue, from which
+//!   governance can release them to their beneficiary or reject them.
+//! - Governance can pause an origin in an emergency. Deposits from a paused origin fail, so the
+//!   assets end up in the asset trap. Pausing a chain also pauses the locations under it, e.g.
+//!   the accounts of the chain.
+//!
+//! Messages executed locally (e.g. through pallet-xcm execute) are not limited.
+//!
+//! Deposits are attributed to the origin of the message that performs them, even once the
+//! message cleared its origin (e.g. with the ClearOrigin of a reserve transfer). For this, the
+//! xcm executor must be wrapped in `XcmExecutorWrapper`, which records the origin of the
+//! executed message.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+mod benchmarks;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+use sp_std::marker::PhantomData;
+use xcm::latest::{prelude::*, Weight};
+
+environmental::environmental!(XCM_MESSAGE_ORIGIN: MultiLocation);
+
+/// Xcm executor wrapper that records the origin of the executed message, so that the assets
+/// deposited by the message are attributed to it even after the origin was cleared
+pub struct XcmExecutorWrapper<RuntimeCall, InnerXcmExecutor>(
+	PhantomData<(RuntimeCall, InnerXcmExecutor)>,
+);
+impl<RuntimeCall, InnerXcmExecutor> ExecuteXcm<RuntimeCall>
+	for XcmExecutorWrapper<RuntimeCall, InnerXcmExecutor>
+where
+	InnerXcmExecutor: ExecuteXcm<RuntimeCall>,
+{
+	type Prepared = InnerXcmExecutor::Prepared;
+
+	fn prepare(message: Xcm<RuntimeCall>) -> Result<Self::Prepared, Xcm<RuntimeCall>> {
+		InnerXcmExecutor::prepare(message)
+	}
+
+	fn execute(
+		origin: impl Into<MultiLocation>,
+		pre: Self::Prepared,
+		hash: &mut XcmHash,
+		weight_credit: Weight,
+	) -> Outcome {
+		let mut origin = origin.into();
+		XCM_MESSAGE_ORIGIN::using(&mut origin, || {
+			InnerXcmExecutor::execute(origin, pre, hash, weight_credit)
+		})
+	}
+
+	fn charge_fees(location: impl Into<MultiLocation>, fees: MultiAssets) -> XcmResult {
+		InnerXcmExecutor::charge_fees(location, fees)
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::weights::WeightInfo;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::{
+		traits::{SaturatedConversion, Saturating, Zero},
+		Perbill,
+	};
+	use sp_std::boxed::Box;
+	use xcm::{latest::prelude::*, VersionedMultiLocation};
+	use xcm_executor::{traits::TransactAsset, Assets};
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The wrapped asset transactor, used to deposit the assets that are not held
+		type AssetTransactor: TransactAsset;
+		/// Origin allowed to manage the rate limits and the quarantine, and to unpause origins
+		type ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Origin allowed to pause an origin in an emergency
+		type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Maximum number of deposits held in quarantine
+		#[pallet::constant]
+		type MaxQuarantinedDeposits: Get<u32>;
+		/// Location of an asset that the wrapped transactor can deposit, used to benchmark the
+		/// release of quarantined deposits
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkReleasableAsset: Get<MultiLocation>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Maximum amount of an asset that can be deposited over a rolling window of blocks
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct RateLimit<BlockNumber> {
+		/// Maximum amount deposited over the window
+		pub amount: u128,
+		/// Length of the window, in blocks
+		pub period: BlockNumber,
+	}
+
+	/// Amount of an asset deposited over the current window
+	#[derive(
+		Clone, Copy, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct Inflow<BlockNumber> {
+		/// Amount deposited, as of `updated_at`
+		pub amount: u128,
+		/// Block at which the inflow was last updated
+		pub updated_at: BlockNumber,
+	}
+
+	/// A deposit exceeding the rate limit of its asset, waiting for governance decision
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct QuarantinedDeposit<BlockNumber> {
+		/// Origin of the xcm message
+		pub origin: MultiLocation,
+		/// Location of the deposited asset
+		pub asset: MultiLocation,
+		/// Amount deposited
+		pub amount: u128,
+		/// Beneficiary of the deposit
+		pub beneficiary: MultiLocation,
+		/// Block at which the deposit was quarantined
+		pub quarantined_at: BlockNumber,
+	}
+
+	/// Rate limits of the assets, by asset location
+	#[pallet::storage]
+	#[pallet::getter(fn rate_limit)]
+	pub type RateLimits<T: Config> =
+		StorageMap<_, Blake2_128Concat, MultiLocation, RateLimit<BlockNumberFor<T>>>;
+
+	/// Inflows of the rate limited assets, by asset location
+	#[pallet::storage]
+	#[pallet::getter(fn inflow)]
+	pub type Inflows<T: Config> =
+		StorageMap<_, Blake2_128Concat, MultiLocation, Inflow<BlockNumberFor<T>>, ValueQuery>;
+
+	/// Origins whose deposits are paused
+	#[pallet::storage]
+	#[pallet::getter(fn paused_origins)]
+	pub type PausedOrigins<T: Config> = StorageMap<_, Blake2_128Concat, MultiLocation, ()>;
+
+	/// Deposits held in quarantine, by quarantine id
+	#[pallet::storage]
+	#[pallet::getter(fn quarantined_deposit)]
+	pub type QuarantinedDeposits<T: Config> =
+		CountedStorageMap<_, Twox64Concat, u64, QuarantinedDeposit<BlockNumberFor<T>>>;
+
+	/// Id of the next quarantined deposit
+	#[pallet::storage]
+	pub type NextQuarantineId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The location could not be converted to the latest xcm version
+		BadVersion,
+		/// The period of a rate limit can't be zero
+		ZeroPeriod,
+		/// There is no rate limit for this asset
+		RateLimitNotFound,
+		/// The origin is already paused
+		AlreadyPaused,
+		/// The origin is not paused
+		NotPaused,
+		/// There is no quarantined deposit with this id
+		QuarantinedDepositNotFound,
+		/// The wrapped asset transactor failed to deposit the quarantined assets
+		ReleaseFailed,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The rate limit of an asset has been set
+		RateLimitSet {
+			asset: MultiLocation,
+			amount: u128,
+			period: BlockNumberFor<T>,
+		},
+		/// The rate limit of an asset has been removed
+		RateLimitRemoved { asset: MultiLocation },
+		/// The deposits from an origin have been paused
+		OriginPaused { location: MultiLocation },
+		/// The deposits from an origin have been unpaused
+		OriginUnpaused { location: MultiLocation },
+		/// A deposit exceeding the rate limit of its asset has been quarantined
+		DepositQuarantined {
+			id: u64,
+			origin: MultiLocation,
+			asset: MultiLocation,
+			amount: u128,
+			beneficiary: MultiLocation,
+		},
+		/// A quarantined deposit has been deposited to its beneficiary
+		QuarantinedDepositReleased { id: u64 },
+		/// A quarantined deposit has been rejected, its assets are not minted
+		QuarantinedDepositRejected { id: u64 },
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the maximum amount of an asset that can be deposited over `period` blocks
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_rate_limit())]
+		pub fn set_rate_limit(
+			origin: OriginFor<T>,
+			asset: Box<VersionedMultiLocation>,
+			amount: u128,
+			period: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+			ensure!(!period.is_zero(), Error::<T>::ZeroPeriod);
+			let asset = MultiLocation::try_from(*asset).map_err(|()| Error::<T>::BadVersion)?;
+
+			RateLimits::<T>::insert(&asset, RateLimit { amount, period });
+
+			Self::deposit_event(Event::RateLimitSet {
+				asset,
+				amount,
+				period,
+			});
+			Ok(())
+		}
+
+		/// Remove the rate limit of an asset
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::remove_rate_limit())]
+		pub fn remove_rate_limit(
+			origin: OriginFor<T>,
+			asset: Box<VersionedMultiLocation>,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+			let asset = MultiLocation::try_from(*asset).map_err(|()| Error::<T>::BadVersion)?;
+			ensure!(
+				RateLimits::<T>::contains_key(&asset),
+				Error::<T>::RateLimitNotFound
+			);
+
+			RateLimits::<T>::remove(&asset);
+			Inflows::<T>::remove(&asset);
+
+			Self::deposit_event(Event::RateLimitRemoved { asset });
+			Ok(())
+		}
+
+		/// Make the deposits from an origin, and from the locations under it, fail
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::pause_origin())]
+		pub fn pause_origin(
+			origin: OriginFor<T>,
+			location: Box<VersionedMultiLocation>,
+		) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+			let location =
+				MultiLocation::try_from(*location).map_err(|()| Error::<T>::BadVersion)?;
+			ensure!(
+				!PausedOrigins::<T>::contains_key(&location),
+				Error::<T>::AlreadyPaused
+			);
+
+			PausedOrigins::<T>::insert(&location, ());
+
+			Self::deposit_event(Event::OriginPaused { location });
+			Ok(())
+		}
+
+		/// Allow again the deposits from a paused origin
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::unpause_origin())]
+		pub fn unpause_origin(
+			origin: OriginFor<T>,
+			location: Box<VersionedMultiLocation>,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+			let location =
+				MultiLocation::try_from(*location).map_err(|()| Error::<T>::BadVersion)?;
+			ensure!(
+				PausedOrigins::<T>::contains_key(&location),
+				Error::<T>::NotPaused
+			);
+
+			PausedOrigins::<T>::remove(&location);
+
+			Self::deposit_event(Event::OriginUnpaused { location });
+			Ok(())
+		}
+
+		/// Deposit a quarantined deposit to its beneficiary
+		/// The released amount does not count towards the inflow of the asset
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::release_quarantined_deposit())]
+		pub fn release_quarantined_deposit(origin: OriginFor<T>, id: u64) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+			let deposit =
+				QuarantinedDeposits::<T>::take(id).ok_or(Error::<T>::QuarantinedDepositNotFound)?;
+
+			let context = XcmContext {
+				origin: Some(deposit.origin),
+				message_id: XcmHash::default(),
+				topic: None,
+			};
+			T::AssetTransactor::deposit_asset(
+				&(deposit.asset, deposit.amount).into(),
+				&deposit.beneficiary,
+				&context,
+			)
+			.map_err(|_| Error::<T>::ReleaseFailed)?;
+
+			Self::deposit_event(Event::QuarantinedDepositReleased { id });
+			Ok(())
+		}
+
+		/// Discard a quarantined deposit, its assets are never minted
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::reject_quarantined_deposit())]
+		pub fn reject_quarantined_deposit(origin: OriginFor<T>, id: u64) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+			ensure!(
+				QuarantinedDeposits::<T>::contains_key(id),
+				Error::<T>::QuarantinedDepositNotFound
+			);
+
+			QuarantinedDeposits::<T>::remove(id);
+
+			Self::deposit_event(Event::QuarantinedDepositRejected { id });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Whether the deposits from an origin are paused, either because the origin itself or
+		/// one of the locations above it (excluding the bare parent) is paused
+		pub fn is_paused(origin: &MultiLocation) -> bool {
+			let mut location = *origin;
+			loop {
+				if PausedOrigins::<T>::contains_key(&location) {
+					return true;
+				}
+				if location.interior().len() <= 1 {
+					return false;
+				}
+				location.take_last();
+			}
+		}
+
+		/// Inflow of an asset at the current block, decayed linearly over the rate limit period
+		fn current_inflow(
+			asset: &MultiLocation,
+			rate_limit: &RateLimit<BlockNumberFor<T>>,
+		) -> u128 {
+			let inflow = Inflows::<T>::get(asset);
+			let elapsed =
+				frame_system::Pallet::<T>::block_number().saturating_sub(inflow.updated_at);
+			if elapsed >= rate_limit.period {
+				return 0;
+			}
+
+			let decayed = Perbill::from_rational(
+				elapsed.saturated_into::<u128>(),
+				rate_limit.period.saturated_into::<u128>(),
+			)
+			.mul_floor(rate_limit.amount);
+			inflow.amount.saturating_sub(decayed)
+		}
+
+		fn quarantine(
+			origin: MultiLocation,
+			asset: MultiLocation,
+			amount: u128,
+			beneficiary: MultiLocation,
+		) -> XcmResult {
+			if QuarantinedDeposits::<T>::count() >= T::MaxQuarantinedDeposits::get() {
+				return Err(XcmError::FailedToTransactAsset("Quarantine is full"));
+			}
+
+			let id = NextQuarantineId::<T>::mutate(|next_id| {
+				let id = *next_id;
+				*next_id = next_id.saturating_add(1);
+				id
+			});
+			QuarantinedDeposits::<T>::insert(
+				id,
+				QuarantinedDeposit {
+					origin,
+					asset,
+					amount,
+					beneficiary,
+					quarantined_at: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+
+			Self::deposit_event(Event::DepositQuarantined {
+				id,
+				origin,
+				asset,
+				amount,
+				beneficiary,
+			});
+			Ok(())
+		}
+	}
+
+	impl<T: Config> TransactAsset for Pallet<T> {
+		fn can_check_in(
+			origin: &MultiLocation,
+			what: &MultiAsset,
+			context: &XcmContext,
+		) -> XcmResult {
+			T::AssetTransactor::can_check_in(origin, what, context)
+		}
+
+		fn check_in(origin: &MultiLocation, what: &MultiAsset, context: &XcmContext) {
+			T::AssetTransactor::check_in(origin, what, context)
+		}
+
+		fn can_check_out(
+			dest: &MultiLocation,
+			what: &MultiAsset,
+			context: &XcmContext,
+		) -> XcmResult {
+			T::AssetTransactor::can_check_out(dest, what, context)
+		}
+
+		fn check_out(dest: &MultiLocation, what: &MultiAsset, context: &XcmContext) {
+			T::AssetTransactor::check_out(dest, what, context)
+		}
+
+		// Only the deposits of messages coming from other consensus systems are inspected.
+		// If the message cleared its origin, the deposit is attributed to the origin the message
+		// was executed with.
+		// The inflow is only recorded once the wrapped transactor has deposited the assets.
+		fn deposit_asset(
+			what: &MultiAsset,
+			who: &MultiLocation,
+			context: &XcmContext,
+		) -> XcmResult {
+			let origin = context
+				.origin
+				.or_else(|| crate::XCM_MESSAGE_ORIGIN::with(|origin| *origin));
+			let Some(origin) = origin.filter(|origin| origin.parents > 0) else {
+				return T::AssetTransactor::deposit_asset(what, who, context);
+			};
+
+			if Self::is_paused(&origin) {
+				return Err(XcmError::FailedToTransactAsset("Xcm origin is paused"));
+			}
+
+			let (asset, amount) = match what {
+				MultiAsset {
+					id: Concrete(asset),
+					fun: Fungible(amount),
+				} => (asset, *amount),
+				_ => return T::AssetTransactor::deposit_asset(what, who, context),
+			};
+			let Some(rate_limit) = RateLimits::<T>::get(asset) else {
+				return T::AssetTransactor::deposit_asset(what, who, context);
+			};
+
+			let inflow = Self::current_inflow(asset, &rate_limit).saturating_add(amount);
+			if inflow > rate_limit.amount {
+				return Self::quarantine(origin, *asset, amount, *who);
+			}
+
+			T::AssetTransactor::deposit_asset(what, who, context)?;
+			Inflows::<T>::insert(
+				asset,
+				Inflow {
+					amount: inflow,
+					updated_at: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+			Ok(())
+		}
+
+		fn withdraw_asset(
+			what: &MultiAsset,
+			who: &MultiLocation,
+			maybe_context: Option<&XcmContext>,
+		) -> Result<Assets, XcmError> {
+			T::AssetTransactor::withdraw_asset(what, who, maybe_context)
+		}
+
+		fn internal_transfer_asset(
+			asset: &MultiAsset,
+			from: &MultiLocation,
+			to: &MultiLocation,
+			context: &XcmContext,
+		) -> Result<Assets, XcmError> {
+			T::AssetTransactor::internal_transfer_asset(asset, from, to, context)
+		}
+
+		fn transfer_asset(
+			asset: &MultiAsset,
+			from: &MultiLocation,
+			to: &MultiLocation,
+			context: &XcmContext,
+		) -> Result<Assets, XcmError> {
+			T::AssetTransactor::transfer_asset(asset, from, to, context)
+		}
+	}
+}
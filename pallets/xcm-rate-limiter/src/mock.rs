@@ -0,0 +1,171 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Test utilities
+use crate as pallet_xcm_rate_limiter;
+
+use frame_support::traits::{Everything, Nothing};
+use frame_support::{construct_runtime, parameter_types};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
+use sp_runtime::BuildStorage;
+use xcm::latest::{prelude::*, Weight};
+use xcm_builder::{AllowUnpaidExecutionFrom, FixedWeightBounds};
+use xcm_executor::traits::TransactAsset;
+
+pub type AccountId = u64;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		XcmRateLimiter: pallet_xcm_rate_limiter,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u32 = 250;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type Nonce = u64;
+	type Block = Block;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+parameter_types! {
+	// Deposits performed by the wrapped transactor
+	pub static Deposited: Vec<(MultiAsset, MultiLocation)> = vec![];
+	pub static DepositFails: bool = false;
+}
+
+pub struct MockTransactor;
+impl TransactAsset for MockTransactor {
+	fn deposit_asset(what: &MultiAsset, who: &MultiLocation, _context: &XcmContext) -> XcmResult {
+		if DepositFails::get() {
+			return Err(XcmError::FailedToTransactAsset("Deposit failed"));
+		}
+		Deposited::mutate(|deposited| deposited.push((what.clone(), *who)));
+		Ok(())
+	}
+}
+
+parameter_types! {
+	pub const MaxQuarantinedDeposits: u32 = 2;
+	pub BenchmarkReleasableAsset: MultiLocation = MultiLocation::parent();
+}
+
+impl pallet_xcm_rate_limiter::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetTransactor = MockTransactor;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	type PauseOrigin = EnsureRoot<AccountId>;
+	type MaxQuarantinedDeposits = MaxQuarantinedDeposits;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkReleasableAsset = BenchmarkReleasableAsset;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const BaseXcmWeight: Weight = Weight::from_parts(1_000, 0);
+	pub const MaxInstructions: u32 = 100;
+	pub const MaxAssetsIntoHolding: u32 = 64;
+	pub UniversalLocation: InteriorMultiLocation =
+		X2(GlobalConsensus(NetworkId::Polkadot), Parachain(2000));
+}
+
+pub struct XcmConfig;
+impl xcm_executor::Config for XcmConfig {
+	type RuntimeCall = RuntimeCall;
+	type XcmSender = ();
+	type AssetTransactor = XcmRateLimiter;
+	type OriginConverter = ();
+	type IsReserve = Everything;
+	type IsTeleporter = ();
+	type UniversalLocation = UniversalLocation;
+	type Barrier = AllowUnpaidExecutionFrom<Everything>;
+	type Weigher = FixedWeightBounds<BaseXcmWeight, RuntimeCall, MaxInstructions>;
+	type Trader = ();
+	type ResponseHandler = ();
+	type SubscriptionService = ();
+	type AssetTrap = ();
+	type AssetClaims = ();
+	type CallDispatcher = RuntimeCall;
+	type AssetLocker = ();
+	type AssetExchanger = ();
+	type PalletInstancesInfo = ();
+	type MaxAssetsIntoHolding = MaxAssetsIntoHolding;
+	type FeeManager = ();
+	type MessageExporter = ();
+	type UniversalAliases = Nothing;
+	type SafeCallFilter = Everything;
+	type Aliasers = Nothing;
+}
+
+/// Xcm executor recording the origin of the executed messages, as in the runtimes
+pub type XcmExecutor =
+	pallet_xcm_rate_limiter::XcmExecutorWrapper<RuntimeCall, xcm_executor::XcmExecutor<XcmConfig>>;
+
+pub(crate) fn events() -> Vec<pallet_xcm_rate_limiter::Event<Test>> {
+	System::events()
+		.into_iter()
+		.map(|r| r.event)
+		.filter_map(|e| {
+			if let RuntimeEvent::XcmRateLimiter(inner) = e {
+				Some(inner)
+			} else {
+				None
+			}
+		})
+		.collect::<Vec<_>>()
+}
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default()
+		.build_storage()
+		.expect("Frame system builds valid default genesis config");
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		Deposited::take();
+		DepositFails::set(false);
+	});
+	ext
+}
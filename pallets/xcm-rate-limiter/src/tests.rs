@@ -0,0 +1,390 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::{
+	events, new_test_ext, DepositFails, Deposited, RuntimeCall, RuntimeOrigin, System, Test,
+	XcmExecutor, XcmRateLimiter,
+};
+use crate::{Error, Event, Inflow, QuarantinedDeposit, RateLimit};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+use xcm::latest::{prelude::*, Weight};
+use xcm::VersionedMultiLocation;
+use xcm_executor::traits::TransactAsset;
+
+const SIBLING: u32 = 1000;
+
+fn versioned(location: MultiLocation) -> Box<VersionedMultiLocation> {
+	Box::new(VersionedMultiLocation::V3(location))
+}
+
+fn sibling() -> MultiLocation {
+	MultiLocation::new(1, X1(Parachain(SIBLING)))
+}
+
+fn beneficiary() -> MultiLocation {
+	MultiLocation::new(
+		0,
+		X1(AccountKey20 {
+			network: None,
+			key: [1u8; 20],
+		}),
+	)
+}
+
+fn deposit_from(origin: Option<MultiLocation>, asset: MultiLocation, amount: u128) -> XcmResult {
+	let context = XcmContext {
+		origin,
+		message_id: XcmHash::default(),
+		topic: None,
+	};
+	XcmRateLimiter::deposit_asset(&(asset, amount).into(), &beneficiary(), &context)
+}
+
+/// Reserve transfer of `amount` of `asset` to `beneficiary()`, with the ClearOrigin instruction
+/// sent by pallet-xtokens and pallet-xcm
+fn reserve_transfer(asset: MultiLocation, amount: u128) -> Xcm<RuntimeCall> {
+	Xcm(vec![
+		ReserveAssetDeposited((asset, amount).into()),
+		ClearOrigin,
+		DepositAsset {
+			assets: Wild(AllCounted(1)),
+			beneficiary: beneficiary(),
+		},
+	])
+}
+
+fn execute_from(origin: MultiLocation, message: Xcm<RuntimeCall>) -> Outcome {
+	XcmExecutor::execute_xcm(origin, message, XcmHash::default(), Weight::MAX)
+}
+
+#[test]
+fn set_and_remove_rate_limit() {
+	new_test_ext().execute_with(|| {
+		let asset = MultiLocation::parent();
+
+		assert_noop!(
+			XcmRateLimiter::set_rate_limit(RuntimeOrigin::signed(1), versioned(asset), 1_000, 10),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			XcmRateLimiter::set_rate_limit(RuntimeOrigin::root(), versioned(asset), 1_000, 0),
+			Error::<Test>::ZeroPeriod
+		);
+		assert_ok!(XcmRateLimiter::set_rate_limit(
+			RuntimeOrigin::root(),
+			versioned(asset),
+			1_000,
+			10
+		));
+		assert_eq!(
+			XcmRateLimiter::rate_limit(asset),
+			Some(RateLimit {
+				amount: 1_000,
+				period: 10
+			})
+		);
+
+		assert_ok!(XcmRateLimiter::remove_rate_limit(
+			RuntimeOrigin::root(),
+			versioned(asset)
+		));
+		assert_noop!(
+			XcmRateLimiter::remove_rate_limit(RuntimeOrigin::root(), versioned(asset)),
+			Error::<Test>::RateLimitNotFound
+		);
+
+		assert_eq!(
+			events(),
+			vec![
+				Event::RateLimitSet {
+					asset,
+					amount: 1_000,
+					period: 10
+				},
+				Event::RateLimitRemoved { asset },
+			]
+		);
+	});
+}
+
+#[test]
+fn deposits_exceeding_rate_limit_are_quarantined() {
+	new_test_ext().execute_with(|| {
+		let asset = MultiLocation::parent();
+		assert_ok!(XcmRateLimiter::set_rate_limit(
+			RuntimeOrigin::root(),
+			versioned(asset),
+			1_000,
+			10
+		));
+
+		assert_ok!(deposit_from(Some(sibling()), asset, 600));
+		assert_eq!(
+			XcmRateLimiter::inflow(asset),
+			Inflow {
+				amount: 600,
+				updated_at: 1
+			}
+		);
+
+		// Exceeds the limit, the deposit is held
+		assert_ok!(deposit_from(Some(sibling()), asset, 500));
+		assert_eq!(Deposited::get(), vec![((asset, 600).into(), beneficiary())]);
+		assert_eq!(
+			XcmRateLimiter::quarantined_deposit(0),
+			Some(QuarantinedDeposit {
+				origin: sibling(),
+				asset,
+				amount: 500,
+				beneficiary: beneficiary(),
+				quarantined_at: 1,
+			})
+		);
+
+		// Half of the window later, half of the limit is available again
+		System::set_block_number(6);
+		assert_ok!(deposit_from(Some(sibling()), asset, 500));
+		assert_eq!(
+			XcmRateLimiter::inflow(asset),
+			Inflow {
+				amount: 600,
+				updated_at: 6
+			}
+		);
+		assert_eq!(Deposited::get().len(), 2);
+
+		assert_eq!(
+			events(),
+			vec![
+				Event::RateLimitSet {
+					asset,
+					amount: 1_000,
+					period: 10
+				},
+				Event::DepositQuarantined {
+					id: 0,
+					origin: sibling(),
+					asset,
+					amount: 500,
+					beneficiary: beneficiary(),
+				},
+			]
+		);
+	});
+}
+
+#[test]
+fn local_deposits_are_not_limited() {
+	new_test_ext().execute_with(|| {
+		let asset = MultiLocation::parent();
+		assert_ok!(XcmRateLimiter::set_rate_limit(
+			RuntimeOrigin::root(),
+			versioned(asset),
+			1_000,
+			10
+		));
+
+		assert_ok!(deposit_from(Some(beneficiary()), asset, 2_000));
+		assert_ok!(deposit_from(None, asset, 2_000));
+
+		assert_eq!(Deposited::get().len(), 2);
+		assert_eq!(XcmRateLimiter::inflow(asset), Inflow::default());
+	});
+}
+
+#[test]
+fn release_and_reject_quarantined_deposits() {
+	new_test_ext().execute_with(|| {
+		let asset = MultiLocation::parent();
+		assert_ok!(XcmRateLimiter::set_rate_limit(
+			RuntimeOrigin::root(),
+			versioned(asset),
+			1_000,
+			10
+		));
+
+		assert_ok!(deposit_from(Some(sibling()), asset, 2_000));
+		assert_ok!(deposit_from(Some(sibling()), asset, 3_000));
+		// The quarantine is full
+		assert_eq!(
+			deposit_from(Some(sibling()), asset, 4_000),
+			Err(XcmError::FailedToTransactAsset("Quarantine is full"))
+		);
+		assert!(Deposited::get().is_empty());
+
+		// A failed release keeps the deposit in quarantine
+		DepositFails::set(true);
+		assert_noop!(
+			XcmRateLimiter::release_quarantined_deposit(RuntimeOrigin::root(), 0),
+			Error::<Test>::ReleaseFailed
+		);
+		DepositFails::set(false);
+
+		assert_noop!(
+			XcmRateLimiter::release_quarantined_deposit(RuntimeOrigin::signed(1), 0),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(XcmRateLimiter::release_quarantined_deposit(
+			RuntimeOrigin::root(),
+			0
+		));
+		assert_eq!(
+			Deposited::get(),
+			vec![((asset, 2_000).into(), beneficiary())]
+		);
+
+		assert_ok!(XcmRateLimiter::reject_quarantined_deposit(
+			RuntimeOrigin::root(),
+			1
+		));
+		assert_eq!(Deposited::get().len(), 1);
+
+		assert_noop!(
+			XcmRateLimiter::release_quarantined_deposit(RuntimeOrigin::root(), 1),
+			Error::<Test>::QuarantinedDepositNotFound
+		);
+		assert_noop!(
+			XcmRateLimiter::reject_quarantined_deposit(RuntimeOrigin::root(), 0),
+			Error::<Test>::QuarantinedDepositNotFound
+		);
+
+		assert_eq!(
+			events()[3..],
+			vec![
+				Event::QuarantinedDepositReleased { id: 0 },
+				Event::QuarantinedDepositRejected { id: 1 },
+			]
+		);
+	});
+}
+
+#[test]
+fn paused_origins_cannot_deposit() {
+	new_test_ext().execute_with(|| {
+		let asset = MultiLocation::parent();
+		let sibling_account = MultiLocation::new(
+			1,
+			X2(
+				Parachain(SIBLING),
+				AccountKey20 {
+					network: None,
+					key: [2u8; 20],
+				},
+			),
+		);
+		let other_sibling = MultiLocation::new(1, X1(Parachain(SIBLING + 1)));
+
+		assert_ok!(XcmRateLimiter::pause_origin(
+			RuntimeOrigin::root(),
+			versioned(sibling())
+		));
+		assert_noop!(
+			XcmRateLimiter::pause_origin(RuntimeOrigin::root(), versioned(sibling())),
+			Error::<Test>::AlreadyPaused
+		);
+
+		let paused = Err(XcmError::FailedToTransactAsset("Xcm origin is paused"));
+		assert_eq!(deposit_from(Some(sibling()), asset, 1), paused);
+		assert_eq!(deposit_from(Some(sibling_account), asset, 1), paused);
+		assert_ok!(deposit_from(Some(other_sibling), asset, 1));
+
+		// Pausing the relay does not pause the other chains
+		assert_ok!(XcmRateLimiter::pause_origin(
+			RuntimeOrigin::root(),
+			versioned(MultiLocation::parent())
+		));
+		assert_eq!(
+			deposit_from(Some(MultiLocation::parent()), asset, 1),
+			paused
+		);
+		assert_ok!(deposit_from(Some(other_sibling), asset, 1));
+
+		assert_ok!(XcmRateLimiter::unpause_origin(
+			RuntimeOrigin::root(),
+			versioned(sibling())
+		));
+		assert_noop!(
+			XcmRateLimiter::unpause_origin(RuntimeOrigin::root(), versioned(sibling())),
+			Error::<Test>::NotPaused
+		);
+		assert_ok!(deposit_from(Some(sibling_account), asset, 1));
+
+		assert_eq!(Deposited::get().len(), 3);
+	});
+}
+
+#[test]
+fn reserve_transfers_clearing_origin_are_rate_limited() {
+	new_test_ext().execute_with(|| {
+		let asset = MultiLocation::parent();
+		assert_ok!(XcmRateLimiter::set_rate_limit(
+			RuntimeOrigin::root(),
+			versioned(asset),
+			1_000,
+			10
+		));
+
+		assert!(matches!(
+			execute_from(sibling(), reserve_transfer(asset, 600)),
+			Outcome::Complete(_)
+		));
+		assert_eq!(Deposited::get(), vec![((asset, 600).into(), beneficiary())]);
+		assert_eq!(XcmRateLimiter::inflow(asset).amount, 600);
+
+		// The deposit is attributed to the sibling although the message cleared its origin
+		assert!(matches!(
+			execute_from(sibling(), reserve_transfer(asset, 600)),
+			Outcome::Complete(_)
+		));
+		assert_eq!(Deposited::get().len(), 1);
+		assert_eq!(
+			XcmRateLimiter::quarantined_deposit(0),
+			Some(QuarantinedDeposit {
+				origin: sibling(),
+				asset,
+				amount: 600,
+				beneficiary: beneficiary(),
+				quarantined_at: 1,
+			})
+		);
+	});
+}
+
+#[test]
+fn reserve_transfers_clearing_origin_from_paused_origins_fail() {
+	new_test_ext().execute_with(|| {
+		let asset = MultiLocation::parent();
+		assert_ok!(XcmRateLimiter::pause_origin(
+			RuntimeOrigin::root(),
+			versioned(sibling())
+		));
+
+		assert!(matches!(
+			execute_from(sibling(), reserve_transfer(asset, 1)),
+			Outcome::Incomplete(_, XcmError::FailedToTransactAsset(_))
+		));
+		assert!(Deposited::get().is_empty());
+
+		// Other origins can still deposit
+		let other_sibling = MultiLocation::new(1, X1(Parachain(SIBLING + 1)));
+		assert!(matches!(
+			execute_from(other_sibling, reserve_transfer(asset, 1)),
+			Outcome::Complete(_)
+		));
+		assert_eq!(Deposited::get().len(), 1);
+	});
+}
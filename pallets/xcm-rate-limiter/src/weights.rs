@@ -0,0 +1,209 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+
+//! Autogenerated weights for pallet_xcm_rate_limiter
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-11-27, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `ip-10-0-0-176`, CPU: `Intel(R) Xeon(R) Platinum 8375C CPU @ 2.90GHz`
+//! EXECUTION: None, WASM-EXECUTION: Compiled, CHAIN: Some("moonbase-dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/moonbeam
+// benchmark
+// pallet
+// --chain=moonbase-dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_xcm_rate_limiter
+// --extrinsic=*
+// --wasm-execution=compiled
+// --template=./benchmarking/frame-weight-template.hbs
+// --output=./pallets/xcm-rate-limiter/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_xcm_rate_limiter.
+pub trait WeightInfo {
+	fn set_rate_limit() -> Weight;
+	fn remove_rate_limit() -> Weight;
+	fn pause_origin() -> Weight;
+	fn unpause_origin() -> Weight;
+	fn release_quarantined_deposit() -> Weight;
+	fn reject_quarantined_deposit() -> Weight;
+}
+
+/// Weights for pallet_xcm_rate_limiter using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: XcmRateLimiter RateLimits (r:0 w:1)
+	/// Proof: XcmRateLimiter RateLimits (max_values: None, max_size: Some(614), added: 3089, mode: MaxEncodedLen)
+	fn set_rate_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `1494`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 1494)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: XcmRateLimiter RateLimits (r:1 w:1)
+	/// Proof: XcmRateLimiter RateLimits (max_values: None, max_size: Some(614), added: 3089, mode: MaxEncodedLen)
+	/// Storage: XcmRateLimiter Inflows (r:0 w:1)
+	/// Proof: XcmRateLimiter Inflows (max_values: None, max_size: Some(614), added: 3089, mode: MaxEncodedLen)
+	fn remove_rate_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `155`
+		//  Estimated: `4079`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 4079)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: XcmRateLimiter PausedOrigins (r:1 w:1)
+	/// Proof: XcmRateLimiter PausedOrigins (max_values: None, max_size: Some(590), added: 3065, mode: MaxEncodedLen)
+	fn pause_origin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `4055`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 4055)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: XcmRateLimiter PausedOrigins (r:1 w:1)
+	/// Proof: XcmRateLimiter PausedOrigins (max_values: None, max_size: Some(590), added: 3065, mode: MaxEncodedLen)
+	fn unpause_origin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `131`
+		//  Estimated: `4055`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 4055)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: XcmRateLimiter QuarantinedDeposits (r:1 w:1)
+	/// Proof: XcmRateLimiter QuarantinedDeposits (max_values: None, max_size: Some(1749), added: 4224, mode: MaxEncodedLen)
+	/// Storage: XcmRateLimiter CounterForQuarantinedDeposits (r:1 w:1)
+	/// Proof: XcmRateLimiter CounterForQuarantinedDeposits (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	fn release_quarantined_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `368`
+		//  Estimated: `5214`
+		// Minimum execution time: 41_000_000 picoseconds.
+		Weight::from_parts(41_000_000, 5214)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: XcmRateLimiter QuarantinedDeposits (r:1 w:1)
+	/// Proof: XcmRateLimiter QuarantinedDeposits (max_values: None, max_size: Some(1749), added: 4224, mode: MaxEncodedLen)
+	/// Storage: XcmRateLimiter CounterForQuarantinedDeposits (r:1 w:1)
+	/// Proof: XcmRateLimiter CounterForQuarantinedDeposits (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn reject_quarantined_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `257`
+		//  Estimated: `5214`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 5214)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: XcmRateLimiter RateLimits (r:0 w:1)
+	/// Proof: XcmRateLimiter RateLimits (max_values: None, max_size: Some(614), added: 3089, mode: MaxEncodedLen)
+	fn set_rate_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `1494`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 1494)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: XcmRateLimiter RateLimits (r:1 w:1)
+	/// Proof: XcmRateLimiter RateLimits (max_values: None, max_size: Some(614), added: 3089, mode: MaxEncodedLen)
+	/// Storage: XcmRateLimiter Inflows (r:0 w:1)
+	/// Proof: XcmRateLimiter Inflows (max_values: None, max_size: Some(614), added: 3089, mode: MaxEncodedLen)
+	fn remove_rate_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `155`
+		//  Estimated: `4079`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 4079)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: XcmRateLimiter PausedOrigins (r:1 w:1)
+	/// Proof: XcmRateLimiter PausedOrigins (max_values: None, max_size: Some(590), added: 3065, mode: MaxEncodedLen)
+	fn pause_origin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `4055`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 4055)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: XcmRateLimiter PausedOrigins (r:1 w:1)
+	/// Proof: XcmRateLimiter PausedOrigins (max_values: None, max_size: Some(590), added: 3065, mode: MaxEncodedLen)
+	fn unpause_origin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `131`
+		//  Estimated: `4055`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 4055)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: XcmRateLimiter QuarantinedDeposits (r:1 w:1)
+	/// Proof: XcmRateLimiter QuarantinedDeposits (max_values: None, max_size: Some(1749), added: 4224, mode: MaxEncodedLen)
+	/// Storage: XcmRateLimiter CounterForQuarantinedDeposits (r:1 w:1)
+	/// Proof: XcmRateLimiter CounterForQuarantinedDeposits (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	fn release_quarantined_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `368`
+		//  Estimated: `5214`
+		// Minimum execution time: 41_000_000 picoseconds.
+		Weight::from_parts(41_000_000, 5214)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: XcmRateLimiter QuarantinedDeposits (r:1 w:1)
+	/// Proof: XcmRateLimiter QuarantinedDeposits (max_values: None, max_size: Some(1749), added: 4224, mode: MaxEncodedLen)
+	/// Storage: XcmRateLimiter CounterForQuarantinedDeposits (r:1 w:1)
+	/// Proof: XcmRateLimiter CounterForQuarantinedDeposits (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn reject_quarantined_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `257`
+		//  Estimated: `5214`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 5214)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
@@ -16,9 +16,12 @@
 
 #![cfg(feature = "runtime-benchmarks")]
 
-use crate::{Call, Config, Currency, CurrencyPayment, HrmpOperation, Pallet, TransactWeights};
+use crate::{
+	Call, Config, Currency, CurrencyPayment, HrmpOperation, Pallet, TransactReport,
+	TransactStatusQueries, TransactStatusQuery, TransactStatusQueryCount, TransactWeights,
+};
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
-use frame_support::weights::Weight;
+use frame_support::{traits::EnsureOrigin, weights::Weight};
 use frame_system::RawOrigin;
 use sp_std::boxed::Box;
 use sp_std::vec;
@@ -279,6 +282,62 @@ benchmarks! {
 			overall_weight: None
 		}
 	)
+
+	register_transact_status_query {
+		let user: T::AccountId  = account("account id", 0u32, 0u32);
+	}: {
+		Pallet::<T>::with_report_appendix(
+			None,
+			Some(TransactReport::TransactStatus),
+			&MultiLocation::parent(),
+			user,
+			MultiLocation::here(),
+		)?;
+	}
+	verify {
+		assert_eq!(TransactStatusQueries::<T>::iter().count(), 1);
+	}
+
+	notify_transact_status {
+		let user: T::AccountId  = account("account id", 0u32, 0u32);
+		let origin = T::ResponseOrigin::try_successful_origin()
+			.map_err(|_| frame_benchmarking::BenchmarkError::Weightless)?;
+		let responder = T::ResponseOrigin::ensure_origin(origin.clone())
+			.map_err(|_| frame_benchmarking::BenchmarkError::Weightless)?;
+		let query_id = 1u64;
+		TransactStatusQueries::<T>::insert(query_id, TransactStatusQuery {
+			account: user,
+			dest: responder,
+			report: TransactReport::TransactStatus,
+			timeout: 1u32.into(),
+			response: None,
+		});
+		let response = Response::DispatchResult(MaybeErrorCode::Error(
+			vec![0u8; 128].try_into().expect("128 bytes is the max dispatch error length")
+		));
+	}: _<T::RuntimeOrigin>(origin, query_id, response.clone())
+	verify {
+		assert_eq!(
+			Pallet::<T>::transact_status(query_id).and_then(|query| query.response),
+			Some(response)
+		);
+	}
+
+	remove_transact_status {
+		let user: T::AccountId  = account("account id", 0u32, 0u32);
+		let query_id = 1u64;
+		TransactStatusQueries::<T>::insert(query_id, TransactStatusQuery {
+			account: user.clone(),
+			dest: MultiLocation::parent(),
+			report: TransactReport::TransactStatus,
+			timeout: 1u32.into(),
+			response: Some(Response::DispatchResult(MaybeErrorCode::Success)),
+		});
+		TransactStatusQueryCount::<T>::insert(&user, 1);
+	}: _(RawOrigin::Signed(user), query_id)
+	verify {
+		assert!(Pallet::<T>::transact_status(query_id).is_none());
+	}
 }
 
 #[cfg(test)]
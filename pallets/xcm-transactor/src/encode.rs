@@ -18,8 +18,9 @@
 //!
 //! ## Overview
 //!
-//! Module to provide `StakeEncodeCall`, `HrmpEncodeCall` and `UtilityEncodeCall` implementations
-//! for the Xcm Transactor pallet
+//! Module to provide `StakeEncodeCall`, `HrmpEncodeCall`, `UtilityEncodeCall`,
+//! `NominationPoolsEncodeCall`, `ConvictionVotingEncodeCall` and `ProxyEncodeCall`
+//! implementations for the Xcm Transactor pallet
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -27,8 +28,10 @@ use frame_support::pallet_prelude::*;
 use sp_runtime::traits::{AccountIdLookup, StaticLookup};
 use sp_std::prelude::*;
 use xcm_primitives::{
-	AvailableStakeCalls, HrmpAvailableCalls, HrmpEncodeCall, StakeEncodeCall,
-	UtilityAvailableCalls, UtilityEncodeCall,
+	AvailableConvictionVotingCalls, AvailableNominationPoolsCalls, AvailableProxyCalls,
+	AvailableStakeCalls, ConvictionVotingEncodeCall, HrmpAvailableCalls, HrmpEncodeCall,
+	NominationPoolsEncodeCall, ProxyEncodeCall, StakeEncodeCall, UtilityAvailableCalls,
+	UtilityEncodeCall,
 };
 
 pub use crate::pallet::*;
@@ -226,3 +229,149 @@ impl<T: Config> StakeEncodeCall for Pallet<T> {
 		}
 	}
 }
+
+fn encode_lookup_source(account: sp_runtime::AccountId32) -> Vec<u8> {
+	let source: <AccountIdLookup<sp_runtime::AccountId32, ()> as StaticLookup>::Source =
+		account.into();
+	source.encode()
+}
+
+impl<T: Config> NominationPoolsEncodeCall for Pallet<T> {
+	fn nomination_pools_encode_call(call: AvailableNominationPoolsCalls) -> Vec<u8> {
+		match call {
+			AvailableNominationPoolsCalls::Join(a, b) => {
+				let mut encoded_call: Vec<u8> = Vec::new();
+				// pallet index
+				encoded_call.push(RelayIndices::<T>::get().nomination_pools);
+				// call index
+				encoded_call.push(RelayIndices::<T>::get().pool_join);
+				// encoded arguments
+				encoded_call.append(&mut encode_compact_arg(a));
+				encoded_call.append(&mut b.encode());
+				encoded_call
+			}
+
+			AvailableNominationPoolsCalls::BondExtra(a) => {
+				let mut encoded_call: Vec<u8> = Vec::new();
+				// pallet index
+				encoded_call.push(RelayIndices::<T>::get().nomination_pools);
+				// call index
+				encoded_call.push(RelayIndices::<T>::get().pool_bond_extra);
+				// encoded argument
+				encoded_call.append(&mut a.encode());
+				encoded_call
+			}
+
+			AvailableNominationPoolsCalls::Unbond(a, b) => {
+				let mut encoded_call: Vec<u8> = Vec::new();
+				// pallet index
+				encoded_call.push(RelayIndices::<T>::get().nomination_pools);
+				// call index
+				encoded_call.push(RelayIndices::<T>::get().pool_unbond);
+				// encoded arguments
+				encoded_call.append(&mut encode_lookup_source(a));
+				encoded_call.append(&mut encode_compact_arg(b));
+				encoded_call
+			}
+
+			AvailableNominationPoolsCalls::ClaimPayout => {
+				let mut encoded_call: Vec<u8> = Vec::new();
+				// pallet index
+				encoded_call.push(RelayIndices::<T>::get().nomination_pools);
+				// call index
+				encoded_call.push(RelayIndices::<T>::get().pool_claim_payout);
+				encoded_call
+			}
+		}
+	}
+}
+
+impl<T: Config> ConvictionVotingEncodeCall for Pallet<T> {
+	fn conviction_voting_encode_call(call: AvailableConvictionVotingCalls) -> Vec<u8> {
+		match call {
+			AvailableConvictionVotingCalls::Vote(a, b) => {
+				let mut encoded_call: Vec<u8> = Vec::new();
+				// pallet index
+				encoded_call.push(RelayIndices::<T>::get().conviction_voting);
+				// call index
+				encoded_call.push(RelayIndices::<T>::get().vote);
+				// encoded arguments
+				encoded_call.append(&mut encode_compact_arg(a));
+				encoded_call.append(&mut b.encode());
+				encoded_call
+			}
+
+			AvailableConvictionVotingCalls::RemoveVote(a, b) => {
+				let mut encoded_call: Vec<u8> = Vec::new();
+				// pallet index
+				encoded_call.push(RelayIndices::<T>::get().conviction_voting);
+				// call index
+				encoded_call.push(RelayIndices::<T>::get().remove_vote);
+				// encoded arguments
+				encoded_call.append(&mut a.encode());
+				encoded_call.append(&mut b.encode());
+				encoded_call
+			}
+
+			AvailableConvictionVotingCalls::Delegate(a, b, c, d) => {
+				let mut encoded_call: Vec<u8> = Vec::new();
+				// pallet index
+				encoded_call.push(RelayIndices::<T>::get().conviction_voting);
+				// call index
+				encoded_call.push(RelayIndices::<T>::get().delegate);
+				// encoded arguments
+				encoded_call.append(&mut a.encode());
+				encoded_call.append(&mut encode_lookup_source(b));
+				encoded_call.append(&mut c.encode());
+				encoded_call.append(&mut d.encode());
+				encoded_call
+			}
+		}
+	}
+}
+
+impl<T: Config> ProxyEncodeCall for Pallet<T> {
+	fn proxy_encode_call(call: AvailableProxyCalls) -> Vec<u8> {
+		match call {
+			AvailableProxyCalls::Proxy(a, b, mut c) => {
+				let mut encoded_call: Vec<u8> = Vec::new();
+				// pallet index
+				encoded_call.push(RelayIndices::<T>::get().proxy);
+				// call index
+				encoded_call.push(RelayIndices::<T>::get().proxy_call);
+				// encoded arguments
+				encoded_call.append(&mut encode_lookup_source(a));
+				encoded_call.append(&mut b.encode());
+				// the inner call is appended as is, without length prefix
+				encoded_call.append(&mut c);
+				encoded_call
+			}
+
+			AvailableProxyCalls::AddProxy(a, b, c) => {
+				let mut encoded_call: Vec<u8> = Vec::new();
+				// pallet index
+				encoded_call.push(RelayIndices::<T>::get().proxy);
+				// call index
+				encoded_call.push(RelayIndices::<T>::get().add_proxy);
+				// encoded arguments
+				encoded_call.append(&mut encode_lookup_source(a));
+				encoded_call.append(&mut b.encode());
+				encoded_call.append(&mut c.encode());
+				encoded_call
+			}
+
+			AvailableProxyCalls::RemoveProxy(a, b, c) => {
+				let mut encoded_call: Vec<u8> = Vec::new();
+				// pallet index
+				encoded_call.push(RelayIndices::<T>::get().proxy);
+				// call index
+				encoded_call.push(RelayIndices::<T>::get().remove_proxy);
+				// encoded arguments
+				encoded_call.append(&mut encode_lookup_source(a));
+				encoded_call.append(&mut b.encode());
+				encoded_call.append(&mut c.encode());
+				encoded_call
+			}
+		}
+	}
+}
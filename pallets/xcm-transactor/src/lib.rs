@@ -98,7 +98,7 @@ pub mod pallet {
 	use frame_support::{pallet_prelude::*, weights::constants::WEIGHT_REF_TIME_PER_SECOND};
 	use frame_system::{ensure_signed, pallet_prelude::*};
 	use orml_traits::location::{Parse, Reserve};
-	use sp_runtime::traits::{AtLeast32BitUnsigned, Bounded, Convert};
+	use sp_runtime::traits::{AtLeast32BitUnsigned, Bounded, Convert, Saturating};
 	use sp_std::boxed::Box;
 	use sp_std::convert::TryFrom;
 	use sp_std::prelude::*;
@@ -106,8 +106,8 @@ pub mod pallet {
 	use xcm::{latest::prelude::*, VersionedMultiLocation};
 	use xcm_executor::traits::{TransactAsset, WeightBounds};
 	use xcm_primitives::{
-		FilterMaxAssetFee, HrmpAvailableCalls, HrmpEncodeCall, UtilityAvailableCalls,
-		UtilityEncodeCall, XcmTransact,
+		FilterMaxAssetFee, HrmpAvailableCalls, HrmpEncodeCall, UnitsToWeightRatio,
+		UtilityAvailableCalls, UtilityEncodeCall, XcmTransact,
 	};
 
 	#[pallet::pallet]
@@ -179,9 +179,63 @@ pub mod pallet {
 		/// The way to filter the max fee to use for HRMP management operations
 		type MaxHrmpFee: FilterMaxAssetFee;
 
+		/// Registers the queries answered by the transact status reports
+		type QueryRegistrar: NotifyQueryRegistrar<Call<Self>, BlockNumberFor<Self>>;
+
+		/// Origin of the transact status reports, resolving to the responder location
+		type ResponseOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = MultiLocation>;
+
+		/// Number of blocks after which a transact status query expires
+		#[pallet::constant]
+		type TransactStatusQueryTimeout: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of transact status queries an account can have stored at once
+		#[pallet::constant]
+		type MaxTransactStatusQueries: Get<u32>;
+
+		/// Source of fee per second rates (e.g. derived from a price oracle), preferred over the
+		/// ones stored in DestinationAssetFeePerSecond when available
+		type FeePerSecondProvider: UnitsToWeightRatio<MultiLocation>;
+
 		type WeightInfo: WeightInfo;
 	}
 
+	/// Registers xcm queries whose responses are notified through a call of this pallet
+	pub trait NotifyQueryRegistrar<Call, BlockNumber> {
+		/// Register a query expecting a response from `responder`, returning its id.
+		/// `match_querier` is the querier that the response must be sent from.
+		fn new_notify_query(
+			responder: MultiLocation,
+			notify: Call,
+			timeout: BlockNumber,
+			match_querier: MultiLocation,
+		) -> QueryId;
+	}
+
+	/// Kind of report sent back once the transact message is executed in the destination chain
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, scale_info::TypeInfo)]
+	pub enum TransactReport {
+		/// Report the dispatch result of the Transact instruction (ReportTransactStatus)
+		TransactStatus,
+		/// Report the execution result of the whole xcm message (ReportError)
+		ExecutionResult,
+	}
+
+	/// Information about a transact status query
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct TransactStatusQuery<AccountId, BlockNumber> {
+		/// The account that sent the transact message
+		pub account: AccountId,
+		/// The destination of the transact message
+		pub dest: MultiLocation,
+		/// The kind of report requested
+		pub report: TransactReport,
+		/// The block after which the response is not expected anymore
+		pub timeout: BlockNumber,
+		/// The response, if it has been received
+		pub response: Option<Response>,
+	}
+
 	/// Stores the information to be able to issue a transact operation in another chain use an
 	/// asset as fee payer.
 	#[derive(
@@ -322,6 +376,21 @@ pub mod pallet {
 	#[pallet::getter(fn relay_indices)]
 	pub type RelayIndices<T: Config> = StorageValue<_, RelayChainIndices, ValueQuery>;
 
+	/// Stores the transact status queries, by query id
+	#[pallet::storage]
+	#[pallet::getter(fn transact_status)]
+	pub type TransactStatusQueries<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		QueryId,
+		TransactStatusQuery<T::AccountId, BlockNumberFor<T>>,
+	>;
+
+	/// Number of transact status queries stored for each account
+	#[pallet::storage]
+	pub type TransactStatusQueryCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
 	/// An error that can occur while executing the mapping pallet's logic.
 	#[pallet::error]
 	pub enum Error<T> {
@@ -352,6 +421,12 @@ pub mod pallet {
 		TooMuchFeeUsed,
 		ErrorValidating,
 		RefundNotSupportedWithTransactInfo,
+		ReportNotSupportedWithTransactInfo,
+		UnknownQuery,
+		UnexpectedResponder,
+		QueryAlreadyAnswered,
+		QueryNotAnswered,
+		TooManyTransactStatusQueries,
 	}
 
 	#[pallet::event]
@@ -410,6 +485,22 @@ pub mod pallet {
 		HrmpManagementSent {
 			action: HrmpOperation,
 		},
+		/// A transact status query has been registered
+		TransactStatusQueryRegistered {
+			query_id: QueryId,
+			account: T::AccountId,
+			dest: MultiLocation,
+		},
+		/// The destination chain reported the status of a transact message
+		TransactStatusReported {
+			query_id: QueryId,
+			dest: MultiLocation,
+			response: Response,
+		},
+		/// A transact status query has been removed
+		TransactStatusQueryRemoved {
+			query_id: QueryId,
+		},
 	}
 
 	#[pallet::genesis_config]
@@ -509,80 +600,17 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			let fee_location = Self::currency_to_multilocation(fee.currency)
-				.ok_or(Error::<T>::NotCrossChainTransferableCurrency)?;
-
-			// The index exists
-			let account = IndexToAccount::<T>::get(index).ok_or(Error::<T>::UnclaimedIndex)?;
-			// The derivative index is owned by the origin
-			ensure!(account == who, Error::<T>::NotOwner);
-
-			// Encode call bytes
-			// We make sure the inner call is wrapped on a as_derivative dispatchable
-			let call_bytes: Vec<u8> = dest
-				.clone()
-				.encode_call(UtilityAvailableCalls::AsDerivative(index, inner_call));
-
-			// Grab the destination
-			let dest = dest.destination();
-
-			// Calculate the total weight that the xcm message is going to spend in the
-			// destination chain
-			let total_weight = weight_info.overall_weight.map_or_else(
-				|| -> Result<_, DispatchError> {
-					let weight_info = Self::take_weight_from_transact_info(
-						dest.clone(),
-						weight_info.transact_required_weight_at_most,
-						refund,
-					)?;
-					Ok(WeightLimit::from(Some(weight_info)))
-				},
-				|v| Ok(v),
-			)?;
-
-			let total_weight_fee_calculation = match total_weight {
-				Unlimited => MAX_WEIGHT,
-				Limited(x) => x,
-			};
-
-			// Calculate fee based on FeePerSecond
-			let fee = Self::calculate_fee(
-				fee_location,
-				fee.fee_amount,
-				dest.clone(),
-				total_weight_fee_calculation,
-			)?;
-
-			// If refund is true, the appendix instruction will be a deposit back to the sovereign
-			let appendix = refund
-				.then(|| -> Result<_, DispatchError> {
-					Ok(vec![
-						RefundSurplus,
-						Self::deposit_instruction(T::SelfLocation::get(), &dest, 1u32)?,
-					])
-				})
-				.transpose()?;
-
-			Self::transact_in_dest_chain_asset_non_signed(
-				dest.clone(),
-				Some(who.clone()),
-				fee,
-				call_bytes.clone(),
-				OriginKind::SovereignAccount,
-				total_weight,
-				weight_info.transact_required_weight_at_most,
-				appendix,
-			)?;
-
-			// Deposit event
-			Self::deposit_event(Event::<T>::TransactedDerivative {
-				account_id: who,
+			Self::do_transact_through_derivative(
+				who,
 				dest,
-				call: call_bytes,
 				index,
-			});
-
-			Ok(())
+				fee,
+				inner_call,
+				weight_info,
+				refund,
+				None,
+			)
+			.map(|_| ())
 		}
 
 		/// Transact the call through the sovereign account in a destination chain,
@@ -613,68 +641,17 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::SovereignAccountDispatcherOrigin::ensure_origin(origin)?;
 
-			let fee_location = Self::currency_to_multilocation(fee.currency)
-				.ok_or(Error::<T>::NotCrossChainTransferableCurrency)?;
-
-			let dest = MultiLocation::try_from(*dest).map_err(|()| Error::<T>::BadVersion)?;
-
-			// Calculate the total weight that the xcm message is going to spend in the
-			// destination chain
-			let total_weight = weight_info.overall_weight.map_or_else(
-				|| -> Result<_, DispatchError> {
-					let weight_info = Self::take_weight_from_transact_info(
-						dest.clone(),
-						weight_info.transact_required_weight_at_most,
-						refund,
-					)?;
-					Ok(WeightLimit::from(Some(weight_info)))
-				},
-				|v| Ok(v),
-			)?;
-
-			let total_weight_fee_calculation = match total_weight {
-				Unlimited => MAX_WEIGHT,
-				Limited(x) => x,
-			};
-
-			// Calculate fee based on FeePerSecond and total_weight
-			let fee = Self::calculate_fee(
-				fee_location,
-				fee.fee_amount,
-				dest.clone(),
-				total_weight_fee_calculation,
-			)?;
-
-			// If refund is true, the appendix instruction will be a deposit back to the sovereign
-			let appendix = refund
-				.then(|| -> Result<_, DispatchError> {
-					Ok(vec![
-						RefundSurplus,
-						Self::deposit_instruction(T::SelfLocation::get(), &dest, 1u32)?,
-					])
-				})
-				.transpose()?;
-
-			// Grab the destination
-			Self::transact_in_dest_chain_asset_non_signed(
-				dest.clone(),
-				Some(fee_payer.clone()),
-				fee,
-				call.clone(),
-				origin_kind,
-				total_weight,
-				weight_info.transact_required_weight_at_most,
-				appendix,
-			)?;
-
-			// Deposit event
-			Self::deposit_event(Event::<T>::TransactedSovereign {
-				fee_payer,
+			Self::do_transact_through_sovereign(
 				dest,
+				fee_payer,
+				fee,
 				call,
-			});
-
-			Ok(())
+				origin_kind,
+				weight_info,
+				refund,
+				None,
+			)
+			.map(|_| ())
 		}
 
 		/// Change the transact info of a location
@@ -745,69 +722,8 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			let dest = MultiLocation::try_from(*dest).map_err(|()| Error::<T>::BadVersion)?;
-
-			let fee_location = Self::currency_to_multilocation(fee.currency)
-				.ok_or(Error::<T>::NotCrossChainTransferableCurrency)?;
-
-			// Calculate the total weight that the xcm message is going to spend in the
-			// destination chain
-			let total_weight = weight_info.overall_weight.map_or_else(
-				|| -> Result<_, DispatchError> {
-					let weight_info = Self::take_weight_from_transact_info_signed(
-						dest.clone(),
-						weight_info.transact_required_weight_at_most,
-						refund,
-					)?;
-					Ok(WeightLimit::from(Some(weight_info)))
-				},
-				|v| Ok(v),
-			)?;
-
-			let total_weight_fee_calculation = match total_weight {
-				Unlimited => MAX_WEIGHT,
-				Limited(x) => x,
-			};
-
-			// Fee to be paid
-			let fee = Self::calculate_fee(
-				fee_location,
-				fee.fee_amount,
-				dest.clone(),
-				total_weight_fee_calculation,
-			)?;
-
-			// If refund is true, the appendix instruction will be a deposit back to the sender
-			let appendix = refund
-				.then(|| -> Result<_, DispatchError> {
-					let sender = T::AccountIdToMultiLocation::convert(who.clone());
-					Ok(vec![
-						RefundSurplus,
-						Self::deposit_instruction(sender, &dest, 1u32)?,
-					])
-				})
-				.transpose()?;
-
-			// Grab the destination
-			Self::transact_in_dest_chain_asset_signed(
-				dest.clone(),
-				who.clone(),
-				fee,
-				call.clone(),
-				OriginKind::SovereignAccount,
-				total_weight,
-				weight_info.transact_required_weight_at_most,
-				appendix,
-			)?;
-
-			// Deposit event
-			Self::deposit_event(Event::<T>::TransactedSigned {
-				fee_payer: who,
-				dest,
-				call,
-			});
-
-			Ok(())
+			Self::do_transact_through_signed(who, dest, fee, call, weight_info, refund, None)
+				.map(|_| ())
 		}
 
 		/// Set the fee per second of an asset on its reserve chain
@@ -947,30 +863,545 @@ pub mod pallet {
 
 			Ok(())
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
-		fn transact_in_dest_chain_asset_non_signed(
-			dest: MultiLocation,
-			fee_payer: Option<T::AccountId>,
-			fee: MultiAsset,
-			call: Vec<u8>,
-			origin_kind: OriginKind,
-			total_weight: WeightLimit,
-			transact_required_weight_at_most: Weight,
-			with_appendix: Option<Vec<Instruction<()>>>,
+		/// Same as transact_through_derivative, additionally requesting the destination chain
+		/// to report the status of the transact.
+		/// The report is recorded in TransactStatusQueries.
+		#[pallet::call_index(10)]
+		#[pallet::weight(
+			Pallet::<T>::weight_of_initiate_reserve_withdraw()
+			.saturating_add(T::WeightInfo::transact_through_derivative())
+			.saturating_add(T::WeightInfo::register_transact_status_query())
+		)]
+		pub fn transact_through_derivative_with_report(
+			origin: OriginFor<T>,
+			dest: T::Transactor,
+			index: u16,
+			fee: CurrencyPayment<CurrencyIdOf<T>>,
+			inner_call: Vec<u8>,
+			weight_info: TransactWeights,
+			refund: bool,
+			report: TransactReport,
 		) -> DispatchResult {
-			if let Some(fee_payer) = fee_payer {
-				// Convert origin to multilocation
-				let origin_as_mult = T::AccountIdToMultiLocation::convert(fee_payer);
+			let who = ensure_signed(origin)?;
 
-				// Construct the local withdraw message with the previous calculated amount
-				// This message deducts and burns "amount" from the caller when executed
-				T::AssetTransactor::withdraw_asset(&fee.clone().into(), &origin_as_mult, None)
-					.map_err(|_| Error::<T>::UnableToWithdrawAsset)?;
-			}
+			Self::do_transact_through_derivative(
+				who,
+				dest,
+				index,
+				fee,
+				inner_call,
+				weight_info,
+				refund,
+				Some(report),
+			)
+			.map(|_| ())
+		}
 
-			// Construct the transact message. This is composed of WithdrawAsset||BuyExecution||
+		/// Same as transact_through_sovereign, additionally requesting the destination chain
+		/// to report the status of the transact.
+		/// The report is recorded in TransactStatusQueries.
+		#[pallet::call_index(11)]
+		#[pallet::weight(
+			Pallet::<T>::weight_of_initiate_reserve_withdraw()
+			.saturating_add(T::WeightInfo::transact_through_sovereign())
+			.saturating_add(T::WeightInfo::register_transact_status_query())
+		)]
+		pub fn transact_through_sovereign_with_report(
+			origin: OriginFor<T>,
+			dest: Box<VersionedMultiLocation>,
+			fee_payer: T::AccountId,
+			fee: CurrencyPayment<CurrencyIdOf<T>>,
+			call: Vec<u8>,
+			origin_kind: OriginKind,
+			weight_info: TransactWeights,
+			refund: bool,
+			report: TransactReport,
+		) -> DispatchResult {
+			T::SovereignAccountDispatcherOrigin::ensure_origin(origin)?;
+
+			Self::do_transact_through_sovereign(
+				dest,
+				fee_payer,
+				fee,
+				call,
+				origin_kind,
+				weight_info,
+				refund,
+				Some(report),
+			)
+			.map(|_| ())
+		}
+
+		/// Same as transact_through_signed, additionally requesting the destination chain
+		/// to report the status of the transact.
+		/// The report is recorded in TransactStatusQueries.
+		#[pallet::call_index(12)]
+		#[pallet::weight(
+			T::WeightInfo::transact_through_signed()
+			.saturating_add(T::WeightInfo::register_transact_status_query())
+		)]
+		pub fn transact_through_signed_with_report(
+			origin: OriginFor<T>,
+			dest: Box<VersionedMultiLocation>,
+			fee: CurrencyPayment<CurrencyIdOf<T>>,
+			call: Vec<u8>,
+			weight_info: TransactWeights,
+			refund: bool,
+			report: TransactReport,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_transact_through_signed(
+				who,
+				dest,
+				fee,
+				call,
+				weight_info,
+				refund,
+				Some(report),
+			)
+			.map(|_| ())
+		}
+
+		/// Record the response to a transact status query. Dispatchable by ResponseOrigin,
+		/// when the destination chain answers the query.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::notify_transact_status())]
+		pub fn notify_transact_status(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			response: Response,
+		) -> DispatchResult {
+			let responder = T::ResponseOrigin::ensure_origin(origin)?;
+
+			let dest = TransactStatusQueries::<T>::try_mutate(
+				query_id,
+				|maybe_query| -> Result<MultiLocation, DispatchError> {
+					let query = maybe_query.as_mut().ok_or(Error::<T>::UnknownQuery)?;
+					ensure!(query.dest == responder, Error::<T>::UnexpectedResponder);
+					ensure!(query.response.is_none(), Error::<T>::QueryAlreadyAnswered);
+					query.response = Some(response.clone());
+					Ok(query.dest)
+				},
+			)?;
+
+			Self::deposit_event(Event::TransactStatusReported {
+				query_id,
+				dest,
+				response,
+			});
+			Ok(())
+		}
+
+		/// Remove a transact status query. The account that sent the transact message can remove
+		/// it once it has been answered or has expired, any account can remove it once expired.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::remove_transact_status())]
+		pub fn remove_transact_status(origin: OriginFor<T>, query_id: QueryId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let query =
+				TransactStatusQueries::<T>::get(query_id).ok_or(Error::<T>::UnknownQuery)?;
+			if frame_system::Pallet::<T>::block_number() <= query.timeout {
+				ensure!(query.account == who, Error::<T>::NotOwner);
+				ensure!(query.response.is_some(), Error::<T>::QueryNotAnswered);
+			}
+
+			TransactStatusQueries::<T>::remove(query_id);
+			TransactStatusQueryCount::<T>::mutate_exists(&query.account, |count| {
+				*count = count
+					.and_then(|count| count.checked_sub(1))
+					.filter(|count| *count > 0);
+			});
+
+			Self::deposit_event(Event::TransactStatusQueryRemoved { query_id });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Transact through a derivative of the sovereign account, returning the id of the
+		/// transact status query if a report is requested
+		pub fn do_transact_through_derivative(
+			who: T::AccountId,
+			dest: T::Transactor,
+			index: u16,
+			fee: CurrencyPayment<CurrencyIdOf<T>>,
+			inner_call: Vec<u8>,
+			weight_info: TransactWeights,
+			refund: bool,
+			report: Option<TransactReport>,
+		) -> Result<Option<QueryId>, DispatchError> {
+			// TransactInfo does not account for the weight of the report instruction
+			ensure!(
+				report.is_none() || weight_info.overall_weight.is_some(),
+				Error::<T>::ReportNotSupportedWithTransactInfo
+			);
+
+			let fee_location = Self::currency_to_multilocation(fee.currency)
+				.ok_or(Error::<T>::NotCrossChainTransferableCurrency)?;
+
+			// The index exists
+			let account = IndexToAccount::<T>::get(index).ok_or(Error::<T>::UnclaimedIndex)?;
+			// The derivative index is owned by the origin
+			ensure!(account == who, Error::<T>::NotOwner);
+
+			// Encode call bytes
+			// We make sure the inner call is wrapped on a as_derivative dispatchable
+			let call_bytes: Vec<u8> = dest
+				.clone()
+				.encode_call(UtilityAvailableCalls::AsDerivative(index, inner_call));
+
+			// Grab the destination
+			let dest = dest.destination();
+
+			// Calculate the total weight that the xcm message is going to spend in the
+			// destination chain
+			let total_weight = weight_info.overall_weight.map_or_else(
+				|| -> Result<_, DispatchError> {
+					let weight_info = Self::take_weight_from_transact_info(
+						dest.clone(),
+						weight_info.transact_required_weight_at_most,
+						refund,
+					)?;
+					Ok(WeightLimit::from(Some(weight_info)))
+				},
+				|v| Ok(v),
+			)?;
+
+			let total_weight_fee_calculation = match total_weight {
+				Unlimited => MAX_WEIGHT,
+				Limited(x) => x,
+			};
+
+			// Calculate fee based on FeePerSecond
+			let fee = Self::calculate_fee(
+				fee_location,
+				fee.fee_amount,
+				dest.clone(),
+				total_weight_fee_calculation,
+			)?;
+
+			// If refund is true, the appendix instruction will be a deposit back to the sovereign
+			let refund_appendix = refund
+				.then(|| -> Result<_, DispatchError> {
+					Ok(vec![
+						RefundSurplus,
+						Self::deposit_instruction(T::SelfLocation::get(), &dest, 1u32)?,
+					])
+				})
+				.transpose()?;
+
+			// If a report is requested, a query is registered and the report instruction is
+			// added to the appendix
+			let (appendix, query_id) = Self::with_report_appendix(
+				refund_appendix,
+				report,
+				&dest,
+				who.clone(),
+				MultiLocation::here(),
+			)?;
+
+			Self::transact_in_dest_chain_asset_non_signed(
+				dest.clone(),
+				Some(who.clone()),
+				fee,
+				call_bytes.clone(),
+				OriginKind::SovereignAccount,
+				total_weight,
+				weight_info.transact_required_weight_at_most,
+				appendix,
+			)?;
+
+			// Deposit event
+			Self::deposit_event(Event::<T>::TransactedDerivative {
+				account_id: who,
+				dest,
+				call: call_bytes,
+				index,
+			});
+
+			Ok(query_id)
+		}
+
+		fn do_transact_through_sovereign(
+			dest: Box<VersionedMultiLocation>,
+			fee_payer: T::AccountId,
+			fee: CurrencyPayment<CurrencyIdOf<T>>,
+			call: Vec<u8>,
+			origin_kind: OriginKind,
+			weight_info: TransactWeights,
+			refund: bool,
+			report: Option<TransactReport>,
+		) -> Result<Option<QueryId>, DispatchError> {
+			// TransactInfo does not account for the weight of the report instruction
+			ensure!(
+				report.is_none() || weight_info.overall_weight.is_some(),
+				Error::<T>::ReportNotSupportedWithTransactInfo
+			);
+
+			let fee_location = Self::currency_to_multilocation(fee.currency)
+				.ok_or(Error::<T>::NotCrossChainTransferableCurrency)?;
+
+			let dest = MultiLocation::try_from(*dest).map_err(|()| Error::<T>::BadVersion)?;
+
+			// Calculate the total weight that the xcm message is going to spend in the
+			// destination chain
+			let total_weight = weight_info.overall_weight.map_or_else(
+				|| -> Result<_, DispatchError> {
+					let weight_info = Self::take_weight_from_transact_info(
+						dest.clone(),
+						weight_info.transact_required_weight_at_most,
+						refund,
+					)?;
+					Ok(WeightLimit::from(Some(weight_info)))
+				},
+				|v| Ok(v),
+			)?;
+
+			let total_weight_fee_calculation = match total_weight {
+				Unlimited => MAX_WEIGHT,
+				Limited(x) => x,
+			};
+
+			// Calculate fee based on FeePerSecond and total_weight
+			let fee = Self::calculate_fee(
+				fee_location,
+				fee.fee_amount,
+				dest.clone(),
+				total_weight_fee_calculation,
+			)?;
+
+			// If refund is true, the appendix instruction will be a deposit back to the sovereign
+			let refund_appendix = refund
+				.then(|| -> Result<_, DispatchError> {
+					Ok(vec![
+						RefundSurplus,
+						Self::deposit_instruction(T::SelfLocation::get(), &dest, 1u32)?,
+					])
+				})
+				.transpose()?;
+
+			// If a report is requested, a query is registered and the report instruction is
+			// added to the appendix
+			let (appendix, query_id) = Self::with_report_appendix(
+				refund_appendix,
+				report,
+				&dest,
+				fee_payer.clone(),
+				MultiLocation::here(),
+			)?;
+
+			// Grab the destination
+			Self::transact_in_dest_chain_asset_non_signed(
+				dest.clone(),
+				Some(fee_payer.clone()),
+				fee,
+				call.clone(),
+				origin_kind,
+				total_weight,
+				weight_info.transact_required_weight_at_most,
+				appendix,
+			)?;
+
+			// Deposit event
+			Self::deposit_event(Event::<T>::TransactedSovereign {
+				fee_payer,
+				dest,
+				call,
+			});
+
+			Ok(query_id)
+		}
+
+		/// Transact through the account derived from the signed origin, returning the id of the
+		/// transact status query if a report is requested
+		pub fn do_transact_through_signed(
+			who: T::AccountId,
+			dest: Box<VersionedMultiLocation>,
+			fee: CurrencyPayment<CurrencyIdOf<T>>,
+			call: Vec<u8>,
+			weight_info: TransactWeights,
+			refund: bool,
+			report: Option<TransactReport>,
+		) -> Result<Option<QueryId>, DispatchError> {
+			// TransactInfo does not account for the weight of the report instruction
+			ensure!(
+				report.is_none() || weight_info.overall_weight.is_some(),
+				Error::<T>::ReportNotSupportedWithTransactInfo
+			);
+
+			let dest = MultiLocation::try_from(*dest).map_err(|()| Error::<T>::BadVersion)?;
+
+			let fee_location = Self::currency_to_multilocation(fee.currency)
+				.ok_or(Error::<T>::NotCrossChainTransferableCurrency)?;
+
+			// Calculate the total weight that the xcm message is going to spend in the
+			// destination chain
+			let total_weight = weight_info.overall_weight.map_or_else(
+				|| -> Result<_, DispatchError> {
+					let weight_info = Self::take_weight_from_transact_info_signed(
+						dest.clone(),
+						weight_info.transact_required_weight_at_most,
+						refund,
+					)?;
+					Ok(WeightLimit::from(Some(weight_info)))
+				},
+				|v| Ok(v),
+			)?;
+
+			let total_weight_fee_calculation = match total_weight {
+				Unlimited => MAX_WEIGHT,
+				Limited(x) => x,
+			};
+
+			// Fee to be paid
+			let fee = Self::calculate_fee(
+				fee_location,
+				fee.fee_amount,
+				dest.clone(),
+				total_weight_fee_calculation,
+			)?;
+
+			// If refund is true, the appendix instruction will be a deposit back to the sender
+			let refund_appendix = refund
+				.then(|| -> Result<_, DispatchError> {
+					let sender = T::AccountIdToMultiLocation::convert(who.clone());
+					Ok(vec![
+						RefundSurplus,
+						Self::deposit_instruction(sender, &dest, 1u32)?,
+					])
+				})
+				.transpose()?;
+
+			// If a report is requested, a query is registered and the report instruction is
+			// added to the appendix
+			let (appendix, query_id) = Self::with_report_appendix(
+				refund_appendix,
+				report,
+				&dest,
+				who.clone(),
+				T::AccountIdToMultiLocation::convert(who.clone()),
+			)?;
+
+			// Grab the destination
+			Self::transact_in_dest_chain_asset_signed(
+				dest.clone(),
+				who.clone(),
+				fee,
+				call.clone(),
+				OriginKind::SovereignAccount,
+				total_weight,
+				weight_info.transact_required_weight_at_most,
+				appendix,
+			)?;
+
+			// Deposit event
+			Self::deposit_event(Event::<T>::TransactedSigned {
+				fee_payer: who,
+				dest,
+				call,
+			});
+
+			Ok(query_id)
+		}
+
+		/// Add the report instruction to the appendix if a report is requested, registering
+		/// the query that will be answered by the destination chain and returning its id.
+		/// `querier` is the origin of the message as seen from this chain.
+		pub(crate) fn with_report_appendix(
+			appendix: Option<Vec<Instruction<()>>>,
+			report: Option<TransactReport>,
+			dest: &MultiLocation,
+			account: T::AccountId,
+			querier: MultiLocation,
+		) -> Result<(Option<Vec<Instruction<()>>>, Option<QueryId>), DispatchError> {
+			let report = match report {
+				Some(report) => report,
+				None => return Ok((appendix, None)),
+			};
+
+			TransactStatusQueryCount::<T>::try_mutate(&account, |count| -> DispatchResult {
+				ensure!(
+					*count < T::MaxTransactStatusQueries::get(),
+					Error::<T>::TooManyTransactStatusQueries
+				);
+				*count += 1;
+				Ok(())
+			})?;
+
+			let timeout = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::TransactStatusQueryTimeout::get());
+			let query_id = T::QueryRegistrar::new_notify_query(
+				dest.clone(),
+				Call::<T>::notify_transact_status {
+					query_id: Default::default(),
+					response: Response::Null,
+				},
+				timeout,
+				querier,
+			);
+
+			// The response is sent back to this chain
+			let mut destination = T::SelfLocation::get();
+			destination
+				.reanchor(dest, T::UniversalLocation::get())
+				.map_err(|_| Error::<T>::CannotReanchor)?;
+			let response_info = QueryResponseInfo {
+				destination,
+				query_id,
+				max_weight: T::WeightInfo::notify_transact_status(),
+			};
+
+			TransactStatusQueries::<T>::insert(
+				query_id,
+				TransactStatusQuery {
+					account: account.clone(),
+					dest: dest.clone(),
+					report,
+					timeout,
+					response: None,
+				},
+			);
+			Self::deposit_event(Event::<T>::TransactStatusQueryRegistered {
+				query_id,
+				account,
+				dest: dest.clone(),
+			});
+
+			let mut appendix = appendix.unwrap_or_default();
+			appendix.insert(
+				0,
+				match report {
+					TransactReport::TransactStatus => ReportTransactStatus(response_info),
+					TransactReport::ExecutionResult => ReportError(response_info),
+				},
+			);
+			Ok((Some(appendix), Some(query_id)))
+		}
+
+		fn transact_in_dest_chain_asset_non_signed(
+			dest: MultiLocation,
+			fee_payer: Option<T::AccountId>,
+			fee: MultiAsset,
+			call: Vec<u8>,
+			origin_kind: OriginKind,
+			total_weight: WeightLimit,
+			transact_required_weight_at_most: Weight,
+			with_appendix: Option<Vec<Instruction<()>>>,
+		) -> DispatchResult {
+			if let Some(fee_payer) = fee_payer {
+				// Convert origin to multilocation
+				let origin_as_mult = T::AccountIdToMultiLocation::convert(fee_payer);
+
+				// Construct the local withdraw message with the previous calculated amount
+				// This message deducts and burns "amount" from the caller when executed
+				T::AssetTransactor::withdraw_asset(&fee.clone().into(), &origin_as_mult, None)
+					.map_err(|_| Error::<T>::UnableToWithdrawAsset)?;
+			}
+
+			// Construct the transact message. This is composed of WithdrawAsset||BuyExecution||
 			// Transact.
 			// WithdrawAsset: Withdraws "amount" from the sovereign account. These tokens will be
 			// used to pay fees
@@ -1276,6 +1707,13 @@ pub mod pallet {
 			Ok(total_weight)
 		}
 
+		/// Returns the fee per second charged for an asset in its reserve chain, taken from
+		/// FeePerSecondProvider and falling back to the value set in storage
+		pub fn fee_per_second(fee_location: &MultiLocation) -> Option<u128> {
+			T::FeePerSecondProvider::get_units_per_second(*fee_location)
+				.or_else(|| DestinationAssetFeePerSecond::<T>::get(fee_location))
+		}
+
 		/// Returns the fee per second charged by a reserve chain for an asset
 		/// it takes this information from storage
 		pub fn take_fee_per_second_from_storage(
@@ -1283,8 +1721,8 @@ pub mod pallet {
 			destination: MultiLocation,
 			total_weight: Weight,
 		) -> Result<u128, DispatchError> {
-			let fee_per_second = DestinationAssetFeePerSecond::<T>::get(&fee_location)
-				.ok_or(Error::<T>::FeePerSecondNotSet)?;
+			let fee_per_second =
+				Self::fee_per_second(&fee_location).ok_or(Error::<T>::FeePerSecondNotSet)?;
 
 			// Ensure the asset is a reserve
 			// We only store information about asset fee per second on its reserve chain
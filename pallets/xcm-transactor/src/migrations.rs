@@ -13,3 +13,101 @@
 
 // You should have received a copy of the GNU General Public License
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{relay_indices::RelayChainIndices, Config, RelayIndices};
+use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// `RelayChainIndices` layout before the nomination pools, conviction voting and
+/// proxy indices were appended
+#[derive(Clone, Copy, Debug, Default, Encode, Decode, PartialEq, Eq)]
+pub struct OldRelayChainIndices {
+	pub staking: u8,
+	pub utility: u8,
+	pub hrmp: u8,
+	pub bond: u8,
+	pub bond_extra: u8,
+	pub unbond: u8,
+	pub withdraw_unbonded: u8,
+	pub validate: u8,
+	pub nominate: u8,
+	pub chill: u8,
+	pub set_payee: u8,
+	pub set_controller: u8,
+	pub rebond: u8,
+	pub as_derivative: u8,
+	pub init_open_channel: u8,
+	pub accept_open_channel: u8,
+	pub close_channel: u8,
+	pub cancel_open_request: u8,
+}
+
+/// Keeps the indices already stored and fills the newly added ones
+/// (nomination pools, conviction voting and proxy) from the provided relay indices
+pub struct ExtendRelayIndices<T, NewIndices>(PhantomData<(T, NewIndices)>);
+
+impl<T: Config, NewIndices: Get<RelayChainIndices>> OnRuntimeUpgrade
+	for ExtendRelayIndices<T, NewIndices>
+{
+	fn on_runtime_upgrade() -> Weight {
+		log::info!(target: "ExtendRelayIndices", "running migration");
+
+		let new_indices = NewIndices::get();
+		let _ = RelayIndices::<T>::translate::<OldRelayChainIndices, _>(|old| {
+			old.map(|old| RelayChainIndices {
+				staking: old.staking,
+				utility: old.utility,
+				hrmp: old.hrmp,
+				bond: old.bond,
+				bond_extra: old.bond_extra,
+				unbond: old.unbond,
+				withdraw_unbonded: old.withdraw_unbonded,
+				validate: old.validate,
+				nominate: old.nominate,
+				chill: old.chill,
+				set_payee: old.set_payee,
+				set_controller: old.set_controller,
+				rebond: old.rebond,
+				as_derivative: old.as_derivative,
+				init_open_channel: old.init_open_channel,
+				accept_open_channel: old.accept_open_channel,
+				close_channel: old.close_channel,
+				cancel_open_request: old.cancel_open_request,
+				..new_indices
+			})
+		})
+		.map_err(|_| log::error!(target: "ExtendRelayIndices", "failed to decode old indices"));
+
+		T::DbWeight::get().reads_writes(1, 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+		let old = frame_support::storage::unhashed::get::<OldRelayChainIndices>(
+			&RelayIndices::<T>::hashed_key(),
+		)
+		.unwrap_or_default();
+		Ok(old.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+		let old = OldRelayChainIndices::decode(&mut &state[..])
+			.map_err(|_| sp_runtime::DispatchError::Other("invalid pre-upgrade state"))?;
+		let new_indices = NewIndices::get();
+		let indices = RelayIndices::<T>::get();
+		ensure!(
+			indices.staking == old.staking && indices.utility == old.utility,
+			"existing indices should be kept"
+		);
+		ensure!(
+			indices.nomination_pools == new_indices.nomination_pools
+				&& indices.conviction_voting == new_indices.conviction_voting
+				&& indices.proxy == new_indices.proxy,
+			"new indices should be populated"
+		);
+		Ok(())
+	}
+}
@@ -21,7 +21,7 @@ use crate as pallet_xcm_transactor;
 use cumulus_primitives_core::MultiAssets;
 use frame_support::traits::PalletInfo as PalletInfoTrait;
 use frame_support::{construct_runtime, parameter_types, weights::Weight};
-use frame_system::EnsureRoot;
+use frame_system::{EnsureRoot, EnsureRootWithSuccess};
 use parity_scale_codec::{Decode, Encode};
 
 use sp_core::{H160, H256};
@@ -31,8 +31,8 @@ use sp_runtime::BuildStorage;
 use xcm::latest::{
 	opaque, Error as XcmError, Instruction, InteriorMultiLocation,
 	Junction::{AccountKey20, GlobalConsensus, PalletInstance, Parachain},
-	Junctions, MultiAsset, MultiLocation, NetworkId, Result as XcmResult, SendError, SendResult,
-	SendXcm, Xcm, XcmContext, XcmHash,
+	Junctions, MultiAsset, MultiLocation, NetworkId, QueryId, Result as XcmResult, SendError,
+	SendResult, SendXcm, Xcm, XcmContext, XcmHash,
 };
 use xcm::{IntoVersion, VersionedXcm, WrapVersion};
 use xcm_primitives::{UtilityAvailableCalls, UtilityEncodeCall, XcmTransact};
@@ -350,6 +350,47 @@ thread_local! {
 pub fn sent_xcm() -> Vec<(MultiLocation, opaque::Xcm)> {
 	SENT_XCM.with(|q| (*q.borrow()).clone())
 }
+thread_local! {
+	pub static REGISTERED_QUERIES: RefCell<Vec<(QueryId, MultiLocation, u64, MultiLocation)>> =
+		RefCell::new(Vec::new());
+}
+pub fn registered_queries() -> Vec<(QueryId, MultiLocation, u64, MultiLocation)> {
+	REGISTERED_QUERIES.with(|q| (*q.borrow()).clone())
+}
+pub struct TestQueryRegistrar;
+impl crate::NotifyQueryRegistrar<crate::Call<Test>, u64> for TestQueryRegistrar {
+	fn new_notify_query(
+		responder: MultiLocation,
+		_notify: crate::Call<Test>,
+		timeout: u64,
+		match_querier: MultiLocation,
+	) -> QueryId {
+		REGISTERED_QUERIES.with(|q| {
+			let query_id = q.borrow().len() as QueryId;
+			q.borrow_mut()
+				.push((query_id, responder, timeout, match_querier));
+			query_id
+		})
+	}
+}
+
+parameter_types! {
+	pub ParentLocation: MultiLocation = MultiLocation::parent();
+	pub const TransactStatusQueryTimeout: u64 = 100;
+	pub const MaxTransactStatusQueries: u32 = 3;
+	pub static ProvidedFeePerSecond: Option<u128> = None;
+}
+
+pub struct TestFeePerSecondProvider;
+impl xcm_primitives::UnitsToWeightRatio<MultiLocation> for TestFeePerSecondProvider {
+	fn payment_is_supported(_asset_type: MultiLocation) -> bool {
+		ProvidedFeePerSecond::get().is_some()
+	}
+	fn get_units_per_second(_asset_type: MultiLocation) -> Option<u128> {
+		ProvidedFeePerSecond::get()
+	}
+}
+
 pub struct TestSendXcm;
 impl SendXcm for TestSendXcm {
 	type Ticket = ();
@@ -396,6 +437,11 @@ impl Config for Test {
 	type WeightInfo = ();
 	type HrmpManipulatorOrigin = EnsureRoot<u64>;
 	type MaxHrmpFee = MaxHrmpRelayFee;
+	type QueryRegistrar = TestQueryRegistrar;
+	type ResponseOrigin = EnsureRootWithSuccess<u64, ParentLocation>;
+	type TransactStatusQueryTimeout = TransactStatusQueryTimeout;
+	type MaxTransactStatusQueries = MaxTransactStatusQueries;
+	type FeePerSecondProvider = TestFeePerSecondProvider;
 }
 
 pub(crate) struct ExtBuilder {
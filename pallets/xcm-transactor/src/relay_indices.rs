@@ -44,4 +44,21 @@ pub struct RelayChainIndices {
 	pub accept_open_channel: u8,
 	pub close_channel: u8,
 	pub cancel_open_request: u8,
+	// Pallet indices
+	pub nomination_pools: u8,
+	pub conviction_voting: u8,
+	pub proxy: u8,
+	// Nomination pools indices
+	pub pool_join: u8,
+	pub pool_bond_extra: u8,
+	pub pool_unbond: u8,
+	pub pool_claim_payout: u8,
+	// Conviction voting indices
+	pub vote: u8,
+	pub remove_vote: u8,
+	pub delegate: u8,
+	// Proxy indices
+	pub proxy_call: u8,
+	pub add_proxy: u8,
+	pub remove_proxy: u8,
 }
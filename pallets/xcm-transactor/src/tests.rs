@@ -582,6 +582,43 @@ fn test_fee_calculation_works() {
 		})
 }
 
+#[test]
+fn test_fee_per_second_provider_is_preferred() {
+	ExtBuilder::default()
+		.with_balances(vec![])
+		.build()
+		.execute_with(|| {
+			assert_eq!(
+				XcmTransactor::fee_per_second(&MultiLocation::parent()),
+				None
+			);
+
+			assert_ok!(XcmTransactor::set_fee_per_second(
+				RuntimeOrigin::root(),
+				Box::new(xcm::VersionedMultiLocation::V3(MultiLocation::parent())),
+				1
+			));
+			assert_eq!(
+				XcmTransactor::fee_per_second(&MultiLocation::parent()),
+				Some(1)
+			);
+
+			// The provided rate takes precedence over the stored one
+			ProvidedFeePerSecond::set(Some(5));
+			assert_eq!(
+				XcmTransactor::fee_per_second(&MultiLocation::parent()),
+				Some(5)
+			);
+
+			// And the stored one is used again when the provider has none
+			ProvidedFeePerSecond::set(None);
+			assert_eq!(
+				XcmTransactor::fee_per_second(&MultiLocation::parent()),
+				Some(1)
+			);
+		})
+}
+
 // Kusama case
 #[test]
 fn test_fee_calculation_works_kusama_0_9_20_case() {
@@ -1685,3 +1722,400 @@ fn test_transact_through_signed_with_refund_works() {
 			]))));
 		})
 }
+
+#[test]
+fn test_transact_through_signed_with_report_works() {
+	ExtBuilder::default()
+		.with_balances(vec![])
+		.build()
+		.execute_with(|| {
+			// Set fee per second
+			assert_ok!(XcmTransactor::set_fee_per_second(
+				RuntimeOrigin::root(),
+				Box::new(xcm::VersionedMultiLocation::V3(MultiLocation::parent())),
+				1
+			));
+
+			// Overall weight to use
+			let total_weight: Weight = 10_100u64.into();
+			assert_ok!(XcmTransactor::transact_through_signed_with_report(
+				RuntimeOrigin::signed(1u64),
+				Box::new(xcm::VersionedMultiLocation::V3(MultiLocation::parent())),
+				CurrencyPayment {
+					currency: Currency::AsCurrencyId(CurrencyId::OtherReserve(0)),
+					fee_amount: None
+				},
+				vec![1u8],
+				TransactWeights {
+					transact_required_weight_at_most: 100u64.into(),
+					overall_weight: Some(Limited(total_weight))
+				},
+				true,
+				TransactReport::TransactStatus
+			));
+
+			let expected = vec![
+				crate::Event::DestFeePerSecondChanged {
+					location: MultiLocation::parent(),
+					fee_per_second: 1,
+				},
+				crate::Event::TransactStatusQueryRegistered {
+					query_id: 0,
+					account: 1u64,
+					dest: MultiLocation::parent(),
+				},
+				crate::Event::TransactedSigned {
+					fee_payer: 1u64,
+					dest: MultiLocation::parent(),
+					call: vec![1u8],
+				},
+			];
+			assert_eq!(events(), expected);
+
+			// The query expects a response from the destination, sent by the signed origin
+			assert_eq!(
+				mock::registered_queries(),
+				vec![(
+					0,
+					MultiLocation::parent(),
+					1 + TransactStatusQueryTimeout::get(),
+					AccountIdToMultiLocation::convert(1)
+				)]
+			);
+			assert_eq!(
+				XcmTransactor::transact_status(0),
+				Some(TransactStatusQuery {
+					account: 1u64,
+					dest: MultiLocation::parent(),
+					report: TransactReport::TransactStatus,
+					timeout: 1 + TransactStatusQueryTimeout::get(),
+					response: None,
+				})
+			);
+
+			let sent_messages = mock::sent_xcm();
+			let (_, sent_message) = sent_messages.first().unwrap();
+
+			// Check message contains the report before the refund in the appendix
+			assert!(sent_message.0.contains(&SetAppendix(Xcm(vec![
+				ReportTransactStatus(QueryResponseInfo {
+					destination: MultiLocation::new(0, X1(Junction::Parachain(100))),
+					query_id: 0,
+					max_weight: <() as WeightInfo>::notify_transact_status(),
+				}),
+				RefundSurplus,
+				DepositAsset {
+					assets: Wild(AllCounted(1u32)),
+					beneficiary: MultiLocation {
+						parents: 0,
+						interior: X2(
+							Junction::Parachain(100),
+							AccountIdToMultiLocation::convert(1)
+								.interior
+								.take_first()
+								.unwrap()
+						)
+					}
+				}
+			]))));
+		})
+}
+
+#[test]
+fn test_transact_through_derivative_with_report_error() {
+	ExtBuilder::default()
+		.with_balances(vec![])
+		.build()
+		.execute_with(|| {
+			assert_ok!(XcmTransactor::register(RuntimeOrigin::root(), 1u64, 1));
+
+			// The weight of the report is not taken into account by the transact info
+			assert_noop!(
+				XcmTransactor::transact_through_derivative_with_report(
+					RuntimeOrigin::signed(1u64),
+					Transactors::Relay,
+					1,
+					CurrencyPayment {
+						currency: Currency::AsCurrencyId(CurrencyId::OtherReserve(0)),
+						fee_amount: Some(100)
+					},
+					vec![1u8],
+					TransactWeights {
+						transact_required_weight_at_most: 100u64.into(),
+						overall_weight: None
+					},
+					false,
+					TransactReport::ExecutionResult
+				),
+				Error::<Test>::ReportNotSupportedWithTransactInfo
+			);
+
+			assert_ok!(XcmTransactor::transact_through_derivative_with_report(
+				RuntimeOrigin::signed(1u64),
+				Transactors::Relay,
+				1,
+				CurrencyPayment {
+					currency: Currency::AsCurrencyId(CurrencyId::OtherReserve(0)),
+					fee_amount: Some(100)
+				},
+				vec![1u8],
+				TransactWeights {
+					transact_required_weight_at_most: 100u64.into(),
+					overall_weight: Some(Limited(10_100u64.into()))
+				},
+				false,
+				TransactReport::ExecutionResult
+			));
+
+			// Non-signed transacts are sent by this chain itself
+			assert_eq!(
+				mock::registered_queries(),
+				vec![(
+					0,
+					MultiLocation::parent(),
+					1 + TransactStatusQueryTimeout::get(),
+					MultiLocation::here()
+				)]
+			);
+
+			let sent_messages = mock::sent_xcm();
+			let (_, sent_message) = sent_messages.first().unwrap();
+			assert!(sent_message.0.contains(&SetAppendix(Xcm(vec![ReportError(
+				QueryResponseInfo {
+					destination: MultiLocation::new(0, X1(Junction::Parachain(100))),
+					query_id: 0,
+					max_weight: <() as WeightInfo>::notify_transact_status(),
+				}
+			)]))));
+		})
+}
+
+#[test]
+fn test_notify_and_remove_transact_status() {
+	ExtBuilder::default()
+		.with_balances(vec![])
+		.build()
+		.execute_with(|| {
+			assert_ok!(XcmTransactor::set_fee_per_second(
+				RuntimeOrigin::root(),
+				Box::new(xcm::VersionedMultiLocation::V3(MultiLocation::parent())),
+				1
+			));
+			for _ in 0..2 {
+				assert_ok!(XcmTransactor::transact_through_sovereign_with_report(
+					RuntimeOrigin::root(),
+					Box::new(xcm::VersionedMultiLocation::V3(MultiLocation::parent())),
+					1u64,
+					CurrencyPayment {
+						currency: Currency::AsCurrencyId(CurrencyId::OtherReserve(0)),
+						fee_amount: None
+					},
+					vec![1u8],
+					OriginKind::SovereignAccount,
+					TransactWeights {
+						transact_required_weight_at_most: 100u64.into(),
+						overall_weight: Some(Limited(10_100u64.into()))
+					},
+					false,
+					TransactReport::TransactStatus
+				));
+			}
+
+			let response =
+				Response::DispatchResult(MaybeErrorCode::Error(vec![1u8].try_into().unwrap()));
+
+			// Only the response origin can notify
+			assert_noop!(
+				XcmTransactor::notify_transact_status(
+					RuntimeOrigin::signed(1u64),
+					0,
+					response.clone()
+				),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				XcmTransactor::notify_transact_status(RuntimeOrigin::root(), 5, response.clone()),
+				Error::<Test>::UnknownQuery
+			);
+
+			assert_ok!(XcmTransactor::notify_transact_status(
+				RuntimeOrigin::root(),
+				0,
+				response.clone()
+			));
+			assert_eq!(
+				XcmTransactor::transact_status(0).and_then(|query| query.response),
+				Some(response.clone())
+			);
+			assert_eq!(
+				events().last(),
+				Some(&crate::Event::TransactStatusReported {
+					query_id: 0,
+					dest: MultiLocation::parent(),
+					response: response.clone(),
+				})
+			);
+			assert_noop!(
+				XcmTransactor::notify_transact_status(RuntimeOrigin::root(), 0, response),
+				Error::<Test>::QueryAlreadyAnswered
+			);
+
+			// Only the owner can remove an answered query
+			assert_noop!(
+				XcmTransactor::remove_transact_status(RuntimeOrigin::signed(2u64), 0),
+				Error::<Test>::NotOwner
+			);
+			assert_ok!(XcmTransactor::remove_transact_status(
+				RuntimeOrigin::signed(1u64),
+				0
+			));
+			assert!(XcmTransactor::transact_status(0).is_none());
+
+			// Pending queries can only be removed once expired
+			assert_noop!(
+				XcmTransactor::remove_transact_status(RuntimeOrigin::signed(1u64), 1),
+				Error::<Test>::QueryNotAnswered
+			);
+			assert_eq!(TransactStatusQueryCount::<Test>::get(1u64), 1);
+
+			// Any account can remove an expired query
+			System::set_block_number(2 + TransactStatusQueryTimeout::get());
+			assert_ok!(XcmTransactor::remove_transact_status(
+				RuntimeOrigin::signed(2u64),
+				1
+			));
+			assert_eq!(
+				events().last(),
+				Some(&crate::Event::TransactStatusQueryRemoved { query_id: 1 })
+			);
+			assert!(!TransactStatusQueryCount::<Test>::contains_key(1u64));
+		})
+}
+
+#[test]
+fn test_transact_status_queries_are_bounded_per_account() {
+	ExtBuilder::default()
+		.with_balances(vec![])
+		.build()
+		.execute_with(|| {
+			assert_ok!(XcmTransactor::set_fee_per_second(
+				RuntimeOrigin::root(),
+				Box::new(xcm::VersionedMultiLocation::V3(MultiLocation::parent())),
+				1
+			));
+			let transact_with_report = || {
+				XcmTransactor::do_transact_through_signed(
+					1u64,
+					Box::new(xcm::VersionedMultiLocation::V3(MultiLocation::parent())),
+					CurrencyPayment {
+						currency: Currency::AsCurrencyId(CurrencyId::OtherReserve(0)),
+						fee_amount: None,
+					},
+					vec![1u8],
+					TransactWeights {
+						transact_required_weight_at_most: 100u64.into(),
+						overall_weight: Some(Limited(10_100u64.into())),
+					},
+					false,
+					Some(TransactReport::TransactStatus),
+				)
+			};
+
+			// The id of the registered query is returned
+			for query_id in 0..MaxTransactStatusQueries::get() as u64 {
+				assert_eq!(transact_with_report(), Ok(Some(query_id)));
+			}
+			assert_noop!(
+				transact_with_report(),
+				Error::<Test>::TooManyTransactStatusQueries
+			);
+
+			// Removing a query frees a slot
+			System::set_block_number(2 + TransactStatusQueryTimeout::get());
+			assert_ok!(XcmTransactor::remove_transact_status(
+				RuntimeOrigin::signed(1u64),
+				0
+			));
+			assert_eq!(
+				transact_with_report(),
+				Ok(Some(MaxTransactStatusQueries::get() as u64))
+			);
+		})
+}
+
+#[test]
+fn test_extend_relay_indices_migration_keeps_existing_indices() {
+	use crate::migrations::{ExtendRelayIndices, OldRelayChainIndices};
+	use frame_support::traits::OnRuntimeUpgrade;
+
+	frame_support::parameter_types! {
+		pub const NewIndices: relay_indices::RelayChainIndices =
+			relay_indices::RelayChainIndices {
+				nomination_pools: 39u8,
+				conviction_voting: 20u8,
+				proxy: 29u8,
+				pool_join: 0u8,
+				pool_bond_extra: 1u8,
+				pool_unbond: 3u8,
+				pool_claim_payout: 2u8,
+				vote: 0u8,
+				remove_vote: 4u8,
+				delegate: 1u8,
+				proxy_call: 0u8,
+				add_proxy: 1u8,
+				remove_proxy: 2u8,
+				// Only the appended indices are taken from here
+				staking: 100u8,
+				utility: 100u8,
+				hrmp: 100u8,
+				bond: 100u8,
+				bond_extra: 100u8,
+				unbond: 100u8,
+				withdraw_unbonded: 100u8,
+				validate: 100u8,
+				nominate: 100u8,
+				chill: 100u8,
+				set_payee: 100u8,
+				set_controller: 100u8,
+				rebond: 100u8,
+				as_derivative: 100u8,
+				init_open_channel: 100u8,
+				accept_open_channel: 100u8,
+				close_channel: 100u8,
+				cancel_open_request: 100u8,
+			};
+	}
+
+	ExtBuilder::default()
+		.with_balances(vec![])
+		.build()
+		.execute_with(|| {
+			let old = OldRelayChainIndices {
+				staking: 7u8,
+				utility: 26u8,
+				hrmp: 60u8,
+				rebond: 19u8,
+				as_derivative: 1u8,
+				cancel_open_request: 6u8,
+				..Default::default()
+			};
+			frame_support::storage::unhashed::put(&RelayIndices::<Test>::hashed_key(), &old);
+
+			ExtendRelayIndices::<Test, NewIndices>::on_runtime_upgrade();
+
+			let indices = RelayIndices::<Test>::get();
+			assert_eq!(indices.staking, 7u8);
+			assert_eq!(indices.utility, 26u8);
+			assert_eq!(indices.hrmp, 60u8);
+			assert_eq!(indices.rebond, 19u8);
+			assert_eq!(indices.as_derivative, 1u8);
+			assert_eq!(indices.cancel_open_request, 6u8);
+			assert_eq!(indices.bond, 0u8);
+			assert_eq!(indices.nomination_pools, 39u8);
+			assert_eq!(indices.conviction_voting, 20u8);
+			assert_eq!(indices.proxy, 29u8);
+			assert_eq!(indices.pool_unbond, 3u8);
+			assert_eq!(indices.remove_vote, 4u8);
+			assert_eq!(indices.remove_proxy, 2u8);
+		});
+}
@@ -61,6 +61,9 @@ pub trait WeightInfo {
 	fn transact_through_sovereign() -> Weight;
 	fn transact_through_signed() -> Weight;
 	fn hrmp_manage() -> Weight;
+	fn register_transact_status_query() -> Weight;
+	fn notify_transact_status() -> Weight;
+	fn remove_transact_status() -> Weight;
 }
 
 /// Weights for xcm_transactor using the Substrate node and recommended hardware.
@@ -205,6 +208,47 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	/// Proof Skipped: PolkadotXcm QueryCounter (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PolkadotXcm Queries (r:0 w:1)
+	/// Proof Skipped: PolkadotXcm Queries (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor TransactStatusQueryCount (r:1 w:1)
+	/// Proof Skipped: XcmTransactor TransactStatusQueryCount (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor TransactStatusQueries (r:0 w:1)
+	/// Proof Skipped: XcmTransactor TransactStatusQueries (max_values: None, max_size: None, mode: Measured)
+	fn register_transact_status_query() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `139`
+		//  Estimated: `3604`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(27_000_000, 3604)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: XcmTransactor TransactStatusQueries (r:1 w:1)
+	/// Proof Skipped: XcmTransactor TransactStatusQueries (max_values: None, max_size: None, mode: Measured)
+	fn notify_transact_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `245`
+		//  Estimated: `3710`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 3710)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: XcmTransactor TransactStatusQueries (r:1 w:1)
+	/// Proof Skipped: XcmTransactor TransactStatusQueries (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor TransactStatusQueryCount (r:1 w:1)
+	/// Proof Skipped: XcmTransactor TransactStatusQueryCount (max_values: None, max_size: None, mode: Measured)
+	fn remove_transact_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `272`
+		//  Estimated: `3737`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 3737)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -348,4 +392,45 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	/// Proof Skipped: PolkadotXcm QueryCounter (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PolkadotXcm Queries (r:0 w:1)
+	/// Proof Skipped: PolkadotXcm Queries (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor TransactStatusQueryCount (r:1 w:1)
+	/// Proof Skipped: XcmTransactor TransactStatusQueryCount (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor TransactStatusQueries (r:0 w:1)
+	/// Proof Skipped: XcmTransactor TransactStatusQueries (max_values: None, max_size: None, mode: Measured)
+	fn register_transact_status_query() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `139`
+		//  Estimated: `3604`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(27_000_000, 3604)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: XcmTransactor TransactStatusQueries (r:1 w:1)
+	/// Proof Skipped: XcmTransactor TransactStatusQueries (max_values: None, max_size: None, mode: Measured)
+	fn notify_transact_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `245`
+		//  Estimated: `3710`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 3710)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: XcmTransactor TransactStatusQueries (r:1 w:1)
+	/// Proof Skipped: XcmTransactor TransactStatusQueries (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor TransactStatusQueryCount (r:1 w:1)
+	/// Proof Skipped: XcmTransactor TransactStatusQueryCount (max_values: None, max_size: None, mode: Measured)
+	fn remove_transact_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `272`
+		//  Estimated: `3737`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 3737)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
\ No newline at end of file
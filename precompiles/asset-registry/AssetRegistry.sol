@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The AssetRegistry contract's address.
+address constant ASSET_REGISTRY_ADDRESS = 0x000000000000000000000000000000000000081D;
+
+/// @dev The AssetRegistry contract's instance.
+AssetRegistry constant ASSET_REGISTRY_CONTRACT = AssetRegistry(
+    ASSET_REGISTRY_ADDRESS
+);
+
+/// @author The Moonbeam Team
+/// @title Asset Registry Interface
+/// The interface through which solidity contracts will resolve the foreign assets registered in
+/// the asset manager pallet
+/// @custom:address 0x000000000000000000000000000000000000081D
+interface AssetRegistry {
+    // A multilocation is defined by its number of parents and the encoded junctions (interior)
+    struct Multilocation {
+        uint8 parents;
+        bytes[] interior;
+    }
+
+    /// Get the asset id of the foreign asset registered at a multilocation
+    /// @custom:selector d21e5074
+    /// @param multilocation The multilocation of the asset, which can be an alias
+    /// @return exists Whether an asset is registered at the multilocation
+    /// @return assetId The asset id of the asset
+    function assetIdOf(Multilocation memory multilocation)
+        external
+        view
+        returns (bool exists, uint128 assetId);
+
+    /// Get the multilocation of a foreign asset
+    /// @custom:selector 403cb517
+    /// @param assetId The asset id of the asset
+    /// @return exists Whether the asset is registered
+    /// @return multilocation The multilocation of the asset
+    function locationOf(uint128 assetId)
+        external
+        view
+        returns (bool exists, Multilocation memory multilocation);
+
+    /// Get the address of the ERC-20 precompile of a foreign asset
+    /// @custom:selector 18e4b65d
+    /// @param assetId The asset id of the asset
+    /// @return exists Whether the asset is registered
+    /// @return asset The address of the ERC-20 precompile of the asset
+    function addressOf(uint128 assetId)
+        external
+        view
+        returns (bool exists, address asset);
+
+    /// Get the asset id of a foreign asset from the address of its ERC-20 precompile
+    /// @custom:selector 454ee525
+    /// @param asset The address of the ERC-20 precompile of the asset
+    /// @return exists Whether the address is the one of a registered asset
+    /// @return assetId The asset id of the asset
+    function assetIdOfAddress(address asset)
+        external
+        view
+        returns (bool exists, uint128 assetId);
+
+    /// Get the multilocations of the assets supported for xcm fee payment
+    /// @custom:selector aab30b7e
+    /// @return multilocations The multilocations of the supported assets
+    function supportedFeePaymentAssets()
+        external
+        view
+        returns (Multilocation[] memory multilocations);
+
+    /// Check whether an asset is supported for xcm fee payment
+    /// @custom:selector 213f120b
+    /// @param multilocation The multilocation of the asset, which can be an alias
+    /// @return supported Whether the asset is supported
+    function isSupportedFeePaymentAsset(Multilocation memory multilocation)
+        external
+        view
+        returns (bool supported);
+}
@@ -0,0 +1,173 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile to resolve the foreign assets registered in pallet-asset-manager
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::traits::Get;
+use pallet_asset_manager::{AssetIdType, SupportedFeePaymentAssets};
+use pallet_evm::AddressMapping;
+use pallet_evm_precompileset_assets_erc20::AccountIdAssetIdConversion;
+use parity_scale_codec::MaxEncodedLen;
+use precompile_utils::prelude::*;
+use sp_core::H160;
+use sp_std::{marker::PhantomData, vec::Vec};
+use xcm::latest::MultiLocation;
+use xcm_primitives::{AssetTypeGetter, UnitsToWeightRatio};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub type AssetIdOf<Runtime> = <Runtime as pallet_asset_manager::Config>::AssetId;
+pub type ForeignAssetTypeOf<Runtime> = <Runtime as pallet_asset_manager::Config>::ForeignAssetType;
+
+/// Max encoded len of an entry of the maps between asset ids and asset types:
+/// hash (16) + asset id (16) + asset type (1 + Multilocation)
+fn asset_entry_max_encoded_len() -> usize {
+	16 + 16 + 1 + MultiLocation::max_encoded_len()
+}
+
+/// Max encoded len of SupportedFeePaymentAssets: compact length (5) + asset types
+/// (1 + Multilocation)
+/// TODO SupportedFeePaymentAssets is unbounded, we account for a theoretical 50 assets.
+fn supported_fee_payment_assets_max_encoded_len() -> usize {
+	5 + 50 * (1 + MultiLocation::max_encoded_len())
+}
+
+/// A precompile to resolve the foreign assets registered in pallet-asset-manager
+pub struct AssetRegistryPrecompile<Runtime, ForeignAssetPrefix>(
+	PhantomData<(Runtime, ForeignAssetPrefix)>,
+);
+
+#[precompile_utils::precompile]
+impl<Runtime, ForeignAssetPrefix> AssetRegistryPrecompile<Runtime, ForeignAssetPrefix>
+where
+	Runtime: pallet_asset_manager::Config
+		+ pallet_evm::Config
+		+ AccountIdAssetIdConversion<Runtime::AccountId, AssetIdOf<Runtime>>,
+	Runtime::AccountId: Into<H160>,
+	AssetIdOf<Runtime>: Into<u128> + From<u128>,
+	ForeignAssetTypeOf<Runtime>: From<MultiLocation> + Into<Option<MultiLocation>>,
+	ForeignAssetPrefix: Get<&'static [u8]>,
+{
+	#[precompile::public("assetIdOf((uint8,bytes[]))")]
+	#[precompile::view]
+	fn asset_id_of(
+		handle: &mut impl PrecompileHandle,
+		multilocation: MultiLocation,
+	) -> EvmResult<(bool, u128)> {
+		// storage items: AssetTypeId, then AssetTypeAliases and AssetIdType for aliases
+		handle.record_db_read::<Runtime>(asset_entry_max_encoded_len())?;
+		handle.record_db_read::<Runtime>(asset_entry_max_encoded_len())?;
+		handle.record_db_read::<Runtime>(asset_entry_max_encoded_len())?;
+
+		Ok(
+			match pallet_asset_manager::Pallet::<Runtime>::get_asset_id(multilocation.into()) {
+				Some(asset_id) => (true, asset_id.into()),
+				None => (false, 0),
+			},
+		)
+	}
+
+	#[precompile::public("locationOf(uint128)")]
+	#[precompile::view]
+	fn location_of(
+		handle: &mut impl PrecompileHandle,
+		asset_id: u128,
+	) -> EvmResult<(bool, MultiLocation)> {
+		// storage item: AssetIdType
+		handle.record_db_read::<Runtime>(asset_entry_max_encoded_len())?;
+
+		let location = pallet_asset_manager::Pallet::<Runtime>::get_asset_type(asset_id.into())
+			.and_then(Into::<Option<MultiLocation>>::into);
+
+		Ok(match location {
+			Some(location) => (true, location),
+			None => (false, MultiLocation::default()),
+		})
+	}
+
+	#[precompile::public("addressOf(uint128)")]
+	#[precompile::view]
+	fn address_of(
+		handle: &mut impl PrecompileHandle,
+		asset_id: u128,
+	) -> EvmResult<(bool, Address)> {
+		// storage item: AssetIdType
+		handle.record_db_read::<Runtime>(asset_entry_max_encoded_len())?;
+
+		let exists = AssetIdType::<Runtime>::contains_key(AssetIdOf::<Runtime>::from(asset_id));
+		let address: H160 =
+			Runtime::asset_id_to_account(ForeignAssetPrefix::get(), asset_id.into()).into();
+
+		Ok((exists, Address(address)))
+	}
+
+	#[precompile::public("assetIdOfAddress(address)")]
+	#[precompile::view]
+	fn asset_id_of_address(
+		handle: &mut impl PrecompileHandle,
+		asset: Address,
+	) -> EvmResult<(bool, u128)> {
+		// storage item: AssetIdType
+		handle.record_db_read::<Runtime>(asset_entry_max_encoded_len())?;
+
+		let account = Runtime::AddressMapping::into_account_id(asset.into());
+		Ok(match Runtime::account_to_asset_id(account) {
+			Some((prefix, asset_id))
+				if prefix == ForeignAssetPrefix::get()
+					&& AssetIdType::<Runtime>::contains_key(asset_id) =>
+			{
+				(true, asset_id.into())
+			}
+			_ => (false, 0),
+		})
+	}
+
+	#[precompile::public("supportedFeePaymentAssets()")]
+	#[precompile::view]
+	fn supported_fee_payment_assets(
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<Vec<MultiLocation>> {
+		// storage item: SupportedFeePaymentAssets
+		handle.record_db_read::<Runtime>(supported_fee_payment_assets_max_encoded_len())?;
+
+		Ok(SupportedFeePaymentAssets::<Runtime>::get()
+			.into_iter()
+			.filter_map(Into::<Option<MultiLocation>>::into)
+			.collect())
+	}
+
+	#[precompile::public("isSupportedFeePaymentAsset((uint8,bytes[]))")]
+	#[precompile::view]
+	fn is_supported_fee_payment_asset(
+		handle: &mut impl PrecompileHandle,
+		multilocation: MultiLocation,
+	) -> EvmResult<bool> {
+		// storage items: AssetTypeAliases, AssetIdType (twice for aliases) and
+		// SupportedFeePaymentAssets
+		handle.record_db_read::<Runtime>(asset_entry_max_encoded_len())?;
+		handle.record_db_read::<Runtime>(asset_entry_max_encoded_len())?;
+		handle.record_db_read::<Runtime>(asset_entry_max_encoded_len())?;
+		handle.record_db_read::<Runtime>(supported_fee_payment_assets_max_encoded_len())?;
+
+		Ok(pallet_asset_manager::Pallet::<Runtime>::payment_is_supported(multilocation.into()))
+	}
+}
@@ -0,0 +1,321 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::H160;
+use xcm::latest::{Junction, Junctions, MultiLocation};
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn relay_location() -> MultiLocation {
+	MultiLocation::parent()
+}
+
+fn para_location() -> MultiLocation {
+	MultiLocation::new(1, Junctions::X1(Junction::Parachain(1000)))
+}
+
+fn register_relay_asset() -> AssetId {
+	pallet_asset_manager::Pallet::<Runtime>::register_foreign_asset(
+		RuntimeOrigin::root(),
+		MockAssetType::Xcm(relay_location()),
+		0u32,
+		1u128,
+		true,
+	)
+	.expect("asset registration succeeds");
+
+	MockAssetType::Xcm(relay_location()).into()
+}
+
+fn asset_address(asset_id: AssetId) -> H160 {
+	let mut data = [0u8; 20];
+	data[0..4].copy_from_slice(FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX);
+	data[4..20].copy_from_slice(&asset_id.to_be_bytes());
+	H160(data)
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(
+		&["AssetRegistry.sol"],
+		PCall::supports_selector,
+	)
+}
+
+#[test]
+fn test_generated_interface_matches_solidity_interface() {
+	check_precompile_generated_interface::<
+		crate::AssetRegistryPrecompile<Runtime, ForeignAssetPrefix>,
+	>(&["AssetRegistry.sol"])
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::asset_id_of_selectors().contains(&0xd21e5074));
+	assert!(PCall::location_of_selectors().contains(&0x403cb517));
+	assert!(PCall::address_of_selectors().contains(&0x18e4b65d));
+	assert!(PCall::asset_id_of_address_selectors().contains(&0x454ee525));
+	assert!(PCall::supported_fee_payment_assets_selectors().contains(&0xaab30b7e));
+	assert!(PCall::is_supported_fee_payment_asset_selectors().contains(&0x213f120b));
+}
+
+#[test]
+fn modifiers() {
+	new_test_ext().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+		tester.test_view_modifier(PCall::asset_id_of_selectors());
+		tester.test_view_modifier(PCall::location_of_selectors());
+		tester.test_view_modifier(PCall::address_of_selectors());
+		tester.test_view_modifier(PCall::asset_id_of_address_selectors());
+		tester.test_view_modifier(PCall::supported_fee_payment_assets_selectors());
+		tester.test_view_modifier(PCall::is_supported_fee_payment_asset_selectors());
+	});
+}
+
+#[test]
+fn asset_id_of_works() {
+	new_test_ext().execute_with(|| {
+		let asset_id = register_relay_asset();
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::asset_id_of {
+					multilocation: relay_location(),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns((true, asset_id));
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::asset_id_of {
+					multilocation: para_location(),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns((false, 0u128));
+	});
+}
+
+#[test]
+fn asset_id_of_resolves_aliases() {
+	new_test_ext().execute_with(|| {
+		let asset_id = register_relay_asset();
+		pallet_asset_manager::Pallet::<Runtime>::add_asset_type_alias(
+			RuntimeOrigin::root(),
+			asset_id,
+			MockAssetType::Xcm(para_location()),
+		)
+		.expect("alias registration succeeds");
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::asset_id_of {
+					multilocation: para_location(),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns((true, asset_id));
+	});
+}
+
+#[test]
+fn location_of_works() {
+	new_test_ext().execute_with(|| {
+		let asset_id = register_relay_asset();
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::location_of { asset_id })
+			.expect_no_logs()
+			.execute_returns((true, relay_location()));
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::location_of {
+					asset_id: asset_id.wrapping_add(1),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns((false, MultiLocation::default()));
+	});
+}
+
+#[test]
+fn address_of_works() {
+	new_test_ext().execute_with(|| {
+		let asset_id = register_relay_asset();
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::address_of { asset_id })
+			.expect_no_logs()
+			.execute_returns((true, Address(asset_address(asset_id))));
+
+		let unknown = asset_id.wrapping_add(1);
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::address_of { asset_id: unknown })
+			.expect_no_logs()
+			.execute_returns((false, Address(asset_address(unknown))));
+	});
+}
+
+#[test]
+fn asset_id_of_address_works() {
+	new_test_ext().execute_with(|| {
+		let asset_id = register_relay_asset();
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::asset_id_of_address {
+					asset: Address(asset_address(asset_id)),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns((true, asset_id));
+
+		// Unregistered asset id behind the foreign asset prefix
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::asset_id_of_address {
+					asset: Address(asset_address(asset_id.wrapping_add(1))),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns((false, 0u128));
+
+		// Address outside of the foreign asset prefix
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::asset_id_of_address {
+					asset: Address(Alice.into()),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns((false, 0u128));
+	});
+}
+
+#[test]
+fn supported_fee_payment_assets_works() {
+	new_test_ext().execute_with(|| {
+		let asset_id = register_relay_asset();
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::supported_fee_payment_assets {})
+			.expect_no_logs()
+			.execute_returns(Vec::<MultiLocation>::new());
+
+		pallet_asset_manager::Pallet::<Runtime>::set_asset_units_per_second(
+			RuntimeOrigin::root(),
+			MockAssetType::Xcm(relay_location()),
+			200u128,
+			0,
+		)
+		.expect("setting units per second succeeds");
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::supported_fee_payment_assets {})
+			.expect_no_logs()
+			.execute_returns(vec![relay_location()]);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::is_supported_fee_payment_asset {
+					multilocation: relay_location(),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(true);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::is_supported_fee_payment_asset {
+					multilocation: para_location(),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(false);
+
+		// Aliases of a supported asset are supported as well
+		pallet_asset_manager::Pallet::<Runtime>::add_asset_type_alias(
+			RuntimeOrigin::root(),
+			asset_id,
+			MockAssetType::Xcm(para_location()),
+		)
+		.expect("alias registration succeeds");
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::is_supported_fee_payment_asset {
+					multilocation: para_location(),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(true);
+	});
+}
+
+#[test]
+fn supported_fee_payment_assets_are_charged_before_being_read() {
+	new_test_ext().execute_with(|| {
+		let max_encoded_len = crate::supported_fee_payment_assets_max_encoded_len() as u64;
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::supported_fee_payment_assets {})
+			.expect_proof_size(max_encoded_len)
+			.execute_returns(Vec::<MultiLocation>::new());
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::supported_fee_payment_assets {})
+			.with_proof_size_limit(max_encoded_len - 1)
+			.execute_error(fp_evm::ExitError::OutOfGas);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::is_supported_fee_payment_asset {
+					multilocation: relay_location(),
+				},
+			)
+			.with_proof_size_limit(max_encoded_len - 1)
+			.execute_error(fp_evm::ExitError::OutOfGas);
+	});
+}
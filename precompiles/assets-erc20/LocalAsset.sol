@@ -5,6 +5,26 @@ pragma solidity >=0.8.3;
 /// @title Extension of the ERC20 interface that allows an owner
 /// @dev Contract to pilot the ERC20 contract.
 interface LocalAsset {
+    /// @dev Get the owner of the asset
+    /// @custom:selector 8da5cb5b
+    /// @return owner address The owner of the asset
+    function owner() external view returns (address);
+
+    /// @dev Get the issuer of the asset
+    /// @custom:selector 1d143848
+    /// @return issuer address The address capable of issuing tokens
+    function issuer() external view returns (address);
+
+    /// @dev Get the admin of the asset
+    /// @custom:selector f851a440
+    /// @return admin address The address capable of burning tokens and unfreezing accounts/assets
+    function admin() external view returns (address);
+
+    /// @dev Get the freezer of the asset
+    /// @custom:selector 92716054
+    /// @return freezer address The address capable of freezing accounts/asset
+    function freezer() external view returns (address);
+
     /// @dev Mint tokens to an address
     /// @custom:selector 40c10f19
     /// @param to address The address to which you want to mint tokens
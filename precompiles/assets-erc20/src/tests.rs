@@ -176,6 +176,41 @@ fn get_total_supply() {
 		});
 }
 
+#[test]
+fn get_total_supply_records_proof_size() {
+	ExtBuilder::default()
+		.with_balances(vec![(CryptoAlith.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ForeignAssets::force_create(
+				RuntimeOrigin::root(),
+				0u128,
+				CryptoAlith.into(),
+				true,
+				1
+			));
+
+			// Reading the asset details accounts for 175 bytes of proof.
+			precompiles()
+				.prepare_test(
+					CryptoAlith,
+					ForeignAssetId(0u128),
+					ForeignPCall::total_supply {},
+				)
+				.expect_proof_size(175)
+				.execute_returns(U256::zero());
+
+			precompiles()
+				.prepare_test(
+					CryptoAlith,
+					ForeignAssetId(0u128),
+					ForeignPCall::total_supply {},
+				)
+				.with_proof_size_limit(174)
+				.execute_error(ExitError::OutOfGas);
+		});
+}
+
 #[test]
 fn get_balances_known_user() {
 	ExtBuilder::default()
@@ -2539,6 +2574,13 @@ fn test_solidity_interface_has_all_function_selectors_documented_and_implemented
 	)
 }
 
+#[test]
+fn test_generated_interface_matches_solidity_interface() {
+	check_precompile_generated_interface::<
+		crate::Erc20AssetsPrecompileSet<Runtime, IsLocal, pallet_assets::Instance2>,
+	>(&["ERC20.sol", "LocalAsset.sol", "Permit.sol"])
+}
+
 #[test]
 fn test_deprecated_solidity_selectors_are_supported() {
 	for deprecated_function in [
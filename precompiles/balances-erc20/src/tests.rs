@@ -1311,3 +1311,10 @@ fn test_solidity_interface_has_all_function_selectors_documented_and_implemented
 		PCall::supports_selector,
 	)
 }
+
+#[test]
+fn test_generated_interface_matches_solidity_interface() {
+	check_precompile_generated_interface::<
+		crate::Erc20BalancesPrecompile<Runtime, NativeErc20Metadata, ()>,
+	>(&["ERC20.sol", "Permit.sol"])
+}
@@ -13,6 +13,14 @@ Batch constant BATCH_CONTRACT = Batch(BATCH_ADDRESS);
 /// Can be used by EOA to do multiple calls in a single transaction.
 /// @custom:address 0x0000000000000000000000000000000000000808
 interface Batch {
+    /// @dev Outcome of a subcall.
+    struct Result {
+        /// Is `true` if the subcall succeeded, `false` if it reverted, failed or was skipped.
+        bool success;
+        /// The data returned by the subcall, or its revert data.
+        bytes returnData;
+    }
+
     /// @dev Batch multiple calls into a single transaction.
     /// All calls are performed from the address calling this precompile.
     ///
@@ -74,6 +82,49 @@ interface Batch {
         uint64[] memory gasLimit
     ) external;
 
+    /// @dev Batch multiple calls into a single transaction and return their outcome.
+    /// All calls are performed from the address calling this precompile.
+    ///
+    /// In case of one subcall reverting following subcalls will still be attempted.
+    /// Subcalls that are not attempted due to a lack of gas still have a result, which is
+    /// unsuccessful with empty return data.
+    ///
+    /// @param to List of addresses to call.
+    /// @param value List of values for each subcall. If array is shorter than "to" then additional
+    /// calls will be performed with a value of 0.
+    /// @param callData Call data for each `to` address. If array is shorter than "to" then
+    /// additional calls will be performed with an empty call data.
+    /// @param gasLimit Gas limit for each `to` address. Use 0 to forward all the remaining gas.
+    /// If array is shorter than "to" then the remaining gas available will be used.
+    /// @return results The outcome of each subcall, in order.
+    /// @custom:selector e1a62eee
+    function batchSomeWithResults(
+        address[] memory to,
+        uint256[] memory value,
+        bytes[] memory callData,
+        uint64[] memory gasLimit
+    ) external returns (Result[] memory results);
+
+    /// @dev Perform multiple static calls and return their outcome, as a read-only multicall.
+    /// All calls are performed from the address calling this precompile and no event is emitted.
+    ///
+    /// In case of one subcall reverting following subcalls will still be attempted.
+    /// Subcalls that are not attempted due to a lack of gas still have a result, which is
+    /// unsuccessful with empty return data.
+    ///
+    /// @param to List of addresses to call.
+    /// @param callData Call data for each `to` address. If array is shorter than "to" then
+    /// additional calls will be performed with an empty call data.
+    /// @param gasLimit Gas limit for each `to` address. Use 0 to forward all the remaining gas.
+    /// If array is shorter than "to" then the remaining gas available will be used.
+    /// @return results The outcome of each subcall, in order.
+    /// @custom:selector 58927e21
+    function staticBatch(
+        address[] memory to,
+        bytes[] memory callData,
+        uint64[] memory gasLimit
+    ) external view returns (Result[] memory results);
+
     /// Emitted when a subcall succeeds.
     event SubcallSucceeded(uint256 index);
 
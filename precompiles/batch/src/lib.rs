@@ -35,6 +35,24 @@ pub enum Mode {
 	BatchSome,             // = "batchSome(address[],uint256[],bytes[],uint64[])",
 	BatchSomeUntilFailure, // = "batchSomeUntilFailure(address[],uint256[],bytes[],uint64[])",
 	BatchAll,              // = "batchAll(address[],uint256[],bytes[],uint64[])",
+	BatchSomeWithResults,  // = "batchSomeWithResults(address[],uint256[],bytes[],uint64[])",
+	StaticBatch,           // = "staticBatch(address[],bytes[],uint64[])",
+}
+
+impl Mode {
+	/// Subcalls are performed in a static context, which forbids state changes and logs.
+	fn is_static(self) -> bool {
+		self == Mode::StaticBatch
+	}
+}
+
+/// Outcome of a subcall, returned by the batch functions providing results.
+/// Subcalls that are not attempted due to a lack of gas have the default result, which is
+/// unsuccessful with empty return data.
+#[derive(Default, Debug, Eq, PartialEq, solidity::Codec)]
+pub struct SubcallResult {
+	pub success: bool,
+	pub return_data: UnboundedBytes,
 }
 
 pub const LOG_SUBCALL_SUCCEEDED: [u8; 32] = keccak256!("SubcallSucceeded(uint256)");
@@ -45,20 +63,30 @@ pub const ARRAY_LIMIT: u32 = 2u32.pow(9);
 type GetCallDataLimit = ConstU32<CALL_DATA_LIMIT>;
 type GetArrayLimit = ConstU32<ARRAY_LIMIT>;
 
+/// Emitted when a subcall succeeds.
+#[derive(Debug, solidity::Event)]
+pub struct SubcallSucceeded {
+	pub index: U256,
+}
+
+/// Emitted when a subcall fails.
+#[derive(Debug, solidity::Event)]
+pub struct SubcallFailed {
+	pub index: U256,
+}
+
 pub fn log_subcall_succeeded(address: impl Into<H160>, index: usize) -> Log {
-	log1(
-		address,
-		LOG_SUBCALL_SUCCEEDED,
-		solidity::encode_event_data(U256::from(index)),
-	)
+	SubcallSucceeded {
+		index: index.into(),
+	}
+	.into_log(address)
 }
 
 pub fn log_subcall_failed(address: impl Into<H160>, index: usize) -> Log {
-	log1(
-		address,
-		LOG_SUBCALL_FAILED,
-		solidity::encode_event_data(U256::from(index)),
-	)
+	SubcallFailed {
+		index: index.into(),
+	}
+	.into_log(address)
 }
 
 /// Batch precompile.
@@ -68,6 +96,7 @@ pub struct BatchPrecompile<Runtime>(PhantomData<Runtime>);
 // No funds are transfered to the precompile address.
 // Transfers will directly be made on the behalf of the user by the precompile.
 #[precompile_utils::precompile]
+#[precompile::events(SubcallSucceeded, SubcallFailed)]
 impl<Runtime> BatchPrecompile<Runtime>
 where
 	Runtime: pallet_evm::Config,
@@ -80,7 +109,8 @@ where
 		call_data: BoundedVec<BoundedBytes<GetCallDataLimit>, GetArrayLimit>,
 		gas_limit: BoundedVec<u64, GetArrayLimit>,
 	) -> EvmResult {
-		Self::inner_batch(Mode::BatchSome, handle, to, value, call_data, gas_limit)
+		Self::inner_batch(Mode::BatchSome, handle, to, value, call_data, gas_limit)?;
+		Ok(())
 	}
 
 	#[precompile::public("batchSomeUntilFailure(address[],uint256[],bytes[],uint64[])")]
@@ -98,7 +128,8 @@ where
 			value,
 			call_data,
 			gas_limit,
-		)
+		)?;
+		Ok(())
 	}
 
 	#[precompile::public("batchAll(address[],uint256[],bytes[],uint64[])")]
@@ -109,7 +140,44 @@ where
 		call_data: BoundedVec<BoundedBytes<GetCallDataLimit>, GetArrayLimit>,
 		gas_limit: BoundedVec<u64, GetArrayLimit>,
 	) -> EvmResult {
-		Self::inner_batch(Mode::BatchAll, handle, to, value, call_data, gas_limit)
+		Self::inner_batch(Mode::BatchAll, handle, to, value, call_data, gas_limit)?;
+		Ok(())
+	}
+
+	#[precompile::public("batchSomeWithResults(address[],uint256[],bytes[],uint64[])")]
+	fn batch_some_with_results(
+		handle: &mut impl PrecompileHandle,
+		to: BoundedVec<Address, GetArrayLimit>,
+		value: BoundedVec<U256, GetArrayLimit>,
+		call_data: BoundedVec<BoundedBytes<GetCallDataLimit>, GetArrayLimit>,
+		gas_limit: BoundedVec<u64, GetArrayLimit>,
+	) -> EvmResult<Vec<SubcallResult>> {
+		Self::inner_batch(
+			Mode::BatchSomeWithResults,
+			handle,
+			to,
+			value,
+			call_data,
+			gas_limit,
+		)
+	}
+
+	#[precompile::public("staticBatch(address[],bytes[],uint64[])")]
+	#[precompile::view]
+	fn static_batch(
+		handle: &mut impl PrecompileHandle,
+		to: BoundedVec<Address, GetArrayLimit>,
+		call_data: BoundedVec<BoundedBytes<GetCallDataLimit>, GetArrayLimit>,
+		gas_limit: BoundedVec<u64, GetArrayLimit>,
+	) -> EvmResult<Vec<SubcallResult>> {
+		Self::inner_batch(
+			Mode::StaticBatch,
+			handle,
+			to,
+			vec![].into(),
+			call_data,
+			gas_limit,
+		)
 	}
 
 	fn inner_batch(
@@ -119,7 +187,7 @@ where
 		value: BoundedVec<U256, GetArrayLimit>,
 		call_data: BoundedVec<BoundedBytes<GetCallDataLimit>, GetArrayLimit>,
 		gas_limit: BoundedVec<u64, GetArrayLimit>,
-	) -> EvmResult {
+	) -> EvmResult<Vec<SubcallResult>> {
 		let addresses = Vec::from(to).into_iter().enumerate();
 		let values = Vec::from(value)
 			.into_iter()
@@ -139,9 +207,16 @@ where
 		).chain(repeat(None));
 
 		// Cost of batch log. (doesn't change when index changes)
-		let log_cost = log_subcall_failed(handle.code_address(), 0)
-			.compute_cost()
-			.map_err(|_| revert("Failed to compute log cost"))?;
+		// No logs are emitted in a static context.
+		let log_cost = if mode.is_static() {
+			0
+		} else {
+			log_subcall_failed(handle.code_address(), 0)
+				.compute_cost()
+				.map_err(|_| revert("Failed to compute log cost"))?
+		};
+
+		let mut results = Vec::new();
 
 		for ((i, address), (value, (call_data, gas_limit))) in
 			addresses.zip(values.zip(calls_data.zip(gas_limits)))
@@ -178,7 +253,7 @@ where
 					})
 				}
 				(None, _) => {
-					return Ok(());
+					return Ok(results);
 				}
 			};
 
@@ -188,9 +263,13 @@ where
 			let forwarded_gas = match forwarded_gas.checked_sub(call_cost) {
 				Some(remaining) => remaining,
 				None => {
-					let log = log_subcall_failed(handle.code_address(), i);
-					handle.record_log_costs(&[&log])?;
-					log.record(handle)?;
+					if !mode.is_static() {
+						let log = log_subcall_failed(handle.code_address(), i);
+						handle.record_log_costs(&[&log])?;
+						log.record(handle)?;
+					}
+					// The skipped subcall has an unsuccessful result with empty return data.
+					results.push(SubcallResult::default());
 
 					match mode {
 						Mode::BatchAll => {
@@ -198,8 +277,10 @@ where
 								exit_status: ExitError::OutOfGas,
 							})
 						}
-						Mode::BatchSomeUntilFailure => return Ok(()),
-						Mode::BatchSome => continue,
+						Mode::BatchSomeUntilFailure => return Ok(results),
+						Mode::BatchSome | Mode::BatchSomeWithResults | Mode::StaticBatch => {
+							continue
+						}
 					}
 				}
 			};
@@ -209,9 +290,13 @@ where
 				None => forwarded_gas, // provide all gas if no gas limit,
 				Some(limit) => {
 					if limit > forwarded_gas {
-						let log = log_subcall_failed(handle.code_address(), i);
-						handle.record_log_costs(&[&log])?;
-						log.record(handle)?;
+						if !mode.is_static() {
+							let log = log_subcall_failed(handle.code_address(), i);
+							handle.record_log_costs(&[&log])?;
+							log.record(handle)?;
+						}
+						// The skipped subcall has an unsuccessful result with empty return data.
+						results.push(SubcallResult::default());
 
 						match mode {
 							Mode::BatchAll => {
@@ -219,8 +304,10 @@ where
 									exit_status: ExitError::OutOfGas,
 								})
 							}
-							Mode::BatchSomeUntilFailure => return Ok(()),
-							Mode::BatchSome => continue,
+							Mode::BatchSomeUntilFailure => return Ok(results),
+							Mode::BatchSome | Mode::BatchSomeWithResults | Mode::StaticBatch => {
+								continue
+							}
 						}
 					}
 					limit
@@ -232,13 +319,14 @@ where
 				transfer,
 				call_data,
 				Some(forwarded_gas),
-				false,
+				mode.is_static(),
 				&sub_context,
 			);
 
 			// Logs
 			// We reserved enough gas so this should not OOG.
 			match reason {
+				_ if mode.is_static() => (),
 				ExitReason::Revert(_) | ExitReason::Error(_) => {
 					let log = log_subcall_failed(handle.code_address(), i);
 					handle.record_log_costs(&[&log])?;
@@ -273,15 +361,22 @@ where
 				// BatchSomeUntilFailure : Reverts and errors prevent subsequent subcalls to
 				// be executed but the precompile still succeed.
 				(Mode::BatchSomeUntilFailure, ExitReason::Revert(_) | ExitReason::Error(_)) => {
-					return Ok(())
+					results.push(SubcallResult {
+						success: false,
+						return_data: output.into(),
+					});
+					return Ok(results);
 				}
 
 				// Success or ignored revert/error.
-				(_, _) => (),
+				(_, reason) => results.push(SubcallResult {
+					success: matches!(reason, ExitReason::Succeed(_)),
+					return_data: output.into(),
+				}),
 			}
 		}
 
-		Ok(())
+		Ok(results)
 	}
 }
 
@@ -326,6 +421,18 @@ where
 				call_data,
 				gas_limit,
 			},
+			Mode::BatchSomeWithResults => Self::batch_some_with_results {
+				to,
+				value,
+				call_data,
+				gas_limit,
+			},
+			// Static subcalls don't transfer any value.
+			Mode::StaticBatch => Self::static_batch {
+				to,
+				call_data,
+				gas_limit,
+			},
 		}
 	}
 }
@@ -19,7 +19,8 @@ use crate::mock::{
 	RuntimeOrigin,
 };
 use crate::{
-	log_subcall_failed, log_subcall_succeeded, Mode, LOG_SUBCALL_FAILED, LOG_SUBCALL_SUCCEEDED,
+	log_subcall_failed, log_subcall_succeeded, BatchPrecompile, Mode, SubcallFailed, SubcallResult,
+	SubcallSucceeded, LOG_SUBCALL_FAILED, LOG_SUBCALL_SUCCEEDED,
 };
 use fp_evm::ExitError;
 use frame_support::assert_ok;
@@ -60,6 +61,8 @@ fn selectors() {
 	assert!(PCall::batch_some_selectors().contains(&0x79df4b9c));
 	assert!(PCall::batch_some_until_failure_selectors().contains(&0xcf0491c7));
 	assert!(PCall::batch_all_selectors().contains(&0x96e292b8));
+	assert!(PCall::batch_some_with_results_selectors().contains(&0xe1a62eee));
+	assert!(PCall::static_batch_selectors().contains(&0x58927e21));
 	assert_eq!(
 		LOG_SUBCALL_FAILED,
 		hex_literal::hex!("dbc5d06f4f877f959b1ff12d2161cdd693fa8e442ee53f1790b2804b24881f05")
@@ -68,6 +71,11 @@ fn selectors() {
 		LOG_SUBCALL_SUCCEEDED,
 		hex_literal::hex!("bf855484633929c3d6688eb3caf8eff910fb4bef030a8d7dbc9390d26759714d")
 	);
+	assert_eq!(SubcallFailed::topic0(), H256::from(LOG_SUBCALL_FAILED));
+	assert_eq!(
+		SubcallSucceeded::topic0(),
+		H256::from(LOG_SUBCALL_SUCCEEDED)
+	);
 }
 
 #[test]
@@ -81,6 +89,8 @@ fn modifiers() {
 			tester.test_default_modifier(PCall::batch_some_selectors());
 			tester.test_default_modifier(PCall::batch_some_until_failure_selectors());
 			tester.test_default_modifier(PCall::batch_all_selectors());
+			tester.test_default_modifier(PCall::batch_some_with_results_selectors());
+			tester.test_view_modifier(PCall::static_batch_selectors());
 		});
 }
 
@@ -238,9 +248,9 @@ fn batch_some_returns() {
 	ExtBuilder::default().build().execute_with(|| {
 		batch_returns(&precompiles(), Mode::BatchSome)
 			.expect_log(log1(Bob, H256::repeat_byte(0x11), vec![]))
-			.expect_log(log_subcall_succeeded(Batch, 0))
+			.expect_event(SubcallSucceeded { index: 0.into() })
 			.expect_log(log1(Charlie, H256::repeat_byte(0x22), vec![]))
-			.expect_log(log_subcall_succeeded(Batch, 1))
+			.expect_event(SubcallSucceeded { index: 1.into() })
 			.execute_returns(())
 	})
 }
@@ -269,6 +279,142 @@ fn batch_all_returns() {
 	})
 }
 
+#[test]
+fn batch_some_with_results_returns() {
+	ExtBuilder::default().build().execute_with(|| {
+		batch_returns(&precompiles(), Mode::BatchSomeWithResults)
+			.expect_log(log1(Bob, H256::repeat_byte(0x11), vec![]))
+			.expect_log(log_subcall_succeeded(Batch, 0))
+			.expect_log(log1(Charlie, H256::repeat_byte(0x22), vec![]))
+			.expect_log(log_subcall_succeeded(Batch, 1))
+			.execute_returns(vec![
+				SubcallResult {
+					success: true,
+					return_data: vec![].into(),
+				},
+				SubcallResult {
+					success: true,
+					return_data: vec![].into(),
+				},
+			])
+	})
+}
+
+#[test]
+fn batch_some_with_results_returns_revert_data() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Batch,
+				PCall::batch_some_with_results {
+					to: vec![Address(Bob.into()), Address(Charlie.into())].into(),
+					value: vec![].into(),
+					call_data: vec![].into(),
+					gas_limit: vec![].into(),
+				},
+			)
+			.with_target_gas(Some(100_000))
+			.with_subcall_handle(move |Subcall { address, .. }| match address {
+				a if a == Bob.into() => SubcallOutput {
+					output: revert_as_bytes("Bob reverted"),
+					cost: 13,
+					..SubcallOutput::revert()
+				},
+				a if a == Charlie.into() => SubcallOutput {
+					output: b"Charlie output".to_vec(),
+					cost: 17,
+					..SubcallOutput::succeed()
+				},
+				_ => panic!("unexpected subcall"),
+			})
+			.expect_log(log_subcall_failed(Batch, 0))
+			.expect_log(log_subcall_succeeded(Batch, 1))
+			.execute_returns(vec![
+				SubcallResult {
+					success: false,
+					return_data: revert_as_bytes("Bob reverted").into(),
+				},
+				SubcallResult {
+					success: true,
+					return_data: b"Charlie output".to_vec().into(),
+				},
+			])
+	})
+}
+
+#[test]
+fn static_batch_returns() {
+	ExtBuilder::default().build().execute_with(|| {
+		let total_call_cost = call_cost(U256::zero(), <Runtime as pallet_evm::Config>::config());
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Batch,
+				PCall::static_batch {
+					to: vec![Address(Bob.into()), Address(Charlie.into())].into(),
+					call_data: vec![b"one".to_vec().into(), b"two".to_vec().into()].into(),
+					gas_limit: vec![].into(),
+				},
+			)
+			.with_static_call(true)
+			.with_target_gas(Some(100_000))
+			.with_subcall_handle(move |subcall| {
+				let Subcall {
+					address,
+					transfer,
+					input,
+					target_gas,
+					is_static,
+					context,
+				} = subcall;
+
+				// Called from the precompile caller in a static context.
+				assert_eq!(context.caller, Alice.into());
+				assert_eq!(is_static, true);
+				assert!(transfer.is_none());
+				assert_eq!(context.apparent_value, U256::zero());
+
+				match address {
+					a if a == Bob.into() => {
+						assert_eq!(target_gas, Some(100_000 - total_call_cost));
+						assert_eq!(&input, b"one");
+
+						SubcallOutput {
+							output: b"Bob output".to_vec(),
+							cost: 13,
+							..SubcallOutput::succeed()
+						}
+					}
+					a if a == Charlie.into() => {
+						assert_eq!(target_gas, Some(100_000 - 13 - total_call_cost * 2));
+						assert_eq!(&input, b"two");
+
+						SubcallOutput {
+							output: revert_as_bytes("Charlie reverted"),
+							cost: 17,
+							..SubcallOutput::revert()
+						}
+					}
+					_ => panic!("unexpected subcall"),
+				}
+			})
+			.expect_cost(13 + 17 + total_call_cost * 2)
+			.expect_no_logs()
+			.execute_returns(vec![
+				SubcallResult {
+					success: true,
+					return_data: b"Bob output".to_vec().into(),
+				},
+				SubcallResult {
+					success: false,
+					return_data: revert_as_bytes("Charlie reverted").into(),
+				},
+			])
+	})
+}
+
 fn batch_out_of_gas(
 	precompiles: &Precompiles<Runtime>,
 	mode: Mode,
@@ -1089,3 +1235,8 @@ fn batch_is_not_callable_by_dummy_code() {
 fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
 	check_precompile_implements_solidity_interfaces(&["Batch.sol"], PCall::supports_selector)
 }
+
+#[test]
+fn test_generated_interface_matches_solidity_interface() {
+	check_precompile_generated_interface::<BatchPrecompile<Runtime>>(&["Batch.sol"])
+}
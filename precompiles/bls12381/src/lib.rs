@@ -0,0 +1,386 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! EIP-2537 precompiles for operations on the BLS12-381 curve.
+//!
+//! Field elements are encoded as 64 bytes big-endian (the top 16 bytes being zero), points as
+//! the concatenation of their coordinates and the point at infinity as zeroes. Elements of Fp2
+//! are encoded as `c0 || c1` and scalars as 32 bytes big-endian.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ark_bls12_381::{
+	g1, g2, Bls12_381, Fq, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective,
+};
+use ark_ec::{
+	hashing::{curve_maps::wb::WBMap, map_to_curve_hasher::MapToCurve},
+	pairing::Pairing,
+	AffineRepr, CurveGroup, VariableBaseMSM,
+};
+use ark_ff::{BigInt, BigInteger, One, PrimeField, Zero};
+use fp_evm::{
+	ExitError, ExitSucceed, Precompile, PrecompileFailure, PrecompileHandle, PrecompileOutput,
+	PrecompileResult,
+};
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod tests;
+
+/// Length of an encoded element of Fp
+const FP_LENGTH: usize = 64;
+/// Length of an encoded element of Fp2
+const FP2_LENGTH: usize = 2 * FP_LENGTH;
+/// Length of an encoded point of G1
+const G1_LENGTH: usize = 2 * FP_LENGTH;
+/// Length of an encoded point of G2
+const G2_LENGTH: usize = 2 * FP2_LENGTH;
+/// Length of an encoded scalar
+const SCALAR_LENGTH: usize = 32;
+/// Length of the padding of an encoded element of Fp
+const FP_PADDING_LENGTH: usize = FP_LENGTH - 48;
+
+pub const G1_ADD_GAS: u64 = 375;
+pub const G1_MUL_GAS: u64 = 12_000;
+pub const G2_ADD_GAS: u64 = 600;
+pub const G2_MUL_GAS: u64 = 22_500;
+pub const PAIRING_BASE_GAS: u64 = 37_700;
+pub const PAIRING_PER_PAIR_GAS: u64 = 32_600;
+pub const MAP_FP_TO_G1_GAS: u64 = 5_500;
+pub const MAP_FP2_TO_G2_GAS: u64 = 23_800;
+
+/// Discounts (per mille) of G1 multi-scalar multiplications by number of pairs, the last one
+/// applying to any larger number of pairs
+const G1_MSM_DISCOUNTS: [u16; 128] = [
+	1000, 949, 848, 797, 764, 750, 738, 728, 719, 712, 705, 698, 692, 687, 682, 677, 673, 669, 665,
+	661, 658, 654, 651, 648, 645, 642, 640, 637, 635, 632, 630, 627, 625, 623, 621, 619, 617, 615,
+	613, 611, 609, 608, 606, 604, 603, 601, 599, 598, 596, 595, 593, 592, 591, 589, 588, 586, 585,
+	584, 582, 581, 580, 579, 577, 576, 575, 574, 573, 572, 570, 569, 568, 567, 566, 565, 564, 563,
+	562, 561, 560, 559, 558, 557, 556, 555, 554, 553, 552, 551, 550, 549, 548, 547, 547, 546, 545,
+	544, 543, 542, 541, 540, 540, 539, 538, 537, 536, 536, 535, 534, 533, 532, 532, 531, 530, 529,
+	528, 528, 527, 526, 525, 525, 524, 523, 522, 522, 521, 520, 520, 519,
+];
+
+/// Discounts (per mille) of G2 multi-scalar multiplications by number of pairs, the last one
+/// applying to any larger number of pairs
+const G2_MSM_DISCOUNTS: [u16; 128] = [
+	1000, 1000, 923, 884, 855, 832, 812, 796, 782, 770, 759, 749, 740, 732, 724, 717, 711, 704,
+	699, 693, 688, 683, 679, 674, 670, 666, 663, 659, 655, 652, 649, 646, 643, 640, 637, 634, 632,
+	629, 627, 624, 622, 620, 618, 615, 613, 611, 609, 607, 606, 604, 602, 600, 598, 597, 595, 593,
+	592, 590, 589, 587, 586, 584, 583, 582, 580, 579, 578, 576, 575, 574, 573, 571, 570, 569, 568,
+	567, 566, 565, 563, 562, 561, 560, 559, 558, 557, 556, 555, 554, 553, 552, 552, 551, 550, 549,
+	548, 547, 546, 545, 545, 544, 543, 542, 541, 541, 540, 539, 538, 537, 537, 536, 535, 535, 534,
+	533, 532, 532, 531, 530, 530, 529, 528, 528, 527, 526, 526, 525, 524, 524,
+];
+
+/// Cost of a multi-scalar multiplication of `pairs` pairs.
+pub fn msm_gas(pairs: usize, mul_gas: u64, discounts: &[u16]) -> u64 {
+	if pairs == 0 {
+		return 0;
+	}
+	let discount = discounts[pairs.min(discounts.len()) - 1];
+
+	(pairs as u64)
+		.saturating_mul(mul_gas)
+		.saturating_mul(discount.into())
+		/ 1000
+}
+
+/// Cost of a multi-scalar multiplication of `pairs` pairs in G1.
+pub fn g1_msm_gas(pairs: usize) -> u64 {
+	msm_gas(pairs, G1_MUL_GAS, &G1_MSM_DISCOUNTS)
+}
+
+/// Cost of a multi-scalar multiplication of `pairs` pairs in G2.
+pub fn g2_msm_gas(pairs: usize) -> u64 {
+	msm_gas(pairs, G2_MUL_GAS, &G2_MSM_DISCOUNTS)
+}
+
+/// Cost of a pairing check of `pairs` pairs.
+pub fn pairing_gas(pairs: usize) -> u64 {
+	(pairs as u64)
+		.saturating_mul(PAIRING_PER_PAIR_GAS)
+		.saturating_add(PAIRING_BASE_GAS)
+}
+
+fn error(message: &'static str) -> PrecompileFailure {
+	PrecompileFailure::Error {
+		exit_status: ExitError::Other(message.into()),
+	}
+}
+
+fn output(output: Vec<u8>) -> PrecompileResult {
+	Ok(PrecompileOutput {
+		exit_status: ExitSucceed::Returned,
+		output,
+	})
+}
+
+/// Number of items of `item_length` bytes in a non-empty input.
+fn input_items(input_length: usize, item_length: usize) -> Result<usize, PrecompileFailure> {
+	if input_length == 0 || input_length % item_length != 0 {
+		return Err(error("invalid input length"));
+	}
+	Ok(input_length / item_length)
+}
+
+fn decode_fp(input: &[u8]) -> Result<Fq, PrecompileFailure> {
+	let (padding, value) = input[..FP_LENGTH].split_at(FP_PADDING_LENGTH);
+	if padding.iter().any(|byte| *byte != 0) {
+		return Err(error("invalid field element padding"));
+	}
+
+	let mut limbs = [0u64; 6];
+	for (limb, bytes) in limbs.iter_mut().zip(value.rchunks_exact(8)) {
+		let mut limb_bytes = [0u8; 8];
+		limb_bytes.copy_from_slice(bytes);
+		*limb = u64::from_be_bytes(limb_bytes);
+	}
+
+	Fq::from_bigint(BigInt::new(limbs)).ok_or_else(|| error("field element is not canonical"))
+}
+
+fn decode_fp2(input: &[u8]) -> Result<Fq2, PrecompileFailure> {
+	Ok(Fq2::new(
+		decode_fp(&input[..FP_LENGTH])?,
+		decode_fp(&input[FP_LENGTH..FP2_LENGTH])?,
+	))
+}
+
+fn encode_fp(value: &Fq, output: &mut Vec<u8>) {
+	output.extend_from_slice(&[0u8; FP_PADDING_LENGTH]);
+	output.extend_from_slice(&value.into_bigint().to_bytes_be());
+}
+
+fn encode_fp2(value: &Fq2, output: &mut Vec<u8>) {
+	encode_fp(&value.c0, output);
+	encode_fp(&value.c1, output);
+}
+
+/// Decode a point of G1, checking it is on the curve and optionally in the subgroup.
+fn decode_g1(input: &[u8], subgroup_check: bool) -> Result<G1Affine, PrecompileFailure> {
+	let x = decode_fp(&input[..FP_LENGTH])?;
+	let y = decode_fp(&input[FP_LENGTH..G1_LENGTH])?;
+	if x.is_zero() && y.is_zero() {
+		return Ok(G1Affine::zero());
+	}
+
+	let point = G1Affine::new_unchecked(x, y);
+	if !point.is_on_curve() {
+		return Err(error("point is not on curve"));
+	}
+	if subgroup_check && !point.is_in_correct_subgroup_assuming_on_curve() {
+		return Err(error("point is not in the correct subgroup"));
+	}
+	Ok(point)
+}
+
+/// Decode a point of G2, checking it is on the curve and optionally in the subgroup.
+fn decode_g2(input: &[u8], subgroup_check: bool) -> Result<G2Affine, PrecompileFailure> {
+	let x = decode_fp2(&input[..FP2_LENGTH])?;
+	let y = decode_fp2(&input[FP2_LENGTH..G2_LENGTH])?;
+	if x.is_zero() && y.is_zero() {
+		return Ok(G2Affine::zero());
+	}
+
+	let point = G2Affine::new_unchecked(x, y);
+	if !point.is_on_curve() {
+		return Err(error("point is not on curve"));
+	}
+	if subgroup_check && !point.is_in_correct_subgroup_assuming_on_curve() {
+		return Err(error("point is not in the correct subgroup"));
+	}
+	Ok(point)
+}
+
+fn encode_g1(point: G1Affine) -> Vec<u8> {
+	let mut output = Vec::with_capacity(G1_LENGTH);
+	match point.xy() {
+		Some((x, y)) => {
+			encode_fp(x, &mut output);
+			encode_fp(y, &mut output);
+		}
+		None => output.resize(G1_LENGTH, 0),
+	}
+	output
+}
+
+fn encode_g2(point: G2Affine) -> Vec<u8> {
+	let mut output = Vec::with_capacity(G2_LENGTH);
+	match point.xy() {
+		Some((x, y)) => {
+			encode_fp2(x, &mut output);
+			encode_fp2(y, &mut output);
+		}
+		None => output.resize(G2_LENGTH, 0),
+	}
+	output
+}
+
+fn decode_scalar(input: &[u8]) -> Fr {
+	Fr::from_be_bytes_mod_order(&input[..SCALAR_LENGTH])
+}
+
+/// Addition of two points of G1, at address 0x0b.
+pub struct Bls12381G1Add;
+
+impl Precompile for Bls12381G1Add {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(G1_ADD_GAS)?;
+
+		let input = handle.input();
+		if input.len() != 2 * G1_LENGTH {
+			return Err(error("invalid input length"));
+		}
+		let a = decode_g1(&input[..G1_LENGTH], false)?;
+		let b = decode_g1(&input[G1_LENGTH..], false)?;
+
+		output(encode_g1((G1Projective::from(a) + b).into_affine()))
+	}
+}
+
+/// Multi-scalar multiplication in G1, at address 0x0c.
+pub struct Bls12381G1Msm;
+
+impl Precompile for Bls12381G1Msm {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let pairs = input_items(handle.input().len(), G1_LENGTH + SCALAR_LENGTH)?;
+		handle.record_cost(g1_msm_gas(pairs))?;
+
+		let mut points = Vec::with_capacity(pairs);
+		let mut scalars = Vec::with_capacity(pairs);
+		for pair in handle.input().chunks_exact(G1_LENGTH + SCALAR_LENGTH) {
+			points.push(decode_g1(&pair[..G1_LENGTH], true)?);
+			scalars.push(decode_scalar(&pair[G1_LENGTH..]));
+		}
+
+		output(encode_g1(
+			G1Projective::msm_unchecked(&points, &scalars).into_affine(),
+		))
+	}
+}
+
+/// Addition of two points of G2, at address 0x0d.
+pub struct Bls12381G2Add;
+
+impl Precompile for Bls12381G2Add {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(G2_ADD_GAS)?;
+
+		let input = handle.input();
+		if input.len() != 2 * G2_LENGTH {
+			return Err(error("invalid input length"));
+		}
+		let a = decode_g2(&input[..G2_LENGTH], false)?;
+		let b = decode_g2(&input[G2_LENGTH..], false)?;
+
+		output(encode_g2((G2Projective::from(a) + b).into_affine()))
+	}
+}
+
+/// Multi-scalar multiplication in G2, at address 0x0e.
+pub struct Bls12381G2Msm;
+
+impl Precompile for Bls12381G2Msm {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let pairs = input_items(handle.input().len(), G2_LENGTH + SCALAR_LENGTH)?;
+		handle.record_cost(g2_msm_gas(pairs))?;
+
+		let mut points = Vec::with_capacity(pairs);
+		let mut scalars = Vec::with_capacity(pairs);
+		for pair in handle.input().chunks_exact(G2_LENGTH + SCALAR_LENGTH) {
+			points.push(decode_g2(&pair[..G2_LENGTH], true)?);
+			scalars.push(decode_scalar(&pair[G2_LENGTH..]));
+		}
+
+		output(encode_g2(
+			G2Projective::msm_unchecked(&points, &scalars).into_affine(),
+		))
+	}
+}
+
+/// Pairing check, at address 0x0f. Returns 1 (as a 32 bytes word) if the product of the
+/// pairings of the given pairs of points is the identity, 0 otherwise.
+pub struct Bls12381Pairing;
+
+impl Precompile for Bls12381Pairing {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let pairs = input_items(handle.input().len(), G1_LENGTH + G2_LENGTH)?;
+		handle.record_cost(pairing_gas(pairs))?;
+
+		let mut g1_points = Vec::with_capacity(pairs);
+		let mut g2_points = Vec::with_capacity(pairs);
+		for pair in handle.input().chunks_exact(G1_LENGTH + G2_LENGTH) {
+			g1_points.push(decode_g1(&pair[..G1_LENGTH], true)?);
+			g2_points.push(decode_g2(&pair[G1_LENGTH..], true)?);
+		}
+
+		let mut result = [0u8; 32];
+		if Bls12_381::multi_pairing(g1_points, g2_points).0.is_one() {
+			result[31] = 1;
+		}
+		output(result.to_vec())
+	}
+}
+
+/// Mapping of an element of Fp to a point of G1, at address 0x10.
+pub struct Bls12381MapFpToG1;
+
+impl Precompile for Bls12381MapFpToG1 {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(MAP_FP_TO_G1_GAS)?;
+
+		let input = handle.input();
+		if input.len() != FP_LENGTH {
+			return Err(error("invalid input length"));
+		}
+		let element = decode_fp(input)?;
+
+		let point = WBMap::<g1::Config>::new()
+			.and_then(|map| map.map_to_curve(element))
+			.map_err(|_| error("mapping to curve failed"))?;
+		output(encode_g1(point.clear_cofactor()))
+	}
+}
+
+/// Mapping of an element of Fp2 to a point of G2, at address 0x11.
+pub struct Bls12381MapFp2ToG2;
+
+impl Precompile for Bls12381MapFp2ToG2 {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(MAP_FP2_TO_G2_GAS)?;
+
+		let input = handle.input();
+		if input.len() != FP2_LENGTH {
+			return Err(error("invalid input length"));
+		}
+		let element = decode_fp2(input)?;
+
+		let point = WBMap::<g2::Config>::new()
+			.and_then(|map| map.map_to_curve(element))
+			.map_err(|_| error("mapping to curve failed"))?;
+		output(encode_g2(point.clear_cofactor()))
+	}
+}
+
+// The precompiles have no Solidity interface.
+impl precompile_utils::solidity::abi::SolidityInterface for Bls12381G1Add {}
+impl precompile_utils::solidity::abi::SolidityInterface for Bls12381G1Msm {}
+impl precompile_utils::solidity::abi::SolidityInterface for Bls12381G2Add {}
+impl precompile_utils::solidity::abi::SolidityInterface for Bls12381G2Msm {}
+impl precompile_utils::solidity::abi::SolidityInterface for Bls12381Pairing {}
+impl precompile_utils::solidity::abi::SolidityInterface for Bls12381MapFpToG1 {}
+impl precompile_utils::solidity::abi::SolidityInterface for Bls12381MapFp2ToG2 {}
@@ -0,0 +1,431 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use fp_evm::Context;
+use hex_literal::hex;
+use precompile_utils::testing::MockHandle;
+use sp_core::{H160, U256};
+
+const G1_GENERATOR: [u8; 128] = hex!(
+	"0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"
+	"0000000000000000000000000000000008b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1"
+);
+const G1_GENERATOR_NEG: [u8; 128] = hex!(
+	"0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"
+	"00000000000000000000000000000000114d1d6855d545a8aa7d76c8cf2e21f267816aef1db507c96655b9d5caac42364e6f38ba0ecb751bad54dcd6b939c2ca"
+);
+const G1_GENERATOR_DOUBLE: [u8; 128] = hex!(
+	"000000000000000000000000000000000572cbea904d67468808c8eb50a9450c9721db309128012543902d0ac358a62ae28f75bb8f1c7c42c39a8c5529bf0f4e"
+	"00000000000000000000000000000000166a9d8cabc673a322fda673779d8e3822ba3ecb8670e461f73bb9021d5fd76a4c56d9d4cd16bd1bba86881979749d28"
+);
+/// A point on the curve (x = 4) outside of the G1 subgroup
+const G1_NOT_IN_SUBGROUP: [u8; 128] = hex!(
+	"00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004"
+	"000000000000000000000000000000000a989badd40d6212b33cffc3f3763e9bc760f988c9926b26da9dd85e928483446346b8ed00e1de5d5ea93e354abe706c"
+);
+const G1_NOT_IN_SUBGROUP_PLUS_GENERATOR: [u8; 128] = hex!(
+	"0000000000000000000000000000000017bcbbfdd2442c328150f65465bd7b9c4ff36e35261ad3549222e532758a1cf0945ba133ec513517b4ea9de098a037f9"
+	"0000000000000000000000000000000006d1d4f6580f49b4e0a98509ffd18f24afcada36fd0d44e9fc9e5f0c19df3ec01474eefc659d57d149b97ca899010a5d"
+);
+const G2_GENERATOR: [u8; 256] = hex!(
+	"00000000000000000000000000000000024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"
+	"0000000000000000000000000000000013e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e"
+	"000000000000000000000000000000000ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801"
+	"000000000000000000000000000000000606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be"
+);
+const G2_GENERATOR_DOUBLE: [u8; 256] = hex!(
+	"000000000000000000000000000000001638533957d540a9d2370f17cc7ed5863bc0b995b8825e0ee1ea1e1e4d00dbae81f14b0bf3611b78c952aacab827a053"
+	"000000000000000000000000000000000a4edef9c1ed7f729f520e47730a124fd70662a904ba1074728114d1031e1572c6c886f6b57ec72a6178288c47c33577"
+	"000000000000000000000000000000000468fb440d82b0630aeb8dca2b5256789a66da69bf91009cbfe6bd221e47aa8ae88dece9764bf3bd999d95d71e4c9899"
+	"000000000000000000000000000000000f6d4552fa65dd2638b361543f887136a43253d9c66c411697003f7a13c308f5422e1aa0a59c8967acdefd8b6e36ccf3"
+);
+/// The field modulus, which is not a canonical encoding of a field element
+const FIELD_MODULUS: [u8; 64] = hex!(
+	"000000000000000000000000000000001a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab"
+);
+/// The order of the subgroups
+const GROUP_ORDER: [u8; 32] =
+	hex!("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001");
+
+fn scalar(value: u64) -> [u8; 32] {
+	U256::from(value).into()
+}
+
+fn execute<P: Precompile>(input: Vec<u8>) -> (PrecompileResult, u64) {
+	let mut handle = MockHandle::new(
+		H160::from_low_u64_be(11),
+		Context {
+			address: H160::from_low_u64_be(11),
+			caller: H160::repeat_byte(0x11),
+			apparent_value: U256::zero(),
+		},
+	);
+	handle.input = input;
+
+	let result = P::execute(&mut handle);
+	(result, handle.gas_used)
+}
+
+fn assert_output<P: Precompile>(input: Vec<u8>, expected_output: &[u8], expected_gas: u64) {
+	let (result, gas_used) = execute::<P>(input);
+	assert_eq!(result.expect("precompile succeeds").output, expected_output);
+	assert_eq!(gas_used, expected_gas);
+}
+
+fn assert_error<P: Precompile>(input: Vec<u8>, message: &'static str) {
+	assert_eq!(execute::<P>(input).0, Err(error(message)));
+}
+
+#[test]
+fn msm_discounts_follow_the_eip() {
+	assert_eq!(g1_msm_gas(0), 0);
+	assert_eq!(g1_msm_gas(1), 12_000);
+	assert_eq!(g1_msm_gas(2), 22_776);
+	assert_eq!(g1_msm_gas(128), 128 * 12_000 * 519 / 1000);
+	assert_eq!(g1_msm_gas(200), 200 * 12_000 * 519 / 1000);
+	assert_eq!(g2_msm_gas(1), 22_500);
+	assert_eq!(g2_msm_gas(2), 45_000);
+	assert_eq!(g2_msm_gas(200), 200 * 22_500 * 524 / 1000);
+	assert_eq!(pairing_gas(2), 102_900);
+}
+
+#[test]
+fn g1_add_works() {
+	assert_output::<Bls12381G1Add>(
+		[G1_GENERATOR, G1_GENERATOR].concat(),
+		&G1_GENERATOR_DOUBLE,
+		G1_ADD_GAS,
+	);
+	assert_output::<Bls12381G1Add>(
+		[G1_GENERATOR, [0u8; 128]].concat(),
+		&G1_GENERATOR,
+		G1_ADD_GAS,
+	);
+	assert_output::<Bls12381G1Add>(
+		[G1_GENERATOR, G1_GENERATOR_NEG].concat(),
+		&[0u8; 128],
+		G1_ADD_GAS,
+	);
+}
+
+#[test]
+fn g1_add_does_not_check_subgroup() {
+	assert_output::<Bls12381G1Add>(
+		[G1_NOT_IN_SUBGROUP, G1_GENERATOR].concat(),
+		&G1_NOT_IN_SUBGROUP_PLUS_GENERATOR,
+		G1_ADD_GAS,
+	);
+}
+
+#[test]
+fn g1_add_rejects_invalid_inputs() {
+	assert_error::<Bls12381G1Add>(G1_GENERATOR.to_vec(), "invalid input length");
+
+	let mut not_on_curve = G1_GENERATOR;
+	not_on_curve[127] ^= 1;
+	assert_error::<Bls12381G1Add>(
+		[G1_GENERATOR, not_on_curve].concat(),
+		"point is not on curve",
+	);
+
+	let mut non_canonical = G1_GENERATOR;
+	non_canonical[..64].copy_from_slice(&FIELD_MODULUS);
+	assert_error::<Bls12381G1Add>(
+		[G1_GENERATOR, non_canonical].concat(),
+		"field element is not canonical",
+	);
+
+	let mut bad_padding = G1_GENERATOR;
+	bad_padding[0] = 1;
+	assert_error::<Bls12381G1Add>(
+		[G1_GENERATOR, bad_padding].concat(),
+		"invalid field element padding",
+	);
+}
+
+#[test]
+fn g1_msm_works() {
+	assert_output::<Bls12381G1Msm>(
+		[&G1_GENERATOR[..], &scalar(2)].concat(),
+		&G1_GENERATOR_DOUBLE,
+		12_000,
+	);
+	assert_output::<Bls12381G1Msm>(
+		[&G1_GENERATOR[..], &scalar(1), &G1_GENERATOR, &scalar(1)].concat(),
+		&G1_GENERATOR_DOUBLE,
+		22_776,
+	);
+	assert_output::<Bls12381G1Msm>(
+		[&G1_GENERATOR[..], &GROUP_ORDER].concat(),
+		&[0u8; 128],
+		12_000,
+	);
+}
+
+#[test]
+fn g1_msm_checks_subgroup() {
+	assert_error::<Bls12381G1Msm>(
+		[&G1_NOT_IN_SUBGROUP[..], &scalar(1)].concat(),
+		"point is not in the correct subgroup",
+	);
+}
+
+#[test]
+fn g1_msm_rejects_invalid_length() {
+	assert_error::<Bls12381G1Msm>(vec![], "invalid input length");
+	assert_error::<Bls12381G1Msm>(G1_GENERATOR.to_vec(), "invalid input length");
+}
+
+#[test]
+fn g2_add_works() {
+	assert_output::<Bls12381G2Add>(
+		[G2_GENERATOR, G2_GENERATOR].concat(),
+		&G2_GENERATOR_DOUBLE,
+		G2_ADD_GAS,
+	);
+	assert_output::<Bls12381G2Add>(
+		[[0u8; 256], G2_GENERATOR].concat(),
+		&G2_GENERATOR,
+		G2_ADD_GAS,
+	);
+}
+
+#[test]
+fn g2_add_rejects_point_not_on_curve() {
+	let mut not_on_curve = G2_GENERATOR;
+	not_on_curve[255] ^= 1;
+	assert_error::<Bls12381G2Add>(
+		[G2_GENERATOR, not_on_curve].concat(),
+		"point is not on curve",
+	);
+}
+
+#[test]
+fn g2_msm_works() {
+	assert_output::<Bls12381G2Msm>(
+		[&G2_GENERATOR[..], &scalar(2)].concat(),
+		&G2_GENERATOR_DOUBLE,
+		22_500,
+	);
+	assert_output::<Bls12381G2Msm>(
+		[&G2_GENERATOR[..], &scalar(1), &G2_GENERATOR, &scalar(1)].concat(),
+		&G2_GENERATOR_DOUBLE,
+		45_000,
+	);
+}
+
+#[test]
+fn pairing_works() {
+	let mut success = [0u8; 32];
+	success[31] = 1;
+
+	assert_output::<Bls12381Pairing>(
+		[
+			&G1_GENERATOR[..],
+			&G2_GENERATOR,
+			&G1_GENERATOR_NEG,
+			&G2_GENERATOR,
+		]
+		.concat(),
+		&success,
+		102_900,
+	);
+	assert_output::<Bls12381Pairing>(
+		[&G1_GENERATOR[..], &G2_GENERATOR].concat(),
+		&[0u8; 32],
+		70_300,
+	);
+	assert_output::<Bls12381Pairing>([&[0u8; 128][..], &G2_GENERATOR].concat(), &success, 70_300);
+}
+
+#[test]
+fn pairing_rejects_invalid_inputs() {
+	assert_error::<Bls12381Pairing>(vec![], "invalid input length");
+	assert_error::<Bls12381Pairing>(
+		[&G1_NOT_IN_SUBGROUP[..], &G2_GENERATOR].concat(),
+		"point is not in the correct subgroup",
+	);
+}
+
+#[test]
+fn map_fp_to_g1_returns_point_of_subgroup() {
+	let (result, gas_used) = execute::<Bls12381MapFpToG1>(G1_GENERATOR[..64].to_vec());
+	let output = result.expect("precompile succeeds").output;
+
+	assert_eq!(gas_used, MAP_FP_TO_G1_GAS);
+	assert!(decode_g1(&output, true).is_ok());
+	assert_ne!(output, [0u8; 128]);
+}
+
+#[test]
+fn map_fp_to_g1_rejects_invalid_inputs() {
+	assert_error::<Bls12381MapFpToG1>(FIELD_MODULUS.to_vec(), "field element is not canonical");
+	assert_error::<Bls12381MapFpToG1>(G1_GENERATOR.to_vec(), "invalid input length");
+}
+
+#[test]
+fn map_fp2_to_g2_returns_point_of_subgroup() {
+	let (result, gas_used) = execute::<Bls12381MapFp2ToG2>(G2_GENERATOR[..128].to_vec());
+	let output = result.expect("precompile succeeds").output;
+
+	assert_eq!(gas_used, MAP_FP2_TO_G2_GAS);
+	assert!(decode_g2(&output, true).is_ok());
+	assert_ne!(output, [0u8; 256]);
+}
+
+#[test]
+fn map_fp2_to_g2_rejects_invalid_inputs() {
+	assert_error::<Bls12381MapFp2ToG2>(
+		[FIELD_MODULUS, FIELD_MODULUS].concat(),
+		"field element is not canonical",
+	);
+	assert_error::<Bls12381MapFp2ToG2>(G2_GENERATOR.to_vec(), "invalid input length");
+}
+
+// Known-answer vectors of the EIP-2537 test suite
+
+/// Point of G1 of the `bls_g1add_g1+p1` vector
+const G1_VECTOR_POINT: [u8; 128] = hex!(
+	"00000000000000000000000000000000112b98340eee2777cc3c14163dea3ec97977ac3dc5c70da32e6e87578f44912e902ccef9efe28d4a78b8999dfbca9426"
+	"00000000000000000000000000000000186b28d92356c4dfec4b5201ad099dbdede3781f8998ddf929b4cd7756192185ca7b8f4ef7088f813270ac3d48868a21"
+);
+/// Point of G2 of the `bls_g2add_g2+p2` vector
+const G2_VECTOR_POINT: [u8; 256] = hex!(
+	"00000000000000000000000000000000103121a2ceaae586d240843a398967325f8eb5a93e8fea99b62b9f88d8556c80dd726a4b30e84a36eeabaf3592937f27"
+	"00000000000000000000000000000000086b990f3da2aeac0a36143b7d7c824428215140db1bb859338764cb58458f081d92664f9053b50b3fbd2e4723121b68"
+	"000000000000000000000000000000000f9e7ba9a86a8f7624aa2b42dcc8772e1af4ae115685e60abc2c9b90242167acef3d0be4050bf935eed7c3b6fc7ba77e"
+	"000000000000000000000000000000000d22c3652d0dc6f0fc9316e14268477c2049ef772e852108d269d9c38dba1d4802e8dae479818184c08f9a569d878451"
+);
+/// Scalar of the `bls_g1mul_random*g1` and `bls_g2mul_random*g2` vectors
+const VECTOR_SCALAR: [u8; 32] =
+	hex!("263dbd792f5b1be47ed85f8938c0f29586af0d3ac7b977f21c278fe1462040e3");
+const G1_GENERATOR_TIMES_VECTOR_SCALAR: [u8; 128] = hex!(
+	"000000000000000000000000000000000491d1b0ecd9bb917989f0e74f0dea0422eac4a873e5e2644f368dffb9a6e20fd6e10c1b77654d067c0618f6e5a7f79a"
+	"0000000000000000000000000000000017cd7061575d3e8034fcea62adaa1a3bc38dca4b50e4c5c01d04dd78037c9cee914e17944ea99e7ad84278e5d49f36c4"
+);
+const G2_GENERATOR_TIMES_VECTOR_SCALAR: [u8; 256] = hex!(
+	"0000000000000000000000000000000014856c22d8cdb2967c720e963eedc999e738373b14172f06fc915769d3cc5ab7ae0a1b9c38f48b5585fb09d4bd2733bb"
+	"000000000000000000000000000000000c400b70f6f8cd35648f5c126cce5417f3be4d8eefbd42ceb4286a14df7e03135313fe5845e3a575faab3e8b949d2488"
+	"00000000000000000000000000000000149a0aacc34beba2beb2f2a19a440166e76e373194714f108e4ab1c3fd331e80f4e73e6b9ea65fe3ec96d7136de81544"
+	"000000000000000000000000000000000e4622fef26bdb9b1e8ef6591a7cc99f5b73164500c1ee224b6a761e676b8799b09a3fd4fa7e242645cc1a34708285e4"
+);
+
+#[test]
+fn g1_add_matches_eip_vectors() {
+	assert_output::<Bls12381G1Add>(
+		[G1_GENERATOR, G1_VECTOR_POINT].concat(),
+		&hex!(
+			"000000000000000000000000000000000a40300ce2dec9888b60690e9a41d3004fda4886854573974fab73b046d3147ba5b7a5bde85279ffede1b45b3918d82d"
+			"0000000000000000000000000000000006d3d887e9f53b9ec4eb6cedf5607226754b07c01ace7834f57f3e7315faefb739e59018e22c492006190fba4a870025"
+		),
+		G1_ADD_GAS,
+	);
+}
+
+#[test]
+fn g2_add_matches_eip_vectors() {
+	assert_output::<Bls12381G2Add>(
+		[G2_GENERATOR, G2_VECTOR_POINT].concat(),
+		&hex!(
+			"000000000000000000000000000000000b54a8a7b08bd6827ed9a797de216b8c9057b3a9ca93e2f88e7f04f19accc42da90d883632b9ca4dc38d013f71ede4db"
+			"00000000000000000000000000000000077eba4eecf0bd764dce8ed5f45040dd8f3b3427cb35230509482c14651713282946306247866dfe39a8e33016fcbe52"
+			"0000000000000000000000000000000014e60a76a29ef85cbd69f251b9f29147b67cfe3ed2823d3f9776b3a0efd2731941d47436dc6d2b58d9e65f8438bad073"
+			"000000000000000000000000000000001586c3c910d95754fef7a732df78e279c3d37431c6a2b77e67a00c7c130a8fcd4d19f159cbeb997a178108fffffcbd20"
+		),
+		G2_ADD_GAS,
+	);
+}
+
+#[test]
+fn g1_msm_matches_eip_vectors() {
+	assert_output::<Bls12381G1Msm>(
+		[&G1_GENERATOR[..], &VECTOR_SCALAR].concat(),
+		&G1_GENERATOR_TIMES_VECTOR_SCALAR,
+		G1_MUL_GAS,
+	);
+}
+
+#[test]
+fn g2_msm_matches_eip_vectors() {
+	assert_output::<Bls12381G2Msm>(
+		[&G2_GENERATOR[..], &VECTOR_SCALAR].concat(),
+		&G2_GENERATOR_TIMES_VECTOR_SCALAR,
+		G2_MUL_GAS,
+	);
+}
+
+#[test]
+fn pairing_matches_eip_vectors() {
+	// e(s * G1, G2) * e(-G1, s * G2) == 1
+	let mut success = [0u8; 32];
+	success[31] = 1;
+	assert_output::<Bls12381Pairing>(
+		[
+			&G1_GENERATOR_TIMES_VECTOR_SCALAR[..],
+			&G2_GENERATOR,
+			&G1_GENERATOR_NEG,
+			&G2_GENERATOR_TIMES_VECTOR_SCALAR,
+		]
+		.concat(),
+		&success,
+		pairing_gas(2),
+	);
+	assert_output::<Bls12381Pairing>(
+		[
+			&G1_GENERATOR_TIMES_VECTOR_SCALAR[..],
+			&G2_GENERATOR,
+			&G1_GENERATOR_TIMES_VECTOR_SCALAR,
+			&G2_GENERATOR_TIMES_VECTOR_SCALAR,
+		]
+		.concat(),
+		&[0u8; 32],
+		pairing_gas(2),
+	);
+}
+
+#[test]
+fn map_fp_to_g1_matches_eip_vectors() {
+	assert_output::<Bls12381MapFpToG1>(
+		hex!(
+			"00000000000000000000000000000000156c8a6a2c184569d69a76be144b5cdc5141d2d2ca4fe341f011e25e3969c55ad9e9b9ce2eb833c81a908e5fa4ac5f03"
+		)
+		.to_vec(),
+		&hex!(
+			"00000000000000000000000000000000184bb665c37ff561a89ec2122dd343f20e0f4cbcaec84e3c3052ea81d1834e192c426074b02ed3dca4e7676ce4ce48ba"
+			"0000000000000000000000000000000004407b8d35af4dacc809927071fc0405218f1401a6d15af775810e4e460064bcc9468beeba82fdc751be70476c888bf3"
+		),
+		MAP_FP_TO_G1_GAS,
+	);
+}
+
+#[test]
+fn map_fp2_to_g2_matches_eip_vectors() {
+	assert_output::<Bls12381MapFp2ToG2>(
+		hex!(
+			"0000000000000000000000000000000007355d25caf6e7f2f0cb2812ca0e513bd026ed09dda65b177500fa31714e09ea0ded3a078b526bed3307f804d4b93b04"
+			"0000000000000000000000000000000002829ce3c021339ccb5caf3e187f6370e1e2a311dec9b75363117063ab2015603ff52c3d3b98f19c2f65575e99e8b78c"
+		)
+		.to_vec(),
+		&hex!(
+			"0000000000000000000000000000000000e7f4568a82b4b7dc1f14c6aaa055edf51502319c723c4dc2688c7fe5944c213f510328082396515734b6612c4e7bb7"
+			"00000000000000000000000000000000126b855e9e69b1f691f816e48ac6977664d24d99f8724868a184186469ddfd4617367e94527d4b74fc86413483afb35b"
+			"000000000000000000000000000000000caead0fd7b6176c01436833c79d305c78be307da5f6af6c133c47311def6ff1e0babf57a0fb5539fce7ee12407b0a42"
+			"000000000000000000000000000000001498aadcf7ae2b345243e281ae076df6de84455d766ab6fcdaad71fab60abb2e8b980a440043cd305db09d283c895e3d"
+		),
+		MAP_FP2_TO_G2_GAS,
+	);
+}
@@ -13,6 +13,18 @@ CallPermit constant CALL_PERMIT_CONTRACT = CallPermit(CALL_PERMIT_ADDRESS);
 /// and signed messages can be dispatched by another network participant with a transaction
 /// @custom:address 0x000000000000000000000000000000000000080a
 interface CallPermit {
+    /// @dev A call of a batch permit.
+    struct Call {
+        /// Which address the call is made to.
+        address to;
+        /// Value being transfered from the "from" account.
+        uint256 value;
+        /// Call data.
+        bytes data;
+        /// Gaslimit the dispatched call requires.
+        uint64 gaslimit;
+    }
+
     /// @dev Dispatch a call on the behalf of an other user with a EIP712 permit.
     /// Will revert if the permit is not valid or if the dispatched call reverts or errors (such as
     /// out of gas).
@@ -41,12 +53,53 @@ interface CallPermit {
         bytes32 s
     ) external returns (bytes memory output);
 
+    /// @dev Dispatch an ordered batch of calls on the behalf of an other user with a EIP712 permit,
+    /// and optionally reimburse the dispatcher up to a fee agreed by the signer.
+    /// Will revert if the permit is not valid, if any dispatched call reverts or errors, or if the
+    /// fee payment fails.
+    /// If successful the nonce of the lane is increased to prevent this permit to be replayed.
+    /// @param from Who made the permit and want its calls to be dispatched on their behalf.
+    /// @param calls The calls to dispatch, in order.
+    /// @param lane Nonce lane of the permit. Permits of different lanes don't need to be
+    ///     dispatched in order. Lane 0 shares its nonce with `dispatch`.
+    /// @param deadline Deadline in UNIX seconds after which the permit will no longer be valid.
+    /// @param feeToken ERC20 token in which the fee is paid, or the zero address for the native
+    ///     currency.
+    /// @param maxFee Maximum fee the signer agrees to pay to the dispatcher.
+    /// @param fee Fee requested by the dispatcher, up to `maxFee`.
+    /// @param v V part of the signature.
+    /// @param r R part of the signature.
+    /// @param s S part of the signature.
+    /// @return outputs Output of each call.
+    /// @custom:selector 3fb88711
+    function dispatchBatch(
+        address from,
+        Call[] memory calls,
+        uint256 lane,
+        uint256 deadline,
+        address feeToken,
+        uint256 maxFee,
+        uint256 fee,
+        uint8 v,
+        bytes32 r,
+        bytes32 s
+    ) external returns (bytes[] memory outputs);
+
     /// @dev Returns the current nonce for given owner.
     /// A permit must have this nonce to be consumed, which will
     /// increase the nonce by one.
     /// @custom:selector 7ecebe00
     function nonces(address owner) external view returns (uint256);
 
+    /// @dev Returns the current nonce of a lane for given owner.
+    /// A batch permit of this lane must have this nonce to be consumed, which will
+    /// increase the nonce by one.
+    /// @custom:selector 51792860
+    function laneNonces(address owner, uint256 lane)
+        external
+        view
+        returns (uint256);
+
     /// @dev Returns the EIP712 domain separator. It is used to avoid replay
     /// attacks accross assets or other similar EIP712 message structures.
     /// @custom:selector 3644e515
@@ -21,7 +21,7 @@ use evm::ExitReason;
 use fp_evm::{Context, ExitRevert, PrecompileFailure, PrecompileHandle, Transfer};
 use frame_support::{
 	ensure,
-	storage::types::{StorageMap, ValueQuery},
+	storage::types::{StorageDoubleMap, StorageMap, ValueQuery},
 	traits::{ConstU32, Get, StorageInstance},
 	Blake2_128Concat,
 };
@@ -57,18 +57,67 @@ pub type NoncesStorage = StorageMap<
 	ValueQuery,
 >;
 
+/// Storage prefix for nonces of lanes other than the default one.
+pub struct LaneNonces;
+
+impl StorageInstance for LaneNonces {
+	const STORAGE_PREFIX: &'static str = "LaneNonces";
+
+	fn pallet_prefix() -> &'static str {
+		"PrecompileCallPermit"
+	}
+}
+
+/// Storage type used to store nonces of lanes other than the default one.
+/// The default lane (0) uses `NoncesStorage`.
+pub type LaneNoncesStorage = StorageDoubleMap<
+	LaneNonces,
+	// From
+	Blake2_128Concat,
+	H160,
+	// Lane
+	Blake2_128Concat,
+	U256,
+	// Nonce
+	U256,
+	ValueQuery,
+>;
+
 /// EIP712 permit typehash.
 pub const PERMIT_TYPEHASH: [u8; 32] = keccak256!(
 	"CallPermit(address from,address to,uint256 value,bytes data,uint64 gaslimit\
 ,uint256 nonce,uint256 deadline)"
 );
 
+/// EIP712 batch permit typehash.
+pub const BATCH_PERMIT_TYPEHASH: [u8; 32] = keccak256!(
+	"CallBatchPermit(address from,Call[] calls,uint256 lane,uint256 nonce,uint256 deadline\
+,address feeToken,uint256 maxFee)Call(address to,uint256 value,bytes data,uint64 gaslimit)"
+);
+
+/// EIP712 typehash of a call of a batch permit.
+pub const CALL_TYPEHASH: [u8; 32] =
+	keccak256!("Call(address to,uint256 value,bytes data,uint64 gaslimit)");
+
+/// Selector of the ERC20 `transfer(address,uint256)` function, used to pay fees in tokens.
+pub const SELECTOR_ERC20_TRANSFER: u32 = 0xa9059cbb;
+
 /// EIP712 permit domain used to compute an individualized domain separator.
 const PERMIT_DOMAIN: [u8; 32] = keccak256!(
 	"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"
 );
 
 pub const CALL_DATA_LIMIT: u32 = 2u32.pow(16);
+pub const CALLS_LIMIT: u32 = 2u32.pow(6);
+
+/// A call of a batch permit.
+#[derive(Clone, Debug, Eq, PartialEq, solidity::Codec)]
+pub struct PermitCall {
+	pub to: Address,
+	pub value: U256,
+	pub data: BoundedBytes<ConstU32<CALL_DATA_LIMIT>>,
+	pub gas_limit: u64,
+}
 
 /// Precompile allowing to issue and dispatch call permits for gasless transactions.
 /// A user can sign a permit for a call that can be dispatched and paid by another user or
@@ -120,7 +169,48 @@ where
 			nonce,
 			deadline,
 		));
-		let permit_content = keccak_256(&permit_content);
+		Self::typed_data_digest(domain_separator, keccak_256(&permit_content))
+	}
+
+	pub fn generate_batch_permit(
+		address: H160,
+		from: H160,
+		calls: &[PermitCall],
+		lane: U256,
+		nonce: U256,
+		deadline: U256,
+		fee_token: H160,
+		max_fee: U256,
+	) -> [u8; 32] {
+		let domain_separator = Self::compute_domain_separator(address);
+
+		// arrays of structs are encoded as the keccak_256 of the concatenated struct hashes
+		let mut calls_hashes = Vec::with_capacity(calls.len() * 32);
+		for call in calls {
+			let call_content = solidity::encode_arguments((
+				H256::from(CALL_TYPEHASH),
+				call.to,
+				call.value,
+				H256::from(keccak_256(call.data.as_bytes())),
+				call.gas_limit,
+			));
+			calls_hashes.extend_from_slice(&keccak_256(&call_content));
+		}
+
+		let permit_content = solidity::encode_arguments((
+			H256::from(BATCH_PERMIT_TYPEHASH),
+			Address(from),
+			H256::from(keccak_256(&calls_hashes)),
+			lane,
+			nonce,
+			deadline,
+			Address(fee_token),
+			max_fee,
+		));
+		Self::typed_data_digest(domain_separator, keccak_256(&permit_content))
+	}
+
+	fn typed_data_digest(domain_separator: [u8; 32], permit_content: [u8; 32]) -> [u8; 32] {
 		let mut pre_digest = Vec::with_capacity(2 + 32 + 32);
 		pre_digest.extend_from_slice(b"\x19\x01");
 		pre_digest.extend_from_slice(&domain_separator);
@@ -128,6 +218,76 @@ where
 		keccak_256(&pre_digest)
 	}
 
+	fn ensure_signed_by(permit: [u8; 32], from: H160, v: u8, r: H256, s: H256) -> EvmResult {
+		let mut sig = [0u8; 65];
+		sig[0..32].copy_from_slice(&r.as_bytes());
+		sig[32..64].copy_from_slice(&s.as_bytes());
+		sig[64] = v;
+
+		let signer = sp_io::crypto::secp256k1_ecdsa_recover(&sig, &permit)
+			.map_err(|_| revert("Invalid permit"))?;
+		let signer = H160::from(H256::from_slice(keccak_256(&signer).as_slice()));
+
+		ensure!(
+			signer != H160::zero() && signer == from,
+			revert("Invalid permit")
+		);
+
+		Ok(())
+	}
+
+	fn lane_nonce(from: H160, lane: U256) -> U256 {
+		if lane.is_zero() {
+			NoncesStorage::get(from)
+		} else {
+			LaneNoncesStorage::get(from, lane)
+		}
+	}
+
+	fn set_lane_nonce(from: H160, lane: U256, nonce: U256) {
+		if lane.is_zero() {
+			NoncesStorage::insert(from, nonce)
+		} else {
+			LaneNoncesStorage::insert(from, lane, nonce)
+		}
+	}
+
+	fn call_as(
+		handle: &mut impl PrecompileHandle,
+		from: H160,
+		to: H160,
+		value: U256,
+		data: Vec<u8>,
+		gas_limit: Option<u64>,
+	) -> EvmResult<Vec<u8>> {
+		let sub_context = Context {
+			caller: from,
+			address: to.clone(),
+			apparent_value: value,
+		};
+
+		let transfer = if value.is_zero() {
+			None
+		} else {
+			Some(Transfer {
+				source: from,
+				target: to.clone(),
+				value,
+			})
+		};
+
+		let (reason, output) = handle.call(to, transfer, data, gas_limit, false, &sub_context);
+		match reason {
+			ExitReason::Error(exit_status) => Err(PrecompileFailure::Error { exit_status }),
+			ExitReason::Fatal(exit_status) => Err(PrecompileFailure::Fatal { exit_status }),
+			ExitReason::Revert(_) => Err(PrecompileFailure::Revert {
+				exit_status: ExitRevert::Reverted,
+				output,
+			}),
+			ExitReason::Succeed(_) => Ok(output),
+		}
+	}
+
 	pub fn dispatch_inherent_cost() -> u64 {
 		3_000 // cost of ECRecover precompile for reference
 			+ RuntimeHelper::<Runtime>::db_write_gas_cost() // we write nonce
@@ -189,50 +349,126 @@ where
 			deadline,
 		);
 
-		let mut sig = [0u8; 65];
-		sig[0..32].copy_from_slice(&r.as_bytes());
-		sig[32..64].copy_from_slice(&s.as_bytes());
-		sig[64] = v;
-
-		let signer = sp_io::crypto::secp256k1_ecdsa_recover(&sig, &permit)
-			.map_err(|_| revert("Invalid permit"))?;
-		let signer = H160::from(H256::from_slice(keccak_256(&signer).as_slice()));
-
-		ensure!(
-			signer != H160::zero() && signer == from,
-			revert("Invalid permit")
-		);
+		Self::ensure_signed_by(permit, from, v, r, s)?;
 
 		NoncesStorage::insert(from, nonce + U256::one());
 
 		// DISPATCH CALL
-		let sub_context = Context {
-			caller: from,
-			address: to.clone(),
-			apparent_value: value,
-		};
+		let output = Self::call_as(handle, from, to, value, data, Some(gas_limit))?;
 
-		let transfer = if value.is_zero() {
-			None
-		} else {
-			Some(Transfer {
-				source: from,
-				target: to.clone(),
-				value,
-			})
-		};
+		Ok(output.into())
+	}
 
-		let (reason, output) =
-			handle.call(to, transfer, data, Some(gas_limit), false, &sub_context);
-		match reason {
-			ExitReason::Error(exit_status) => Err(PrecompileFailure::Error { exit_status }),
-			ExitReason::Fatal(exit_status) => Err(PrecompileFailure::Fatal { exit_status }),
-			ExitReason::Revert(_) => Err(PrecompileFailure::Revert {
-				exit_status: ExitRevert::Reverted,
-				output,
-			}),
-			ExitReason::Succeed(_) => Ok(output.into()),
+	#[precompile::public(
+		"dispatchBatch(address,(address,uint256,bytes,uint64)[],uint256,uint256,address,uint256,uint256,uint8,bytes32,bytes32)"
+	)]
+	fn dispatch_batch(
+		handle: &mut impl PrecompileHandle,
+		from: Address,
+		calls: BoundedVec<PermitCall, ConstU32<CALLS_LIMIT>>,
+		lane: U256,
+		deadline: U256,
+		fee_token: Address,
+		max_fee: U256,
+		fee: U256,
+		v: u8,
+		r: H256,
+		s: H256,
+	) -> EvmResult<Vec<UnboundedBytes>> {
+		// Now: 8
+		handle.record_db_read::<Runtime>(8)?;
+		// LaneNoncesStorage: Blake2_128(16) + contract(20) + Blake2_128(16) + owner(20)
+		// + Blake2_128(16) + lane(32) + nonce(32)
+		handle.record_db_read::<Runtime>(152)?;
+
+		handle.record_cost(Self::dispatch_inherent_cost())?;
+
+		let from: H160 = from.into();
+		let fee_token: H160 = fee_token.into();
+		let calls: Vec<PermitCall> = calls.into();
+
+		ensure!(fee <= max_fee, revert("Fee exceeds permit max fee"));
+
+		// ENSURE GASLIMIT IS SUFFICIENT
+		let mut total_cost = 0u64;
+		for call in &calls {
+			let call_cost = call_cost(call.value, <Runtime as pallet_evm::Config>::config());
+
+			total_cost = total_cost
+				.checked_add(call.gas_limit)
+				.and_then(|cost| cost.checked_add(call_cost))
+				.ok_or_else(|| revert("Calls require too much gas (uint64 overflow)"))?;
 		}
+
+		if total_cost > handle.remaining_gas() {
+			return Err(revert("Gaslimit is too low to dispatch provided calls"));
+		}
+
+		// VERIFY PERMIT
+
+		// pallet_timestamp is in ms while Ethereum use second timestamps.
+		let timestamp: U256 = (pallet_timestamp::Pallet::<Runtime>::get()).into() / 1000;
+		ensure!(deadline >= timestamp, revert("Permit expired"));
+
+		let nonce = Self::lane_nonce(from, lane);
+
+		let permit = Self::generate_batch_permit(
+			handle.context().address,
+			from,
+			&calls,
+			lane,
+			nonce,
+			deadline,
+			fee_token,
+			max_fee,
+		);
+
+		Self::ensure_signed_by(permit, from, v, r, s)?;
+
+		Self::set_lane_nonce(from, lane, nonce + U256::one());
+
+		// DISPATCH CALLS
+		// Any failing call reverts the whole batch, including the nonce increase.
+		let mut outputs = Vec::with_capacity(calls.len());
+		for call in calls {
+			let output = Self::call_as(
+				handle,
+				from,
+				call.to.into(),
+				call.value,
+				call.data.into(),
+				Some(call.gas_limit),
+			)?;
+			outputs.push(output.into());
+		}
+
+		// REIMBURSE RELAYER
+		if !fee.is_zero() {
+			let relayer = handle.context().caller;
+
+			// The relayer pays for the gas used by the fee payment.
+			let result = if fee_token.is_zero() {
+				Self::call_as(handle, from, relayer, fee, Vec::new(), None).map(|_| true)
+			} else {
+				let data = solidity::encode_with_selector(
+					SELECTOR_ERC20_TRANSFER,
+					(Address(relayer), fee),
+				);
+
+				// Tokens not returning a value are supported, and a returned value must be `true`.
+				Self::call_as(handle, from, fee_token, U256::zero(), data, None)
+					.map(|output| output.is_empty() || output == solidity::encode_arguments(true))
+			};
+
+			let paid = match result {
+				Ok(paid) => paid,
+				Err(e @ PrecompileFailure::Fatal { .. }) => return Err(e),
+				Err(_) => false,
+			};
+			ensure!(paid, revert("Fee payment failed"));
+		}
+
+		Ok(outputs)
 	}
 
 	#[precompile::public("nonces(address)")]
@@ -248,6 +484,20 @@ where
 		Ok(nonce)
 	}
 
+	#[precompile::public("laneNonces(address,uint256)")]
+	#[precompile::view]
+	fn lane_nonces(
+		handle: &mut impl PrecompileHandle,
+		owner: Address,
+		lane: U256,
+	) -> EvmResult<U256> {
+		// LaneNoncesStorage: Blake2_128(16) + contract(20) + Blake2_128(16) + owner(20)
+		// + Blake2_128(16) + lane(32) + nonce(32)
+		handle.record_db_read::<Runtime>(152)?;
+
+		Ok(Self::lane_nonce(owner.into(), lane))
+	}
+
 	#[precompile::public("DOMAIN_SEPARATOR()")]
 	#[precompile::view]
 	fn domain_separator(handle: &mut impl PrecompileHandle) -> EvmResult<H256> {
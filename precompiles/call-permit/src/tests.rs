@@ -16,7 +16,7 @@
 
 use crate::{
 	mock::{CallPermit, ExtBuilder, PCall, Precompiles, PrecompilesValue, Runtime},
-	CallPermitPrecompile,
+	CallPermitPrecompile, PermitCall, SELECTOR_ERC20_TRANSFER,
 };
 use libsecp256k1::{sign, Message, SecretKey};
 use precompile_utils::{
@@ -37,6 +37,8 @@ fn selectors() {
 	assert!(PCall::dispatch_selectors().contains(&0xb5ea0966));
 	assert!(PCall::nonces_selectors().contains(&0x7ecebe00));
 	assert!(PCall::domain_separator_selectors().contains(&0x3644e515));
+	assert!(PCall::dispatch_batch_selectors().contains(&0x3fb88711));
+	assert!(PCall::lane_nonces_selectors().contains(&0x51792860));
 }
 
 #[test]
@@ -50,6 +52,8 @@ fn modifiers() {
 			tester.test_default_modifier(PCall::dispatch_selectors());
 			tester.test_view_modifier(PCall::nonces_selectors());
 			tester.test_view_modifier(PCall::domain_separator_selectors());
+			tester.test_default_modifier(PCall::dispatch_batch_selectors());
+			tester.test_view_modifier(PCall::lane_nonces_selectors());
 		});
 }
 
@@ -670,7 +674,363 @@ fn valid_permit_returns_with_metamask_signed_data() {
 		})
 }
 
+fn sign_batch_permit(
+	calls: &[PermitCall],
+	lane: U256,
+	nonce: U256,
+	fee_token: H160,
+	max_fee: U256,
+) -> (u8, H256, H256) {
+	let permit = CallPermitPrecompile::<Runtime>::generate_batch_permit(
+		CallPermit.into(),
+		CryptoAlith.into(),
+		calls,
+		lane,
+		nonce,
+		1_000u32.into(),
+		fee_token,
+		max_fee,
+	);
+
+	let secret_key = SecretKey::parse(&alith_secret_key()).unwrap();
+	let message = Message::parse(&permit);
+	let (rs, v) = sign(&message, &secret_key);
+	(
+		v.serialize(),
+		H256::from(rs.r.b32()),
+		H256::from(rs.s.b32()),
+	)
+}
+
+#[test]
+fn valid_batch_permit_returns_and_pays_native_fee() {
+	ExtBuilder::default()
+		.with_balances(vec![(CryptoAlith.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			let calls = vec![
+				PermitCall {
+					to: Address(Bob.into()),
+					value: 42u8.into(),
+					data: b"one".to_vec().into(),
+					gas_limit: 100_000,
+				},
+				PermitCall {
+					to: Address(David.into()),
+					value: U256::zero(),
+					data: b"two".to_vec().into(),
+					gas_limit: 50_000,
+				},
+			];
+			let lane: U256 = 1u8.into();
+			let (v, r, s) = sign_batch_permit(&calls, lane, 0u8.into(), H160::zero(), 10u8.into());
+
+			let mut counter = 0;
+
+			precompiles()
+				.prepare_test(
+					Charlie, // can be anyone, and receives the fee
+					CallPermit,
+					PCall::dispatch_batch {
+						from: Address(CryptoAlith.into()),
+						calls: calls.into(),
+						lane,
+						deadline: 1_000u32.into(),
+						fee_token: Address(H160::zero()),
+						max_fee: 10u8.into(),
+						fee: 5u8.into(),
+						v,
+						r,
+						s,
+					},
+				)
+				.with_subcall_handle(move |subcall| {
+					let Subcall {
+						address,
+						transfer,
+						input,
+						target_gas,
+						is_static,
+						context,
+					} = subcall;
+
+					// Called on the behalf of the permit maker.
+					assert_eq!(context.caller, CryptoAlith.into());
+					assert_eq!(is_static, false);
+
+					counter += 1;
+					match counter {
+						1 => {
+							assert_eq!(address, Bob.into());
+							assert_eq!(target_gas, Some(100_000), "forward requested gas");
+							let transfer = transfer.expect("there is a transfer");
+							assert_eq!(transfer.value, 42u8.into());
+							assert_eq!(&input, b"one");
+
+							SubcallOutput {
+								output: b"ONE".to_vec(),
+								cost: 13,
+								..SubcallOutput::succeed()
+							}
+						}
+						2 => {
+							assert_eq!(address, David.into());
+							assert_eq!(target_gas, Some(50_000), "forward requested gas");
+							assert!(transfer.is_none());
+							assert_eq!(&input, b"two");
+
+							SubcallOutput {
+								output: b"TWO".to_vec(),
+								cost: 17,
+								..SubcallOutput::succeed()
+							}
+						}
+						3 => {
+							// Fee payment to the dispatcher.
+							assert_eq!(address, Charlie.into());
+							let transfer = transfer.expect("there is a transfer");
+							assert_eq!(transfer.source, CryptoAlith.into());
+							assert_eq!(transfer.target, Charlie.into());
+							assert_eq!(transfer.value, 5u8.into());
+							assert!(input.is_empty());
+
+							SubcallOutput::succeed()
+						}
+						_ => panic!("unexpected subcall"),
+					}
+				})
+				.with_target_gas(Some(1_000_000))
+				.expect_no_logs()
+				.execute_returns(vec![
+					UnboundedBytes::from(b"ONE"),
+					UnboundedBytes::from(b"TWO"),
+				]);
+
+			precompiles()
+				.prepare_test(
+					CryptoAlith,
+					CallPermit,
+					PCall::lane_nonces {
+						owner: Address(CryptoAlith.into()),
+						lane,
+					},
+				)
+				.execute_returns(U256::from(1u8));
+
+			// The default lane is untouched.
+			precompiles()
+				.prepare_test(
+					CryptoAlith,
+					CallPermit,
+					PCall::nonces {
+						owner: Address(CryptoAlith.into()),
+					},
+				)
+				.execute_returns(U256::from(0u8));
+		})
+}
+
+#[test]
+fn valid_batch_permit_pays_token_fee() {
+	ExtBuilder::default()
+		.with_balances(vec![(CryptoAlith.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			let calls = vec![PermitCall {
+				to: Address(Bob.into()),
+				value: U256::zero(),
+				data: b"one".to_vec().into(),
+				gas_limit: 100_000,
+			}];
+			let (v, r, s) =
+				sign_batch_permit(&calls, U256::zero(), 0u8.into(), David.into(), 10u8.into());
+
+			precompiles()
+				.prepare_test(
+					Charlie,
+					CallPermit,
+					PCall::dispatch_batch {
+						from: Address(CryptoAlith.into()),
+						calls: calls.into(),
+						lane: U256::zero(),
+						deadline: 1_000u32.into(),
+						fee_token: Address(David.into()),
+						max_fee: 10u8.into(),
+						fee: 10u8.into(),
+						v,
+						r,
+						s,
+					},
+				)
+				.with_subcall_handle(move |subcall| {
+					assert_eq!(subcall.context.caller, CryptoAlith.into());
+					assert!(subcall.transfer.is_none());
+
+					match subcall.address {
+						a if a == Bob.into() => SubcallOutput::succeed(),
+						a if a == David.into() => {
+							assert_eq!(
+								subcall.input,
+								solidity::encode_with_selector(
+									SELECTOR_ERC20_TRANSFER,
+									(Address(Charlie.into()), U256::from(10u8))
+								)
+							);
+
+							SubcallOutput {
+								output: solidity::encode_arguments(true),
+								..SubcallOutput::succeed()
+							}
+						}
+						_ => panic!("unexpected subcall"),
+					}
+				})
+				.with_target_gas(Some(1_000_000))
+				.execute_returns(vec![UnboundedBytes::from(b"")]);
+
+			// The default lane shares its nonce with `dispatch`.
+			precompiles()
+				.prepare_test(
+					CryptoAlith,
+					CallPermit,
+					PCall::nonces {
+						owner: Address(CryptoAlith.into()),
+					},
+				)
+				.execute_returns(U256::from(1u8));
+		})
+}
+
+#[test]
+fn batch_permit_reverts_if_token_fee_payment_fails() {
+	ExtBuilder::default()
+		.with_balances(vec![(CryptoAlith.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			let calls = vec![PermitCall {
+				to: Address(Bob.into()),
+				value: U256::zero(),
+				data: b"one".to_vec().into(),
+				gas_limit: 100_000,
+			}];
+			let (v, r, s) =
+				sign_batch_permit(&calls, U256::zero(), 0u8.into(), David.into(), 10u8.into());
+
+			precompiles()
+				.prepare_test(
+					Charlie,
+					CallPermit,
+					PCall::dispatch_batch {
+						from: Address(CryptoAlith.into()),
+						calls: calls.into(),
+						lane: U256::zero(),
+						deadline: 1_000u32.into(),
+						fee_token: Address(David.into()),
+						max_fee: 10u8.into(),
+						fee: 10u8.into(),
+						v,
+						r,
+						s,
+					},
+				)
+				.with_subcall_handle(move |subcall| match subcall.address {
+					a if a == Bob.into() => SubcallOutput::succeed(),
+					// The token returns `false`.
+					a if a == David.into() => SubcallOutput {
+						output: solidity::encode_arguments(false),
+						..SubcallOutput::succeed()
+					},
+					_ => panic!("unexpected subcall"),
+				})
+				.with_target_gas(Some(1_000_000))
+				.execute_reverts(|x| x == b"Fee payment failed");
+		})
+}
+
+#[test]
+fn batch_permit_reverts_if_fee_exceeds_max_fee() {
+	ExtBuilder::default()
+		.with_balances(vec![(CryptoAlith.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			let calls = vec![PermitCall {
+				to: Address(Bob.into()),
+				value: U256::zero(),
+				data: b"one".to_vec().into(),
+				gas_limit: 100_000,
+			}];
+			let (v, r, s) =
+				sign_batch_permit(&calls, U256::zero(), 0u8.into(), H160::zero(), 10u8.into());
+
+			precompiles()
+				.prepare_test(
+					Charlie,
+					CallPermit,
+					PCall::dispatch_batch {
+						from: Address(CryptoAlith.into()),
+						calls: calls.into(),
+						lane: U256::zero(),
+						deadline: 1_000u32.into(),
+						fee_token: Address(H160::zero()),
+						max_fee: 10u8.into(),
+						fee: 11u8.into(),
+						v,
+						r,
+						s,
+					},
+				)
+				.with_subcall_handle(move |_| panic!("should not perform subcall"))
+				.with_target_gas(Some(1_000_000))
+				.execute_reverts(|x| x == b"Fee exceeds permit max fee");
+		})
+}
+
+#[test]
+fn batch_permit_reverts_if_dispatched_in_other_lane() {
+	ExtBuilder::default()
+		.with_balances(vec![(CryptoAlith.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			let calls = vec![PermitCall {
+				to: Address(Bob.into()),
+				value: U256::zero(),
+				data: b"one".to_vec().into(),
+				gas_limit: 100_000,
+			}];
+			let (v, r, s) =
+				sign_batch_permit(&calls, 1u8.into(), 0u8.into(), H160::zero(), U256::zero());
+
+			precompiles()
+				.prepare_test(
+					Charlie,
+					CallPermit,
+					PCall::dispatch_batch {
+						from: Address(CryptoAlith.into()),
+						calls: calls.into(),
+						lane: 2u8.into(),
+						deadline: 1_000u32.into(),
+						fee_token: Address(H160::zero()),
+						max_fee: U256::zero(),
+						fee: U256::zero(),
+						v,
+						r,
+						s,
+					},
+				)
+				.with_subcall_handle(move |_| panic!("should not perform subcall"))
+				.with_target_gas(Some(1_000_000))
+				.execute_reverts(|x| x == b"Invalid permit");
+		})
+}
+
 #[test]
 fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
 	check_precompile_implements_solidity_interfaces(&["CallPermit.sol"], PCall::supports_selector)
 }
+
+#[test]
+fn test_generated_interface_matches_solidity_interface() {
+	check_precompile_generated_interface::<crate::CallPermitPrecompile<Runtime>>(&[
+		"CallPermit.sol",
+	])
+}
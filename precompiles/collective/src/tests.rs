@@ -33,6 +33,13 @@ fn test_solidity_interface_has_all_function_selectors_documented_and_implemented
 	check_precompile_implements_solidity_interfaces(&["Collective.sol"], PCall::supports_selector)
 }
 
+#[test]
+fn test_generated_interface_matches_solidity_interface() {
+	check_precompile_generated_interface::<
+		crate::CollectivePrecompile<Runtime, pallet_collective::Instance1>,
+	>(&["Collective.sol"])
+}
+
 #[test]
 fn selector_less_than_four_bytes() {
 	ExtBuilder::default().build().execute_with(|| {
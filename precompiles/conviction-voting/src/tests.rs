@@ -56,6 +56,13 @@ fn test_solidity_interface_has_all_function_selectors_documented_and_implemented
 	)
 }
 
+#[test]
+fn test_generated_interface_matches_solidity_interface() {
+	check_precompile_generated_interface::<crate::ConvictionVotingPrecompile<Runtime>>(&[
+		"ConvictionVoting.sol",
+	])
+}
+
 fn standard_vote(
 	direction: bool,
 	vote_amount: U256,
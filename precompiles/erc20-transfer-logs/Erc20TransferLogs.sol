@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Erc20TransferLogs contract's address.
+address constant ERC20_TRANSFER_LOGS_ADDRESS = 0x000000000000000000000000000000000000081C;
+
+/// @dev The Erc20TransferLogs contract's instance.
+Erc20TransferLogs constant ERC20_TRANSFER_LOGS_CONTRACT = Erc20TransferLogs(
+    ERC20_TRANSFER_LOGS_ADDRESS
+);
+
+/// @author The Moonbeam Team
+/// @title Erc20 Transfer Logs Interface
+/// @title Emits the ERC-20 Transfer logs of the balance and asset movements done outside of the
+/// EVM. It is only callable by the synthetic transactions of pallet-erc20-transfer-logs.
+/// @custom:address 0x000000000000000000000000000000000000081C
+interface Erc20TransferLogs {
+    /// @dev A movement of an ERC-20 token.
+    struct TransferLog {
+        /// The address of the ERC-20 token, emitting the log.
+        address token;
+        /// The sender, or the zero address for a mint.
+        address from;
+        /// The recipient, or the zero address for a burn.
+        address to;
+        /// The amount transferred.
+        uint256 value;
+    }
+
+    /// @dev Emit a Transfer log for each movement, from the address of its token.
+    /// @custom:selector eb2ac898
+    /// @param transfers The movements to report
+    function emitTransfers(TransferLog[] memory transfers) external;
+
+    /// @dev Emitted from the address of the token, as the ERC-20 Transfer event.
+    /// @custom:selector ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef
+    event Transfer(address indexed from, address indexed to, uint256 value);
+}
@@ -0,0 +1,95 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile emitting the ERC-20 `Transfer` logs synthesized by pallet-erc20-transfer-logs for
+//! the movements done outside of the EVM.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::{Log, PrecompileHandle};
+use frame_support::traits::{ConstU32, Get};
+use precompile_utils::prelude::*;
+use sp_core::{H160, U256};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Maximum number of transfers emitted by a call, bounded by the input size of the synthetic
+/// transactions.
+pub const MAX_TRANSFERS: u32 = 511;
+
+/// A movement of an ERC-20 token.
+#[derive(Clone, Debug, Eq, PartialEq, solidity::Codec)]
+pub struct TransferLog {
+	pub token: Address,
+	pub from: Address,
+	pub to: Address,
+	pub value: U256,
+}
+
+/// The ERC-20 `Transfer` event.
+#[derive(Debug, solidity::Event)]
+pub struct Transfer {
+	#[indexed]
+	pub from: Address,
+	#[indexed]
+	pub to: Address,
+	pub value: U256,
+}
+
+/// Precompile emitting the transfer logs. It is only callable by `LogsSource`, the sender of the
+/// synthetic transactions.
+pub struct Erc20TransferLogsPrecompile<Runtime, LogsSource>(PhantomData<(Runtime, LogsSource)>);
+
+#[precompile_utils::precompile]
+impl<Runtime, LogsSource> Erc20TransferLogsPrecompile<Runtime, LogsSource>
+where
+	Runtime: pallet_evm::Config,
+	LogsSource: Get<H160>,
+{
+	#[precompile::public("emitTransfers((address,address,address,uint256)[])")]
+	fn emit_transfers(
+		handle: &mut impl PrecompileHandle,
+		transfers: BoundedVec<TransferLog, ConstU32<MAX_TRANSFERS>>,
+	) -> EvmResult {
+		if handle.context().caller != LogsSource::get() {
+			return Err(revert("Only callable by the transfer logs source"));
+		}
+
+		let transfers: Vec<TransferLog> = transfers.into();
+		let logs: Vec<Log> = transfers
+			.into_iter()
+			.map(|transfer| {
+				Transfer {
+					from: transfer.from,
+					to: transfer.to,
+					value: transfer.value,
+				}
+				.into_log(transfer.token)
+			})
+			.collect();
+
+		handle.record_log_costs(&logs.iter().collect::<Vec<_>>())?;
+		for log in logs {
+			log.record(handle)?;
+		}
+
+		Ok(())
+	}
+}
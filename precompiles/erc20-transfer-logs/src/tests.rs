@@ -0,0 +1,117 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::{Transfer, TransferLog};
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::{H160, U256};
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(
+		&["Erc20TransferLogs.sol"],
+		PCall::supports_selector,
+	)
+}
+
+#[test]
+fn test_generated_interface_matches_solidity_interface() {
+	check_precompile_generated_interface::<crate::Erc20TransferLogsPrecompile<Runtime, LogsSource>>(
+		&["Erc20TransferLogs.sol"],
+	)
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::emit_transfers_selectors().contains(&0xeb2ac898));
+	assert_eq!(
+		Transfer::topic0(),
+		sp_core::H256(keccak256!("Transfer(address,address,uint256)"))
+	);
+}
+
+#[test]
+fn emit_transfers_emits_logs_from_token_addresses() {
+	new_test_ext().execute_with(|| {
+		let native = H160::from_low_u64_be(2050);
+		let foreign = H160::repeat_byte(0xff);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::emit_transfers {
+					transfers: vec![
+						TransferLog {
+							token: Address(native),
+							from: Address(Bob.into()),
+							to: Address(Charlie.into()),
+							value: 100.into(),
+						},
+						TransferLog {
+							token: Address(foreign),
+							from: Address(H160::zero()),
+							to: Address(Bob.into()),
+							value: 5.into(),
+						},
+					]
+					.into(),
+				},
+			)
+			.expect_log(
+				Transfer {
+					from: Address(Bob.into()),
+					to: Address(Charlie.into()),
+					value: U256::from(100),
+				}
+				.into_log(native),
+			)
+			.expect_log(
+				Transfer {
+					from: Address(H160::zero()),
+					to: Address(Bob.into()),
+					value: U256::from(5),
+				}
+				.into_log(foreign),
+			)
+			.execute_returns(());
+	});
+}
+
+#[test]
+fn emit_transfers_is_only_callable_by_logs_source() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Bob,
+				Precompile1,
+				PCall::emit_transfers {
+					transfers: vec![TransferLog {
+						token: Address(H160::repeat_byte(0xff)),
+						from: Address(Bob.into()),
+						to: Address(Bob.into()),
+						value: 1.into(),
+					}]
+					.into(),
+				},
+			)
+			.execute_reverts(|output| output == b"Only callable by the transfer logs source");
+	});
+}
@@ -23,4 +23,11 @@ interface Gmp {
     ///
     /// @custom:selector f53774ab
     function wormholeTransferERC20(bytes memory vaa) external;
+
+    /// Receive the payload of another bridge protocol and process it
+    /// The payload is authenticated by the verifier configured for the protocol
+    /// Reverts with "Unknown selector" when no verifier is configured
+    ///
+    /// @custom:selector 23928a64
+    function processTransfer(bytes memory payload) external;
 }
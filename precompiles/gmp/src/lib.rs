@@ -26,8 +26,11 @@ use frame_support::{
 	traits::ConstU32,
 };
 use pallet_evm::AddressMapping;
+use pallet_xcm_transactor::{Currency, CurrencyPayment, TransactWeights};
 use parity_scale_codec::{Decode, DecodeLimit};
-use precompile_utils::{prelude::*, solidity::revert::revert_as_bytes};
+use precompile_utils::{
+	precompile_set::is_precompile_or_fail, prelude::*, solidity::revert::revert_as_bytes,
+};
 use sp_core::{H160, U256};
 use sp_runtime::traits::Dispatchable;
 use sp_std::boxed::Box;
@@ -36,6 +39,8 @@ use types::*;
 use xcm::opaque::latest::WeightLimit;
 use xcm_primitives::AccountIdToCurrencyId;
 
+pub use types::{GmpSender, GmpVerifier, VerifiedTransfer};
+
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -57,22 +62,45 @@ const WRAPPED_ASSET_SELECTOR: u32 = 0x1ff1e286_u32;
 const CHAIN_ID_SELECTOR: u32 = 0x9a8a0592_u32;
 const BALANCE_OF_SELECTOR: u32 = 0x70a08231_u32;
 const TRANSFER_SELECTOR: u32 = 0xa9059cbb_u32;
+const APPROVE_SELECTOR: u32 = 0x095ea7b3_u32;
+
+/// Identifier of the Wormhole protocol in GmpSender
+pub const WORMHOLE_PROTOCOL_ID: u8 = 0;
 
 /// Gmp precompile.
+/// Transfers of other bridge protocols than Wormhole are processed by `Verifier`.
 #[derive(Debug, Clone)]
-pub struct GmpPrecompile<Runtime>(PhantomData<Runtime>);
+pub struct GmpPrecompile<Runtime, Verifier = ()>(PhantomData<(Runtime, Verifier)>);
 
 #[precompile_utils::precompile]
-impl<Runtime> GmpPrecompile<Runtime>
+impl<Runtime, Verifier> GmpPrecompile<Runtime, Verifier>
 where
 	Runtime: pallet_evm::Config + frame_system::Config + pallet_xcm::Config + orml_xtokens::Config,
+	Runtime: pallet_xcm_transactor::Config<CurrencyId = CurrencyIdOf<Runtime>>,
 	SystemCallOf<Runtime>: Dispatchable<PostInfo = PostDispatchInfo> + Decode + GetDispatchInfo,
 	<<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
 		From<Option<Runtime::AccountId>>,
 	<Runtime as frame_system::Config>::RuntimeCall: From<orml_xtokens::Call<Runtime>>,
+	<Runtime as frame_system::Config>::RuntimeCall: From<pallet_xcm_transactor::Call<Runtime>>,
 	Runtime: AccountIdToCurrencyId<Runtime::AccountId, CurrencyIdOf<Runtime>>,
 	XBalanceOf<Runtime>: TryFrom<U256> + Into<U256> + solidity::Codec,
+	Verifier: GmpVerifier,
 {
+	#[precompile::pre_check]
+	fn pre_check(handle: &mut impl PrecompileHandle) -> EvmResult {
+		// processTransfer is not exposed when no verifier is configured
+		if !Verifier::ENABLED {
+			if let Ok(selector) = handle.read_u32_selector() {
+				if GmpPrecompileCall::<Runtime, Verifier>::process_transfer_selectors()
+					.contains(&selector)
+				{
+					return Err(RevertReason::UnknownSelector.into());
+				}
+			}
+		}
+		Ok(())
+	}
+
 	#[precompile::public("wormholeTransferERC20(bytes)")]
 	pub fn wormhole_transfer_erc20(
 		handle: &mut impl PrecompileHandle,
@@ -105,7 +133,7 @@ where
 
 		// get the wormhole VM from the provided VAA. Unfortunately, this forces us to parse
 		// the VAA twice -- this seems to be a restriction imposed from the Wormhole contract design
-		let output = Self::call(
+		let output = call_contract(
 			handle,
 			wormhole,
 			solidity::encode_with_selector(PARSE_VM_SELECTOR, wormhole_vaa.clone()),
@@ -113,7 +141,7 @@ where
 		let wormhole_vm: WormholeVM = solidity::decode_return_value(&output[..])?;
 
 		// get the bridge transfer data from the wormhole VM payload
-		let output = Self::call(
+		let output = call_contract(
 			handle,
 			wormhole_bridge,
 			solidity::encode_with_selector(
@@ -125,7 +153,7 @@ where
 			solidity::decode_return_value(&output[..])?;
 
 		// get the chainId that is "us" according to the bridge
-		let output = Self::call(
+		let output = call_contract(
 			handle,
 			wormhole_bridge,
 			solidity::encode_with_selector(CHAIN_ID_SELECTOR, ()),
@@ -138,7 +166,7 @@ where
 			Address::from(H160::from(transfer_with_payload.token_address))
 		} else {
 			// get the wrapper for this asset by calling wrappedAsset()
-			let output = Self::call(
+			let output = call_contract(
 				handle,
 				wormhole_bridge,
 				solidity::encode_with_selector(
@@ -156,52 +184,84 @@ where
 		};
 
 		// query our "before" balance (our being this precompile)
-		let output = Self::call(
-			handle,
-			asset_erc20_address.into(),
-			solidity::encode_with_selector(BALANCE_OF_SELECTOR, Address(handle.code_address())),
-		)?;
-		let before_amount: U256 = solidity::decode_return_value(&output[..])?;
+		let precompile_address = handle.code_address();
+		let before_amount = Self::balance_of(handle, asset_erc20_address, precompile_address)?;
 		log::debug!(target: "gmp-precompile", "before balance: {}", before_amount);
 
-		// our inner-most payload should be a VersionedUserAction
-		let user_action = VersionedUserAction::decode_with_depth_limit(
-			32,
-			&mut transfer_with_payload.payload.as_bytes(),
-		)
-		.map_err(|_| RevertReason::Custom("Invalid GMP Payload".into()))?;
-		log::debug!(target: "gmp-precompile", "user action: {:?}", user_action);
-
-		let currency_account_id =
-			Runtime::AddressMapping::into_account_id(asset_erc20_address.into());
-
-		let currency_id: <Runtime as orml_xtokens::Config>::CurrencyId =
-			Runtime::account_to_currency_id(currency_account_id)
-				.ok_or(revert("Unsupported asset, not a valid currency id"))?;
-
 		// Complete a "Contract Controlled Transfer" with the given Wormhole VAA.
 		// We need to invoke Wormhole's completeTransferWithPayload function, passing it the VAA.
 		// Upon success, it should have transferred tokens to this precompile's address.
-		Self::call(
+		call_contract(
 			handle,
 			wormhole_bridge,
 			solidity::encode_with_selector(COMPLETE_TRANSFER_WITH_PAYLOAD_SELECTOR, wormhole_vaa),
 		)?;
 
 		// query our "after" balance (our being this precompile)
-		let output = Self::call(
-			handle,
-			asset_erc20_address.into(),
-			solidity::encode_with_selector(
-				BALANCE_OF_SELECTOR,
-				Address::from(handle.code_address()),
-			),
-		)?;
-		let after_amount: U256 = solidity::decode_return_value(&output[..])?;
+		let after_amount = Self::balance_of(handle, asset_erc20_address, precompile_address)?;
 		log::debug!(target: "gmp-precompile", "after balance: {}", after_amount);
 
-		let amount_transferred = after_amount.saturating_sub(before_amount);
-		let amount = amount_transferred
+		let sender = GmpSender {
+			protocol: WORMHOLE_PROTOCOL_ID,
+			chain_id: wormhole_vm.emitter_chain_id.into(),
+			address: transfer_with_payload.from_address,
+		};
+
+		Self::execute_user_action(
+			handle,
+			VerifiedTransfer {
+				token: asset_erc20_address,
+				amount: after_amount.saturating_sub(before_amount),
+				sender,
+				user_action: transfer_with_payload.payload.into(),
+			},
+		)
+	}
+
+	/// Process a transfer of another bridge protocol than Wormhole. The payload is authenticated
+	/// and the transfer completed by the configured verifier.
+	#[precompile::public("processTransfer(bytes)")]
+	pub fn process_transfer(
+		handle: &mut impl PrecompileHandle,
+		payload: BoundedBytes<GetCallDataLimit>,
+	) -> EvmResult {
+		// 2500 as fudge for computation, esp. payload decoding
+		handle.record_cost(2500)?;
+		// PrecompileEnabled: AccountId(1)
+		handle.record_db_read::<Runtime>(1)?;
+
+		ensure_enabled()?;
+
+		let transfer = Verifier::verify_and_complete(handle, payload.as_bytes())?
+			.ok_or(RevertReason::custom("Unsupported GMP protocol"))?;
+
+		Self::execute_user_action(handle, transfer)
+	}
+
+	/// Execute the user action of a transfer whose tokens are held by this precompile.
+	fn execute_user_action(
+		handle: &mut impl PrecompileHandle,
+		transfer: VerifiedTransfer,
+	) -> EvmResult {
+		let VerifiedTransfer {
+			token,
+			amount: amount_transferred,
+			sender,
+			user_action,
+		} = transfer;
+
+		// our inner-most payload should be a VersionedUserAction
+		let user_action = VersionedUserAction::decode_with_depth_limit(32, &mut &user_action[..])
+			.map_err(|_| RevertReason::Custom("Invalid GMP Payload".into()))?;
+		log::debug!(target: "gmp-precompile", "user action: {:?}", user_action);
+
+		let currency_id = || -> EvmResult<CurrencyIdOf<Runtime>> {
+			let currency_account_id = Runtime::AddressMapping::into_account_id(token.into());
+			Runtime::account_to_currency_id(currency_account_id)
+				.ok_or(revert("Unsupported asset, not a valid currency id"))
+		};
+
+		let amount: XBalanceOf<Runtime> = amount_transferred
 			.try_into()
 			.map_err(|_| revert("Amount overflows balance"))?;
 
@@ -210,7 +270,7 @@ where
 			VersionedUserAction::V1(action) => {
 				log::debug!(target: "gmp-precompile", "Payload: V1");
 				Some(orml_xtokens::Call::<Runtime>::transfer {
-					currency_id,
+					currency_id: currency_id()?,
 					amount,
 					dest: Box::new(action.destination),
 					dest_weight_limit: WeightLimit::Unlimited,
@@ -218,14 +278,15 @@ where
 			}
 			VersionedUserAction::V2(action) => {
 				log::debug!(target: "gmp-precompile", "Payload: V2");
+				let currency_id = currency_id()?;
 				// if the specified fee is more than the amount being transferred, we'll be nice to
 				// the sender and pay them the entire amount.
 				let fee = action.fee.min(amount_transferred);
 
 				if fee > U256::zero() {
-					let output = Self::call(
+					let output = call_contract(
 						handle,
-						asset_erc20_address.into(),
+						token.into(),
 						solidity::encode_with_selector(
 							TRANSFER_SELECTOR,
 							(Address::from(handle.context().caller), fee),
@@ -261,21 +322,54 @@ where
 					None
 				}
 			}
+			VersionedUserAction::V3(action) => {
+				log::debug!(target: "gmp-precompile", "Payload: V3");
+				Self::execute_evm_call(handle, token, amount_transferred, &sender, action)?;
+				None
+			}
+			VersionedUserAction::V4(action) => {
+				log::debug!(target: "gmp-precompile", "Payload: V4");
+				let currency_id = currency_id()?;
+				if !amount.is_zero() {
+					Self::dispatch_from_precompile(
+						handle,
+						orml_xtokens::Call::<Runtime>::transfer {
+							currency_id: currency_id.clone(),
+							amount,
+							dest: Box::new(action.destination),
+							dest_weight_limit: WeightLimit::Unlimited,
+						},
+					)?;
+				}
+
+				// The transact is sent from the account derived from the sender
+				let origin = Runtime::AddressMapping::into_account_id(sender.derived_address());
+				let call = pallet_xcm_transactor::Call::<Runtime>::transact_through_signed {
+					dest: Box::new(action.transact_destination),
+					fee: CurrencyPayment {
+						currency: Currency::AsCurrencyId(currency_id),
+						fee_amount: action.fee_amount,
+					},
+					call: action.call,
+					weight_info: TransactWeights {
+						transact_required_weight_at_most: action.transact_required_weight_at_most,
+						overall_weight: action.overall_weight,
+					},
+					refund: action.refund,
+				};
+				log::debug!(target: "gmp-precompile", "sending transact {:?}", call);
+				RuntimeHelper::<Runtime>::try_dispatch(
+					handle,
+					Some(origin).into(),
+					call,
+					SYSTEM_ACCOUNT_SIZE,
+				)?;
+				None
+			}
 		};
 
 		if let Some(call) = call {
-			log::debug!(target: "gmp-precompile", "sending xcm {:?}", call);
-			let origin = Runtime::AddressMapping::into_account_id(handle.code_address());
-			RuntimeHelper::<Runtime>::try_dispatch(
-				handle,
-				Some(origin).into(),
-				call,
-				SYSTEM_ACCOUNT_SIZE,
-			)
-			.map_err(|e| {
-				log::debug!(target: "gmp-precompile", "error sending XCM: {:?}", e);
-				e
-			})?;
+			Self::dispatch_from_precompile(handle, call)?;
 		} else {
 			log::debug!(target: "gmp-precompile", "no call provided, no XCM transfer");
 		}
@@ -283,33 +377,191 @@ where
 		Ok(())
 	}
 
-	/// call the given contract / function selector and return its output. Returns Err if the EVM
-	/// exit reason is not Succeed.
-	fn call(
+	/// Move the transferred tokens to the account derived from the sender, approve the contract
+	/// to spend them and call it from this account, then refund the tokens that were not spent.
+	/// The call is never made from this precompile, so that it cannot act with the tokens held
+	/// by the precompile or on its behalf.
+	fn execute_evm_call(
 		handle: &mut impl PrecompileHandle,
-		contract_address: H160,
-		call_data: Vec<u8>,
-	) -> EvmResult<Vec<u8>> {
+		token: Address,
+		amount: U256,
+		sender: &GmpSender,
+		action: EvmCallUserAction,
+	) -> EvmResult {
+		// The precompiles dispatch calls on behalf of their caller, calling them would allow to
+		// use the derived account outside of the allowed user action.
+		// The precompile set is optimized to do at most one storage read, see precompile-registry.
+		handle.record_db_read::<Runtime>(175)?;
+		if is_precompile_or_fail::<Runtime>(action.contract, handle.remaining_gas())? {
+			return Err(RevertReason::custom("Cannot call a precompile").into());
+		}
+
+		let derived_address = sender.derived_address();
+
+		// The tokens held by the derived account before the transfer are not refunded
+		let before_amount = Self::balance_of(handle, token, derived_address)?;
+
+		if !amount.is_zero() {
+			let precompile_address = handle.code_address();
+			Self::transfer(handle, token, precompile_address, derived_address, amount)?;
+		}
+
+		Self::approve(handle, token, derived_address, action.contract, amount)?;
+
 		let sub_context = Context {
-			caller: handle.code_address(),
-			address: contract_address,
+			caller: derived_address,
+			address: action.contract,
 			apparent_value: U256::zero(),
 		};
-
-		log::debug!(
-			target: "gmp-precompile",
-			"calling {} from {} ...", contract_address, sub_context.caller,
+		let (reason, output) = handle.call(
+			action.contract,
+			None,
+			action.call_data,
+			Some(action.gas_limit),
+			false,
+			&sub_context,
 		);
+		// A failed call is not fatal, the tokens are refunded instead
+		if let Err(e) = ensure_exit_reason_success(reason, &output[..]) {
+			log::debug!(target: "gmp-precompile", "evm call failed: {:?}", e);
+		}
+
+		Self::approve(
+			handle,
+			token,
+			derived_address,
+			action.contract,
+			U256::zero(),
+		)?;
+
+		let remaining =
+			Self::balance_of(handle, token, derived_address)?.saturating_sub(before_amount);
+		if !remaining.is_zero() {
+			log::debug!(target: "gmp-precompile", "refunding {} to {}", remaining, action.refund_address);
+			Self::transfer(
+				handle,
+				token,
+				derived_address,
+				action.refund_address,
+				remaining,
+			)
+			.map_err(|_| RevertReason::custom("failed to refund remaining tokens"))?;
+		}
+
+		Ok(())
+	}
+
+	fn transfer(
+		handle: &mut impl PrecompileHandle,
+		token: Address,
+		from: H160,
+		to: H160,
+		amount: U256,
+	) -> EvmResult {
+		let output = call_contract_from(
+			handle,
+			from,
+			token.into(),
+			solidity::encode_with_selector(TRANSFER_SELECTOR, (Address(to), amount)),
+		)?;
+		let transferred: bool = solidity::decode_return_value(&output[..])?;
+		if !transferred {
+			return Err(RevertReason::custom("failed to transfer() tokens").into());
+		}
+		Ok(())
+	}
 
-		let (reason, output) =
-			handle.call(contract_address, None, call_data, None, false, &sub_context);
+	fn approve(
+		handle: &mut impl PrecompileHandle,
+		token: Address,
+		owner: H160,
+		spender: H160,
+		amount: U256,
+	) -> EvmResult {
+		let output = call_contract_from(
+			handle,
+			owner,
+			token.into(),
+			solidity::encode_with_selector(APPROVE_SELECTOR, (Address(spender), amount)),
+		)?;
+		let approved: bool = solidity::decode_return_value(&output[..])?;
+		if !approved {
+			return Err(RevertReason::custom("failed to approve() tokens").into());
+		}
+		Ok(())
+	}
 
-		ensure_exit_reason_success(reason, &output[..])?;
+	/// The balance of the given account in the given erc20 token
+	fn balance_of(
+		handle: &mut impl PrecompileHandle,
+		token: Address,
+		account: H160,
+	) -> EvmResult<U256> {
+		let output = call_contract(
+			handle,
+			token.into(),
+			solidity::encode_with_selector(BALANCE_OF_SELECTOR, Address(account)),
+		)?;
+		solidity::decode_return_value(&output[..])
+	}
 
-		Ok(output)
+	fn dispatch_from_precompile(
+		handle: &mut impl PrecompileHandle,
+		call: orml_xtokens::Call<Runtime>,
+	) -> EvmResult {
+		log::debug!(target: "gmp-precompile", "sending xcm {:?}", call);
+		let origin = Runtime::AddressMapping::into_account_id(handle.code_address());
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(origin).into(),
+			call,
+			SYSTEM_ACCOUNT_SIZE,
+		)
+		.map_err(|e| {
+			log::debug!(target: "gmp-precompile", "error sending XCM: {:?}", e);
+			e
+		})?;
+		Ok(())
 	}
 }
 
+/// call the given contract / function selector and return its output. Returns Err if the EVM
+/// exit reason is not Succeed.
+pub fn call_contract(
+	handle: &mut impl PrecompileHandle,
+	contract_address: H160,
+	call_data: Vec<u8>,
+) -> EvmResult<Vec<u8>> {
+	let caller = handle.code_address();
+	call_contract_from(handle, caller, contract_address, call_data)
+}
+
+/// Same as `call_contract`, with the given caller instead of this precompile.
+fn call_contract_from(
+	handle: &mut impl PrecompileHandle,
+	caller: H160,
+	contract_address: H160,
+	call_data: Vec<u8>,
+) -> EvmResult<Vec<u8>> {
+	let sub_context = Context {
+		caller,
+		address: contract_address,
+		apparent_value: U256::zero(),
+	};
+
+	log::debug!(
+		target: "gmp-precompile",
+		"calling {} from {} ...", contract_address, sub_context.caller,
+	);
+
+	let (reason, output) =
+		handle.call(contract_address, None, call_data, None, false, &sub_context);
+
+	ensure_exit_reason_success(reason, &output[..])?;
+
+	Ok(output)
+}
+
 fn ensure_exit_reason_success(reason: ExitReason, output: &[u8]) -> EvmResult<()> {
 	log::trace!(target: "gmp-precompile", "reason: {:?}", reason);
 	log::trace!(target: "gmp-precompile", "output: {:x?}", output);
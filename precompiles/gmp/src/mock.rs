@@ -53,10 +53,16 @@ construct_runtime!(
 		Timestamp: pallet_timestamp,
 		Xtokens: orml_xtokens,
 		PolkadotXcm: pallet_xcm,
+		XcmTransactor: pallet_xcm_transactor,
 	}
 );
 
 mock_account!(SelfReserveAccount, |_| MockAccount::from_u64(2));
+mock_account!(RelayAssetAccount, |_| {
+	let mut account = [0u8; 20];
+	account[..4].copy_from_slice(&0xffffffffu32.to_be_bytes());
+	MockAccount::from(account)
+});
 
 parameter_types! {
 	pub ParachainId: cumulus_primitives_core::ParaId = 100.into();
@@ -199,7 +205,7 @@ impl xcm_executor::Config for XcmConfig {
 pub type Precompiles<R> = PrecompileSetBuilder<
 	R,
 	(
-		PrecompileAt<AddressU64<1>, GmpPrecompile<R>, (SubcallWithMaxNesting<1>,)>,
+		PrecompileAt<AddressU64<1>, GmpPrecompile<R, MockVerifier>, (SubcallWithMaxNesting<1>,)>,
 		RevertPrecompile<AddressU64<2>>,
 	),
 >;
@@ -237,7 +243,7 @@ impl WeightTrader for DummyWeightTrader {
 	}
 }
 
-pub type PCall = GmpPrecompileCall<Runtime>;
+pub type PCall = GmpPrecompileCall<Runtime, MockVerifier>;
 
 mock_account!(Batch, |_| MockAccount::from_u64(1));
 mock_account!(Revert, |_| MockAccount::from_u64(2));
@@ -332,7 +338,7 @@ impl sp_runtime::traits::Convert<AccountId, MultiLocation> for AccountIdToMultiL
 	fn convert(account: AccountId) -> MultiLocation {
 		let as_h160: H160 = account.into();
 		MultiLocation::new(
-			1,
+			0,
 			Junctions::X1(AccountKey20 {
 				network: None,
 				key: as_h160.as_fixed_bytes().clone(),
@@ -409,6 +415,139 @@ impl orml_xtokens::Config for Runtime {
 	type UniversalLocation = UniversalLocation;
 }
 
+// We need to use the encoding from the relay mock runtime
+#[derive(Encode, Decode)]
+pub enum RelayCall {
+	#[codec(index = 5u8)]
+	// the index should match the position of the module in `construct_runtime!`
+	Utility(UtilityCall),
+}
+
+#[derive(Encode, Decode)]
+pub enum UtilityCall {
+	#[codec(index = 1u8)]
+	AsDerivative(u16),
+}
+
+#[derive(Clone, Eq, Debug, PartialEq, Ord, PartialOrd, Encode, Decode, TypeInfo)]
+pub enum MockTransactors {
+	Relay,
+}
+
+impl TryFrom<u8> for MockTransactors {
+	type Error = ();
+
+	fn try_from(value: u8) -> Result<Self, Self::Error> {
+		match value {
+			0x0 => Ok(MockTransactors::Relay),
+			_ => Err(()),
+		}
+	}
+}
+
+impl xcm_primitives::XcmTransact for MockTransactors {
+	fn destination(self) -> MultiLocation {
+		match self {
+			MockTransactors::Relay => MultiLocation::parent(),
+		}
+	}
+}
+
+impl xcm_primitives::UtilityEncodeCall for MockTransactors {
+	fn encode_call(self, call: xcm_primitives::UtilityAvailableCalls) -> Vec<u8> {
+		match self {
+			MockTransactors::Relay => match call {
+				xcm_primitives::UtilityAvailableCalls::AsDerivative(a, b) => {
+					let mut call =
+						RelayCall::Utility(UtilityCall::AsDerivative(a.clone())).encode();
+					call.append(&mut b.clone());
+					call
+				}
+			},
+		}
+	}
+}
+
+parameter_types! {
+	pub ParentLocation: MultiLocation = MultiLocation::parent();
+	pub const TransactStatusQueryTimeout: u32 = 100;
+	pub const MaxTransactStatusQueries: u32 = 3;
+	pub static NextQueryId: QueryId = 0;
+	pub static MockTransferToken: H160 = SelfReserveAccount.into();
+	pub static MockTransferAmount: U256 = U256::zero();
+}
+
+pub struct TestQueryRegistrar;
+impl pallet_xcm_transactor::NotifyQueryRegistrar<pallet_xcm_transactor::Call<Runtime>, u32>
+	for TestQueryRegistrar
+{
+	fn new_notify_query(
+		_responder: MultiLocation,
+		_notify: pallet_xcm_transactor::Call<Runtime>,
+		_timeout: u32,
+		_match_querier: MultiLocation,
+	) -> QueryId {
+		let query_id = NextQueryId::get();
+		NextQueryId::set(query_id + 1);
+		query_id
+	}
+}
+
+impl pallet_xcm_transactor::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Transactor = MockTransactors;
+	type DerivativeAddressRegistrationOrigin = frame_system::EnsureRoot<AccountId>;
+	type SovereignAccountDispatcherOrigin = frame_system::EnsureRoot<AccountId>;
+	type CurrencyId = CurrencyId;
+	type AccountIdToMultiLocation = AccountIdToMultiLocation;
+	type CurrencyIdToMultiLocation = CurrencyIdToMultiLocation;
+	type SelfLocation = SelfLocation;
+	type Weigher = FixedWeightBounds<BaseXcmWeight, RuntimeCall, MaxInstructions>;
+	type UniversalLocation = UniversalLocation;
+	type BaseXcmWeight = BaseXcmWeight;
+	type XcmSender = DoNothingRouter;
+	type AssetTransactor = DummyAssetTransactor;
+	type ReserveProvider = orml_traits::location::RelativeReserveProvider;
+	type WeightInfo = ();
+	type HrmpManipulatorOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxHrmpFee = ();
+	type QueryRegistrar = TestQueryRegistrar;
+	type ResponseOrigin = frame_system::EnsureRootWithSuccess<AccountId, ParentLocation>;
+	type TransactStatusQueryTimeout = TransactStatusQueryTimeout;
+	type MaxTransactStatusQueries = MaxTransactStatusQueries;
+	type FeePerSecondProvider = ();
+}
+
+/// The sender of the transfers verified by MockVerifier
+pub fn mock_gmp_sender() -> GmpSender {
+	GmpSender {
+		protocol: 1,
+		chain_id: 1,
+		address: H256::repeat_byte(1),
+	}
+}
+
+/// Accepts the payloads prefixed by "mock", the rest of the payload being the user action.
+/// The transfers are transfers of MockTransferAmount of MockTransferToken, empty transfers of
+/// the native currency by default.
+pub struct MockVerifier;
+impl GmpVerifier for MockVerifier {
+	fn verify_and_complete(
+		_handle: &mut impl PrecompileHandle,
+		payload: &[u8],
+	) -> EvmResult<Option<VerifiedTransfer>> {
+		Ok(payload
+			.strip_prefix(b"mock")
+			.map(|user_action| VerifiedTransfer {
+				token: Address(MockTransferToken::get()),
+				amount: MockTransferAmount::get(),
+				sender: mock_gmp_sender(),
+				user_action: user_action.to_vec(),
+			}))
+	}
+}
+
 pub(crate) struct ExtBuilder {
 	/// Endowed accounts with balances
 	balances: Vec<(AccountId, Balance)>,
@@ -15,13 +15,104 @@
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::mock::*;
+use crate::types::{
+	EvmCallUserAction, GmpSender, VersionedUserAction, XcmRoutingUserAction, XcmTransactUserAction,
+};
+use crate::{GmpPrecompile, APPROVE_SELECTOR, BALANCE_OF_SELECTOR, TRANSFER_SELECTOR};
 use fp_evm::{ExitRevert, PrecompileFailure};
-use precompile_utils::{solidity::revert::revert_as_bytes, testing::*};
+use frame_support::weights::Weight;
+use parity_scale_codec::Encode;
+use precompile_utils::{
+	precompile_set::{AddressU64, PrecompileAt, PrecompileSetBuilder, SubcallWithMaxNesting},
+	prelude::*,
+	solidity::revert::revert_as_bytes,
+	testing::*,
+};
+use sp_core::{H160, H256, U256};
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+use xcm::latest::{MultiLocation, WeightLimit};
 
 fn precompiles() -> Precompiles<Runtime> {
 	PrecompilesValue::get()
 }
 
+type Balances = Rc<RefCell<BTreeMap<H160, U256>>>;
+
+fn move_tokens(balances: &mut BTreeMap<H160, U256>, from: H160, to: H160, amount: U256) {
+	let from_balance = balances.entry(from).or_default();
+	assert!(*from_balance >= amount, "not enough tokens");
+	*from_balance -= amount;
+	*balances.entry(to).or_default() += amount;
+}
+
+/// Mocks the ERC20 token of the transfers and the contract of the EVM call user action, which
+/// pulls `pulled` tokens from its caller using its allowance, or reverts if `pulled` is None.
+fn erc20_and_contract(
+	balances: Balances,
+	contract: H160,
+	expected_caller: H160,
+	pulled: Option<U256>,
+) -> impl FnMut(Subcall) -> SubcallOutput + 'static {
+	let mut allowances = BTreeMap::<(H160, H160), U256>::new();
+
+	move |Subcall {
+	          address,
+	          input,
+	          context,
+	          ..
+	      }| {
+		let mut balances = balances.borrow_mut();
+
+		if address == contract {
+			assert_eq!(context.caller, expected_caller);
+			let Some(pulled) = pulled else {
+				return SubcallOutput::revert();
+			};
+			let allowance = allowances.entry((context.caller, contract)).or_default();
+			assert!(*allowance >= pulled, "not enough allowance");
+			*allowance -= pulled;
+			move_tokens(&mut balances, context.caller, contract, pulled);
+			return SubcallOutput::succeed();
+		}
+
+		assert_eq!(address, MockTransferToken::get());
+		let output = match solidity::codec::selector(&input) {
+			Some(BALANCE_OF_SELECTOR) => {
+				let account: Address = solidity::decode_arguments(&input[4..]).unwrap();
+				solidity::encode_arguments(balances.get(&account.0).copied().unwrap_or_default())
+			}
+			Some(TRANSFER_SELECTOR) => {
+				let (to, amount): (Address, U256) =
+					solidity::decode_arguments(&input[4..]).unwrap();
+				move_tokens(&mut balances, context.caller, to.0, amount);
+				solidity::encode_arguments(true)
+			}
+			Some(APPROVE_SELECTOR) => {
+				let (spender, amount): (Address, U256) =
+					solidity::decode_arguments(&input[4..]).unwrap();
+				allowances.insert((context.caller, spender.0), amount);
+				solidity::encode_arguments(true)
+			}
+			_ => panic!("unexpected token call"),
+		};
+
+		SubcallOutput {
+			output,
+			..SubcallOutput::succeed()
+		}
+	}
+}
+
+fn evm_call_payload(contract: H160) -> Vec<u8> {
+	let action = VersionedUserAction::V3(EvmCallUserAction {
+		contract,
+		call_data: vec![1, 2, 3],
+		gas_limit: 100_000,
+		refund_address: Bob.into(),
+	});
+	[b"mock".to_vec(), action.encode()].concat()
+}
+
 #[test]
 fn contract_disabling_default_value_is_false() {
 	ExtBuilder::default()
@@ -104,7 +195,282 @@ fn contract_disabling_works() {
 		})
 }
 
+#[test]
+fn process_transfer_requires_enabled_precompile() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 100_000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					CryptoAlith,
+					Precompile1,
+					PCall::process_transfer {
+						payload: b"mock".to_vec().into(),
+					},
+				)
+				.execute_reverts(|output| output == b"GMP Precompile is not enabled");
+		})
+}
+
+#[test]
+fn process_transfer_reverts_for_unsupported_protocol() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 100_000)])
+		.build()
+		.execute_with(|| {
+			crate::storage::PrecompileEnabled::set(Some(true));
+
+			precompiles()
+				.prepare_test(
+					CryptoAlith,
+					Precompile1,
+					PCall::process_transfer {
+						payload: b"other protocol".to_vec().into(),
+					},
+				)
+				.execute_reverts(|output| output == b"Unsupported GMP protocol");
+		})
+}
+
+#[test]
+fn process_transfer_reverts_for_invalid_user_action() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 100_000)])
+		.build()
+		.execute_with(|| {
+			crate::storage::PrecompileEnabled::set(Some(true));
+
+			precompiles()
+				.prepare_test(
+					CryptoAlith,
+					Precompile1,
+					PCall::process_transfer {
+						payload: b"mock\xff".to_vec().into(),
+					},
+				)
+				.execute_reverts(|output| output == b"Invalid GMP Payload");
+		})
+}
+
+#[test]
+fn process_transfer_calls_contract_from_derived_address() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 100_000)])
+		.build()
+		.execute_with(|| {
+			crate::storage::PrecompileEnabled::set(Some(true));
+			MockTransferAmount::set(100.into());
+
+			let contract: H160 = Charlie.into();
+			let derived_address = mock_gmp_sender().derived_address();
+			let balances = Balances::default();
+			balances
+				.borrow_mut()
+				.insert(Precompile1.into(), U256::from(100));
+			// tokens held before the transfer are not refunded
+			balances.borrow_mut().insert(derived_address, U256::from(5));
+
+			precompiles()
+				.prepare_test(
+					CryptoAlith,
+					Precompile1,
+					PCall::process_transfer {
+						payload: evm_call_payload(contract).into(),
+					},
+				)
+				.with_subcall_handle(erc20_and_contract(
+					balances.clone(),
+					contract,
+					derived_address,
+					Some(60.into()),
+				))
+				.execute_returns(());
+
+			let balances = balances.borrow();
+			assert_eq!(balances[&H160::from(Precompile1)], U256::zero());
+			assert_eq!(balances[&contract], U256::from(60));
+			assert_eq!(balances[&H160::from(Bob)], U256::from(40));
+			assert_eq!(balances[&derived_address], U256::from(5));
+		})
+}
+
+#[test]
+fn process_transfer_refunds_failed_contract_call() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 100_000)])
+		.build()
+		.execute_with(|| {
+			crate::storage::PrecompileEnabled::set(Some(true));
+			MockTransferAmount::set(100.into());
+
+			let contract: H160 = Charlie.into();
+			let derived_address = mock_gmp_sender().derived_address();
+			let balances = Balances::default();
+			balances
+				.borrow_mut()
+				.insert(Precompile1.into(), U256::from(100));
+
+			precompiles()
+				.prepare_test(
+					CryptoAlith,
+					Precompile1,
+					PCall::process_transfer {
+						payload: evm_call_payload(contract).into(),
+					},
+				)
+				.with_subcall_handle(erc20_and_contract(
+					balances.clone(),
+					contract,
+					derived_address,
+					None,
+				))
+				.execute_returns(());
+
+			let balances = balances.borrow();
+			assert_eq!(balances[&H160::from(Precompile1)], U256::zero());
+			assert_eq!(balances[&H160::from(Bob)], U256::from(100));
+			assert_eq!(balances[&derived_address], U256::zero());
+		})
+}
+
+#[test]
+fn process_transfer_cannot_call_precompiles() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 100_000)])
+		.build()
+		.execute_with(|| {
+			crate::storage::PrecompileEnabled::set(Some(true));
+
+			precompiles()
+				.prepare_test(
+					CryptoAlith,
+					Precompile1,
+					PCall::process_transfer {
+						payload: evm_call_payload(Precompile1.into()).into(),
+					},
+				)
+				.with_subcall_handle(|Subcall { .. }| panic!("there should be no subcall"))
+				.execute_reverts(|output| output == b"Cannot call a precompile");
+		})
+}
+
+#[test]
+fn process_transfer_sends_transact_from_derived_address() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 100_000)])
+		.build()
+		.execute_with(|| {
+			crate::storage::PrecompileEnabled::set(Some(true));
+			MockTransferToken::set(RelayAssetAccount.into());
+
+			let action = VersionedUserAction::V4(XcmTransactUserAction {
+				destination: MultiLocation::parent().into(),
+				transact_destination: MultiLocation::parent().into(),
+				call: vec![1, 2, 3],
+				fee_amount: Some(500),
+				transact_required_weight_at_most: Weight::from_parts(1_000, 0),
+				overall_weight: Some(WeightLimit::Limited(Weight::from_parts(2_000, 0))),
+				refund: false,
+			});
+
+			precompiles()
+				.prepare_test(
+					CryptoAlith,
+					Precompile1,
+					PCall::process_transfer {
+						payload: [b"mock".to_vec(), action.encode()].concat().into(),
+					},
+				)
+				.with_subcall_handle(|Subcall { .. }| panic!("there should be no subcall"))
+				.execute_returns(());
+
+			let derived_account = AccountId::from(mock_gmp_sender().derived_address());
+			assert!(System::events().iter().any(|record| matches!(
+				&record.event,
+				RuntimeEvent::XcmTransactor(pallet_xcm_transactor::Event::TransactedSigned {
+					fee_payer,
+					call,
+					..
+				}) if *fee_payer == derived_account && *call == vec![1, 2, 3]
+			)));
+		})
+}
+
+#[test]
+fn process_transfer_is_not_exposed_without_verifier() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 100_000)])
+		.build()
+		.execute_with(|| {
+			crate::storage::PrecompileEnabled::set(Some(true));
+
+			PrecompileSetBuilder::<
+				Runtime,
+				PrecompileAt<AddressU64<1>, GmpPrecompile<Runtime>, (SubcallWithMaxNesting<1>,)>,
+			>::new()
+			.prepare_test(
+				CryptoAlith,
+				Precompile1,
+				PCall::process_transfer {
+					payload: b"mock".to_vec().into(),
+				},
+			)
+			.execute_reverts(|output| output == b"Unknown selector");
+		})
+}
+
+#[test]
+fn user_actions_keep_their_encoding() {
+	let v1 = VersionedUserAction::V1(XcmRoutingUserAction {
+		destination: MultiLocation::parent().into(),
+	});
+	assert_eq!(v1.encode()[0], 0);
+
+	let v3 = VersionedUserAction::V3(EvmCallUserAction {
+		contract: H160::repeat_byte(1),
+		call_data: vec![1, 2, 3],
+		gas_limit: 100_000,
+		refund_address: H160::repeat_byte(2),
+	});
+	assert_eq!(v3.encode()[0], 2);
+}
+
+#[test]
+fn derived_address_depends_on_the_whole_sender() {
+	let sender = GmpSender {
+		protocol: 0,
+		chain_id: 2,
+		address: H256::repeat_byte(1),
+	};
+	let same_address_on_other_chain = GmpSender {
+		chain_id: 3,
+		..sender
+	};
+	let same_address_on_other_protocol = GmpSender {
+		protocol: 1,
+		..sender
+	};
+
+	assert_eq!(sender.derived_address(), sender.derived_address());
+	assert_ne!(
+		sender.derived_address(),
+		same_address_on_other_chain.derived_address()
+	);
+	assert_ne!(
+		sender.derived_address(),
+		same_address_on_other_protocol.derived_address()
+	);
+}
+
 #[test]
 fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
 	check_precompile_implements_solidity_interfaces(&["Gmp.sol"], PCall::supports_selector)
 }
+
+#[test]
+fn test_generated_interface_matches_solidity_interface() {
+	check_precompile_generated_interface::<crate::GmpPrecompile<Runtime, MockVerifier>>(&[
+		"Gmp.sol",
+	])
+}
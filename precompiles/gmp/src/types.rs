@@ -16,11 +16,12 @@
 
 //! Precompile to receive GMP callbacks and forward to XCM
 
+use frame_support::weights::Weight;
 use parity_scale_codec::{Decode, Encode};
 use precompile_utils::prelude::*;
-use sp_core::{H256, U256};
+use sp_core::{H160, H256, U256};
 use sp_std::vec::Vec;
-use xcm::VersionedMultiLocation;
+use xcm::{latest::WeightLimit, VersionedMultiLocation};
 
 // A user action which will attempt to route the transferred assets to the account/chain specified
 // by the given MultiLocation. Recall that a MultiLocation can contain both a chain and an account
@@ -38,6 +39,36 @@ pub struct XcmRoutingUserActionWithFee {
 	pub fee: U256,
 }
 
+// A user action which executes an EVM call with the transferred tokens, e.g. a swap followed by
+// forwarding the result. The transferred tokens are moved to the account derived from the sender
+// of the transfer (see GmpSender), which approves the contract to spend them and calls it. The
+// approval is revoked after the call, and the tokens not pulled by the contract are sent to the
+// refund address. If the call fails, all tokens are sent to the refund address. Precompiles
+// cannot be called.
+#[derive(Encode, Decode, Debug)]
+pub struct EvmCallUserAction {
+	pub contract: H160,
+	pub call_data: Vec<u8>,
+	pub gas_limit: u64,
+	pub refund_address: H160,
+}
+
+// A user action which routes the transferred assets like XcmRoutingUserAction and then sends a
+// remote transact through pallet-xcm-transactor. The transact is sent from the account derived
+// from the sender of the transfer (see GmpSender), paying the fees in the transferred asset, so
+// the destination should usually be the account of this derived account on the transact
+// destination.
+#[derive(Encode, Decode, Debug)]
+pub struct XcmTransactUserAction {
+	pub destination: VersionedMultiLocation,
+	pub transact_destination: VersionedMultiLocation,
+	pub call: Vec<u8>,
+	pub fee_amount: Option<u128>,
+	pub transact_required_weight_at_most: Weight,
+	pub overall_weight: Option<WeightLimit>,
+	pub refund: bool,
+}
+
 // A simple versioning wrapper around the initial XcmRoutingUserAction use-case. This should make
 // future breaking changes easy to add in a backwards-compatible way.
 #[derive(Encode, Decode, Debug)]
@@ -45,6 +76,73 @@ pub struct XcmRoutingUserActionWithFee {
 pub enum VersionedUserAction {
 	V1(XcmRoutingUserAction),
 	V2(XcmRoutingUserActionWithFee),
+	V3(EvmCallUserAction),
+	V4(XcmTransactUserAction),
+}
+
+// The sender of a bridged transfer, as authenticated by the bridge protocol.
+#[derive(Encode, Decode, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GmpSender {
+	// Identifier of the bridge protocol, see WORMHOLE_PROTOCOL_ID
+	pub protocol: u8,
+	// Identifier of the source chain, as defined by the bridge protocol
+	pub chain_id: u64,
+	// Address of the sender on the source chain
+	pub address: H256,
+}
+
+impl GmpSender {
+	// The address acting on behalf of the sender on Moonbeam, nobody holds its private key.
+	// keccak256("gmp" ++ scale(sender))[12..]
+	pub fn derived_address(&self) -> H160 {
+		let hash = sp_io::hashing::keccak_256(&(b"gmp", self).encode());
+		H160::from_slice(&hash[12..])
+	}
+}
+
+// A bridged transfer whose tokens are held by this precompile, ready for its user action to be
+// executed.
+#[derive(Debug)]
+pub struct VerifiedTransfer {
+	// The ERC-20 token received
+	pub token: Address,
+	// The amount received
+	pub amount: U256,
+	pub sender: GmpSender,
+	// The SCALE encoded VersionedUserAction
+	pub user_action: Vec<u8>,
+}
+
+// Extension point allowing other bridge protocols to be processed like Wormhole transfers.
+pub trait GmpVerifier {
+	// Whether a protocol is verified. The processTransfer entry point is not exposed otherwise.
+	const ENABLED: bool = true;
+
+	// Authenticate the payload of a bridge protocol and complete the transfer it carries, so that
+	// the transferred tokens are held by this precompile. Returns None if the payload does not
+	// belong to this protocol.
+	fn verify_and_complete(
+		handle: &mut impl PrecompileHandle,
+		payload: &[u8],
+	) -> EvmResult<Option<VerifiedTransfer>>;
+}
+
+// Tries each verifier in order. The empty tuple verifies no protocol.
+#[impl_trait_for_tuples::impl_for_tuples(8)]
+impl GmpVerifier for Tuple {
+	for_tuples!( const ENABLED: bool = false #( || Tuple::ENABLED )*; );
+
+	fn verify_and_complete(
+		handle: &mut impl PrecompileHandle,
+		payload: &[u8],
+	) -> EvmResult<Option<VerifiedTransfer>> {
+		for_tuples!( #(
+			if let Some(transfer) = Tuple::verify_and_complete(handle, payload)? {
+				return Ok(Some(transfer));
+			}
+		)* );
+		Ok(None)
+	}
 }
 
 // Struct representing a Wormhole VM
@@ -56,6 +56,11 @@ fn test_solidity_interface_has_all_function_selectors_documented_and_implemented
 	check_precompile_implements_solidity_interfaces(&["Identity.sol"], PCall::supports_selector)
 }
 
+#[test]
+fn test_generated_interface_matches_solidity_interface() {
+	check_precompile_generated_interface::<crate::IdentityPrecompile<Runtime>>(&["Identity.sol"])
+}
+
 #[test]
 fn test_set_fee_on_existing_registrar_index_succeeds() {
 	ExtBuilder::default()
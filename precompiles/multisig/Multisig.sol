@@ -0,0 +1,112 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Multisig contract's address.
+address constant MULTISIG_ADDRESS = 0x0000000000000000000000000000000000000819;
+
+/// @dev The Multisig contract's instance.
+Multisig constant MULTISIG_CONTRACT = Multisig(MULTISIG_ADDRESS);
+
+/// @author The Moonbeam Team
+/// @title Pallet Multisig Interface
+/// @title The interface through which solidity contracts will interact with the Multisig pallet
+/// @custom:address 0x0000000000000000000000000000000000000819
+interface Multisig {
+    /// @dev Point in the chain history at which a multisig operation was opened.
+    struct Timepoint {
+        /// The block height. A zero height means no timepoint, used for the first approval.
+        uint32 height;
+        /// The index of the extrinsic in the block.
+        uint32 index;
+    }
+
+    /// @dev Maximum weight a multisig call is allowed to consume when executed.
+    struct Weight {
+        /// The computation time.
+        uint64 refTime;
+        /// The size of the proof.
+        uint64 proofSize;
+    }
+
+    /// @dev A multisig operation waiting for approvals.
+    struct PendingOperation {
+        /// Is `true` if the operation exists, else `false`.
+        bool isPending;
+        /// The timepoint at which the operation was opened.
+        Timepoint when;
+        /// The amount reserved from the depositor.
+        uint256 deposit;
+        /// The account that opened the operation.
+        address depositor;
+        /// The accounts that approved the operation so far, including the depositor.
+        address[] approvals;
+    }
+
+    /// @dev Register approval for a dispatch from the multisig account derived from the caller,
+    /// `otherSignatories` and `threshold`. Dispatches the call if the threshold is reached.
+    /// @custom:selector 11749cd4
+    /// @param threshold The total number of approvals required for the dispatch
+    /// @param otherSignatories The accounts other than the caller who can approve the dispatch
+    /// @param maybeTimepoint The timepoint of the first approval, zero if this is the first one
+    /// @param call The SCALE encoded call to dispatch
+    /// @param maxWeight The maximum weight the call is allowed to consume when executed
+    function asMulti(
+        uint16 threshold,
+        address[] memory otherSignatories,
+        Timepoint memory maybeTimepoint,
+        bytes memory call,
+        Weight memory maxWeight
+    ) external;
+
+    /// @dev Register approval for a dispatch from the multisig account derived from the caller,
+    /// `otherSignatories` and `threshold`, only providing the hash of the call.
+    /// @custom:selector 008b964e
+    /// @param threshold The total number of approvals required for the dispatch
+    /// @param otherSignatories The accounts other than the caller who can approve the dispatch
+    /// @param maybeTimepoint The timepoint of the first approval, zero if this is the first one
+    /// @param callHash The blake2-256 hash of the SCALE encoded call to dispatch
+    /// @param maxWeight The maximum weight the call is allowed to consume when executed
+    function approveAsMulti(
+        uint16 threshold,
+        address[] memory otherSignatories,
+        Timepoint memory maybeTimepoint,
+        bytes32 callHash,
+        Weight memory maxWeight
+    ) external;
+
+    /// @dev Cancel an on-going multisig operation opened by the caller and unreserve its deposit.
+    /// @custom:selector 2a42b358
+    /// @param threshold The total number of approvals required for the dispatch
+    /// @param otherSignatories The accounts other than the caller who can approve the dispatch
+    /// @param timepoint The timepoint of the first approval
+    /// @param callHash The blake2-256 hash of the SCALE encoded call to dispatch
+    function cancelAsMulti(
+        uint16 threshold,
+        address[] memory otherSignatories,
+        Timepoint memory timepoint,
+        bytes32 callHash
+    ) external;
+
+    /// @dev Retrieve the multisig account derived from signatories and threshold.
+    /// @custom:selector 3eaec241
+    /// @param signatories The signatories of the multisig, in any order
+    /// @param threshold The number of approvals required for a dispatch
+    function multisigAccount(address[] memory signatories, uint16 threshold)
+        external
+        view
+        returns (address);
+
+    /// @dev Compute the hash of a SCALE encoded call, as used to identify operations.
+    /// @custom:selector b9d04450
+    /// @param call The SCALE encoded call
+    function callHash(bytes memory call) external view returns (bytes32);
+
+    /// @dev Retrieve a pending operation of a multisig account.
+    /// @custom:selector 7017eb84
+    /// @param multisig The multisig account
+    /// @param callHash The blake2-256 hash of the SCALE encoded call of the operation
+    function pendingOperation(address multisig, bytes32 callHash)
+        external
+        view
+        returns (PendingOperation memory);
+}
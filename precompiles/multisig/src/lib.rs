@@ -0,0 +1,332 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile to interact with pallet_multisig.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::{
+	dispatch::{GetDispatchInfo, PostDispatchInfo},
+	traits::{ConstU32, Currency},
+	weights::Weight,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_evm::AddressMapping;
+use parity_scale_codec::{Decode, DecodeLimit as _, MaxEncodedLen};
+use precompile_utils::{prelude::*, substrate::transcode};
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::{Dispatchable, SaturatedConversion};
+use sp_std::{boxed::Box, marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+type BalanceOf<T> = <<T as pallet_multisig::Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+
+type MultisigOf<T> = pallet_multisig::Multisig<
+	BlockNumberFor<T>,
+	BalanceOf<T>,
+	<T as frame_system::Config>::AccountId,
+	<T as pallet_multisig::Config>::MaxSignatories,
+>;
+
+type SystemCallOf<T> = <T as frame_system::Config>::RuntimeCall;
+
+type GetCallLimit = ConstU32<{ 2u32.pow(16) }>;
+type DecodeLimit = ConstU32<8>;
+
+/// Point in the chain history (block height and extrinsic index) at which a multisig operation
+/// was opened. A zero height means that no timepoint is provided, which is expected for the
+/// first approval of an operation.
+#[derive(Default, Debug, Eq, PartialEq, solidity::Codec)]
+pub struct Timepoint {
+	height: u32,
+	index: u32,
+}
+
+/// Maximum weight the multisig call is allowed to consume when executed.
+#[derive(Default, Debug, Eq, PartialEq, solidity::Codec)]
+pub struct MaxWeight {
+	ref_time: u64,
+	proof_size: u64,
+}
+
+#[derive(Default, Debug, Eq, PartialEq, solidity::Codec)]
+pub struct PendingOperation {
+	is_pending: bool,
+	when: Timepoint,
+	deposit: U256,
+	depositor: Address,
+	approvals: Vec<Address>,
+}
+
+/// Mirror of `pallet_multisig::Multisig`, whose fields are not public.
+#[derive(Decode)]
+struct MultisigInfo<BlockNumber, Balance, AccountId> {
+	when: (BlockNumber, u32),
+	deposit: Balance,
+	depositor: AccountId,
+	approvals: Vec<AccountId>,
+}
+
+type MultisigInfoOf<Runtime> = MultisigInfo<
+	BlockNumberFor<Runtime>,
+	BalanceOf<Runtime>,
+	<Runtime as frame_system::Config>::AccountId,
+>;
+
+/// A precompile to wrap the functionality from pallet-multisig.
+pub struct MultisigPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+#[precompile::test_concrete_types(mock::Runtime)]
+impl<Runtime> MultisigPrecompile<Runtime>
+where
+	Runtime: pallet_multisig::Config + pallet_evm::Config,
+	Runtime::AccountId: Into<H160>,
+	SystemCallOf<Runtime>: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo + Decode,
+	<SystemCallOf<Runtime> as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	SystemCallOf<Runtime>: From<pallet_multisig::Call<Runtime>>,
+	<Runtime as pallet_multisig::Config>::RuntimeCall: From<SystemCallOf<Runtime>>,
+	BalanceOf<Runtime>: Into<U256>,
+{
+	/// Register approval for a dispatch to be made from the deterministic multisig account
+	/// derived from the caller, `otherSignatories` and `threshold`. If this approval reaches
+	/// the threshold, the call is dispatched.
+	///
+	/// Parameters:
+	/// * threshold: The total number of approvals required for the dispatch.
+	/// * other_signatories: The accounts other than the caller who can approve the dispatch.
+	/// * maybe_timepoint: The timepoint of the first approval, or a zero timepoint if this is
+	///   the first approval.
+	/// * call: The SCALE encoded call to dispatch.
+	/// * max_weight: The maximum weight the call is allowed to consume when executed.
+	#[precompile::public("asMulti(uint16,address[],(uint32,uint32),bytes,(uint64,uint64))")]
+	fn as_multi(
+		handle: &mut impl PrecompileHandle,
+		threshold: u16,
+		other_signatories: BoundedVec<Address, Runtime::MaxSignatories>,
+		maybe_timepoint: Timepoint,
+		call: BoundedBytes<GetCallLimit>,
+		max_weight: MaxWeight,
+	) -> EvmResult {
+		let other_signatories = Self::sorted_accounts(other_signatories.into());
+		let maybe_timepoint = Self::maybe_timepoint(maybe_timepoint)?;
+
+		let call: Vec<_> = call.into();
+		let call =
+			SystemCallOf::<Runtime>::decode_with_depth_limit(DecodeLimit::get(), &mut &*call)
+				.map_err(|_| RevertReason::custom("Failed to decode call").in_field("call"))?
+				.into();
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(origin).into(),
+			pallet_multisig::Call::<Runtime>::as_multi {
+				threshold,
+				other_signatories,
+				maybe_timepoint,
+				call: Box::new(call),
+				max_weight: Weight::from_parts(max_weight.ref_time, max_weight.proof_size),
+			},
+			0,
+		)?;
+
+		Ok(())
+	}
+
+	/// Register approval for a dispatch to be made from the deterministic multisig account
+	/// derived from the caller, `otherSignatories` and `threshold`, without providing the call.
+	/// The call must be provided with `asMulti` by the final approval.
+	///
+	/// Parameters:
+	/// * threshold: The total number of approvals required for the dispatch.
+	/// * other_signatories: The accounts other than the caller who can approve the dispatch.
+	/// * maybe_timepoint: The timepoint of the first approval, or a zero timepoint if this is
+	///   the first approval.
+	/// * call_hash: The blake2-256 hash of the SCALE encoded call to dispatch.
+	/// * max_weight: The maximum weight the call is allowed to consume when executed.
+	#[precompile::public(
+		"approveAsMulti(uint16,address[],(uint32,uint32),bytes32,(uint64,uint64))"
+	)]
+	fn approve_as_multi(
+		handle: &mut impl PrecompileHandle,
+		threshold: u16,
+		other_signatories: BoundedVec<Address, Runtime::MaxSignatories>,
+		maybe_timepoint: Timepoint,
+		call_hash: H256,
+		max_weight: MaxWeight,
+	) -> EvmResult {
+		let other_signatories = Self::sorted_accounts(other_signatories.into());
+		let maybe_timepoint = Self::maybe_timepoint(maybe_timepoint)?;
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(origin).into(),
+			pallet_multisig::Call::<Runtime>::approve_as_multi {
+				threshold,
+				other_signatories,
+				maybe_timepoint,
+				call_hash: call_hash.into(),
+				max_weight: Weight::from_parts(max_weight.ref_time, max_weight.proof_size),
+			},
+			0,
+		)?;
+
+		Ok(())
+	}
+
+	/// Cancel a pre-existing, on-going multisig operation. The deposit reserved for it is
+	/// unreserved. Only the depositor of the operation can cancel it.
+	///
+	/// Parameters:
+	/// * threshold: The total number of approvals required for the dispatch.
+	/// * other_signatories: The accounts other than the caller who can approve the dispatch.
+	/// * timepoint: The timepoint of the first approval of the operation.
+	/// * call_hash: The blake2-256 hash of the SCALE encoded call to dispatch.
+	#[precompile::public("cancelAsMulti(uint16,address[],(uint32,uint32),bytes32)")]
+	fn cancel_as_multi(
+		handle: &mut impl PrecompileHandle,
+		threshold: u16,
+		other_signatories: BoundedVec<Address, Runtime::MaxSignatories>,
+		timepoint: Timepoint,
+		call_hash: H256,
+	) -> EvmResult {
+		let other_signatories = Self::sorted_accounts(other_signatories.into());
+		let timepoint = Self::maybe_timepoint(timepoint)?
+			.ok_or_else(|| RevertReason::custom("Timepoint is required").in_field("timepoint"))?;
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(origin).into(),
+			pallet_multisig::Call::<Runtime>::cancel_as_multi {
+				threshold,
+				other_signatories,
+				timepoint,
+				call_hash: call_hash.into(),
+			},
+			0,
+		)?;
+
+		Ok(())
+	}
+
+	/// Returns the deterministic multisig account derived from `signatories` and `threshold`.
+	/// The order of the signatories doesn't matter.
+	#[precompile::public("multisigAccount(address[],uint16)")]
+	#[precompile::view]
+	fn multisig_account(
+		_handle: &mut impl PrecompileHandle,
+		signatories: BoundedVec<Address, Runtime::MaxSignatories>,
+		threshold: u16,
+	) -> EvmResult<Address> {
+		let signatories = Self::sorted_accounts(signatories.into());
+		let account = pallet_multisig::Pallet::<Runtime>::multi_account_id(&signatories, threshold);
+
+		Ok(Address(account.into()))
+	}
+
+	/// Returns the blake2-256 hash of a SCALE encoded call, as expected by `approveAsMulti`
+	/// and `cancelAsMulti`.
+	#[precompile::public("callHash(bytes)")]
+	#[precompile::view]
+	fn call_hash(
+		_handle: &mut impl PrecompileHandle,
+		call: BoundedBytes<GetCallLimit>,
+	) -> EvmResult<H256> {
+		let call: Vec<_> = call.into();
+		Ok(sp_io::hashing::blake2_256(&call).into())
+	}
+
+	/// Returns the pending operation of `multisig` for the call with hash `callHash`.
+	/// `isPending` is false if there is no such operation.
+	#[precompile::public("pendingOperation(address,bytes32)")]
+	#[precompile::view]
+	fn pending_operation(
+		handle: &mut impl PrecompileHandle,
+		multisig: Address,
+		call_hash: H256,
+	) -> EvmResult<PendingOperation> {
+		// Storage item: Multisigs ->
+		// 		Twox64Concat(8) + AccountId + Blake2_128Concat(16) + CallHash(32) + Multisig
+		handle.record_db_read::<Runtime>(
+			56usize
+				.saturating_add(Runtime::AccountId::max_encoded_len())
+				.saturating_add(MultisigOf::<Runtime>::max_encoded_len()),
+		)?;
+
+		let multisig = Runtime::AddressMapping::into_account_id(multisig.into());
+		let call_hash: [u8; 32] = call_hash.into();
+		let Some(operation) = pallet_multisig::Multisigs::<Runtime>::get(&multisig, call_hash)
+		else {
+			return Ok(PendingOperation::default());
+		};
+
+		let MultisigInfo {
+			when: (height, index),
+			deposit,
+			depositor,
+			approvals,
+		} = transcode::<MultisigInfoOf<Runtime>>(&operation)?;
+
+		Ok(PendingOperation {
+			is_pending: true,
+			when: Timepoint {
+				height: height.saturated_into(),
+				index,
+			},
+			deposit: deposit.into(),
+			depositor: Address(depositor.into()),
+			approvals: approvals
+				.into_iter()
+				.map(|account| Address(account.into()))
+				.collect(),
+		})
+	}
+
+	/// Maps addresses to accounts, sorted as expected by pallet-multisig.
+	fn sorted_accounts(addresses: Vec<Address>) -> Vec<Runtime::AccountId> {
+		let mut accounts: Vec<_> = addresses
+			.into_iter()
+			.map(|address| Runtime::AddressMapping::into_account_id(address.into()))
+			.collect();
+		accounts.sort();
+		accounts
+	}
+
+	fn maybe_timepoint(
+		timepoint: Timepoint,
+	) -> MayRevert<Option<pallet_multisig::Timepoint<BlockNumberFor<Runtime>>>> {
+		if timepoint.height == 0 {
+			return Ok(None);
+		}
+
+		// The fields of `pallet_multisig::Timepoint` are not public, it is built from its
+		// encoding instead.
+		let height: BlockNumberFor<Runtime> = timepoint.height.into();
+		transcode::<pallet_multisig::Timepoint<_>>(&(height, timepoint.index))
+			.map(Some)
+			.in_field("timepoint")
+	}
+}
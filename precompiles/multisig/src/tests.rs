@@ -0,0 +1,285 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::{MaxWeight, PendingOperation, Timepoint};
+use parity_scale_codec::Encode;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::H256;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn remark_call() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark_with_event {
+		remark: b"multisig".to_vec(),
+	})
+}
+
+fn remark_call_hash() -> H256 {
+	sp_io::hashing::blake2_256(&remark_call().encode()).into()
+}
+
+fn max_weight() -> MaxWeight {
+	MaxWeight {
+		ref_time: 1_000_000_000,
+		proof_size: 100_000,
+	}
+}
+
+fn alice_and_bob_multisig() -> AccountId {
+	let mut signatories: Vec<AccountId> = vec![Alice.into(), Bob.into()];
+	signatories.sort();
+	pallet_multisig::Pallet::<Runtime>::multi_account_id(&signatories, 2)
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(&["Multisig.sol"], PCall::supports_selector)
+}
+
+#[test]
+fn test_generated_interface_matches_solidity_interface() {
+	check_precompile_generated_interface::<crate::MultisigPrecompile<Runtime>>(&["Multisig.sol"])
+}
+
+#[test]
+fn multisig_account_does_not_depend_on_signatories_order() {
+	ExtBuilder::default().build().execute_with(|| {
+		let expected = Address(alice_and_bob_multisig().into());
+
+		precompiles()
+			.prepare_test(
+				Charlie,
+				Precompile1,
+				PCall::multisig_account {
+					signatories: vec![Address(Alice.into()), Address(Bob.into())].into(),
+					threshold: 2,
+				},
+			)
+			.execute_returns(expected);
+
+		precompiles()
+			.prepare_test(
+				Charlie,
+				Precompile1,
+				PCall::multisig_account {
+					signatories: vec![Address(Bob.into()), Address(Alice.into())].into(),
+					threshold: 2,
+				},
+			)
+			.execute_returns(expected);
+	})
+}
+
+#[test]
+fn call_hash_matches_pallet_hashing() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::call_hash {
+					call: remark_call().encode().into(),
+				},
+			)
+			.execute_returns(remark_call_hash());
+	})
+}
+
+#[test]
+fn pending_operation_defaults_when_unknown() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::pending_operation {
+					multisig: Address(alice_and_bob_multisig().into()),
+					call_hash: remark_call_hash(),
+				},
+			)
+			.execute_returns(PendingOperation::default());
+	})
+}
+
+#[test]
+fn as_multi_opens_then_executes_operation() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000), (Bob.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::as_multi {
+						threshold: 2,
+						other_signatories: vec![Address(Bob.into())].into(),
+						maybe_timepoint: Timepoint::default(),
+						call: remark_call().encode().into(),
+						max_weight: max_weight(),
+					},
+				)
+				.execute_returns(());
+
+			// DepositBase + 2 * DepositFactor
+			assert_eq!(Balances::reserved_balance(AccountId::from(Alice)), 12);
+
+			precompiles()
+				.prepare_test(
+					Bob,
+					Precompile1,
+					PCall::pending_operation {
+						multisig: Address(alice_and_bob_multisig().into()),
+						call_hash: remark_call_hash(),
+					},
+				)
+				.execute_returns(PendingOperation {
+					is_pending: true,
+					when: Timepoint {
+						height: 1,
+						index: 0,
+					},
+					deposit: 12.into(),
+					depositor: Address(Alice.into()),
+					approvals: vec![Address(Alice.into())],
+				});
+
+			precompiles()
+				.prepare_test(
+					Bob,
+					Precompile1,
+					PCall::as_multi {
+						threshold: 2,
+						other_signatories: vec![Address(Alice.into())].into(),
+						maybe_timepoint: Timepoint {
+							height: 1,
+							index: 0,
+						},
+						call: remark_call().encode().into(),
+						max_weight: max_weight(),
+					},
+				)
+				.execute_returns(());
+
+			assert!(events().iter().any(|event| matches!(
+				event,
+				RuntimeEvent::Multisig(pallet_multisig::Event::MultisigExecuted {
+					result: Ok(()),
+					..
+				})
+			)));
+			assert!(!pallet_multisig::Multisigs::<Runtime>::contains_key(
+				alice_and_bob_multisig(),
+				<[u8; 32]>::from(remark_call_hash())
+			));
+			assert_eq!(Balances::reserved_balance(AccountId::from(Alice)), 0);
+		})
+}
+
+#[test]
+fn approve_as_multi_then_cancel_as_multi() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::approve_as_multi {
+						threshold: 2,
+						other_signatories: vec![Address(Bob.into())].into(),
+						maybe_timepoint: Timepoint::default(),
+						call_hash: remark_call_hash(),
+						max_weight: max_weight(),
+					},
+				)
+				.execute_returns(());
+
+			assert!(pallet_multisig::Multisigs::<Runtime>::contains_key(
+				alice_and_bob_multisig(),
+				<[u8; 32]>::from(remark_call_hash())
+			));
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::cancel_as_multi {
+						threshold: 2,
+						other_signatories: vec![Address(Bob.into())].into(),
+						timepoint: Timepoint {
+							height: 1,
+							index: 0,
+						},
+						call_hash: remark_call_hash(),
+					},
+				)
+				.execute_returns(());
+
+			assert!(!pallet_multisig::Multisigs::<Runtime>::contains_key(
+				alice_and_bob_multisig(),
+				<[u8; 32]>::from(remark_call_hash())
+			));
+			assert_eq!(Balances::reserved_balance(AccountId::from(Alice)), 0);
+		})
+}
+
+#[test]
+fn cancel_as_multi_requires_timepoint() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::cancel_as_multi {
+						threshold: 2,
+						other_signatories: vec![Address(Bob.into())].into(),
+						timepoint: Timepoint::default(),
+						call_hash: remark_call_hash(),
+					},
+				)
+				.execute_reverts(|output| output == b"timepoint: Timepoint is required");
+		})
+}
+
+#[test]
+fn as_multi_reverts_with_invalid_call() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::as_multi {
+						threshold: 2,
+						other_signatories: vec![Address(Bob.into())].into(),
+						maybe_timepoint: Timepoint::default(),
+						call: vec![0xff, 0xff].into(),
+						max_weight: max_weight(),
+					},
+				)
+				.execute_reverts(|output| output == b"call: Failed to decode call");
+		})
+}
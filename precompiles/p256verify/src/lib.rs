@@ -0,0 +1,88 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! RIP-7212 precompile verifying secp256r1 (P-256) signatures.
+//!
+//! The input is the 160 bytes `hash || r || s || x || y`, where `(r, s)` is the signature and
+//! `(x, y)` the public key. A valid signature returns 1 as a 32 bytes word, while any invalid
+//! input returns no data without failing.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::{ExitSucceed, Precompile, PrecompileHandle, PrecompileOutput, PrecompileResult};
+use p256::{
+	ecdsa::{signature::hazmat::PrehashVerifier, Signature, VerifyingKey},
+	EncodedPoint, FieldBytes,
+};
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod tests;
+
+/// Cost of a signature verification
+pub const P256_VERIFY_GAS: u64 = 3_450;
+
+/// Length of the input
+const INPUT_LENGTH: usize = 160;
+
+pub struct P256Verify;
+
+impl P256Verify {
+	/// Verify a signature of a hash, as encoded in the input.
+	fn verify(input: &[u8]) -> bool {
+		if input.len() != INPUT_LENGTH {
+			return false;
+		}
+
+		let hash = &input[..32];
+		let r = FieldBytes::from_slice(&input[32..64]);
+		let s = FieldBytes::from_slice(&input[64..96]);
+		let x = FieldBytes::from_slice(&input[96..128]);
+		let y = FieldBytes::from_slice(&input[128..160]);
+
+		// Rejects scalars that are zero or not lower than the curve order.
+		let Ok(signature) = Signature::from_scalars(*r, *s) else {
+			return false;
+		};
+		// Rejects coordinates of points not on the curve, including the point at infinity.
+		let point = EncodedPoint::from_affine_coordinates(x, y, false);
+		let Ok(public_key) = VerifyingKey::from_encoded_point(&point) else {
+			return false;
+		};
+
+		public_key.verify_prehash(hash, &signature).is_ok()
+	}
+}
+
+impl Precompile for P256Verify {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(P256_VERIFY_GAS)?;
+
+		let mut output = Vec::new();
+		if Self::verify(handle.input()) {
+			output.resize(32, 0);
+			output[31] = 1;
+		}
+
+		Ok(PrecompileOutput {
+			exit_status: ExitSucceed::Returned,
+			output,
+		})
+	}
+}
+
+// The precompile has no Solidity interface.
+impl precompile_utils::solidity::abi::SolidityInterface for P256Verify {}
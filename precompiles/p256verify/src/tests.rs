@@ -0,0 +1,166 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use fp_evm::Context;
+use hex_literal::hex;
+use precompile_utils::testing::MockHandle;
+use sp_core::{hashing::sha2_256, H160, U256};
+
+/// Order of the curve
+const CURVE_ORDER: [u8; 32] =
+	hex!("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+
+/// Public key of the first test group of the Wycheproof `ecdsa_secp256r1_sha256` vectors
+const WYCHEPROOF_X: [u8; 32] =
+	hex!("2927b10512bae3eddcfe467828128bad2903269919f7086069c8c4df6c732838");
+const WYCHEPROOF_Y: [u8; 32] =
+	hex!("c7787964eaac00e5921fb1498a60f4606766b3d9685001558d1a974e7341513e");
+/// Message signed by the Wycheproof vectors below
+const WYCHEPROOF_MESSAGE: [u8; 6] = hex!("313233343030");
+const WYCHEPROOF_R: [u8; 32] =
+	hex!("2ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18");
+/// Low `s` of the signature
+const WYCHEPROOF_S: [u8; 32] =
+	hex!("4cd60b855d442f5b3c7b11eb6c4e0ae7525fe710fab9aa7c77a67f79e6fadd76");
+/// High `s` of the same signature (signature malleability case)
+const WYCHEPROOF_S_MALLEATED: [u8; 32] =
+	hex!("b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db");
+
+fn input(hash: [u8; 32], r: [u8; 32], s: [u8; 32], x: [u8; 32], y: [u8; 32]) -> Vec<u8> {
+	[hash, r, s, x, y].concat()
+}
+
+fn wycheproof_input(r: [u8; 32], s: [u8; 32]) -> Vec<u8> {
+	input(
+		sha2_256(&WYCHEPROOF_MESSAGE),
+		r,
+		s,
+		WYCHEPROOF_X,
+		WYCHEPROOF_Y,
+	)
+}
+
+fn execute(input: Vec<u8>) -> Vec<u8> {
+	let mut handle = MockHandle::new(
+		H160::from_low_u64_be(256),
+		Context {
+			address: H160::from_low_u64_be(256),
+			caller: H160::repeat_byte(0x11),
+			apparent_value: U256::zero(),
+		},
+	);
+	handle.input = input;
+
+	let output = P256Verify::execute(&mut handle).expect("verification never fails");
+	assert_eq!(output.exit_status, ExitSucceed::Returned);
+	assert_eq!(handle.gas_used, P256_VERIFY_GAS);
+	output.output
+}
+
+fn assert_valid(input: Vec<u8>) {
+	let expected: [u8; 32] = U256::one().into();
+	assert_eq!(execute(input), expected);
+}
+
+fn assert_invalid(input: Vec<u8>) {
+	assert_eq!(execute(input), Vec::<u8>::new());
+}
+
+#[test]
+fn rip7212_reference_vector_is_valid() {
+	assert_valid(
+		hex!(
+			"4cee90eb86eaa050036147a12d49004b6b9c72bd725d39d4785011fe190f0b4d"
+			"a73bd4903f0ce3b639bbbf6e8e80d16931ff4bcf5993d58468e8fb19086e8cac"
+			"36dbcd03009df8c59286b162af3bd7fcc0450c9aa81be5d10d312af6c66b1d60"
+			"4aebd3099c618202fcfe16ae7770b0c49ab5eadf74b754204a3bb6060e44eff3"
+			"7618b065f9832de4ca6ca971a7a1adc826d0f7c00181a5fb2ddf79ae00b4e10e"
+		)
+		.to_vec(),
+	);
+}
+
+#[test]
+fn wycheproof_signatures_are_valid() {
+	assert_valid(wycheproof_input(WYCHEPROOF_R, WYCHEPROOF_S));
+}
+
+#[test]
+fn malleated_signatures_are_valid() {
+	assert_valid(wycheproof_input(WYCHEPROOF_R, WYCHEPROOF_S_MALLEATED));
+}
+
+#[test]
+fn modified_signatures_are_invalid() {
+	let mut r = WYCHEPROOF_R;
+	r[31] ^= 1;
+	assert_invalid(wycheproof_input(r, WYCHEPROOF_S));
+
+	let mut s = WYCHEPROOF_S;
+	s[0] ^= 0x80;
+	assert_invalid(wycheproof_input(WYCHEPROOF_R, s));
+}
+
+#[test]
+fn out_of_range_scalars_are_invalid() {
+	assert_invalid(wycheproof_input([0u8; 32], WYCHEPROOF_S));
+	assert_invalid(wycheproof_input(WYCHEPROOF_R, [0u8; 32]));
+	assert_invalid(wycheproof_input(CURVE_ORDER, WYCHEPROOF_S));
+	assert_invalid(wycheproof_input(WYCHEPROOF_R, CURVE_ORDER));
+}
+
+#[test]
+fn other_hashes_are_invalid() {
+	assert_invalid(input(
+		sha2_256(b"123401"),
+		WYCHEPROOF_R,
+		WYCHEPROOF_S,
+		WYCHEPROOF_X,
+		WYCHEPROOF_Y,
+	));
+}
+
+#[test]
+fn invalid_public_keys_are_invalid() {
+	let mut y = WYCHEPROOF_Y;
+	y[31] ^= 1;
+	assert_invalid(input(
+		sha2_256(&WYCHEPROOF_MESSAGE),
+		WYCHEPROOF_R,
+		WYCHEPROOF_S,
+		WYCHEPROOF_X,
+		y,
+	));
+
+	// The point at infinity
+	assert_invalid(input(
+		sha2_256(&WYCHEPROOF_MESSAGE),
+		WYCHEPROOF_R,
+		WYCHEPROOF_S,
+		[0u8; 32],
+		[0u8; 32],
+	));
+}
+
+#[test]
+fn inputs_of_invalid_length_are_invalid() {
+	let valid = wycheproof_input(WYCHEPROOF_R, WYCHEPROOF_S);
+
+	assert_invalid(vec![]);
+	assert_invalid(valid[..159].to_vec());
+	assert_invalid([&valid[..], &[0u8]].concat());
+}
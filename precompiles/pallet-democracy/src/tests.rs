@@ -1470,6 +1470,13 @@ fn test_solidity_interface_has_all_function_selectors_documented_and_implemented
 	)
 }
 
+#[test]
+fn test_generated_interface_matches_solidity_interface() {
+	check_precompile_generated_interface::<crate::DemocracyPrecompile<Runtime>>(&[
+		"DemocracyInterface.sol",
+	])
+}
+
 #[test]
 fn test_deprecated_solidity_selectors_are_supported() {
 	for deprecated_function in [
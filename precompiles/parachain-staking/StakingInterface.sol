@@ -16,6 +16,60 @@ ParachainStaking constant PARACHAIN_STAKING_CONTRACT = ParachainStaking(
 /// wraps the pallet
 /// @custom:address 0x0000000000000000000000000000000000000800
 interface ParachainStaking {
+    /// @dev Reverted when the dispatch of a staking extrinsic fails with a pallet error
+    /// @param module The index of the pallet in the runtime
+    /// @param index The index of the error in the pallet
+    /// @param message The name of the error, such as "DelegatorDNE"
+    error DispatchError(uint8 module, uint8 index, string message);
+
+    /// @dev A delegation of a candidate
+    struct DelegatorBond {
+        /// The address of the delegator
+        address delegator;
+        /// The amount bonded by the delegator
+        uint256 amount;
+        /// The percent of rewards the delegator auto-compounds
+        uint8 autoCompound;
+    }
+
+    /// @dev A scheduled request to change a delegation
+    struct DelegationRequest {
+        /// Whether a request is scheduled for the delegation
+        bool exists;
+        /// The requested action, 0 to revoke the delegation and 1 to decrease it
+        uint8 action;
+        /// The amount that will be unbonded
+        uint256 amount;
+        /// The round from which the request can be executed
+        uint32 whenExecutable;
+    }
+
+    /// @dev A delegation of a delegator
+    struct DelegationInfo {
+        /// The address of the candidate
+        address candidate;
+        /// The amount bonded in support of the candidate
+        uint256 amount;
+        /// The percent of rewards the delegator auto-compounds
+        uint8 autoCompound;
+        /// The request scheduled for the delegation, if any
+        DelegationRequest request;
+    }
+
+    /// @dev The stake of a collator snapshotted at the start of a round
+    struct CollatorSnapshot {
+        /// Whether the collator has a snapshot for the round, only selected candidates do
+        bool exists;
+        /// The round of the snapshot
+        uint32 round;
+        /// The self bond of the collator
+        uint256 bond;
+        /// The total counted stake of the collator
+        uint256 total;
+        /// The rewardable delegations of the collator
+        DelegatorBond[] delegations;
+    }
+
     /// @dev Check whether the specified address is currently a staking delegator
     /// @custom:selector fd8ab482
     /// @param delegator the address that we want to confirm is a delegator
@@ -302,4 +356,43 @@ interface ParachainStaking {
     function getCandidateTotalCounted(
         address candidate
     ) external view returns (uint256);
+
+    /// @dev Fetch all the delegations of a delegator, with their auto-compound
+    /// percent and scheduled request.
+    /// @custom:selector 0b6f884a
+    /// @param delegator Address of the delegator.
+    /// @return The delegations of the delegator.
+    function getDelegatorDelegations(
+        address delegator
+    ) external view returns (DelegationInfo[] memory);
+
+    /// @dev Fetch the top delegations of a candidate, with their auto-compound
+    /// percent.
+    /// @custom:selector 7e8396a2
+    /// @param candidate Address of the candidate.
+    /// @return The top delegations of the candidate.
+    function getCandidateTopDelegations(
+        address candidate
+    ) external view returns (DelegatorBond[] memory);
+
+    /// @dev Fetch the snapshot of a collator for the current round.
+    /// @custom:selector fee7132b
+    /// @param candidate Address of the collator.
+    /// @return The snapshot of the collator.
+    function getCollatorSnapshot(
+        address candidate
+    ) external view returns (CollatorSnapshot memory);
+
+    /// @dev Estimate the rewards of a delegator for the given rounds that are not paid yet:
+    /// the rewards accrued in the reward pools of the rounds that it can claim, and the
+    /// estimated rewards of the rounds due for payout whose collators were not paid yet.
+    /// Delegations revoked since the round are included.
+    /// @custom:selector 96e1e5ab
+    /// @param delegator Address of the delegator.
+    /// @param rounds The rounds to claim, at most 32.
+    /// @return The total amount of claimable rewards.
+    function getPendingRewards(
+        address delegator,
+        uint32[] memory rounds
+    ) external view returns (uint256);
 }
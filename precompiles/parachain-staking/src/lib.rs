@@ -26,8 +26,9 @@ mod tests;
 use fp_evm::PrecompileHandle;
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
 use frame_support::sp_runtime::Percent;
-use frame_support::traits::{Currency, Get};
+use frame_support::traits::{ConstU32, Currency, Get};
 use pallet_evm::AddressMapping;
+use pallet_parachain_staking::{DelegationAction, MAX_CLAIM_ROUNDS};
 use precompile_utils::prelude::*;
 use sp_core::{H160, U256};
 use sp_runtime::traits::Dispatchable;
@@ -37,6 +38,42 @@ type BalanceOf<Runtime> = <<Runtime as pallet_parachain_staking::Config>::Curren
 	<Runtime as frame_system::Config>::AccountId,
 >>::Balance;
 
+/// A delegation of a candidate, with the auto-compound percent of the delegator.
+#[derive(Default, Debug, Eq, PartialEq, solidity::Codec)]
+pub struct DelegatorBond {
+	pub delegator: Address,
+	pub amount: U256,
+	pub auto_compound: u8,
+}
+
+/// A scheduled request to revoke (action 0) or decrease (action 1) a delegation.
+#[derive(Default, Debug, Eq, PartialEq, solidity::Codec)]
+pub struct DelegationRequest {
+	pub exists: bool,
+	pub action: u8,
+	pub amount: U256,
+	pub when_executable: u32,
+}
+
+/// A delegation of a delegator, with its auto-compound percent and pending request.
+#[derive(Default, Debug, Eq, PartialEq, solidity::Codec)]
+pub struct DelegationInfo {
+	pub candidate: Address,
+	pub amount: U256,
+	pub auto_compound: u8,
+	pub request: DelegationRequest,
+}
+
+/// The stake of a collator snapshotted at the start of a round.
+#[derive(Default, Debug, Eq, PartialEq, solidity::Codec)]
+pub struct CollatorSnapshot {
+	pub exists: bool,
+	pub round: u32,
+	pub bond: U256,
+	pub total: U256,
+	pub delegations: Vec<DelegatorBond>,
+}
+
 /// A precompile to wrap the functionality from parachain_staking.
 ///
 /// EXAMPLE USECASE:
@@ -500,7 +537,8 @@ where
 		};
 
 		// Dispatch call (if enough gas).
-		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)
+			.map_err(TryDispatchError::into_custom_error)?;
 
 		Ok(())
 	}
@@ -520,7 +558,8 @@ where
 		};
 
 		// Dispatch call (if enough gas).
-		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)
+			.map_err(TryDispatchError::into_custom_error)?;
 
 		Ok(())
 	}
@@ -543,7 +582,8 @@ where
 		};
 
 		// Dispatch call (if enough gas).
-		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)
+			.map_err(TryDispatchError::into_custom_error)?;
 
 		Ok(())
 	}
@@ -562,7 +602,8 @@ where
 			pallet_parachain_staking::Call::<Runtime>::cancel_leave_candidates { candidate_count };
 
 		// Dispatch call (if enough gas).
-		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)
+			.map_err(TryDispatchError::into_custom_error)?;
 
 		Ok(())
 	}
@@ -575,7 +616,8 @@ where
 		let call = pallet_parachain_staking::Call::<Runtime>::go_offline {};
 
 		// Dispatch call (if enough gas).
-		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)
+			.map_err(TryDispatchError::into_custom_error)?;
 
 		Ok(())
 	}
@@ -588,7 +630,8 @@ where
 		let call = pallet_parachain_staking::Call::<Runtime>::go_online {};
 
 		// Dispatch call (if enough gas).
-		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)
+			.map_err(TryDispatchError::into_custom_error)?;
 
 		Ok(())
 	}
@@ -603,7 +646,8 @@ where
 		let call = pallet_parachain_staking::Call::<Runtime>::candidate_bond_more { more };
 
 		// Dispatch call (if enough gas).
-		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)
+			.map_err(TryDispatchError::into_custom_error)?;
 
 		Ok(())
 	}
@@ -618,7 +662,8 @@ where
 		let call = pallet_parachain_staking::Call::<Runtime>::schedule_candidate_bond_less { less };
 
 		// Dispatch call (if enough gas).
-		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)
+			.map_err(TryDispatchError::into_custom_error)?;
 
 		Ok(())
 	}
@@ -637,7 +682,8 @@ where
 			pallet_parachain_staking::Call::<Runtime>::execute_candidate_bond_less { candidate };
 
 		// Dispatch call (if enough gas).
-		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)
+			.map_err(TryDispatchError::into_custom_error)?;
 
 		Ok(())
 	}
@@ -650,7 +696,8 @@ where
 		let call = pallet_parachain_staking::Call::<Runtime>::cancel_candidate_bond_less {};
 
 		// Dispatch call (if enough gas).
-		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)
+			.map_err(TryDispatchError::into_custom_error)?;
 
 		Ok(())
 	}
@@ -679,7 +726,8 @@ where
 		};
 
 		// Dispatch call (if enough gas).
-		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)
+			.map_err(TryDispatchError::into_custom_error)?;
 
 		Ok(())
 	}
@@ -723,7 +771,8 @@ where
 		};
 
 		// Dispatch call (if enough gas).
-		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)
+			.map_err(TryDispatchError::into_custom_error)?;
 
 		Ok(())
 	}
@@ -743,7 +792,8 @@ where
 		};
 
 		// Dispatch call (if enough gas).
-		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)
+			.map_err(TryDispatchError::into_custom_error)?;
 
 		Ok(())
 	}
@@ -764,7 +814,8 @@ where
 			pallet_parachain_staking::Call::<Runtime>::delegator_bond_more { candidate, more };
 
 		// Dispatch call (if enough gas).
-		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)
+			.map_err(TryDispatchError::into_custom_error)?;
 
 		Ok(())
 	}
@@ -787,7 +838,8 @@ where
 		};
 
 		// Dispatch call (if enough gas).
-		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)
+			.map_err(TryDispatchError::into_custom_error)?;
 
 		Ok(())
 	}
@@ -810,7 +862,8 @@ where
 		};
 
 		// Dispatch call (if enough gas).
-		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)
+			.map_err(TryDispatchError::into_custom_error)?;
 
 		Ok(())
 	}
@@ -829,7 +882,8 @@ where
 			pallet_parachain_staking::Call::<Runtime>::cancel_delegation_request { candidate };
 
 		// Dispatch call (if enough gas).
-		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)
+			.map_err(TryDispatchError::into_custom_error)?;
 
 		Ok(())
 	}
@@ -867,7 +921,8 @@ where
 		};
 
 		// Dispatch call (if enough gas).
-		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)
+			.map_err(TryDispatchError::into_custom_error)?;
 
 		Ok(())
 	}
@@ -912,6 +967,281 @@ where
 		Ok(amount.into())
 	}
 
+	#[precompile::public("getDelegatorDelegations(address)")]
+	#[precompile::view]
+	fn get_delegator_delegations(
+		handle: &mut impl PrecompileHandle,
+		delegator: Address,
+	) -> EvmResult<Vec<DelegationInfo>> {
+		// DelegatorState:
+		// Twox64Concat(8) + AccountId(20) + Delegator(56 + MaxDelegationsPerDelegator)
+		handle.record_db_read::<Runtime>(
+			84 + (<Runtime as pallet_parachain_staking::Config>::MaxDelegationsPerDelegator::get()
+				as usize),
+		)?;
+
+		let delegator = Runtime::AddressMapping::into_account_id(delegator.0);
+
+		let Some(state) = <pallet_parachain_staking::Pallet<Runtime>>::delegator_state(&delegator)
+		else {
+			return Ok(Vec::new());
+		};
+
+		let mut delegations = Vec::with_capacity(state.delegations.0.len());
+		for pallet_parachain_staking::Bond {
+			owner: candidate,
+			amount,
+		} in state.delegations.0
+		{
+			// AutoCompoundingDelegations:
+			// Blake2128(16) + AccountId(20)
+			// + BoundedVec(
+			// 	AutoCompoundConfig * (MaxTopDelegationsPerCandidate + MaxBottomDelegationsPerCandidate)
+			// )
+			handle.record_db_read::<Runtime>(
+				36 + (
+					22 * (<Runtime as pallet_parachain_staking::Config>::MaxTopDelegationsPerCandidate::get()
+					+ <Runtime as pallet_parachain_staking::Config>::MaxBottomDelegationsPerCandidate::get())
+					as usize),
+			)?;
+			// DelegationScheduledRequests:
+			// Blake2128(16) + AccountId(20)
+			// + Vec(
+			// 	ScheduledRequest(20 + 4 + DelegationAction(18))
+			//	* (MaxTopDelegationsPerCandidate + MaxBottomDelegationsPerCandidate)
+			// )
+			handle.record_db_read::<Runtime>(
+				36 + (
+					42 * (<Runtime as pallet_parachain_staking::Config>::MaxTopDelegationsPerCandidate::get()
+					+ <Runtime as pallet_parachain_staking::Config>::MaxBottomDelegationsPerCandidate::get())
+					as usize),
+			)?;
+
+			let auto_compound =
+				<pallet_parachain_staking::Pallet<Runtime>>::delegation_auto_compound(
+					&candidate, &delegator,
+				);
+			let request =
+				<pallet_parachain_staking::Pallet<Runtime>>::delegation_scheduled_requests(
+					&candidate,
+				)
+				.into_iter()
+				.find(|request| request.delegator == delegator)
+				.map_or(DelegationRequest::default(), |request| {
+					let (action, amount) = match request.action {
+						DelegationAction::Revoke(amount) => (0, amount),
+						DelegationAction::Decrease(amount) => (1, amount),
+					};
+					DelegationRequest {
+						exists: true,
+						action,
+						amount: amount.into(),
+						when_executable: request.when_executable,
+					}
+				});
+
+			delegations.push(DelegationInfo {
+				candidate: Address(candidate.into()),
+				amount: amount.into(),
+				auto_compound: auto_compound.deconstruct(),
+				request,
+			});
+		}
+
+		Ok(delegations)
+	}
+
+	#[precompile::public("getCandidateTopDelegations(address)")]
+	#[precompile::view]
+	fn get_candidate_top_delegations(
+		handle: &mut impl PrecompileHandle,
+		candidate: Address,
+	) -> EvmResult<Vec<DelegatorBond>> {
+		// TopDelegations:
+		// Twox64Concat(8) + AccountId(20) + Balance(16)
+		// + (AccountId(20) + Balance(16) * MaxTopDelegationsPerCandidate)
+		handle.record_db_read::<Runtime>(
+			44 + ((36
+				* <Runtime as pallet_parachain_staking::Config>::MaxTopDelegationsPerCandidate::get(
+				)) as usize),
+		)?;
+		// AutoCompoundingDelegations:
+		// Blake2128(16) + AccountId(20)
+		// + BoundedVec(
+		// 	AutoCompoundConfig * (MaxTopDelegationsPerCandidate + MaxBottomDelegationsPerCandidate)
+		// )
+		handle.record_db_read::<Runtime>(
+			36 + (
+				22 * (<Runtime as pallet_parachain_staking::Config>::MaxTopDelegationsPerCandidate::get()
+				+ <Runtime as pallet_parachain_staking::Config>::MaxBottomDelegationsPerCandidate::get())
+				as usize),
+		)?;
+
+		let candidate = Runtime::AddressMapping::into_account_id(candidate.0);
+
+		let Some(top_delegations) =
+			<pallet_parachain_staking::Pallet<Runtime>>::top_delegations(&candidate)
+		else {
+			return Ok(Vec::new());
+		};
+		let auto_compounding =
+			<pallet_parachain_staking::Pallet<Runtime>>::auto_compounding_delegations(&candidate);
+
+		Ok(top_delegations
+			.delegations
+			.into_iter()
+			.map(|bond| {
+				let auto_compound = auto_compounding
+					.iter()
+					.find(|config| config.delegator == bond.owner)
+					.map_or(0, |config| config.value.deconstruct());
+				DelegatorBond {
+					delegator: Address(bond.owner.into()),
+					amount: bond.amount.into(),
+					auto_compound,
+				}
+			})
+			.collect())
+	}
+
+	#[precompile::public("getCollatorSnapshot(address)")]
+	#[precompile::view]
+	fn get_collator_snapshot(
+		handle: &mut impl PrecompileHandle,
+		candidate: Address,
+	) -> EvmResult<CollatorSnapshot> {
+		// Round: RoundInfo(RoundIndex(4) + BlockNumber(4) + 4)
+		handle.record_db_read::<Runtime>(12)?;
+		// AtStake:
+		// Twox64Concat(8) + RoundIndex(4) + Twox64Concat(8) + AccountId(20) + Balance(16)
+		// + Balance(16) + (AccountId(20) + Balance(16) + Percent(1)) * MaxTopDelegationsPerCandidate
+		handle.record_db_read::<Runtime>(
+			72 + ((37
+				* <Runtime as pallet_parachain_staking::Config>::MaxTopDelegationsPerCandidate::get(
+				)) as usize),
+		)?;
+
+		let candidate = Runtime::AddressMapping::into_account_id(candidate.0);
+		let round = <pallet_parachain_staking::Pallet<Runtime>>::round().current;
+
+		let Some(snapshot) = <pallet_parachain_staking::Pallet<Runtime>>::at_stake(round, &candidate)
+		else {
+			return Ok(CollatorSnapshot {
+				round,
+				..Default::default()
+			});
+		};
+
+		Ok(CollatorSnapshot {
+			exists: true,
+			round,
+			bond: snapshot.bond.into(),
+			total: snapshot.total.into(),
+			delegations: snapshot
+				.delegations
+				.into_iter()
+				.map(|bond| DelegatorBond {
+					delegator: Address(bond.owner.into()),
+					amount: bond.amount.into(),
+					auto_compound: bond.auto_compound.deconstruct(),
+				})
+				.collect(),
+		})
+	}
+
+	#[precompile::public("getPendingRewards(address,uint32[])")]
+	#[precompile::view]
+	fn get_pending_rewards(
+		handle: &mut impl PrecompileHandle,
+		delegator: Address,
+		rounds: BoundedVec<u32, ConstU32<MAX_CLAIM_ROUNDS>>,
+	) -> EvmResult<U256> {
+		let max_top_delegations =
+			<Runtime as pallet_parachain_staking::Config>::MaxTopDelegationsPerCandidate::get()
+				as usize;
+		let delegator = Runtime::AddressMapping::into_account_id(delegator.0);
+		let mut rounds: Vec<u32> = rounds.into();
+		rounds.sort_unstable();
+		rounds.dedup();
+
+		let mut rewards = U256::zero();
+		for round in rounds {
+			// Rewards accrued into the reward pools of the round, which remain until claimed even
+			// if the delegation was revoked since.
+			let mut pools =
+				pallet_parachain_staking::DelegatorRewardPools::<Runtime>::iter_prefix_values(
+					round,
+				);
+			loop {
+				// DelegatorRewardPools:
+				// Twox64Concat(8) + RoundIndex(4) + Twox64Concat(8) + AccountId(20) + Balance(16)
+				// + Balance(16) + (AccountId(20) + Balance(16)) * MaxTopDelegationsPerCandidate
+				handle.record_db_read::<Runtime>(72 + 36 * max_top_delegations)?;
+				let Some(pool) = pools.next() else {
+					break;
+				};
+
+				if let Some(amount) = pool.unclaimed_of(&delegator) {
+					rewards = rewards.saturating_add(
+						<pallet_parachain_staking::Pallet<Runtime>>::delegator_reward_share(
+							&pool, *amount,
+						)
+						.into(),
+					);
+				}
+			}
+
+			// Rewards of the collators of the round that are due for payout but were not paid
+			// yet, estimated from their snapshot.
+			// DelayedPayouts: Twox64Concat(8) + RoundIndex(4) + Balance(16) + Balance(16) + Perbill(4)
+			handle.record_db_read::<Runtime>(48)?;
+			let Some(payout) = <pallet_parachain_staking::Pallet<Runtime>>::delayed_payouts(round)
+			else {
+				continue;
+			};
+			// Points: Twox64Concat(8) + RoundIndex(4) + RewardPoint(4)
+			handle.record_db_read::<Runtime>(16)?;
+			let total_points = <pallet_parachain_staking::Pallet<Runtime>>::points(round);
+
+			let mut snapshots = pallet_parachain_staking::AtStake::<Runtime>::iter_prefix(round);
+			loop {
+				// AtStake:
+				// Twox64Concat(8) + RoundIndex(4) + Twox64Concat(8) + AccountId(20) + Balance(16)
+				// + Balance(16) + (AccountId(20) + Balance(16) + Percent(1)) * MaxTopDelegationsPerCandidate
+				handle.record_db_read::<Runtime>(72 + 37 * max_top_delegations)?;
+				let Some((collator, snapshot)) = snapshots.next() else {
+					break;
+				};
+
+				let Some(bond) = snapshot
+					.delegations
+					.iter()
+					.find(|bond| bond.owner == delegator)
+				else {
+					continue;
+				};
+				// AwardedPts: Twox64Concat(8) + RoundIndex(4) + Twox64Concat(8) + AccountId(20)
+				// + RewardPoint(4)
+				handle.record_db_read::<Runtime>(44)?;
+				let collator_points =
+					<pallet_parachain_staking::Pallet<Runtime>>::awarded_pts(round, &collator);
+
+				rewards = rewards.saturating_add(
+					<pallet_parachain_staking::Pallet<Runtime>>::estimated_delegator_reward(
+						&payout,
+						total_points,
+						collator_points,
+						snapshot.total,
+						bond.amount,
+					)
+					.into(),
+				);
+			}
+		}
+
+		Ok(rewards)
+	}
+
 	fn u256_to_amount(value: U256) -> MayRevert<BalanceOf<Runtime>> {
 		value
 			.try_into()
@@ -15,14 +15,17 @@
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::mock::{
-	events, roll_to, roll_to_round_begin, set_points, ExtBuilder, PCall, ParachainStaking,
-	Precompiles, PrecompilesValue, Runtime, RuntimeCall, RuntimeOrigin,
+	events, roll_to, roll_to_round_begin, set_points, AccountId, Balance, ExtBuilder, PCall,
+	ParachainStaking, Precompiles, PrecompilesValue, Runtime, RuntimeCall, RuntimeOrigin,
 };
+use crate::{CollatorSnapshot, DelegationInfo, DelegationRequest, DelegatorBond};
 use core::str::from_utf8;
 use frame_support::assert_ok;
-use frame_support::sp_runtime::Percent;
+use frame_support::sp_runtime::{Perbill, Percent};
 use pallet_evm::Call as EvmCall;
-use pallet_parachain_staking::Event as StakingEvent;
+use pallet_parachain_staking::{
+	AwardedPts, Bond, DelegatorRewardPool, DelegatorRewardPools, Event as StakingEvent, Points,
+};
 use precompile_utils::{prelude::*, testing::*};
 use sp_core::{H160, U256};
 use sp_runtime::traits::Dispatchable;
@@ -81,6 +84,10 @@ fn selectors() {
 	assert!(PCall::cancel_delegation_request_selectors().contains(&0xc90eee83));
 	assert!(PCall::get_delegator_total_staked_selectors().contains(&0xe6861713));
 	assert!(PCall::get_candidate_total_counted_selectors().contains(&0xbc5a1043));
+	assert!(PCall::get_delegator_delegations_selectors().contains(&0x0b6f884a));
+	assert!(PCall::get_candidate_top_delegations_selectors().contains(&0x7e8396a2));
+	assert!(PCall::get_collator_snapshot_selectors().contains(&0xfee7132b));
+	assert!(PCall::get_pending_rewards_selectors().contains(&0x96e1e5ab));
 }
 
 #[test]
@@ -121,6 +128,10 @@ fn modifiers() {
 		tester.test_default_modifier(PCall::cancel_delegation_request_selectors());
 		tester.test_view_modifier(PCall::get_delegator_total_staked_selectors());
 		tester.test_view_modifier(PCall::get_candidate_total_counted_selectors());
+		tester.test_view_modifier(PCall::get_delegator_delegations_selectors());
+		tester.test_view_modifier(PCall::get_candidate_top_delegations_selectors());
+		tester.test_view_modifier(PCall::get_collator_snapshot_selectors());
+		tester.test_view_modifier(PCall::get_pending_rewards_selectors());
 	});
 }
 
@@ -1218,7 +1229,7 @@ fn delegate_works() {
 				delegator_position: pallet_parachain_staking::DelegatorAdded::AddedToTop {
 					new_total: 2_000,
 				},
-				auto_compound: Percent::zero(),
+				auto_compound: Percent::from_percent(0),
 			}
 			.into();
 			// Assert that the events vector contains the one expected
@@ -1614,7 +1625,11 @@ fn set_auto_compound_fails_if_not_delegation() {
 						delegator_delegation_count: 0.into(),
 					},
 				)
-				.execute_reverts(|output| from_utf8(&output).unwrap().contains("DelegatorDNE"));
+				// Index of ParachainStaking in the mock runtime and index of DelegatorDNE.
+				.execute_reverts_with_custom_error(
+					"DispatchError",
+					(4u8, 0u8, UnboundedString::from("DelegatorDNE")),
+				);
 		});
 }
 
@@ -1695,6 +1710,398 @@ fn get_candidate_total_counted_getter() {
 		});
 }
 
+#[test]
+fn get_delegator_delegations_getter() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(Alice.into(), 1_000),
+			(Bob.into(), 1_000),
+			(Charlie.into(), 1_500),
+		])
+		.with_candidates(vec![(Alice.into(), 1_000), (Bob.into(), 1_000)])
+		.with_auto_compounding_delegations(vec![
+			(
+				Charlie.into(),
+				Alice.into(),
+				1_000,
+				Percent::from_percent(50),
+			),
+			(Charlie.into(), Bob.into(), 499, Percent::zero()),
+		])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+				RuntimeOrigin::signed(Charlie.into()),
+				Bob.into(),
+				100,
+			));
+
+			PrecompilesValue::get()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::get_delegator_delegations {
+						delegator: Address(Charlie.into()),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(vec![
+					DelegationInfo {
+						candidate: Address(Alice.into()),
+						amount: U256::from(1_000),
+						auto_compound: 50,
+						request: DelegationRequest::default(),
+					},
+					DelegationInfo {
+						candidate: Address(Bob.into()),
+						amount: U256::from(499),
+						auto_compound: 0,
+						request: DelegationRequest {
+							exists: true,
+							action: 1,
+							amount: U256::from(100),
+							when_executable: 3,
+						},
+					},
+				]);
+
+			PrecompilesValue::get()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::get_delegator_delegations {
+						delegator: Address(David.into()),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(Vec::<DelegationInfo>::new());
+		});
+}
+
+#[test]
+fn get_candidate_top_delegations_getter() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(Alice.into(), 1_000),
+			(Charlie.into(), 1_000),
+			(David.into(), 500),
+		])
+		.with_candidates(vec![(Alice.into(), 1_000)])
+		.with_auto_compounding_delegations(vec![
+			(
+				Charlie.into(),
+				Alice.into(),
+				1_000,
+				Percent::from_percent(50),
+			),
+			(David.into(), Alice.into(), 500, Percent::zero()),
+		])
+		.build()
+		.execute_with(|| {
+			PrecompilesValue::get()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::get_candidate_top_delegations {
+						candidate: Address(Alice.into()),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(vec![
+					DelegatorBond {
+						delegator: Address(Charlie.into()),
+						amount: U256::from(1_000),
+						auto_compound: 50,
+					},
+					DelegatorBond {
+						delegator: Address(David.into()),
+						amount: U256::from(500),
+						auto_compound: 0,
+					},
+				]);
+
+			PrecompilesValue::get()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::get_candidate_top_delegations {
+						candidate: Address(Bob.into()),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(Vec::<DelegatorBond>::new());
+		});
+}
+
+#[test]
+fn get_collator_snapshot_getter() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(Alice.into(), 1_000),
+			(Charlie.into(), 1_000),
+			(David.into(), 500),
+		])
+		.with_candidates(vec![(Alice.into(), 1_000)])
+		.with_auto_compounding_delegations(vec![
+			(
+				Charlie.into(),
+				Alice.into(),
+				1_000,
+				Percent::from_percent(50),
+			),
+			(David.into(), Alice.into(), 500, Percent::zero()),
+		])
+		.build()
+		.execute_with(|| {
+			PrecompilesValue::get()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::get_collator_snapshot {
+						candidate: Address(Alice.into()),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(CollatorSnapshot {
+					exists: true,
+					round: 1,
+					bond: U256::from(1_000),
+					total: U256::from(2_500),
+					delegations: vec![
+						DelegatorBond {
+							delegator: Address(Charlie.into()),
+							amount: U256::from(1_000),
+							auto_compound: 50,
+						},
+						DelegatorBond {
+							delegator: Address(David.into()),
+							amount: U256::from(500),
+							auto_compound: 0,
+						},
+					],
+				});
+
+			// Not a selected candidate
+			PrecompilesValue::get()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::get_collator_snapshot {
+						candidate: Address(Bob.into()),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(CollatorSnapshot {
+					round: 1,
+					..Default::default()
+				});
+		});
+}
+
+#[test]
+fn get_pending_rewards_getter() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(Alice.into(), 1_000),
+			(Bob.into(), 1_000),
+			(Charlie.into(), 1_500),
+		])
+		.with_candidates(vec![(Alice.into(), 1_000), (Bob.into(), 1_000)])
+		.with_delegations(vec![
+			(Charlie.into(), Alice.into(), 1_000),
+			(Charlie.into(), Bob.into(), 500),
+		])
+		.build()
+		.execute_with(|| {
+			DelegatorRewardPools::<Runtime>::insert(
+				2u32,
+				AccountId::from(Alice),
+				DelegatorRewardPool {
+					rewards: 100,
+					total: 2_000,
+					unclaimed: frame_support::BoundedVec::truncate_from(vec![Bond {
+						owner: Charlie.into(),
+						amount: 1_000,
+					}]),
+				},
+			);
+			DelegatorRewardPools::<Runtime>::insert(
+				3u32,
+				AccountId::from(Bob),
+				DelegatorRewardPool {
+					rewards: 30,
+					total: 1_500,
+					unclaimed: frame_support::BoundedVec::truncate_from(vec![Bond {
+						owner: Charlie.into(),
+						amount: 500,
+					}]),
+				},
+			);
+
+			PrecompilesValue::get()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::get_pending_rewards {
+						delegator: Address(Charlie.into()),
+						rounds: vec![2].into(),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(U256::from(50));
+
+			// Duplicated rounds are only counted once
+			PrecompilesValue::get()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::get_pending_rewards {
+						delegator: Address(Charlie.into()),
+						rounds: vec![3, 2, 3, 4].into(),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(U256::from(60));
+
+			PrecompilesValue::get()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::get_pending_rewards {
+						delegator: Address(David.into()),
+						rounds: vec![2, 3].into(),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(U256::zero());
+		});
+}
+
+#[test]
+fn get_pending_rewards_includes_revoked_delegations() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000), (Charlie.into(), 1_000)])
+		.with_candidates(vec![(Alice.into(), 1_000)])
+		.with_delegations(vec![(Charlie.into(), Alice.into(), 1_000)])
+		.build()
+		.execute_with(|| {
+			// David is no longer delegating but can still claim its share of the pool
+			DelegatorRewardPools::<Runtime>::insert(
+				2u32,
+				AccountId::from(Alice),
+				DelegatorRewardPool {
+					rewards: 100,
+					total: 2_000,
+					unclaimed: frame_support::BoundedVec::truncate_from(vec![
+						Bond {
+							owner: Charlie.into(),
+							amount: 1_000,
+						},
+						Bond {
+							owner: David.into(),
+							amount: 400,
+						},
+					]),
+				},
+			);
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::get_pending_rewards {
+						delegator: Address(David.into()),
+						rounds: vec![2].into(),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(U256::from(20));
+		});
+}
+
+#[test]
+fn get_pending_rewards_estimates_rounds_not_paid_yet() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(Alice.into(), 1_000),
+			(Bob.into(), 1_000),
+			(Charlie.into(), 1_000),
+		])
+		.with_candidates(vec![(Alice.into(), 1_000), (Bob.into(), 1_000)])
+		.with_delegations(vec![(Charlie.into(), Alice.into(), 1_000)])
+		.build()
+		.execute_with(|| {
+			// Round 5 is due for payout and neither collator was paid yet. David revoked its
+			// delegation since the snapshot.
+			pallet_parachain_staking::DelayedPayouts::<Runtime>::insert(
+				5u32,
+				pallet_parachain_staking::DelayedPayout {
+					round_issuance: 1_000,
+					total_staking_reward: 700,
+					collator_commission: Perbill::from_percent(20),
+				},
+			);
+			Points::<Runtime>::insert(5u32, 100);
+			AwardedPts::<Runtime>::insert(5u32, AccountId::from(Alice), 50);
+			AwardedPts::<Runtime>::insert(5u32, AccountId::from(Bob), 50);
+			pallet_parachain_staking::AtStake::<Runtime>::insert(
+				5u32,
+				AccountId::from(Alice),
+				pallet_parachain_staking::CollatorSnapshot {
+					bond: 600,
+					delegations: vec![
+						pallet_parachain_staking::BondWithAutoCompound {
+							owner: Charlie.into(),
+							amount: 1_000,
+							auto_compound: Percent::from_percent(0),
+						},
+						pallet_parachain_staking::BondWithAutoCompound {
+							owner: David.into(),
+							amount: 400,
+							auto_compound: Percent::from_percent(0),
+						},
+					],
+					total: 2_000,
+				},
+			);
+			pallet_parachain_staking::AtStake::<Runtime>::insert(
+				5u32,
+				AccountId::from(Bob),
+				pallet_parachain_staking::CollatorSnapshot {
+					bond: 1_000,
+					delegations: vec![],
+					total: 1_000,
+				},
+			);
+
+			// Alice gets half of the 700 staking rewards, of which 100 are her commission
+			// (20% of half the issuance) and the remaining 250 are shared by stake.
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::get_pending_rewards {
+						delegator: Address(Charlie.into()),
+						rounds: vec![5].into(),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(U256::from(125));
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::get_pending_rewards {
+						delegator: Address(David.into()),
+						rounds: vec![4, 5].into(),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(U256::from(50));
+		});
+}
+
 #[test]
 fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
 	check_precompile_implements_solidity_interfaces(
@@ -1703,6 +2110,13 @@ fn test_solidity_interface_has_all_function_selectors_documented_and_implemented
 	)
 }
 
+#[test]
+fn test_generated_interface_matches_solidity_interface() {
+	check_precompile_generated_interface::<crate::ParachainStakingPrecompile<Runtime>>(&[
+		"StakingInterface.sol",
+	])
+}
+
 #[test]
 fn test_deprecated_solidity_selectors_are_supported() {
 	for deprecated_function in [
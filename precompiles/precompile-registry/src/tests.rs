@@ -200,3 +200,10 @@ fn test_solidity_interface() {
 		PCall::supports_selector,
 	)
 }
+
+#[test]
+fn test_generated_interface_matches_solidity_interface() {
+	check_precompile_generated_interface::<crate::PrecompileRegistry<Runtime>>(&[
+		"PrecompileRegistry.sol",
+	])
+}
@@ -46,6 +46,11 @@ fn test_solidity_interface_has_all_function_selectors_documented_and_implemented
 	check_precompile_implements_solidity_interfaces(&["Preimage.sol"], PCall::supports_selector)
 }
 
+#[test]
+fn test_generated_interface_matches_solidity_interface() {
+	check_precompile_generated_interface::<crate::PreimagePrecompile<Runtime>>(&["Preimage.sol"])
+}
+
 #[test]
 fn note_unnote_preimage_logs_work() {
 	ExtBuilder::default()
@@ -77,6 +77,55 @@ interface Proxy {
         bytes memory callData
     ) external payable;
 
+    /// @dev Spawn a fresh new account that is guaranteed to be otherwise inaccessible, and
+    /// initialize it with a proxy of `proxyType` for the sender
+    /// The pure account cannot call this precompile, it must be killed with the Substrate
+    /// `proxy.killPure` call dispatched through `proxy.proxy`
+    /// @custom:selector f273fc97
+    /// @param proxyType The type of the proxy that the sender will be registered as over the new account
+    /// @param delay The announcement period required of the initial proxy
+    /// @param index A disambiguation index, in case this is called multiple times in the same transaction
+    /// @return pure The address of the new pure account
+    function createPure(
+        ProxyType proxyType,
+        uint32 delay,
+        uint16 index
+    ) external returns (address pure);
+
+    /// @dev Publish the hash of a subcall that will be made on behalf of `real` with
+    /// `proxyAnnounced` once the delay of the proxy has elapsed.
+    /// The hash is `keccak256(abi.encode(callTo, value, callData))`
+    /// @custom:selector 67002de9
+    /// @param real The account that the proxy will make a call on behalf of
+    /// @param callHash The hash of the subcall to be made
+    function announce(address real, bytes32 callHash) external;
+
+    /// @dev Remove an announcement made by the sender
+    /// @custom:selector 7dd11b98
+    /// @param real The account that the proxy would have made a call on behalf of
+    /// @param callHash The hash of the announced subcall
+    function removeAnnouncement(address real, bytes32 callHash) external;
+
+    /// @dev Remove an announcement made by a proxy of the sender, vetoing the subcall
+    /// @custom:selector 6a5ee060
+    /// @param delegate The account that made the announcement
+    /// @param callHash The hash of the announced subcall
+    function rejectAnnouncement(address delegate, bytes32 callHash) external;
+
+    /// @dev Dispatch the given subcall (`callTo`, `callData`) on behalf of `real` through a
+    /// proxy `delegate` that announced it at least the proxy delay ago. Callable by anyone
+    /// @custom:selector 0522d871
+    /// @param delegate The proxy account that announced the subcall
+    /// @param real The account that the proxy will make a call on behalf of
+    /// @param callTo Recipient of the call to be made by the `real` account
+    /// @param callData Data of the call to be made by the `real` account
+    function proxyAnnounced(
+        address delegate,
+        address real,
+        address callTo,
+        bytes memory callData
+    ) external payable;
+
     /// @dev Checks if the caller has an account proxied with a given proxy type
     /// @custom:selector e26d38ed
     /// @param real The real account that maybe has a proxy
@@ -22,13 +22,14 @@ use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
 use pallet_balances::Call as BalancesCall;
 use pallet_evm::AddressMapping;
 use pallet_proxy::Call as ProxyCall;
+use pallet_proxy::CallHashOf;
 use pallet_proxy::Pallet as ProxyPallet;
 use precompile_utils::precompile_set::{self, AddressType, SelectorFilter};
 use precompile_utils::prelude::*;
-use sp_core::{Get, H160, U256};
+use sp_core::{Get, H160, H256, U256};
 use sp_runtime::{
 	codec::Decode,
-	traits::{ConstU32, Dispatchable, StaticLookup, Zero},
+	traits::{ConstU32, Dispatchable, Saturating, StaticLookup, Zero},
 };
 use sp_std::marker::PhantomData;
 
@@ -54,6 +55,8 @@ where
 	<Runtime as frame_system::Config>::RuntimeCall:
 		From<ProxyCall<Runtime>> + From<BalancesCall<Runtime>>,
 	<Runtime as pallet_balances::Config<()>>::Balance: TryFrom<U256> + Into<U256>,
+	Runtime::AccountId: Into<H160>,
+	CallHashOf<Runtime>: From<H256>,
 {
 	fn is_allowed(_caller: H160, selector: Option<u32>) -> bool {
 		match selector {
@@ -86,21 +89,27 @@ where
 	<Runtime as frame_system::Config>::RuntimeCall:
 		From<ProxyCall<Runtime>> + From<BalancesCall<Runtime>>,
 	<Runtime as pallet_balances::Config<()>>::Balance: TryFrom<U256> + Into<U256>,
+	Runtime::AccountId: Into<H160>,
+	CallHashOf<Runtime>: From<H256>,
 {
 	fn is_allowed(_caller: H160, selector: Option<u32>) -> bool {
 		match selector {
 			None => false,
-			Some(selector) => {
-				ProxyPrecompileCall::<Runtime>::is_proxy_selectors().contains(&selector)
-					|| ProxyPrecompileCall::<Runtime>::proxy_selectors().contains(&selector)
-					|| ProxyPrecompileCall::<Runtime>::proxy_force_type_selectors()
-						.contains(&selector)
-			}
+			Some(selector) => [
+				ProxyPrecompileCall::<Runtime>::is_proxy_selectors(),
+				ProxyPrecompileCall::<Runtime>::proxy_selectors(),
+				ProxyPrecompileCall::<Runtime>::proxy_force_type_selectors(),
+				ProxyPrecompileCall::<Runtime>::proxy_announced_selectors(),
+			]
+			.iter()
+			.any(|selectors| selectors.contains(&selector)),
 		}
 	}
 
 	fn description() -> String {
-		"Allowed for all callers only for selectors 'is_proxy', 'proxy', 'proxy_force_type'".into()
+		"Allowed for all callers only for selectors 'is_proxy', 'proxy', 'proxy_force_type', \
+		'proxy_announced'"
+			.into()
 	}
 }
 
@@ -114,6 +123,17 @@ pub struct EvmSubCall {
 	pub call_data: BoundedBytes<ConstU32<CALL_DATA_LIMIT>>,
 }
 
+/// Hash identifying an evm subcall in proxy announcements, which is the Keccak of
+/// `abi.encode(to, value, callData)`.
+pub fn evm_subcall_hash(evm_subcall: &EvmSubCall) -> H256 {
+	sp_io::hashing::keccak_256(&solidity::encode_arguments((
+		evm_subcall.to,
+		evm_subcall.value,
+		evm_subcall.call_data.clone(),
+	)))
+	.into()
+}
+
 /// A trait to filter if an evm subcall is allowed to be executed by a proxy account.
 /// This trait should be implemented by the `ProxyType` type configured in pallet proxy.
 pub trait EvmProxyCallFilter: Sized + Send + Sync {
@@ -147,6 +167,8 @@ where
 	<Runtime as frame_system::Config>::RuntimeCall:
 		From<ProxyCall<Runtime>> + From<BalancesCall<Runtime>>,
 	<Runtime as pallet_balances::Config<()>>::Balance: TryFrom<U256> + Into<U256>,
+	Runtime::AccountId: Into<H160>,
+	CallHashOf<Runtime>: From<H256>,
 {
 	/// Register a proxy account for the sender that is able to make calls on its behalf.
 	/// The dispatch origin for this call must be Signed.
@@ -346,6 +368,192 @@ where
 		Ok(is_proxy)
 	}
 
+	/// Spawn a fresh new account that is guaranteed to be otherwise inaccessible, and
+	/// initialize it with a proxy of `proxy_type` for the sender.
+	/// The dispatch origin for this call must be Signed.
+	/// The pure account cannot call this precompile, it must be killed with
+	/// `pallet_proxy::kill_pure` through a `pallet_proxy::proxy` extrinsic.
+	///
+	/// Parameters:
+	/// * proxy_type: The type of the proxy that the sender will be registered as over the new
+	/// account.
+	/// * delay: The announcement period required of the initial proxy.
+	/// * index: A disambiguation index, in case this is called multiple times in the same
+	/// transaction.
+	#[precompile::public("createPure(uint8,uint32,uint16)")]
+	fn create_pure(
+		handle: &mut impl PrecompileHandle,
+		proxy_type: u8,
+		delay: u32,
+		index: u16,
+	) -> EvmResult<Address> {
+		let proxy_type = Runtime::ProxyType::decode(&mut proxy_type.to_le_bytes().as_slice())
+			.map_err(|_| {
+				RevertReason::custom("Failed decoding value to ProxyType").in_field("proxyType")
+			})?;
+		let delay = delay.into();
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let pure = ProxyPallet::<Runtime>::pure_account(&origin, &proxy_type, index, None);
+
+		let call: ProxyCall<Runtime> = ProxyCall::<Runtime>::create_pure {
+			proxy_type,
+			delay,
+			index,
+		}
+		.into();
+
+		<RuntimeHelper<Runtime>>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+
+		Ok(Address(pure.into()))
+	}
+
+	/// Publish the hash of a proxied subcall that will be made in the future, so it can be
+	/// executed with `proxyAnnounced` once the delay of the proxy has elapsed.
+	/// The dispatch origin for this call must be Signed.
+	///
+	/// Parameters:
+	/// * real: The account that the proxy will make a call on behalf of.
+	/// * call_hash: The hash of the subcall to be made, see `evm_subcall_hash`.
+	#[precompile::public("announce(address,bytes32)")]
+	fn announce(handle: &mut impl PrecompileHandle, real: Address, call_hash: H256) -> EvmResult {
+		let real = Runtime::AddressMapping::into_account_id(real.into());
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call: ProxyCall<Runtime> = ProxyCall::<Runtime>::announce {
+			real: Runtime::Lookup::unlookup(real),
+			call_hash: call_hash.into(),
+		}
+		.into();
+
+		<RuntimeHelper<Runtime>>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+
+		Ok(())
+	}
+
+	/// Remove a given announcement made by the sender.
+	/// The dispatch origin for this call must be Signed.
+	///
+	/// Parameters:
+	/// * real: The account that the proxy would have made a call on behalf of.
+	/// * call_hash: The hash of the announced subcall.
+	#[precompile::public("removeAnnouncement(address,bytes32)")]
+	fn remove_announcement(
+		handle: &mut impl PrecompileHandle,
+		real: Address,
+		call_hash: H256,
+	) -> EvmResult {
+		let real = Runtime::AddressMapping::into_account_id(real.into());
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call: ProxyCall<Runtime> = ProxyCall::<Runtime>::remove_announcement {
+			real: Runtime::Lookup::unlookup(real),
+			call_hash: call_hash.into(),
+		}
+		.into();
+
+		<RuntimeHelper<Runtime>>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+
+		Ok(())
+	}
+
+	/// Remove an announcement made by one of the proxies of the sender, vetoing the subcall.
+	/// The dispatch origin for this call must be Signed.
+	///
+	/// Parameters:
+	/// * delegate: The account that made the announcement.
+	/// * call_hash: The hash of the announced subcall.
+	#[precompile::public("rejectAnnouncement(address,bytes32)")]
+	fn reject_announcement(
+		handle: &mut impl PrecompileHandle,
+		delegate: Address,
+		call_hash: H256,
+	) -> EvmResult {
+		let delegate = Runtime::AddressMapping::into_account_id(delegate.into());
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call: ProxyCall<Runtime> = ProxyCall::<Runtime>::reject_announcement {
+			delegate: Runtime::Lookup::unlookup(delegate),
+			call_hash: call_hash.into(),
+		}
+		.into();
+
+		<RuntimeHelper<Runtime>>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+
+		Ok(())
+	}
+
+	/// Dispatch the given subcall (`call_to`, `call_data`) on behalf of `real` through a proxy
+	/// `delegate` that announced it at least the proxy delay ago. The announcement is removed.
+	/// It can be executed by any account.
+	///
+	/// Parameters:
+	/// - `delegate`: The proxy account that announced the subcall.
+	/// - `real`: The account that the proxy will make a call on behalf of.
+	/// - `call_to`: Recipient of the call to be made by the `real` account.
+	/// - `call_data`: Data of the call to be made by the `real` account.
+	#[precompile::public("proxyAnnounced(address,address,address,bytes)")]
+	#[precompile::payable]
+	fn proxy_announced(
+		handle: &mut impl PrecompileHandle,
+		delegate: Address,
+		real: Address,
+		call_to: Address,
+		call_data: BoundedBytes<GetCallDataLimit>,
+	) -> EvmResult {
+		// Check that we only perform proxy calls on behalf of externally owned accounts
+		let AddressType::EOA = precompile_set::get_address_type::<Runtime>(handle, real.into())? else {
+			return Err(revert("real address must be EOA"));
+		};
+
+		let evm_subcall = EvmSubCall {
+			to: call_to,
+			value: handle.context().apparent_value,
+			call_data,
+		};
+		let call_hash = evm_subcall_hash(&evm_subcall);
+
+		// Read proxy
+		let real_account_id = Runtime::AddressMapping::into_account_id(real.into());
+		let delegate = Runtime::AddressMapping::into_account_id(delegate.into());
+		// Proxies:
+		// Twox64Concat(8) + AccountId(20) + BoundedVec(ProxyDefinition * MaxProxies) + Balance(16)
+		handle.record_db_read::<Runtime>(
+			28 + (29 * (<Runtime as pallet_proxy::Config>::MaxProxies::get() as usize)) + 8,
+		)?;
+		let def = pallet_proxy::Pallet::<Runtime>::find_proxy(&real_account_id, &delegate, None)
+			.map_err(|_| RevertReason::custom("Not proxy"))?;
+
+		// Read announcements
+		// Announcements:
+		// Twox64Concat(8) + AccountId(20) + BoundedVec(Announcement * MaxPending) + Balance(16)
+		// Announcement: AccountId(20) + CallHash(32) + BlockNumber(4)
+		handle.record_db_read::<Runtime>(
+			28 + (56 * (<Runtime as pallet_proxy::Config>::MaxPending::get() as usize)) + 16,
+		)?;
+		let now = frame_system::Pallet::<Runtime>::block_number();
+		let call_hash: CallHashOf<Runtime> = call_hash.into();
+		let is_announced = ProxyPallet::<Runtime>::announcements(&delegate)
+			.0
+			.iter()
+			.any(|announcement| {
+				announcement.real == real_account_id
+					&& announcement.call_hash == call_hash
+					&& now.saturating_sub(announcement.height) >= def.delay
+			});
+		frame_support::ensure!(is_announced, revert("Unannounced"));
+
+		// Consume the announcement, as the proxy pallet does for announced calls.
+		let call: ProxyCall<Runtime> = ProxyCall::<Runtime>::remove_announcement {
+			real: Runtime::Lookup::unlookup(real_account_id),
+			call_hash,
+		}
+		.into();
+		<RuntimeHelper<Runtime>>::try_dispatch(handle, Some(delegate).into(), call, 0)?;
+
+		Self::proxied_subcall(handle, real, def.proxy_type, evm_subcall)
+	}
+
 	fn inner_proxy(
 		handle: &mut impl PrecompileHandle,
 		real: Address,
@@ -370,6 +578,15 @@ where
 				.map_err(|_| RevertReason::custom("Not proxy"))?;
 		frame_support::ensure!(def.delay.is_zero(), revert("Unannounced"));
 
+		Self::proxied_subcall(handle, real, def.proxy_type, evm_subcall)
+	}
+
+	fn proxied_subcall(
+		handle: &mut impl PrecompileHandle,
+		real: Address,
+		proxy_type: <Runtime as pallet_proxy::Config>::ProxyType,
+		evm_subcall: EvmSubCall,
+	) -> EvmResult {
 		// Read subcall recipient code
 		// AccountCodes: Blake2128(16) + H160(20) + Vec(5)
 		// decode_len reads the first 5 bytes to find the payload len under this key
@@ -379,7 +596,7 @@ where
 
 		// Apply proxy type filter
 		frame_support::ensure!(
-			def.proxy_type.is_evm_proxy_call_allowed(
+			proxy_type.is_evm_proxy_call_allowed(
 				&evm_subcall,
 				recipient_has_code,
 				handle.remaining_gas()
@@ -405,6 +622,7 @@ where
 		} else {
 			let contract_address: Runtime::AccountId =
 				Runtime::AddressMapping::into_account_id(handle.context().address);
+			let who = Runtime::AddressMapping::into_account_id(handle.context().caller);
 
 			// Send back funds received by the precompile.
 			RuntimeHelper::<Runtime>::try_dispatch(
@@ -16,8 +16,9 @@
 
 use crate::mock::{
 	AccountId, ExtBuilder, PCall, PrecompilesValue, ProxyType, Runtime, RuntimeCall, RuntimeEvent,
-	RuntimeOrigin,
+	RuntimeOrigin, System,
 };
+use crate::{evm_subcall_hash, EvmSubCall};
 use frame_support::assert_ok;
 use pallet_evm::Call as EvmCall;
 use pallet_proxy::{
@@ -57,6 +58,11 @@ fn selectors() {
 	assert!(PCall::proxy_selectors().contains(&0x0d3cff86));
 	assert!(PCall::proxy_force_type_selectors().contains(&0x4a36b2cd));
 	assert!(PCall::is_proxy_selectors().contains(&0xe26d38ed));
+	assert!(PCall::create_pure_selectors().contains(&0xf273fc97));
+	assert!(PCall::announce_selectors().contains(&0x67002de9));
+	assert!(PCall::remove_announcement_selectors().contains(&0x7dd11b98));
+	assert!(PCall::reject_announcement_selectors().contains(&0x6a5ee060));
+	assert!(PCall::proxy_announced_selectors().contains(&0x0522d871));
 }
 
 #[test]
@@ -71,6 +77,11 @@ fn modifiers() {
 		tester.test_payable_modifier(PCall::proxy_selectors());
 		tester.test_payable_modifier(PCall::proxy_force_type_selectors());
 		tester.test_view_modifier(PCall::is_proxy_selectors());
+		tester.test_default_modifier(PCall::create_pure_selectors());
+		tester.test_default_modifier(PCall::announce_selectors());
+		tester.test_default_modifier(PCall::remove_announcement_selectors());
+		tester.test_default_modifier(PCall::reject_announcement_selectors());
+		tester.test_payable_modifier(PCall::proxy_announced_selectors());
 	});
 }
 
@@ -833,7 +844,217 @@ fn proxy_proxy_should_fail_if_called_by_smart_contract_for_a_non_eoa_account() {
 		})
 }
 
+#[test]
+fn test_create_pure_succeeds() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			let pure: AccountId =
+				ProxyPallet::<Runtime>::pure_account(&Alice.into(), &ProxyType::Something, 0, None);
+
+			PrecompilesValue::get()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::create_pure {
+						proxy_type: ProxyType::Something as u8,
+						delay: 0,
+						index: 0,
+					},
+				)
+				.execute_returns(Address(pure.into()));
+
+			let proxies = <ProxyPallet<Runtime>>::proxies(pure).0;
+			assert_eq!(
+				proxies,
+				vec![ProxyDefinition {
+					delegate: Alice.into(),
+					proxy_type: ProxyType::Something,
+					delay: 0,
+				}],
+			)
+		})
+}
+
+#[test]
+fn test_create_pure_fails_if_invalid_value_for_proxy_type() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			PrecompilesValue::get()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::create_pure {
+						proxy_type: 10,
+						delay: 0,
+						index: 0,
+					},
+				)
+				.execute_reverts(|o| o == b"proxyType: Failed decoding value to ProxyType");
+		})
+}
+
+#[test]
+fn test_proxy_announced_fails_if_not_announced() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000), (Bob.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(RuntimeCall::Proxy(ProxyCall::add_proxy {
+				delegate: Bob.into(),
+				proxy_type: ProxyType::Any,
+				delay: 2,
+			})
+			.dispatch(RuntimeOrigin::signed(Alice.into())));
+
+			PrecompilesValue::get()
+				.prepare_test(
+					Charlie,
+					Precompile1,
+					PCall::proxy_announced {
+						delegate: Address(Bob.into()),
+						real: Address(Alice.into()),
+						call_to: Address(Charlie.into()),
+						call_data: BoundedBytes::from([1]),
+					},
+				)
+				.execute_reverts(|o| o == b"Unannounced");
+		})
+}
+
+#[test]
+fn test_proxy_announced_succeeds_after_delay() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000), (Bob.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(RuntimeCall::Proxy(ProxyCall::add_proxy {
+				delegate: Bob.into(),
+				proxy_type: ProxyType::Any,
+				delay: 2,
+			})
+			.dispatch(RuntimeOrigin::signed(Alice.into())));
+
+			let call_hash = evm_subcall_hash(&EvmSubCall {
+				to: Address(Charlie.into()),
+				value: U256::zero(),
+				call_data: BoundedBytes::from([1]),
+			});
+
+			PrecompilesValue::get()
+				.prepare_test(
+					Bob,
+					Precompile1,
+					PCall::announce {
+						real: Address(Alice.into()),
+						call_hash,
+					},
+				)
+				.execute_returns(());
+
+			// The delay has not elapsed yet
+			PrecompilesValue::get()
+				.prepare_test(
+					Charlie,
+					Precompile1,
+					PCall::proxy_announced {
+						delegate: Address(Bob.into()),
+						real: Address(Alice.into()),
+						call_to: Address(Charlie.into()),
+						call_data: BoundedBytes::from([1]),
+					},
+				)
+				.execute_reverts(|o| o == b"Unannounced");
+
+			System::set_block_number(3);
+
+			let inside = Rc::new(Cell::new(false));
+			let inside2 = inside.clone();
+
+			PrecompilesValue::get()
+				.prepare_test(
+					Charlie,
+					Precompile1,
+					PCall::proxy_announced {
+						delegate: Address(Bob.into()),
+						real: Address(Alice.into()),
+						call_to: Address(Charlie.into()),
+						call_data: BoundedBytes::from([1]),
+					},
+				)
+				.with_subcall_handle(move |subcall| {
+					assert_eq!(subcall.context.caller, Alice.into());
+					assert_eq!(subcall.address, Charlie.into());
+					assert_eq!(&subcall.input, &[1]);
+
+					inside2.set(true);
+
+					SubcallOutput::succeed()
+				})
+				.execute_returns(());
+
+			assert!(inside.get(), "subcall not called");
+			assert!(ProxyPallet::<Runtime>::announcements(AccountId::from(Bob))
+				.0
+				.is_empty());
+		})
+}
+
+#[test]
+fn test_reject_announcement_succeeds() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000), (Bob.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(RuntimeCall::Proxy(ProxyCall::add_proxy {
+				delegate: Bob.into(),
+				proxy_type: ProxyType::Any,
+				delay: 2,
+			})
+			.dispatch(RuntimeOrigin::signed(Alice.into())));
+
+			PrecompilesValue::get()
+				.prepare_test(
+					Bob,
+					Precompile1,
+					PCall::announce {
+						real: Address(Alice.into()),
+						call_hash: H256::repeat_byte(0x11),
+					},
+				)
+				.execute_returns(());
+			assert_eq!(
+				ProxyPallet::<Runtime>::announcements(AccountId::from(Bob))
+					.0
+					.len(),
+				1
+			);
+
+			PrecompilesValue::get()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::reject_announcement {
+						delegate: Address(Bob.into()),
+						call_hash: H256::repeat_byte(0x11),
+					},
+				)
+				.execute_returns(());
+			assert!(ProxyPallet::<Runtime>::announcements(AccountId::from(Bob))
+				.0
+				.is_empty());
+		})
+}
+
 #[test]
 fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
 	check_precompile_implements_solidity_interfaces(&["Proxy.sol"], PCall::supports_selector)
 }
+
+#[test]
+fn test_generated_interface_matches_solidity_interface() {
+	check_precompile_generated_interface::<crate::ProxyPrecompile<Runtime>>(&["Proxy.sol"])
+}
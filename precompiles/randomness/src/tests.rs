@@ -74,6 +74,13 @@ fn test_solidity_interface_has_all_function_selectors_documented_and_implemented
 	check_precompile_implements_solidity_interfaces(&["Randomness.sol"], PCall::supports_selector)
 }
 
+#[test]
+fn test_generated_interface_matches_solidity_interface() {
+	check_precompile_generated_interface::<crate::RandomnessPrecompile<Runtime>>(&[
+		"Randomness.sol",
+	])
+}
+
 #[test]
 fn relay_epoch_index_works() {
 	ExtBuilder::default().build().execute_with(|| {
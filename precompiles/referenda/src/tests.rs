@@ -50,6 +50,13 @@ fn test_solidity_interface_has_all_function_selectors_documented_and_implemented
 	check_precompile_implements_solidity_interfaces(&["Referenda.sol"], PCall::supports_selector)
 }
 
+#[test]
+fn test_generated_interface_matches_solidity_interface() {
+	check_precompile_generated_interface::<crate::ReferendaPrecompile<Runtime, GovOrigin>>(&[
+		"Referenda.sol",
+	])
+}
+
 #[test]
 fn submitted_at_logs_work() {
 	ExtBuilder::default()
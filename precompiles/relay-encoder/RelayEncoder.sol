@@ -132,4 +132,143 @@ interface RelayEncoder {
         uint32 recipient,
         uint32 openRequests
     ) external pure returns (bytes memory result);
+
+    /// @dev Encode 'nominationPools.join' relay call
+    /// @custom:selector 3c5dd983
+    /// @param amount: The amount to bond into the pool
+    /// @param poolId: The id of the pool to join
+    /// @return result The bytes associated with the encoded call
+    function encodePoolJoin(
+        uint256 amount,
+        uint32 poolId
+    ) external pure returns (bytes memory result);
+
+    /// @dev Encode 'nominationPools.bond_extra' relay call, bonding free balance
+    /// @custom:selector a12b5f1e
+    /// @param amount: The extra amount to bond into the pool
+    /// @return result The bytes associated with the encoded call
+    function encodePoolBondExtra(
+        uint256 amount
+    ) external pure returns (bytes memory result);
+
+    /// @dev Encode 'nominationPools.bond_extra' relay call, bonding the pending rewards
+    /// @custom:selector d5930060
+    /// @return result The bytes associated with the encoded call
+    function encodePoolBondExtraRewards()
+        external
+        pure
+        returns (bytes memory result);
+
+    /// @dev Encode 'nominationPools.unbond' relay call
+    /// @custom:selector d460f714
+    /// @param memberAccount: The relay account of the pool member to unbond
+    /// @param unbondingPoints: The amount of points to unbond
+    /// @return result The bytes associated with the encoded call
+    function encodePoolUnbond(
+        bytes32 memberAccount,
+        uint256 unbondingPoints
+    ) external pure returns (bytes memory result);
+
+    /// @dev Encode 'nominationPools.claim_payout' relay call
+    /// @custom:selector d6876ceb
+    /// @return result The bytes associated with the encoded call
+    function encodePoolClaimPayout() external pure returns (bytes memory result);
+
+    /// @dev Encode 'convictionVoting.vote' relay call with a standard vote
+    /// @custom:selector e9829ad9
+    /// @param pollIndex: The index of the referendum
+    /// @param aye: Whether the vote is aye or nay
+    /// @param conviction: The conviction of the vote, from 0 (None) to 6 (Locked6x)
+    /// @param amount: The amount to vote with
+    /// @return result The bytes associated with the encoded call
+    function encodeStandardVote(
+        uint32 pollIndex,
+        bool aye,
+        uint8 conviction,
+        uint256 amount
+    ) external pure returns (bytes memory result);
+
+    /// @dev Encode 'convictionVoting.vote' relay call with a split vote
+    /// @custom:selector 2ed35411
+    /// @param pollIndex: The index of the referendum
+    /// @param aye: The amount to vote aye with
+    /// @param nay: The amount to vote nay with
+    /// @return result The bytes associated with the encoded call
+    function encodeSplitVote(
+        uint32 pollIndex,
+        uint256 aye,
+        uint256 nay
+    ) external pure returns (bytes memory result);
+
+    /// @dev Encode 'convictionVoting.vote' relay call with a split abstain vote
+    /// @custom:selector bb2ad8ba
+    /// @param pollIndex: The index of the referendum
+    /// @param aye: The amount to vote aye with
+    /// @param nay: The amount to vote nay with
+    /// @param abstain: The amount to abstain with
+    /// @return result The bytes associated with the encoded call
+    function encodeSplitAbstainVote(
+        uint32 pollIndex,
+        uint256 aye,
+        uint256 nay,
+        uint256 abstain
+    ) external pure returns (bytes memory result);
+
+    /// @dev Encode 'convictionVoting.remove_vote' relay call
+    /// @custom:selector ce852a38
+    /// @param trackId: The track of the referendum
+    /// @param pollIndex: The index of the referendum
+    /// @return result The bytes associated with the encoded call
+    function encodeRemoveVote(
+        uint16 trackId,
+        uint32 pollIndex
+    ) external pure returns (bytes memory result);
+
+    /// @dev Encode 'convictionVoting.delegate' relay call
+    /// @custom:selector a5961bdb
+    /// @param trackId: The track to delegate votes for
+    /// @param to: The relay account to delegate to
+    /// @param conviction: The conviction of the delegation, from 0 (None) to 6 (Locked6x)
+    /// @param amount: The amount to delegate
+    /// @return result The bytes associated with the encoded call
+    function encodeDelegate(
+        uint16 trackId,
+        bytes32 to,
+        uint8 conviction,
+        uint256 amount
+    ) external pure returns (bytes memory result);
+
+    /// @dev Encode 'proxy.proxy' relay call
+    /// @custom:selector 9736fbde
+    /// @param real: The relay account on behalf of which the call is dispatched
+    /// @param call: The encoded relay call to dispatch
+    /// @return result The bytes associated with the encoded call
+    function encodeProxy(
+        bytes32 real,
+        bytes memory call
+    ) external pure returns (bytes memory result);
+
+    /// @dev Encode 'proxy.add_proxy' relay call
+    /// @custom:selector d951fc0b
+    /// @param delegate: The relay account to register as proxy
+    /// @param proxyType: The index of the relay proxy type
+    /// @param delay: The announcement period required for the proxy, in relay blocks
+    /// @return result The bytes associated with the encoded call
+    function encodeAddProxy(
+        bytes32 delegate,
+        uint8 proxyType,
+        uint32 delay
+    ) external pure returns (bytes memory result);
+
+    /// @dev Encode 'proxy.remove_proxy' relay call
+    /// @custom:selector fbfac19c
+    /// @param delegate: The relay account registered as proxy
+    /// @param proxyType: The index of the relay proxy type
+    /// @param delay: The announcement period of the proxy, in relay blocks
+    /// @return result The bytes associated with the encoded call
+    function encodeRemoveProxy(
+        bytes32 delegate,
+        uint8 proxyType,
+        uint32 delay
+    ) external pure returns (bytes memory result);
 }
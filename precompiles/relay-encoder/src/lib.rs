@@ -32,7 +32,12 @@ use sp_core::{H256, U256};
 use sp_runtime::{traits::Dispatchable, AccountId32, Perbill};
 use sp_std::vec::Vec;
 use sp_std::{convert::TryInto, marker::PhantomData};
-use xcm_primitives::{AvailableStakeCalls, HrmpAvailableCalls, HrmpEncodeCall, StakeEncodeCall};
+use xcm_primitives::{
+	AvailableConvictionVotingCalls, AvailableNominationPoolsCalls, AvailableProxyCalls,
+	AvailableStakeCalls, ConvictionVotingEncodeCall, HrmpAvailableCalls, HrmpEncodeCall,
+	NominationPoolsEncodeCall, PoolBondExtra, ProxyEncodeCall, RelayAccountVote, RelayConviction,
+	RelayVote, StakeEncodeCall,
+};
 
 #[cfg(test)]
 mod mock;
@@ -43,7 +48,9 @@ mod tests;
 
 pub const REWARD_DESTINATION_SIZE_LIMIT: u32 = 2u32.pow(16);
 pub const ARRAY_LIMIT: u32 = 512;
+pub const CALL_DATA_LIMIT: u32 = 2u32.pow(16);
 type GetArrayLimit = ConstU32<ARRAY_LIMIT>;
+type GetCallDataLimit = ConstU32<CALL_DATA_LIMIT>;
 type GetRewardDestinationSizeLimit = ConstU32<REWARD_DESTINATION_SIZE_LIMIT>;
 
 /// A precompile to provide relay stake calls encoding through evm
@@ -375,6 +382,293 @@ where
 		.into();
 		Ok(encoded)
 	}
+	#[precompile::public("encodePoolJoin(uint256,uint32)")]
+	#[precompile::view]
+	fn encode_pool_join(
+		handle: &mut impl PrecompileHandle,
+		amount: U256,
+		pool_id: u32,
+	) -> EvmResult<UnboundedBytes> {
+		// No DB access but lot of logical stuff
+		// To prevent spam, we charge an arbitrary amount of gas
+		handle.record_cost(1000)?;
+
+		let relay_amount = u256_to_relay_amount(amount)?;
+		let encoded = pallet_xcm_transactor::Pallet::<Runtime>::nomination_pools_encode_call(
+			AvailableNominationPoolsCalls::Join(relay_amount, pool_id),
+		)
+		.as_slice()
+		.into();
+
+		Ok(encoded)
+	}
+
+	#[precompile::public("encodePoolBondExtra(uint256)")]
+	#[precompile::view]
+	fn encode_pool_bond_extra(
+		handle: &mut impl PrecompileHandle,
+		amount: U256,
+	) -> EvmResult<UnboundedBytes> {
+		// No DB access but lot of logical stuff
+		// To prevent spam, we charge an arbitrary amount of gas
+		handle.record_cost(1000)?;
+
+		let relay_amount = u256_to_relay_amount(amount)?;
+		let encoded = pallet_xcm_transactor::Pallet::<Runtime>::nomination_pools_encode_call(
+			AvailableNominationPoolsCalls::BondExtra(PoolBondExtra::FreeBalance(relay_amount)),
+		)
+		.as_slice()
+		.into();
+
+		Ok(encoded)
+	}
+
+	#[precompile::public("encodePoolBondExtraRewards()")]
+	#[precompile::view]
+	fn encode_pool_bond_extra_rewards(
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<UnboundedBytes> {
+		// No DB access but lot of logical stuff
+		// To prevent spam, we charge an arbitrary amount of gas
+		handle.record_cost(1000)?;
+
+		let encoded = pallet_xcm_transactor::Pallet::<Runtime>::nomination_pools_encode_call(
+			AvailableNominationPoolsCalls::BondExtra(PoolBondExtra::Rewards),
+		)
+		.as_slice()
+		.into();
+
+		Ok(encoded)
+	}
+
+	#[precompile::public("encodePoolUnbond(bytes32,uint256)")]
+	#[precompile::view]
+	fn encode_pool_unbond(
+		handle: &mut impl PrecompileHandle,
+		member_account: H256,
+		unbonding_points: U256,
+	) -> EvmResult<UnboundedBytes> {
+		// No DB access but lot of logical stuff
+		// To prevent spam, we charge an arbitrary amount of gas
+		handle.record_cost(1000)?;
+
+		let member_account: [u8; 32] = member_account.into();
+		let unbonding_points = u256_to_relay_amount(unbonding_points)?;
+		let encoded = pallet_xcm_transactor::Pallet::<Runtime>::nomination_pools_encode_call(
+			AvailableNominationPoolsCalls::Unbond(member_account.into(), unbonding_points),
+		)
+		.as_slice()
+		.into();
+
+		Ok(encoded)
+	}
+
+	#[precompile::public("encodePoolClaimPayout()")]
+	#[precompile::view]
+	fn encode_pool_claim_payout(handle: &mut impl PrecompileHandle) -> EvmResult<UnboundedBytes> {
+		// No DB access but lot of logical stuff
+		// To prevent spam, we charge an arbitrary amount of gas
+		handle.record_cost(1000)?;
+
+		let encoded = pallet_xcm_transactor::Pallet::<Runtime>::nomination_pools_encode_call(
+			AvailableNominationPoolsCalls::ClaimPayout,
+		)
+		.as_slice()
+		.into();
+
+		Ok(encoded)
+	}
+
+	#[precompile::public("encodeStandardVote(uint32,bool,uint8,uint256)")]
+	#[precompile::view]
+	fn encode_standard_vote(
+		handle: &mut impl PrecompileHandle,
+		poll_index: u32,
+		aye: bool,
+		conviction: u8,
+		amount: U256,
+	) -> EvmResult<UnboundedBytes> {
+		// No DB access but lot of logical stuff
+		// To prevent spam, we charge an arbitrary amount of gas
+		handle.record_cost(1000)?;
+
+		let vote = RelayAccountVote::Standard {
+			vote: RelayVote {
+				aye,
+				conviction: u8_to_relay_conviction(conviction)?,
+			},
+			balance: u256_to_relay_amount(amount)?,
+		};
+		let encoded = pallet_xcm_transactor::Pallet::<Runtime>::conviction_voting_encode_call(
+			AvailableConvictionVotingCalls::Vote(poll_index, vote),
+		)
+		.as_slice()
+		.into();
+
+		Ok(encoded)
+	}
+
+	#[precompile::public("encodeSplitVote(uint32,uint256,uint256)")]
+	#[precompile::view]
+	fn encode_split_vote(
+		handle: &mut impl PrecompileHandle,
+		poll_index: u32,
+		aye: U256,
+		nay: U256,
+	) -> EvmResult<UnboundedBytes> {
+		// No DB access but lot of logical stuff
+		// To prevent spam, we charge an arbitrary amount of gas
+		handle.record_cost(1000)?;
+
+		let vote = RelayAccountVote::Split {
+			aye: u256_to_relay_amount(aye)?,
+			nay: u256_to_relay_amount(nay)?,
+		};
+		let encoded = pallet_xcm_transactor::Pallet::<Runtime>::conviction_voting_encode_call(
+			AvailableConvictionVotingCalls::Vote(poll_index, vote),
+		)
+		.as_slice()
+		.into();
+
+		Ok(encoded)
+	}
+
+	#[precompile::public("encodeSplitAbstainVote(uint32,uint256,uint256,uint256)")]
+	#[precompile::view]
+	fn encode_split_abstain_vote(
+		handle: &mut impl PrecompileHandle,
+		poll_index: u32,
+		aye: U256,
+		nay: U256,
+		abstain: U256,
+	) -> EvmResult<UnboundedBytes> {
+		// No DB access but lot of logical stuff
+		// To prevent spam, we charge an arbitrary amount of gas
+		handle.record_cost(1000)?;
+
+		let vote = RelayAccountVote::SplitAbstain {
+			aye: u256_to_relay_amount(aye)?,
+			nay: u256_to_relay_amount(nay)?,
+			abstain: u256_to_relay_amount(abstain)?,
+		};
+		let encoded = pallet_xcm_transactor::Pallet::<Runtime>::conviction_voting_encode_call(
+			AvailableConvictionVotingCalls::Vote(poll_index, vote),
+		)
+		.as_slice()
+		.into();
+
+		Ok(encoded)
+	}
+
+	#[precompile::public("encodeRemoveVote(uint16,uint32)")]
+	#[precompile::view]
+	fn encode_remove_vote(
+		handle: &mut impl PrecompileHandle,
+		track_id: u16,
+		poll_index: u32,
+	) -> EvmResult<UnboundedBytes> {
+		// No DB access but lot of logical stuff
+		// To prevent spam, we charge an arbitrary amount of gas
+		handle.record_cost(1000)?;
+
+		let encoded = pallet_xcm_transactor::Pallet::<Runtime>::conviction_voting_encode_call(
+			AvailableConvictionVotingCalls::RemoveVote(Some(track_id), poll_index),
+		)
+		.as_slice()
+		.into();
+
+		Ok(encoded)
+	}
+
+	#[precompile::public("encodeDelegate(uint16,bytes32,uint8,uint256)")]
+	#[precompile::view]
+	fn encode_delegate(
+		handle: &mut impl PrecompileHandle,
+		track_id: u16,
+		to: H256,
+		conviction: u8,
+		amount: U256,
+	) -> EvmResult<UnboundedBytes> {
+		// No DB access but lot of logical stuff
+		// To prevent spam, we charge an arbitrary amount of gas
+		handle.record_cost(1000)?;
+
+		let to: [u8; 32] = to.into();
+		let conviction = u8_to_relay_conviction(conviction)?;
+		let relay_amount = u256_to_relay_amount(amount)?;
+		let encoded = pallet_xcm_transactor::Pallet::<Runtime>::conviction_voting_encode_call(
+			AvailableConvictionVotingCalls::Delegate(track_id, to.into(), conviction, relay_amount),
+		)
+		.as_slice()
+		.into();
+
+		Ok(encoded)
+	}
+
+	#[precompile::public("encodeProxy(bytes32,bytes)")]
+	#[precompile::view]
+	fn encode_proxy(
+		handle: &mut impl PrecompileHandle,
+		real: H256,
+		call: BoundedBytes<GetCallDataLimit>,
+	) -> EvmResult<UnboundedBytes> {
+		// No DB access but lot of logical stuff
+		// To prevent spam, we charge an arbitrary amount of gas
+		handle.record_cost(1000)?;
+
+		let real: [u8; 32] = real.into();
+		let encoded = pallet_xcm_transactor::Pallet::<Runtime>::proxy_encode_call(
+			AvailableProxyCalls::Proxy(real.into(), None, call.into()),
+		)
+		.as_slice()
+		.into();
+
+		Ok(encoded)
+	}
+
+	#[precompile::public("encodeAddProxy(bytes32,uint8,uint32)")]
+	#[precompile::view]
+	fn encode_add_proxy(
+		handle: &mut impl PrecompileHandle,
+		delegate: H256,
+		proxy_type: u8,
+		delay: u32,
+	) -> EvmResult<UnboundedBytes> {
+		// No DB access but lot of logical stuff
+		// To prevent spam, we charge an arbitrary amount of gas
+		handle.record_cost(1000)?;
+
+		let delegate: [u8; 32] = delegate.into();
+		let encoded = pallet_xcm_transactor::Pallet::<Runtime>::proxy_encode_call(
+			AvailableProxyCalls::AddProxy(delegate.into(), proxy_type, delay),
+		)
+		.as_slice()
+		.into();
+
+		Ok(encoded)
+	}
+
+	#[precompile::public("encodeRemoveProxy(bytes32,uint8,uint32)")]
+	#[precompile::view]
+	fn encode_remove_proxy(
+		handle: &mut impl PrecompileHandle,
+		delegate: H256,
+		proxy_type: u8,
+		delay: u32,
+	) -> EvmResult<UnboundedBytes> {
+		// No DB access but lot of logical stuff
+		// To prevent spam, we charge an arbitrary amount of gas
+		handle.record_cost(1000)?;
+
+		let delegate: [u8; 32] = delegate.into();
+		let encoded = pallet_xcm_transactor::Pallet::<Runtime>::proxy_encode_call(
+			AvailableProxyCalls::RemoveProxy(delegate.into(), proxy_type, delay),
+		)
+		.as_slice()
+		.into();
+
+		Ok(encoded)
+	}
 }
 
 pub fn u256_to_relay_amount(value: U256) -> EvmResult<relay_chain::Balance> {
@@ -383,6 +677,14 @@ pub fn u256_to_relay_amount(value: U256) -> EvmResult<relay_chain::Balance> {
 		.map_err(|_| revert("amount is too large for provided balance type"))
 }
 
+pub fn u8_to_relay_conviction(value: u8) -> EvmResult<RelayConviction> {
+	RelayConviction::try_from(value).map_err(|_| {
+		RevertReason::custom("Unknown conviction")
+			.in_field("conviction")
+			.into()
+	})
+}
+
 // A wrapper to be able to implement here the solidity::Codec reader
 #[derive(Clone, Eq, PartialEq)]
 pub struct RewardDestinationWrapper(RewardDestination<AccountId32>);
@@ -269,6 +269,29 @@ impl TransactAsset for DummyAssetTransactor {
 	}
 }
 
+parameter_types! {
+	pub ParentLocation: MultiLocation = MultiLocation::parent();
+	pub const TransactStatusQueryTimeout: u32 = 100;
+	pub const MaxTransactStatusQueries: u32 = 3;
+	pub static NextQueryId: QueryId = 0;
+}
+
+pub struct TestQueryRegistrar;
+impl pallet_xcm_transactor::NotifyQueryRegistrar<pallet_xcm_transactor::Call<Runtime>, u32>
+	for TestQueryRegistrar
+{
+	fn new_notify_query(
+		_responder: MultiLocation,
+		_notify: pallet_xcm_transactor::Call<Runtime>,
+		_timeout: u32,
+		_match_querier: MultiLocation,
+	) -> QueryId {
+		let query_id = NextQueryId::get();
+		NextQueryId::set(query_id + 1);
+		query_id
+	}
+}
+
 impl pallet_xcm_transactor::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
@@ -288,6 +311,11 @@ impl pallet_xcm_transactor::Config for Runtime {
 	type WeightInfo = ();
 	type HrmpManipulatorOrigin = frame_system::EnsureRoot<AccountId>;
 	type MaxHrmpFee = ();
+	type QueryRegistrar = TestQueryRegistrar;
+	type ResponseOrigin = frame_system::EnsureRootWithSuccess<AccountId, ParentLocation>;
+	type TransactStatusQueryTimeout = TransactStatusQueryTimeout;
+	type MaxTransactStatusQueries = MaxTransactStatusQueries;
+	type FeePerSecondProvider = ();
 }
 
 pub type Precompiles<R> =
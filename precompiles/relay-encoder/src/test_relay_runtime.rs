@@ -28,6 +28,12 @@ pub enum RelayCall {
 	Stake(StakeCall),
 	#[codec(index = 2u8)]
 	Hrmp(HrmpCall),
+	#[codec(index = 3u8)]
+	NominationPools(NominationPoolsCall),
+	#[codec(index = 4u8)]
+	ConvictionVoting(ConvictionVotingCall),
+	#[codec(index = 5u8)]
+	Proxy(ProxyCall),
 }
 
 #[derive(Encode, Decode)]
@@ -71,6 +77,60 @@ pub enum HrmpCall {
 	CancelOpenChannel(HrmpChannelId, u32),
 }
 
+#[derive(Encode, Decode)]
+pub enum NominationPoolsCall {
+	#[codec(index = 0u8)]
+	Join(
+		#[codec(compact)] cumulus_primitives_core::relay_chain::Balance,
+		u32,
+	),
+	#[codec(index = 1u8)]
+	BondExtra(xcm_primitives::PoolBondExtra),
+	#[codec(index = 2u8)]
+	ClaimPayout,
+	#[codec(index = 3u8)]
+	Unbond(
+		<AccountIdLookup<AccountId32, ()> as StaticLookup>::Source,
+		#[codec(compact)] cumulus_primitives_core::relay_chain::Balance,
+	),
+}
+
+#[derive(Encode, Decode)]
+pub enum ConvictionVotingCall {
+	#[codec(index = 0u8)]
+	Vote(#[codec(compact)] u32, xcm_primitives::RelayAccountVote),
+	#[codec(index = 1u8)]
+	Delegate(
+		u16,
+		<AccountIdLookup<AccountId32, ()> as StaticLookup>::Source,
+		xcm_primitives::RelayConviction,
+		cumulus_primitives_core::relay_chain::Balance,
+	),
+	#[codec(index = 4u8)]
+	RemoveVote(Option<u16>, u32),
+}
+
+#[derive(Encode, Decode)]
+pub enum ProxyCall {
+	#[codec(index = 0u8)]
+	Proxy(
+		<AccountIdLookup<AccountId32, ()> as StaticLookup>::Source,
+		Option<u8>,
+	),
+	#[codec(index = 1u8)]
+	AddProxy(
+		<AccountIdLookup<AccountId32, ()> as StaticLookup>::Source,
+		u8,
+		u32,
+	),
+	#[codec(index = 2u8)]
+	RemoveProxy(
+		<AccountIdLookup<AccountId32, ()> as StaticLookup>::Source,
+		u8,
+		u32,
+	),
+}
+
 use pallet_xcm_transactor::relay_indices::*;
 pub const TEST_RELAY_INDICES: RelayChainIndices = RelayChainIndices {
 	staking: 1u8,
@@ -91,6 +151,19 @@ pub const TEST_RELAY_INDICES: RelayChainIndices = RelayChainIndices {
 	accept_open_channel: 1u8,
 	close_channel: 2u8,
 	cancel_open_request: 6u8,
+	nomination_pools: 3u8,
+	conviction_voting: 4u8,
+	proxy: 5u8,
+	pool_join: 0u8,
+	pool_bond_extra: 1u8,
+	pool_unbond: 3u8,
+	pool_claim_payout: 2u8,
+	vote: 0u8,
+	remove_vote: 4u8,
+	delegate: 1u8,
+	proxy_call: 0u8,
+	add_proxy: 1u8,
+	remove_proxy: 2u8,
 };
 
 pub struct TestEncoder;
@@ -155,3 +228,61 @@ impl xcm_primitives::HrmpEncodeCall for TestEncoder {
 		}
 	}
 }
+
+impl xcm_primitives::NominationPoolsEncodeCall for TestEncoder {
+	fn nomination_pools_encode_call(
+		call: xcm_primitives::AvailableNominationPoolsCalls,
+	) -> Vec<u8> {
+		match call {
+			xcm_primitives::AvailableNominationPoolsCalls::Join(a, b) => {
+				RelayCall::NominationPools(NominationPoolsCall::Join(a, b)).encode()
+			}
+			xcm_primitives::AvailableNominationPoolsCalls::BondExtra(a) => {
+				RelayCall::NominationPools(NominationPoolsCall::BondExtra(a)).encode()
+			}
+			xcm_primitives::AvailableNominationPoolsCalls::Unbond(a, b) => {
+				RelayCall::NominationPools(NominationPoolsCall::Unbond(a.into(), b)).encode()
+			}
+			xcm_primitives::AvailableNominationPoolsCalls::ClaimPayout => {
+				RelayCall::NominationPools(NominationPoolsCall::ClaimPayout).encode()
+			}
+		}
+	}
+}
+
+impl xcm_primitives::ConvictionVotingEncodeCall for TestEncoder {
+	fn conviction_voting_encode_call(
+		call: xcm_primitives::AvailableConvictionVotingCalls,
+	) -> Vec<u8> {
+		match call {
+			xcm_primitives::AvailableConvictionVotingCalls::Vote(a, b) => {
+				RelayCall::ConvictionVoting(ConvictionVotingCall::Vote(a, b)).encode()
+			}
+			xcm_primitives::AvailableConvictionVotingCalls::RemoveVote(a, b) => {
+				RelayCall::ConvictionVoting(ConvictionVotingCall::RemoveVote(a, b)).encode()
+			}
+			xcm_primitives::AvailableConvictionVotingCalls::Delegate(a, b, c, d) => {
+				RelayCall::ConvictionVoting(ConvictionVotingCall::Delegate(a, b.into(), c, d))
+					.encode()
+			}
+		}
+	}
+}
+
+impl xcm_primitives::ProxyEncodeCall for TestEncoder {
+	fn proxy_encode_call(call: xcm_primitives::AvailableProxyCalls) -> Vec<u8> {
+		match call {
+			xcm_primitives::AvailableProxyCalls::Proxy(a, b, mut c) => {
+				let mut call = RelayCall::Proxy(ProxyCall::Proxy(a.into(), b)).encode();
+				call.append(&mut c);
+				call
+			}
+			xcm_primitives::AvailableProxyCalls::AddProxy(a, b, c) => {
+				RelayCall::Proxy(ProxyCall::AddProxy(a.into(), b, c)).encode()
+			}
+			xcm_primitives::AvailableProxyCalls::RemoveProxy(a, b, c) => {
+				RelayCall::Proxy(ProxyCall::RemoveProxy(a.into(), b, c)).encode()
+			}
+		}
+	}
+}
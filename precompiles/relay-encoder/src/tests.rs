@@ -39,6 +39,19 @@ fn selectors() {
 	assert!(PCall::encode_set_payee_selectors().contains(&0x414be337));
 	assert!(PCall::encode_set_controller_selectors().contains(&0x15490616));
 	assert!(PCall::encode_rebond_selectors().contains(&0x0922ee17));
+	assert!(PCall::encode_pool_join_selectors().contains(&0x3c5dd983));
+	assert!(PCall::encode_pool_bond_extra_selectors().contains(&0xa12b5f1e));
+	assert!(PCall::encode_pool_bond_extra_rewards_selectors().contains(&0xd5930060));
+	assert!(PCall::encode_pool_unbond_selectors().contains(&0xd460f714));
+	assert!(PCall::encode_pool_claim_payout_selectors().contains(&0xd6876ceb));
+	assert!(PCall::encode_standard_vote_selectors().contains(&0xe9829ad9));
+	assert!(PCall::encode_split_vote_selectors().contains(&0x2ed35411));
+	assert!(PCall::encode_split_abstain_vote_selectors().contains(&0xbb2ad8ba));
+	assert!(PCall::encode_remove_vote_selectors().contains(&0xce852a38));
+	assert!(PCall::encode_delegate_selectors().contains(&0xa5961bdb));
+	assert!(PCall::encode_proxy_selectors().contains(&0x9736fbde));
+	assert!(PCall::encode_add_proxy_selectors().contains(&0xd951fc0b));
+	assert!(PCall::encode_remove_proxy_selectors().contains(&0xfbfac19c));
 }
 
 #[test]
@@ -57,6 +70,19 @@ fn modifiers() {
 		tester.test_view_modifier(PCall::encode_set_payee_selectors());
 		tester.test_view_modifier(PCall::encode_set_controller_selectors());
 		tester.test_view_modifier(PCall::encode_rebond_selectors());
+		tester.test_view_modifier(PCall::encode_pool_join_selectors());
+		tester.test_view_modifier(PCall::encode_pool_bond_extra_selectors());
+		tester.test_view_modifier(PCall::encode_pool_bond_extra_rewards_selectors());
+		tester.test_view_modifier(PCall::encode_pool_unbond_selectors());
+		tester.test_view_modifier(PCall::encode_pool_claim_payout_selectors());
+		tester.test_view_modifier(PCall::encode_standard_vote_selectors());
+		tester.test_view_modifier(PCall::encode_split_vote_selectors());
+		tester.test_view_modifier(PCall::encode_split_abstain_vote_selectors());
+		tester.test_view_modifier(PCall::encode_remove_vote_selectors());
+		tester.test_view_modifier(PCall::encode_delegate_selectors());
+		tester.test_view_modifier(PCall::encode_proxy_selectors());
+		tester.test_view_modifier(PCall::encode_add_proxy_selectors());
+		tester.test_view_modifier(PCall::encode_remove_proxy_selectors());
 	});
 }
 
@@ -297,11 +323,220 @@ fn test_encode_withdraw_unbonded() {
 		});
 }
 
+#[test]
+fn test_encode_pool_join() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::encode_pool_join {
+						amount: 100.into(),
+						pool_id: 1,
+					},
+				)
+				.expect_cost(1000)
+				.expect_no_logs()
+				.execute_returns(UnboundedBytes::from(
+					TestEncoder::nomination_pools_encode_call(AvailableNominationPoolsCalls::Join(
+						100u32.into(),
+						1,
+					))
+					.as_slice(),
+				));
+		});
+}
+
+#[test]
+fn test_encode_pool_unbond() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::encode_pool_unbond {
+						member_account: H256::from([1u8; 32]),
+						unbonding_points: 100.into(),
+					},
+				)
+				.expect_cost(1000)
+				.expect_no_logs()
+				.execute_returns(UnboundedBytes::from(
+					TestEncoder::nomination_pools_encode_call(
+						AvailableNominationPoolsCalls::Unbond([1u8; 32].into(), 100u32.into()),
+					)
+					.as_slice(),
+				));
+		});
+}
+
+#[test]
+fn test_encode_standard_vote() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			let vote = RelayAccountVote::Standard {
+				vote: RelayVote {
+					aye: true,
+					conviction: RelayConviction::Locked3x,
+				},
+				balance: 100u32.into(),
+			};
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::encode_standard_vote {
+						poll_index: 5,
+						aye: true,
+						conviction: 3,
+						amount: 100.into(),
+					},
+				)
+				.expect_cost(1000)
+				.expect_no_logs()
+				.execute_returns(UnboundedBytes::from(
+					TestEncoder::conviction_voting_encode_call(
+						AvailableConvictionVotingCalls::Vote(5, vote),
+					)
+					.as_slice(),
+				));
+		});
+}
+
+#[test]
+fn test_encode_standard_vote_unknown_conviction_reverts() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::encode_standard_vote {
+						poll_index: 5,
+						aye: true,
+						conviction: 7,
+						amount: 100.into(),
+					},
+				)
+				.execute_reverts(|output| output == b"conviction: Unknown conviction");
+		});
+}
+
+#[test]
+fn test_encode_delegate() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::encode_delegate {
+						track_id: 2,
+						to: H256::from([1u8; 32]),
+						conviction: 1,
+						amount: 100.into(),
+					},
+				)
+				.expect_cost(1000)
+				.expect_no_logs()
+				.execute_returns(UnboundedBytes::from(
+					TestEncoder::conviction_voting_encode_call(
+						AvailableConvictionVotingCalls::Delegate(
+							2,
+							[1u8; 32].into(),
+							RelayConviction::Locked1x,
+							100u32.into(),
+						),
+					)
+					.as_slice(),
+				));
+		});
+}
+
+#[test]
+fn test_encode_proxy() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			let inner_call = TestEncoder::encode_call(AvailableStakeCalls::Chill);
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::encode_proxy {
+						real: H256::from([1u8; 32]),
+						call: inner_call.clone().into(),
+					},
+				)
+				.expect_cost(1000)
+				.expect_no_logs()
+				.execute_returns(UnboundedBytes::from(
+					TestEncoder::proxy_encode_call(AvailableProxyCalls::Proxy(
+						[1u8; 32].into(),
+						None,
+						inner_call,
+					))
+					.as_slice(),
+				));
+		});
+}
+
+#[test]
+fn test_encode_add_proxy() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::encode_add_proxy {
+						delegate: H256::from([1u8; 32]),
+						proxy_type: 3,
+						delay: 10,
+					},
+				)
+				.expect_cost(1000)
+				.expect_no_logs()
+				.execute_returns(UnboundedBytes::from(
+					TestEncoder::proxy_encode_call(AvailableProxyCalls::AddProxy(
+						[1u8; 32].into(),
+						3,
+						10,
+					))
+					.as_slice(),
+				));
+		});
+}
+
 #[test]
 fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
 	check_precompile_implements_solidity_interfaces(&["RelayEncoder.sol"], PCall::supports_selector)
 }
 
+#[test]
+fn test_generated_interface_matches_solidity_interface() {
+	check_precompile_generated_interface::<crate::RelayEncoderPrecompile<Runtime>>(&[
+		"RelayEncoder.sol",
+	])
+}
+
 #[test]
 fn test_deprecated_solidity_selectors_are_supported() {
 	for deprecated_function in [
@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Scheduler contract's address.
+address constant SCHEDULER_ADDRESS = 0x000000000000000000000000000000000000081B;
+
+/// @dev The Scheduler contract's instance.
+Scheduler constant SCHEDULER_CONTRACT = Scheduler(SCHEDULER_ADDRESS);
+
+/// @author The Moonbeam Team
+/// @title Pallet Scheduler Interface
+/// @title The interface through which solidity contracts will schedule calls with the Scheduler pallet
+/// @custom:address 0x000000000000000000000000000000000000081B
+interface Scheduler {
+    /// @dev Schedule a call dispatched at a given block with the caller as origin.
+    /// The execution weight of the call is paid upfront.
+    /// Each caller can have at most 8 pending tasks.
+    /// @custom:selector ee6d1dde
+    /// @param name The name of the task, unique for the caller
+    /// @param when The block at which the call is dispatched
+    /// @param call The SCALE encoded call, up to 128 bytes
+    function schedule(
+        bytes32 name,
+        uint32 when,
+        bytes memory call
+    ) external;
+
+    /// @dev Cancel a task scheduled by the caller.
+    /// @custom:selector c4d252f5
+    /// @param name The name of the task
+    function cancel(bytes32 name) external;
+
+    /// @dev Retrieve the block at which a task will be dispatched.
+    /// @custom:selector c07a2ef8
+    /// @param owner The account that scheduled the task
+    /// @param name The name of the task
+    /// @return isScheduled Is `true` if the task is scheduled
+    /// @return when The block at which the task will be dispatched
+    function nextDispatchTime(address owner, bytes32 name)
+        external
+        view
+        returns (bool isScheduled, uint32 when);
+
+    /// @dev A task has been scheduled.
+    /// @custom:selector de91c120a6825fbf3585fed71ed126e2a92f52bb6ecd036524ccbd3938bcaf5d
+    /// @param owner The account that scheduled the task
+    /// @param name The name of the task
+    /// @param when The block at which the task will be dispatched
+    event Scheduled(address indexed owner, bytes32 indexed name, uint32 when);
+
+    /// @dev A task has been cancelled.
+    /// @custom:selector 5c073039c0ae8fe0c6f63e2a80f3839841104bb172da658d85947b94d2de2d05
+    /// @param owner The account that scheduled the task
+    /// @param name The name of the task
+    event Cancelled(address indexed owner, bytes32 indexed name);
+}
@@ -0,0 +1,301 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile to schedule calls with pallet_scheduler on behalf of the caller.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::{Log, PrecompileHandle};
+use frame_support::{
+	dispatch::{GetDispatchInfo, PostDispatchInfo},
+	traits::{
+		schedule::{v3::Named as ScheduleNamed, DispatchTime, LOWEST_PRIORITY},
+		Bounded, ConstU32, Get,
+	},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_evm::AddressMapping;
+use pallet_scheduler::WeightInfo as _;
+use parity_scale_codec::{Decode, DecodeLimit as _, Encode, MaxEncodedLen};
+use precompile_utils::prelude::*;
+use sp_core::{H160, H256};
+use sp_runtime::traits::{Dispatchable, SaturatedConversion};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+type SystemCallOf<T> = <T as frame_system::Config>::RuntimeCall;
+// Named scheduling is only reachable through the trait, the pallet dispatchables requiring
+// `ScheduleOrigin`.
+type SchedulerOf<T> = pallet_scheduler::Pallet<T>;
+type SchedulerCallOf<T> = <T as pallet_scheduler::Config>::RuntimeCall;
+type PalletsOriginOf<T> = <T as pallet_scheduler::Config>::PalletsOrigin;
+
+/// Calls are stored inline in the agenda, which avoids noting preimages on behalf of the caller.
+type GetCallLimit = ConstU32<128>;
+type DecodeLimit = ConstU32<8>;
+
+/// Prefix of the task names, which are derived from the caller and the name it provides so
+/// callers cannot cancel or shadow the tasks of others.
+pub const TASK_NAME_PREFIX: &[u8] = b"evm/scheduler";
+
+/// Maximum number of pending tasks an account can schedule through this precompile, so a single
+/// account cannot fill the agendas of many blocks.
+pub const MAX_TASKS_PER_CALLER: u32 = 8;
+type GetMaxTasksPerCaller = ConstU32<MAX_TASKS_PER_CALLER>;
+
+/// Solidity selector of the Scheduled log, which is the Keccak of the Log signature.
+pub(crate) const SELECTOR_LOG_SCHEDULED: [u8; 32] = keccak256!("Scheduled(address,bytes32,uint32)");
+
+/// Solidity selector of the Cancelled log, which is the Keccak of the Log signature.
+pub(crate) const SELECTOR_LOG_CANCELLED: [u8; 32] = keccak256!("Cancelled(address,bytes32)");
+
+pub fn log_scheduled(
+	address: impl Into<H160>,
+	owner: impl Into<H160>,
+	name: H256,
+	when: u32,
+) -> Log {
+	log3(
+		address.into(),
+		SELECTOR_LOG_SCHEDULED,
+		owner.into(),
+		name,
+		solidity::encode_event_data(when),
+	)
+}
+
+pub fn log_cancelled(address: impl Into<H160>, owner: impl Into<H160>, name: H256) -> Log {
+	log3(
+		address.into(),
+		SELECTOR_LOG_CANCELLED,
+		owner.into(),
+		name,
+		Vec::new(),
+	)
+}
+
+/// A precompile allowing accounts to schedule named calls dispatched with their own origin.
+pub struct SchedulerPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> SchedulerPrecompile<Runtime>
+where
+	Runtime: pallet_scheduler::Config + pallet_evm::Config,
+	SystemCallOf<Runtime>: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	PalletsOriginOf<Runtime>: From<frame_system::RawOrigin<Runtime::AccountId>>,
+{
+	/// Schedule a call to be dispatched at block `when` with the caller as signed origin.
+	/// The execution weight of the call is paid upfront. Only half of the agenda of a block can
+	/// be filled through this precompile, the rest is left for governance, and each caller can
+	/// have at most `MAX_TASKS_PER_CALLER` pending tasks.
+	///
+	/// Parameters:
+	/// * name: The name of the task, unique for the caller.
+	/// * when: The block at which the call is dispatched.
+	/// * call: The SCALE encoded call, up to 128 bytes.
+	#[precompile::public("schedule(bytes32,uint32,bytes)")]
+	fn schedule(
+		handle: &mut impl PrecompileHandle,
+		name: H256,
+		when: u32,
+		call: BoundedBytes<GetCallLimit>,
+	) -> EvmResult {
+		let caller = handle.context().caller;
+
+		let event = log_scheduled(handle.context().address, caller, name, when);
+		handle.record_log_costs(&[&event])?;
+
+		let call: Vec<_> = call.into();
+		let call =
+			SchedulerCallOf::<Runtime>::decode_with_depth_limit(DecodeLimit::get(), &mut &*call)
+				.map_err(|_| RevertReason::custom("Failed to decode call").in_field("call"))?;
+
+		let max_scheduled = <Runtime as pallet_scheduler::Config>::MaxScheduledPerBlock::get();
+		let weight =
+			<Runtime as pallet_scheduler::Config>::WeightInfo::schedule_named(max_scheduled)
+				.saturating_add(call.get_dispatch_info().weight);
+		RuntimeHelper::<Runtime>::reocrd_external_cost(
+			handle,
+			weight,
+			Self::scheduled_task_size().saturating_add(PendingTask::max_encoded_len()) as u64,
+		)?;
+		RuntimeHelper::<Runtime>::refund_weight_v2_cost(handle, weight, None)?;
+
+		// The tasks dispatched since they were scheduled no longer count towards the limit
+		handle.record_db_read::<Runtime>(Self::pending_tasks_size())?;
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+		let now = frame_system::Pallet::<Runtime>::block_number();
+		let mut pending_tasks = storage::PendingTasks::get(caller);
+		pending_tasks.retain(|task| BlockNumberFor::<Runtime>::from(task.when) > now);
+		pending_tasks
+			.try_push(PendingTask { name, when })
+			.map_err(|_| revert("Too many scheduled tasks"))?;
+
+		let when: BlockNumberFor<Runtime> = when.into();
+		let scheduled = pallet_scheduler::Agenda::<Runtime>::decode_len(when).unwrap_or(0);
+		if scheduled >= (max_scheduled / 2) as usize {
+			return Err(revert("Agenda is too full"));
+		}
+
+		let call = call
+			.encode()
+			.try_into()
+			.map_err(|_| RevertReason::custom("Call is too large").in_field("call"))?;
+		let origin =
+			frame_system::RawOrigin::Signed(Runtime::AddressMapping::into_account_id(caller));
+
+		<SchedulerOf<Runtime> as ScheduleNamed<
+			BlockNumberFor<Runtime>,
+			SchedulerCallOf<Runtime>,
+			PalletsOriginOf<Runtime>,
+		>>::schedule_named(
+			Self::task_name(caller, name),
+			DispatchTime::At(when),
+			None,
+			LOWEST_PRIORITY,
+			origin.into(),
+			Bounded::Inline(call),
+		)
+		.map_err(|e| revert(<&'static str>::from(e)))?;
+
+		storage::PendingTasks::insert(caller, pending_tasks);
+
+		event.record(handle)?;
+
+		Ok(())
+	}
+
+	/// Cancel a task previously scheduled by the caller.
+	///
+	/// Parameters:
+	/// * name: The name of the task, as provided when scheduling it.
+	#[precompile::public("cancel(bytes32)")]
+	fn cancel(handle: &mut impl PrecompileHandle, name: H256) -> EvmResult {
+		let caller = handle.context().caller;
+
+		let event = log_cancelled(handle.context().address, caller, name);
+		handle.record_log_costs(&[&event])?;
+
+		let weight = <Runtime as pallet_scheduler::Config>::WeightInfo::cancel_named(
+			<Runtime as pallet_scheduler::Config>::MaxScheduledPerBlock::get(),
+		);
+		RuntimeHelper::<Runtime>::reocrd_external_cost(handle, weight, 0)?;
+		RuntimeHelper::<Runtime>::refund_weight_v2_cost(handle, weight, None)?;
+
+		handle.record_db_read::<Runtime>(Self::pending_tasks_size())?;
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+		storage::PendingTasks::mutate_exists(caller, |pending_tasks| {
+			if let Some(tasks) = pending_tasks {
+				tasks.retain(|task| task.name != name);
+				if tasks.is_empty() {
+					*pending_tasks = None;
+				}
+			}
+		});
+
+		<SchedulerOf<Runtime> as ScheduleNamed<
+			BlockNumberFor<Runtime>,
+			SchedulerCallOf<Runtime>,
+			PalletsOriginOf<Runtime>,
+		>>::cancel_named(Self::task_name(caller, name))
+		.map_err(|e| revert(<&'static str>::from(e)))?;
+
+		event.record(handle)?;
+
+		Ok(())
+	}
+
+	/// Returns whether a task of `owner` is scheduled and the block at which it will be
+	/// dispatched.
+	///
+	/// Parameters:
+	/// * owner: The account that scheduled the task.
+	/// * name: The name of the task, as provided when scheduling it.
+	#[precompile::public("nextDispatchTime(address,bytes32)")]
+	#[precompile::view]
+	fn next_dispatch_time(
+		handle: &mut impl PrecompileHandle,
+		owner: Address,
+		name: H256,
+	) -> EvmResult<(bool, u32)> {
+		// Lookup: Twox64Concat(8) + TaskName(32) + TaskAddress(4 + 4)
+		handle.record_db_read::<Runtime>(48)?;
+		// Agenda: Twox64Concat(8) + BlockNumber(4) + BoundedVec<Option<Scheduled>>
+		handle.record_db_read::<Runtime>(
+			16 + (1 + Self::scheduled_task_size()).saturating_mul(
+				<Runtime as pallet_scheduler::Config>::MaxScheduledPerBlock::get() as usize,
+			),
+		)?;
+
+		match <SchedulerOf<Runtime> as ScheduleNamed<
+			BlockNumberFor<Runtime>,
+			SchedulerCallOf<Runtime>,
+			PalletsOriginOf<Runtime>,
+		>>::next_dispatch_time(Self::task_name(owner.into(), name))
+		{
+			Ok(when) => Ok((true, when.saturated_into())),
+			Err(_) => Ok((false, 0)),
+		}
+	}
+
+	fn task_name(owner: H160, name: H256) -> [u8; 32] {
+		(TASK_NAME_PREFIX, owner, name).using_encoded(sp_io::hashing::blake2_256)
+	}
+
+	fn scheduled_task_size() -> usize {
+		pallet_scheduler::ScheduledOf::<Runtime>::max_encoded_len()
+	}
+
+	fn pending_tasks_size() -> usize {
+		// PendingTasks: Blake2_128Concat(16) + H160(20) + BoundedVec<PendingTask>
+		36 + storage::PendingTasksOf::max_encoded_len()
+	}
+}
+
+/// A task scheduled through this precompile which has not been dispatched yet.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, Eq, Debug)]
+pub struct PendingTask {
+	pub name: H256,
+	pub when: u32,
+}
+
+/// We use pallet storage in our precompile by implementing a StorageInstance for each item we need
+/// to store.
+mod storage {
+	use super::*;
+	use frame_support::{
+		storage::types::{StorageMap, ValueQuery},
+		traits::StorageInstance,
+		Blake2_128Concat, BoundedVec,
+	};
+
+	pub type PendingTasksOf = BoundedVec<PendingTask, GetMaxTasksPerCaller>;
+
+	// storage for the pending tasks of each caller
+	pub struct PendingTasksStorageInstance;
+	impl StorageInstance for PendingTasksStorageInstance {
+		const STORAGE_PREFIX: &'static str = "PendingTasks";
+		fn pallet_prefix() -> &'static str {
+			"SchedulerPrecompile"
+		}
+	}
+	pub type PendingTasks =
+		StorageMap<PendingTasksStorageInstance, Blake2_128Concat, H160, PendingTasksOf, ValueQuery>;
+}
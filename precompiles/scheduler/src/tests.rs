@@ -0,0 +1,287 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::{log_cancelled, log_scheduled, MAX_TASKS_PER_CALLER};
+use frame_support::traits::OnInitialize;
+use parity_scale_codec::Encode;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::H256;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn remark_call() -> Vec<u8> {
+	RuntimeCall::System(frame_system::Call::remark_with_event {
+		remark: b"scheduled".to_vec(),
+	})
+	.encode()
+}
+
+fn task(id: u8) -> H256 {
+	H256::repeat_byte(id)
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(&["Scheduler.sol"], PCall::supports_selector)
+}
+
+#[test]
+fn test_generated_interface_matches_solidity_interface() {
+	check_precompile_generated_interface::<crate::SchedulerPrecompile<Runtime>>(&["Scheduler.sol"])
+}
+
+#[test]
+fn scheduled_call_is_dispatched_with_caller_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::schedule {
+					name: task(1),
+					when: 5,
+					call: remark_call().into(),
+				},
+			)
+			.expect_log(log_scheduled(Precompile1, Alice, task(1), 5))
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(
+				Bob,
+				Precompile1,
+				PCall::next_dispatch_time {
+					owner: Address(Alice.into()),
+					name: task(1),
+				},
+			)
+			.execute_returns((true, 5u32));
+
+		// The name is scoped to its owner
+		precompiles()
+			.prepare_test(
+				Bob,
+				Precompile1,
+				PCall::next_dispatch_time {
+					owner: Address(Bob.into()),
+					name: task(1),
+				},
+			)
+			.execute_returns((false, 0u32));
+
+		System::set_block_number(5);
+		Scheduler::on_initialize(5);
+
+		assert!(events().iter().any(|event| matches!(
+			event,
+			RuntimeEvent::System(frame_system::Event::Remarked { sender, .. })
+				if *sender == AccountId::from(Alice)
+		)));
+
+		precompiles()
+			.prepare_test(
+				Bob,
+				Precompile1,
+				PCall::next_dispatch_time {
+					owner: Address(Alice.into()),
+					name: task(1),
+				},
+			)
+			.execute_returns((false, 0u32));
+	})
+}
+
+#[test]
+fn only_owner_can_cancel_task() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::schedule {
+					name: task(1),
+					when: 5,
+					call: remark_call().into(),
+				},
+			)
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(Bob, Precompile1, PCall::cancel { name: task(1) })
+			.execute_reverts(|output| output == b"NotFound");
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::cancel { name: task(1) })
+			.expect_log(log_cancelled(Precompile1, Alice, task(1)))
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::next_dispatch_time {
+					owner: Address(Alice.into()),
+					name: task(1),
+				},
+			)
+			.execute_returns((false, 0u32));
+	})
+}
+
+#[test]
+fn schedule_leaves_room_in_agenda() {
+	ExtBuilder::default().build().execute_with(|| {
+		for id in 1..=2 {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::schedule {
+						name: task(id),
+						when: 5,
+						call: remark_call().into(),
+					},
+				)
+				.execute_returns(());
+		}
+
+		// MaxScheduledPerBlock is 4
+		precompiles()
+			.prepare_test(
+				Bob,
+				Precompile1,
+				PCall::schedule {
+					name: task(3),
+					when: 5,
+					call: remark_call().into(),
+				},
+			)
+			.execute_reverts(|output| output == b"Agenda is too full");
+	})
+}
+
+#[test]
+fn schedule_is_limited_per_caller() {
+	ExtBuilder::default().build().execute_with(|| {
+		// At most 2 tasks of the precompile per block, as MaxScheduledPerBlock is 4
+		for id in 0..MAX_TASKS_PER_CALLER as u8 {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::schedule {
+						name: task(id),
+						when: 5 + u32::from(id / 2),
+						call: remark_call().into(),
+					},
+				)
+				.execute_returns(());
+		}
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::schedule {
+					name: task(100),
+					when: 20,
+					call: remark_call().into(),
+				},
+			)
+			.execute_reverts(|output| output == b"Too many scheduled tasks");
+
+		// The limit is per caller
+		precompiles()
+			.prepare_test(
+				Bob,
+				Precompile1,
+				PCall::schedule {
+					name: task(100),
+					when: 30,
+					call: remark_call().into(),
+				},
+			)
+			.execute_returns(());
+
+		// Cancelled tasks are no longer pending
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::cancel { name: task(0) })
+			.execute_returns(());
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::schedule {
+					name: task(100),
+					when: 20,
+					call: remark_call().into(),
+				},
+			)
+			.execute_returns(());
+
+		// Dispatched tasks are no longer pending
+		System::set_block_number(5);
+		Scheduler::on_initialize(5);
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::schedule {
+					name: task(101),
+					when: 21,
+					call: remark_call().into(),
+				},
+			)
+			.execute_returns(());
+	})
+}
+
+#[test]
+fn schedule_reverts_in_the_past() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::schedule {
+					name: task(1),
+					when: 1,
+					call: remark_call().into(),
+				},
+			)
+			.execute_reverts(|output| output == b"TargetBlockNumberInPast");
+	})
+}
+
+#[test]
+fn schedule_reverts_with_invalid_call() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::schedule {
+					name: task(1),
+					when: 5,
+					call: vec![0xff, 0xff].into(),
+				},
+			)
+			.execute_reverts(|output| output == b"call: Failed to decode call");
+	})
+}
@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Treasury contract's address.
+address constant TREASURY_ADDRESS = 0x000000000000000000000000000000000000081A;
+
+/// @dev The Treasury contract's instance.
+Treasury constant TREASURY_CONTRACT = Treasury(TREASURY_ADDRESS);
+
+/// @author The Moonbeam Team
+/// @title Pallet Treasury Interface
+/// @title The interface through which solidity contracts will interact with the Treasury pallet
+/// @custom:address 0x000000000000000000000000000000000000081A
+interface Treasury {
+    /// @dev A spend proposal.
+    struct SpendProposal {
+        /// Is `true` if the proposal is pending, `false` if it doesn't exist or has been closed.
+        bool exists;
+        /// The account proposing it.
+        address proposer;
+        /// The amount to be transferred to the beneficiary if the proposal is awarded.
+        uint256 value;
+        /// The account receiving the funds.
+        address beneficiary;
+        /// The amount reserved from the proposer.
+        uint256 bond;
+        /// Is `true` if the proposal has been approved and waits for the next spend period.
+        bool isApproved;
+    }
+
+    /// @dev Put forward a suggestion for spending. A bond is reserved from the caller.
+    /// @custom:selector 76fd21a6
+    /// @param value The amount to be transferred to the beneficiary
+    /// @param beneficiary The account receiving the funds
+    /// @return proposalIndex The index of the new proposal
+    function proposeSpend(uint256 value, address beneficiary)
+        external
+        returns (uint32 proposalIndex);
+
+    /// @dev Retrieve the number of proposals that have been made.
+    /// @custom:selector da35c664
+    function proposalCount() external view returns (uint32);
+
+    /// @dev Retrieve a spend proposal and its approval status.
+    /// @custom:selector ccf58d0d
+    /// @param proposalIndex The index of the proposal
+    function proposal(uint32 proposalIndex)
+        external
+        view
+        returns (SpendProposal memory);
+
+    /// @dev Retrieve the indices of the approved proposals waiting to be awarded.
+    /// @custom:selector b05dba96
+    function approvals() external view returns (uint32[] memory);
+
+    /// @dev Retrieve the amount of funds available for spending.
+    /// @custom:selector 4ba2363a
+    function pot() external view returns (uint256);
+
+    /// @dev A spend proposal has been made.
+    /// @custom:selector 7d025d7771d5da1aa3ea04a8beee7fc238695d1d7eb4e102ae9c7b6c7f4ba3be
+    /// @param proposalIndex The index of the proposal
+    /// @param proposer The account proposing it
+    /// @param value The amount to be transferred to the beneficiary
+    /// @param beneficiary The account receiving the funds
+    event SpendProposed(
+        uint32 indexed proposalIndex,
+        address indexed proposer,
+        uint256 value,
+        address beneficiary
+    );
+}
@@ -0,0 +1,213 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile to interact with pallet_treasury.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::{Log, PrecompileHandle};
+use frame_support::{
+	dispatch::{GetDispatchInfo, PostDispatchInfo},
+	traits::{Currency, Get},
+};
+use pallet_evm::AddressMapping;
+use parity_scale_codec::{Decode, MaxEncodedLen};
+use precompile_utils::{prelude::*, substrate::transcode};
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::{Dispatchable, StaticLookup};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+type BalanceOf<T> = <<T as pallet_treasury::Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+
+/// Solidity selector of the SpendProposed log, which is the Keccak of the Log signature.
+pub(crate) const SELECTOR_LOG_SPEND_PROPOSED: [u8; 32] =
+	keccak256!("SpendProposed(uint32,address,uint256,address)");
+
+pub fn log_spend_proposed(
+	address: impl Into<H160>,
+	proposal_index: u32,
+	proposer: impl Into<H160>,
+	value: U256,
+	beneficiary: impl Into<H160>,
+) -> Log {
+	log3(
+		address.into(),
+		SELECTOR_LOG_SPEND_PROPOSED,
+		H256::from_slice(&solidity::encode_arguments(proposal_index)),
+		proposer.into(),
+		solidity::encode_arguments((value, Address(beneficiary.into()))),
+	)
+}
+
+#[derive(Default, Debug, Eq, PartialEq, solidity::Codec)]
+pub struct SpendProposal {
+	exists: bool,
+	proposer: Address,
+	value: U256,
+	beneficiary: Address,
+	bond: U256,
+	is_approved: bool,
+}
+
+/// Mirror of `pallet_treasury::Proposal`, whose fields are not public.
+#[derive(Decode)]
+struct ProposalInfo<AccountId, Balance> {
+	proposer: AccountId,
+	value: Balance,
+	beneficiary: AccountId,
+	bond: Balance,
+}
+
+/// A precompile to wrap the functionality from pallet-treasury.
+pub struct TreasuryPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> TreasuryPrecompile<Runtime>
+where
+	Runtime: pallet_treasury::Config + pallet_evm::Config,
+	Runtime::AccountId: Into<H160>,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	Runtime::RuntimeCall: From<pallet_treasury::Call<Runtime>>,
+	BalanceOf<Runtime>: TryFrom<U256> + Into<U256>,
+{
+	/// Put forward a suggestion for spending. A deposit proportional to the value is reserved
+	/// and slashed if the proposal is rejected. It is returned once the proposal is awarded.
+	///
+	/// Parameters:
+	/// * value: The amount to be transferred to the beneficiary if the proposal is awarded.
+	/// * beneficiary: The account receiving the funds.
+	#[precompile::public("proposeSpend(uint256,address)")]
+	fn propose_spend(
+		handle: &mut impl PrecompileHandle,
+		value: U256,
+		beneficiary: Address,
+	) -> EvmResult<u32> {
+		// ProposalCount
+		handle.record_db_read::<Runtime>(4)?;
+		let proposal_index = pallet_treasury::Pallet::<Runtime>::proposal_count();
+
+		let event = log_spend_proposed(
+			handle.context().address,
+			proposal_index,
+			handle.context().caller,
+			value,
+			beneficiary,
+		);
+		handle.record_log_costs(&[&event])?;
+
+		let value: BalanceOf<Runtime> = value
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("balance type").in_field("value"))?;
+		let beneficiary = Runtime::AddressMapping::into_account_id(beneficiary.into());
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(origin).into(),
+			pallet_treasury::Call::<Runtime>::propose_spend {
+				value,
+				beneficiary: Runtime::Lookup::unlookup(beneficiary),
+			},
+			0,
+		)?;
+
+		event.record(handle)?;
+
+		Ok(proposal_index)
+	}
+
+	/// Number of spend proposals that have been made, which is also the index of the next one.
+	#[precompile::public("proposalCount()")]
+	#[precompile::view]
+	fn proposal_count(handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
+		// ProposalCount
+		handle.record_db_read::<Runtime>(4)?;
+
+		Ok(pallet_treasury::Pallet::<Runtime>::proposal_count())
+	}
+
+	/// Spend proposal at a given index, along with its approval status.
+	/// `exists` is false if the proposal doesn't exist or has already been awarded or rejected.
+	#[precompile::public("proposal(uint32)")]
+	#[precompile::view]
+	fn proposal(
+		handle: &mut impl PrecompileHandle,
+		proposal_index: u32,
+	) -> EvmResult<SpendProposal> {
+		// Proposals: Twox64Concat(8) + ProposalIndex(4) + Proposal
+		handle.record_db_read::<Runtime>(
+			12 + pallet_treasury::Proposal::<Runtime::AccountId, BalanceOf<Runtime>>::max_encoded_len(),
+		)?;
+
+		let Some(proposal) = pallet_treasury::Pallet::<Runtime>::proposals(proposal_index) else {
+			return Ok(SpendProposal::default());
+		};
+		let ProposalInfo {
+			proposer,
+			value,
+			beneficiary,
+			bond,
+		} = transcode::<ProposalInfo<Runtime::AccountId, BalanceOf<Runtime>>>(&proposal)?;
+
+		Self::record_approvals_read(handle)?;
+		let is_approved = pallet_treasury::Pallet::<Runtime>::approvals().contains(&proposal_index);
+
+		Ok(SpendProposal {
+			exists: true,
+			proposer: Address(proposer.into()),
+			value: value.into(),
+			beneficiary: Address(beneficiary.into()),
+			bond: bond.into(),
+			is_approved,
+		})
+	}
+
+	/// Indices of the proposals that have been approved but not yet awarded.
+	#[precompile::public("approvals()")]
+	#[precompile::view]
+	fn approvals(handle: &mut impl PrecompileHandle) -> EvmResult<Vec<u32>> {
+		Self::record_approvals_read(handle)?;
+
+		Ok(pallet_treasury::Pallet::<Runtime>::approvals().into_inner())
+	}
+
+	/// Amount of funds available in the treasury pot for spending.
+	#[precompile::public("pot()")]
+	#[precompile::view]
+	fn pot(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+		// Treasury account in System::Account
+		handle.record_db_read::<Runtime>(SYSTEM_ACCOUNT_SIZE as usize)?;
+
+		Ok(pallet_treasury::Pallet::<Runtime>::pot().into())
+	}
+
+	fn record_approvals_read(handle: &mut impl PrecompileHandle) -> EvmResult {
+		// Approvals: BoundedVec<ProposalIndex(4), MaxApprovals>
+		handle.record_db_read::<Runtime>(
+			4 + 4 * (<Runtime as pallet_treasury::Config>::MaxApprovals::get() as usize),
+		)?;
+
+		Ok(())
+	}
+}
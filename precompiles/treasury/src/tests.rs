@@ -0,0 +1,133 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::{log_spend_proposed, SpendProposal};
+use frame_support::{assert_ok, traits::Currency};
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::U256;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(&["Treasury.sol"], PCall::supports_selector)
+}
+
+#[test]
+fn test_generated_interface_matches_solidity_interface() {
+	check_precompile_generated_interface::<crate::TreasuryPrecompile<Runtime>>(&["Treasury.sol"])
+}
+
+#[test]
+fn propose_spend_creates_proposal() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::propose_spend {
+						value: 100.into(),
+						beneficiary: Address(Bob.into()),
+					},
+				)
+				.expect_log(log_spend_proposed(Precompile1, 0, Alice, 100.into(), Bob))
+				.execute_returns(0u32);
+
+			// ProposalBondMinimum is higher than 5% of the value
+			assert_eq!(Balances::reserved_balance(AccountId::from(Alice)), 10);
+
+			precompiles()
+				.prepare_test(Alice, Precompile1, PCall::proposal_count {})
+				.execute_returns(1u32);
+
+			precompiles()
+				.prepare_test(Alice, Precompile1, PCall::proposal { proposal_index: 0 })
+				.execute_returns(SpendProposal {
+					exists: true,
+					proposer: Address(Alice.into()),
+					value: 100.into(),
+					beneficiary: Address(Bob.into()),
+					bond: 10.into(),
+					is_approved: false,
+				});
+		})
+}
+
+#[test]
+fn proposal_reports_approval_status() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::propose_spend {
+						value: 100.into(),
+						beneficiary: Address(Bob.into()),
+					},
+				)
+				.execute_returns(0u32);
+
+			precompiles()
+				.prepare_test(Alice, Precompile1, PCall::approvals {})
+				.execute_returns(Vec::<u32>::new());
+
+			assert_ok!(Treasury::approve_proposal(RuntimeOrigin::root(), 0));
+
+			precompiles()
+				.prepare_test(Alice, Precompile1, PCall::approvals {})
+				.execute_returns(vec![0u32]);
+
+			precompiles()
+				.prepare_test(Alice, Precompile1, PCall::proposal { proposal_index: 0 })
+				.execute_returns(SpendProposal {
+					exists: true,
+					proposer: Address(Alice.into()),
+					value: 100.into(),
+					beneficiary: Address(Bob.into()),
+					bond: 10.into(),
+					is_approved: true,
+				});
+		})
+}
+
+#[test]
+fn unknown_proposal_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::proposal { proposal_index: 3 })
+			.execute_returns(SpendProposal::default());
+	})
+}
+
+#[test]
+fn pot_excludes_existential_deposit() {
+	ExtBuilder::default().build().execute_with(|| {
+		let _ = Balances::deposit_creating(&Treasury::account_id(), 1000);
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::pot {})
+			.execute_returns(U256::from(999));
+	})
+}
@@ -61,6 +61,14 @@ pub fn main(input: TokenStream) -> TokenStream {
 		.iter()
 		.map(|i| LitStr::new(&i.to_string(), i.span()))
 		.collect();
+	let fields_solidity_name_lit: Vec<_> = fields_ident
+		.iter()
+		.map(|i| {
+			use case::CaseExt;
+			LitStr::new(&i.to_string().to_camel_lowercase(), i.span())
+		})
+		.collect();
+	let struct_name_lit = LitStr::new(&ident.to_string(), ident.span());
 
 	let evm_data_trait_path = {
 		let mut segments = Punctuated::<PathSegment, _>::new();
@@ -124,6 +132,20 @@ pub fn main(input: TokenStream) -> TokenStream {
 			fn signature() -> String {
 				<(#(#fields_ty,)*)>::signature()
 			}
+
+			fn abi_type() -> ::precompile_utils::solidity::abi::AbiType {
+				::precompile_utils::solidity::abi::AbiType::named_struct(
+					#struct_name_lit,
+					::sp_std::vec::Vec::from([
+						#(
+							::precompile_utils::solidity::abi::Param::new(
+								#fields_solidity_name_lit,
+								<#fields_ty as ::precompile_utils::solidity::codec::Codec>::abi_type(),
+							),
+						)*
+					]),
+				)
+			}
 		}
 	}
 	.into()
@@ -0,0 +1,133 @@
+// Copyright 2019-2023 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use proc_macro::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{parse_macro_input, spanned::Spanned, DeriveInput, LitStr};
+
+/// Maximum amount of indexed fields, as the first topic is used by the event signature.
+const MAX_INDEXED_FIELDS: usize = 3;
+
+pub fn main(input: TokenStream) -> TokenStream {
+	let DeriveInput {
+		ident,
+		generics,
+		data,
+		..
+	} = parse_macro_input!(input as DeriveInput);
+
+	let fields = match data {
+		syn::Data::Struct(syn::DataStruct {
+			fields: syn::Fields::Named(fields),
+			..
+		}) => fields.named.into_iter().collect(),
+		syn::Data::Struct(syn::DataStruct {
+			fields: syn::Fields::Unit,
+			..
+		}) => vec![],
+		_ => {
+			return quote_spanned! { ident.span() =>
+				compile_error!("Event can only be derived for structs with named fields");
+			}
+			.into()
+		}
+	};
+
+	let is_indexed = |field: &syn::Field| field.attrs.iter().any(|a| a.path.is_ident("indexed"));
+
+	let indexed: Vec<_> = fields.iter().filter(|f| is_indexed(f)).collect();
+	let non_indexed: Vec<_> = fields.iter().filter(|f| !is_indexed(f)).collect();
+
+	if let Some(field) = indexed.get(MAX_INDEXED_FIELDS) {
+		return quote_spanned! { field.span() =>
+			compile_error!("An event cannot have more than 3 indexed fields");
+		}
+		.into();
+	}
+
+	let all_ident: Vec<_> = fields.iter().map(|f| &f.ident).collect();
+	let all_ty: Vec<_> = fields.iter().map(|f| &f.ty).collect();
+	let all_solidity_name: Vec<_> = fields
+		.iter()
+		.map(|f| {
+			use case::CaseExt;
+			let ident = f.ident.as_ref().expect("fields are named");
+			LitStr::new(&ident.to_string().to_camel_lowercase(), ident.span())
+		})
+		.collect();
+	let all_indexed: Vec<_> = fields.iter().map(|f| is_indexed(f)).collect();
+
+	let indexed_ident: Vec<_> = indexed.iter().map(|f| &f.ident).collect();
+	let indexed_ty: Vec<_> = indexed.iter().map(|f| &f.ty).collect();
+	let non_indexed_ident: Vec<_> = non_indexed.iter().map(|f| &f.ident).collect();
+
+	let name_lit = LitStr::new(&ident.to_string(), ident.span());
+
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	quote! {
+		impl #impl_generics ::precompile_utils::solidity::event::Event for #ident #ty_generics
+		#where_clause {
+			fn signature() -> ::precompile_utils::solidity::codec::String {
+				let params: ::sp_std::vec::Vec<::precompile_utils::solidity::codec::String> =
+					::sp_std::vec::Vec::from([
+						#(
+							<#all_ty as ::precompile_utils::solidity::Codec>::signature(),
+						)*
+					]);
+				::precompile_utils::solidity::codec::String::from(#name_lit)
+					+ "(" + &params.join(",") + ")"
+			}
+
+			fn abi_event() -> ::precompile_utils::solidity::abi::Event {
+				::precompile_utils::solidity::abi::Event {
+					name: ::precompile_utils::solidity::codec::String::from(#name_lit),
+					inputs: ::sp_std::vec::Vec::from([
+						#(
+							::precompile_utils::solidity::abi::EventParam {
+								param: ::precompile_utils::solidity::abi::Param::new(
+									#all_solidity_name,
+									<#all_ty as ::precompile_utils::solidity::Codec>::abi_type(),
+								),
+								indexed: #all_indexed,
+							},
+						)*
+					]),
+				}
+			}
+
+			fn into_log(
+				self,
+				address: impl Into<::sp_core::H160>,
+			) -> ::fp_evm::Log {
+				let Self { #(#all_ident,)* } = self;
+				::fp_evm::Log {
+					address: address.into(),
+					topics: ::sp_std::vec::Vec::from([
+						<Self as ::precompile_utils::solidity::event::Event>::topic0(),
+						#(
+							<#indexed_ty as ::precompile_utils::solidity::event::EventTopic>
+								::to_topic(#indexed_ident),
+						)*
+					]),
+					data: ::precompile_utils::solidity::encode_event_data(
+						(#(#non_indexed_ident,)*)
+					),
+				}
+			}
+		}
+	}
+	.into()
+}
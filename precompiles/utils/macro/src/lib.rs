@@ -23,6 +23,7 @@ use sha3::{Digest, Keccak256};
 use syn::{parse_macro_input, spanned::Spanned, Expr, Ident, ItemType, Lit, LitStr};
 
 mod derive_codec;
+mod derive_event;
 mod precompile;
 mod precompile_name_from_address;
 
@@ -74,3 +75,8 @@ pub fn precompile_name_from_address(attr: TokenStream, input: TokenStream) -> To
 pub fn derive_codec(input: TokenStream) -> TokenStream {
 	derive_codec::main(input)
 }
+
+#[proc_macro_derive(Event, attributes(indexed))]
+pub fn derive_event(input: TokenStream) -> TokenStream {
+	derive_event::main(input)
+}
@@ -22,12 +22,14 @@ impl Precompile {
 		let enum_ = self.expand_enum_decl();
 		let enum_impl = self.expand_enum_impl();
 		let precomp_impl = self.expand_precompile_impl();
+		let interface_impl = self.expand_solidity_interface_impl();
 		let test_signature = self.expand_test_solidity_signature();
 
 		quote! {
 			#enum_
 			#enum_impl
 			#precomp_impl
+			#interface_impl
 			#test_signature
 		}
 	}
@@ -185,6 +187,7 @@ impl Precompile {
 
 		let parse_call_data_fn = self.expand_enum_parse_call_data();
 		let execute_fn = self.expand_enum_execute_fn();
+		let solidity_functions_fn = self.expand_enum_solidity_functions_fn();
 
 		quote!(
 			impl #impl_generics #enum_ident #ty_generics #where_clause {
@@ -194,6 +197,8 @@ impl Precompile {
 
 				#execute_fn
 
+				#solidity_functions_fn
+
 				pub fn supports_selector(selector: u32) -> bool {
 					match selector {
 						#(
@@ -310,6 +315,80 @@ impl Precompile {
 		)
 	}
 
+	/// Expand the function describing the Solidity functions of the precompile, with one
+	/// function per selector. The fallback doesn't have a Solidity description.
+	fn expand_enum_solidity_functions_fn(&self) -> impl ToTokens {
+		let functions = self.variants_content.values().flat_map(|variant| {
+			let modifier = match variant.modifier {
+				Modifier::NonPayable => "NonPayable",
+				Modifier::Payable => "Payable",
+				Modifier::View => "View",
+			};
+			let modifier = syn::Ident::new(modifier, Span::call_site());
+
+			let inputs: Vec<_> = variant
+				.arguments
+				.iter()
+				.map(|arg| {
+					use case::CaseExt;
+
+					let name = arg.ident.to_string().to_camel_lowercase();
+					let ty = &arg.ty;
+					quote!(
+						::precompile_utils::solidity::abi::Param::new(
+							#name,
+							<#ty as ::precompile_utils::solidity::Codec>::abi_type(),
+						),
+					)
+				})
+				.collect();
+			let output = Self::output_value_type(&variant.fn_output);
+
+			variant
+				.selectors
+				.iter()
+				.zip(variant.solidity_names.iter())
+				.map(move |(selector, name)| {
+					let inputs = &inputs;
+					quote!(
+						::precompile_utils::solidity::abi::Function {
+							name: ::precompile_utils::solidity::codec::String::from(#name),
+							selector: #selector,
+							inputs: ::sp_std::vec::Vec::from([#(#inputs)*]),
+							outputs: ::precompile_utils::solidity::abi::Param::outputs(
+								<#output as ::precompile_utils::solidity::Codec>::abi_type()
+							),
+							modifier:
+								::precompile_utils::solidity::modifier::FunctionModifier::#modifier,
+						},
+					)
+				})
+		});
+
+		quote!(
+			pub fn solidity_functions(
+			) -> ::sp_std::vec::Vec<::precompile_utils::solidity::abi::Function> {
+				::sp_std::vec::Vec::from([#(#functions)*])
+			}
+		)
+	}
+
+	/// Extract `T` from the `EvmResult<T>` output of a method. `EvmResult` without type argument
+	/// returns `()`.
+	fn output_value_type(fn_output: &syn::Type) -> syn::Type {
+		if let syn::Type::Path(syn::TypePath { path, .. }) = fn_output {
+			if let Some(syn::PathArguments::AngleBracketed(args)) =
+				path.segments.last().map(|segment| &segment.arguments)
+			{
+				if let Some(syn::GenericArgument::Type(ty)) = args.args.first() {
+					return ty.clone();
+				}
+			}
+		}
+
+		syn::parse_quote!(())
+	}
+
 	/// Expand how a variant can be Solidity encoded.
 	fn expand_variant_encoding(variant: &Variant) -> impl ToTokens {
 		match variant.selectors.first() {
@@ -456,6 +535,32 @@ impl Precompile {
 		}
 	}
 
+	/// Expands the impl of the `SolidityInterface` trait.
+	pub fn expand_solidity_interface_impl(&self) -> impl ToTokens {
+		let impl_type = &self.impl_type;
+		let enum_ident = &self.enum_ident;
+		let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+		let events = &self.events;
+
+		quote!(
+			impl #impl_generics ::precompile_utils::solidity::abi::SolidityInterface for #impl_type
+			#where_clause
+			{
+				fn solidity_functions(
+				) -> ::sp_std::vec::Vec<::precompile_utils::solidity::abi::Function> {
+					<#enum_ident #ty_generics>::solidity_functions()
+				}
+
+				fn solidity_events(
+				) -> ::sp_std::vec::Vec<::precompile_utils::solidity::abi::Event> {
+					::sp_std::vec::Vec::from([#(
+						<#events as ::precompile_utils::solidity::event::Event>::abi_event(),
+					)*])
+				}
+			}
+		)
+	}
+
 	/// Expands the Solidity signature test.
 	/// The macro expands an "inner" function in all build profiles, which is
 	/// then called by a test in test profile. This allows to display errors that occurs in
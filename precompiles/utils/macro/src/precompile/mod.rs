@@ -87,6 +87,10 @@ struct Precompile {
 	/// Ident of a function that performs a check before the call is dispatched to the proper
 	/// function.
 	pre_check: Option<syn::Ident>,
+
+	/// Types of the events emitted by the precompile, declared with `#[derive(solidity::Event)]`,
+	/// used to describe the Solidity interface of the precompile.
+	events: Vec<syn::Type>,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -119,6 +123,10 @@ struct Variant {
 	/// Empty if it only the fallback function.
 	selectors: Vec<u32>,
 
+	/// Solidity function name of each selector, used to describe the Solidity
+	/// interface of the precompile.
+	solidity_names: Vec<String>,
+
 	/// Output of the variant fn (for better error messages).
 	fn_output: syn::Type,
 }
@@ -37,6 +37,7 @@ impl Precompile {
 			precompile_set_discriminant_type: None,
 			test_concrete_types: None,
 			pre_check: None,
+			events: vec![],
 		};
 
 		precompile.process_impl_attr(impl_)?;
@@ -60,7 +61,8 @@ impl Precompile {
 	}
 
 	/// Process the attributes used on the `impl` block, which allows to declare
-	/// if it is a PrecompileSet or not, and to provide concrete types for tests if necessary.
+	/// if it is a PrecompileSet or not, to provide concrete types for tests if necessary and
+	/// to declare the events emitted by the precompile.
 	fn process_impl_attr(&mut self, impl_: &mut syn::ItemImpl) -> syn::Result<()> {
 		let attrs = attr::take_attributes::<attr::ImplAttr>(&mut impl_.attrs)?;
 
@@ -83,6 +85,14 @@ impl Precompile {
 
 					self.test_concrete_types = Some(types);
 				}
+				attr::ImplAttr::Events(span, types) => {
+					if !self.events.is_empty() {
+						let msg = "Only one list of events can be provided";
+						return Err(syn::Error::new(span, msg));
+					}
+
+					self.events = types;
+				}
 			}
 		}
 
@@ -129,6 +139,7 @@ impl Precompile {
 		let mut arguments = vec![];
 		let mut is_fallback = false;
 		let mut selectors = vec![];
+		let mut solidity_names = vec![];
 		let initial_arguments = if self.tagged_as_precompile_set { 2 } else { 1 };
 
 		// We first look for unique attributes.
@@ -200,12 +211,13 @@ impl Precompile {
 				attr::MethodAttr::Public(_, signature_lit) => {
 					used = true;
 
-					let selector = self.parse_public_attr(
+					let (selector, solidity_name) = self.parse_public_attr(
 						signature_lit,
 						&method_name,
 						&mut solidity_arguments_type,
 					)?;
 					selectors.push(selector);
+					solidity_names.push(solidity_name);
 				}
 			}
 		}
@@ -298,6 +310,7 @@ impl Precompile {
 				solidity_arguments_type: solidity_arguments_type.unwrap_or(String::from("()")),
 				modifier,
 				selectors,
+				solidity_names,
 				fn_output: output_type.as_ref().clone(),
 			},
 		) {
@@ -495,13 +508,14 @@ impl Precompile {
 		Ok(())
 	}
 
-	/// Process a `public` attribute on a method.
+	/// Process a `public` attribute on a method, returning its selector and Solidity function
+	/// name.
 	fn parse_public_attr(
 		&mut self,
 		signature_lit: syn::LitStr,
 		method_name: &syn::Ident,
 		solidity_arguments_type: &mut Option<String>,
-	) -> syn::Result<u32> {
+	) -> syn::Result<(u32, String)> {
 		let signature = signature_lit.value();
 		// Split signature to get arguments type.
 		let split: Vec<_> = signature.splitn(2, "(").collect();
@@ -535,7 +549,7 @@ impl Precompile {
 			return Err(syn::Error::new(signature_lit.span(), msg));
 		}
 
-		Ok(selector)
+		Ok((selector, split[0].to_string()))
 	}
 
 	/// Check that the provided type doesn't depend on one of the type parameters of the
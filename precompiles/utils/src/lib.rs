@@ -68,9 +68,11 @@ pub mod prelude {
 					UnboundedBytes,
 					UnboundedString,
 				},
+				// Allow usage of Event methods while not exporting the name directly.
+				event::Event as _,
 				revert::{
-					revert, BacktraceExt, InjectBacktrace, MayRevert, Revert, RevertExt,
-					RevertReason,
+					custom_error, revert, BacktraceExt, InjectBacktrace, MayRevert, Revert,
+					RevertExt, RevertReason,
 				},
 			},
 			substrate::{RuntimeHelper, TryDispatchError, SYSTEM_ACCOUNT_SIZE},
@@ -20,7 +20,11 @@
 
 use crate::{
 	evm::handle::PrecompileHandleExt,
-	solidity::{codec::String, revert::revert},
+	solidity::{
+		abi::{Interface, SolidityInterface},
+		codec::String,
+		revert::revert,
+	},
 	EvmResult,
 };
 use fp_evm::{
@@ -497,6 +501,21 @@ pub trait IsActivePrecompile {
 	fn is_active_precompile(&self, address: H160, gas: u64) -> IsPrecompileResult;
 }
 
+/// Describe the Solidity interfaces of the precompiles of a fragment, from the interfaces
+/// generated by the `#[precompile]` macro. Precompiles without Solidity interface, that are not
+/// declared with the macro, implement `SolidityInterface` without items and are skipped.
+pub trait PrecompileSetInterfaces {
+	/// Interfaces of the precompiles, with the address of the precompiles at a single address.
+	fn solidity_interfaces() -> Vec<Interface>;
+}
+
+/// Name of the interface of a precompile: its type name without path nor generics.
+fn interface_name<P>() -> &'static str {
+	let name = core::any::type_name::<P>();
+	let name = name.split('<').next().unwrap_or(name);
+	name.rsplit("::").next().unwrap_or(name)
+}
+
 // INDIVIDUAL PRECOMPILE(SET)
 
 /// A fragment of a PrecompileSet. Should be implemented as is it
@@ -633,6 +652,20 @@ where
 	}
 }
 
+impl<A, P, C> PrecompileSetInterfaces for PrecompileAt<A, P, C>
+where
+	A: Get<H160>,
+	P: SolidityInterface,
+{
+	fn solidity_interfaces() -> Vec<Interface> {
+		let interface = Interface::of::<P>(interface_name::<P>(), Some(A::get()));
+		if interface.functions.is_empty() && interface.events.is_empty() {
+			return vec![];
+		}
+		vec![interface]
+	}
+}
+
 impl<A, P, C> IsActivePrecompile for PrecompileAt<A, P, C>
 where
 	A: Get<H160>,
@@ -767,6 +800,15 @@ where
 	}
 }
 
+impl<A, P, C> PrecompileSetInterfaces for PrecompileSetStartingWith<A, P, C>
+where
+	P: SolidityInterface,
+{
+	fn solidity_interfaces() -> Vec<Interface> {
+		vec![Interface::of::<P>(interface_name::<P>(), None)]
+	}
+}
+
 impl<A, P, C> IsActivePrecompile for PrecompileSetStartingWith<A, P, C>
 where
 	Self: PrecompileSetFragment,
@@ -827,6 +869,12 @@ where
 	}
 }
 
+impl<A> PrecompileSetInterfaces for RevertPrecompile<A> {
+	fn solidity_interfaces() -> Vec<Interface> {
+		vec![]
+	}
+}
+
 impl<A> IsActivePrecompile for RevertPrecompile<A> {
 	#[inline(always)]
 	fn is_active_precompile(&self, _address: H160, _gas: u64) -> IsPrecompileResult {
@@ -886,6 +934,12 @@ where
 	}
 }
 
+impl<A> PrecompileSetInterfaces for RemovedPrecompileAt<A> {
+	fn solidity_interfaces() -> Vec<Interface> {
+		vec![]
+	}
+}
+
 impl<A> IsActivePrecompile for RemovedPrecompileAt<A> {
 	#[inline(always)]
 	fn is_active_precompile(&self, _address: H160, _gas: u64) -> IsPrecompileResult {
@@ -964,6 +1018,17 @@ impl PrecompileSetFragment for Tuple {
 	}
 }
 
+#[impl_for_tuples(1, 100)]
+impl PrecompileSetInterfaces for Tuple {
+	fn solidity_interfaces() -> Vec<Interface> {
+		let mut interfaces = vec![];
+		for_tuples!(#(
+			interfaces.extend(Tuple::solidity_interfaces());
+		)*);
+		interfaces
+	}
+}
+
 #[impl_for_tuples(1, 100)]
 impl IsActivePrecompile for Tuple {
 	#[inline(always)]
@@ -1040,6 +1105,15 @@ where
 	}
 }
 
+impl<S, E, P> PrecompileSetInterfaces for PrecompilesInRangeInclusive<(S, E), P>
+where
+	P: PrecompileSetInterfaces,
+{
+	fn solidity_interfaces() -> Vec<Interface> {
+		P::solidity_interfaces()
+	}
+}
+
 impl<S, E, P> IsActivePrecompile for PrecompilesInRangeInclusive<(S, E), P>
 where
 	P: IsActivePrecompile,
@@ -1078,6 +1152,12 @@ impl<R, P: IsActivePrecompile> IsActivePrecompile for PrecompileSetBuilder<R, P>
 	}
 }
 
+impl<R, P: PrecompileSetInterfaces> PrecompileSetInterfaces for PrecompileSetBuilder<R, P> {
+	fn solidity_interfaces() -> Vec<Interface> {
+		P::solidity_interfaces()
+	}
+}
+
 impl<R: pallet_evm::Config, P: PrecompileSetFragment> PrecompileSetBuilder<R, P> {
 	/// Create a new instance of the PrecompileSet.
 	pub fn new() -> Self {
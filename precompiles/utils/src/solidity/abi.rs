@@ -0,0 +1,434 @@
+// Copyright 2019-2023 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Description of the Solidity interface of a precompile, as recorded by the `#[precompile]`
+//! macro. It allows to generate the Solidity interface and the
+//! [ABI JSON](https://docs.soliditylang.org/en/v0.8.19/abi-spec.html#json) of a precompile.
+
+use {
+	crate::solidity::modifier::FunctionModifier,
+	alloc::{borrow::ToOwned, boxed::Box, format, string::String},
+	sp_core::H160,
+	sp_std::{vec, vec::Vec},
+};
+
+/// Solidity type of a value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AbiType {
+	/// Elementary type, such as `uint256`, `address` or `bytes`.
+	Elementary(String),
+	/// Dynamic-size array of the inner type.
+	Array(Box<AbiType>),
+	/// Tuple of values, which is a struct if it has a name.
+	Tuple {
+		name: Option<String>,
+		components: Vec<Param>,
+	},
+}
+
+impl AbiType {
+	pub fn elementary(ty: &str) -> Self {
+		Self::Elementary(ty.to_owned())
+	}
+
+	pub fn array(inner: AbiType) -> Self {
+		Self::Array(Box::new(inner))
+	}
+
+	pub fn named_struct(name: &str, components: Vec<Param>) -> Self {
+		Self::Tuple {
+			name: Some(name.to_owned()),
+			components,
+		}
+	}
+
+	/// Canonical type used in function signatures, such as `(address,uint256)[]`.
+	pub fn signature(&self) -> String {
+		match self {
+			Self::Elementary(ty) => ty.clone(),
+			Self::Array(inner) => format!("{}[]", inner.signature()),
+			Self::Tuple { components, .. } => {
+				let components: Vec<_> = components.iter().map(|c| c.ty.signature()).collect();
+				format!("({})", components.join(","))
+			}
+		}
+	}
+
+	/// Type as written in the ABI JSON, in which tuples are described by their components.
+	pub fn json_type(&self) -> String {
+		match self {
+			Self::Elementary(ty) => ty.clone(),
+			Self::Array(inner) => format!("{}[]", inner.json_type()),
+			Self::Tuple { .. } => String::from("tuple"),
+		}
+	}
+
+	/// Type as written in a Solidity interface, in which structs are referred by name.
+	pub fn solidity_type(&self) -> String {
+		match self {
+			Self::Elementary(ty) => ty.clone(),
+			Self::Array(inner) => format!("{}[]", inner.solidity_type()),
+			Self::Tuple {
+				name: Some(name), ..
+			} => name.clone(),
+			Self::Tuple { name: None, .. } => self.signature(),
+		}
+	}
+
+	/// Components of the innermost tuple type, if any.
+	fn components(&self) -> Option<&Vec<Param>> {
+		match self {
+			Self::Elementary(_) => None,
+			Self::Array(inner) => inner.components(),
+			Self::Tuple { components, .. } => Some(components),
+		}
+	}
+
+	/// Does this type need a data location when used as a function parameter?
+	fn is_reference_type(&self) -> bool {
+		match self {
+			Self::Elementary(ty) => ty == "bytes" || ty == "string" || ty.ends_with(']'),
+			Self::Array(_) | Self::Tuple { .. } => true,
+		}
+	}
+
+	/// Collect the structs used by this type, inner structs first.
+	fn collect_structs(&self, structs: &mut Vec<(String, Vec<Param>)>) {
+		match self {
+			Self::Elementary(_) => (),
+			Self::Array(inner) => inner.collect_structs(structs),
+			Self::Tuple { name, components } => {
+				for component in components {
+					component.ty.collect_structs(structs);
+				}
+
+				if let Some(name) = name {
+					if !structs.iter().any(|(known, _)| known == name) {
+						structs.push((name.clone(), components.clone()));
+					}
+				}
+			}
+		}
+	}
+}
+
+/// A named value, such as a function argument or a struct field.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Param {
+	/// Name of the value, empty if unnamed.
+	pub name: String,
+	pub ty: AbiType,
+}
+
+impl Param {
+	pub fn new(name: &str, ty: AbiType) -> Self {
+		Self {
+			name: name.to_owned(),
+			ty,
+		}
+	}
+
+	/// Outputs of a function returning a value of given type. A returned tuple that is not a
+	/// struct describes multiple return values.
+	pub fn outputs(ty: AbiType) -> Vec<Self> {
+		match ty {
+			AbiType::Tuple {
+				name: None,
+				components,
+			} => components,
+			ty => vec![Self::new("", ty)],
+		}
+	}
+
+	fn to_solidity(&self) -> String {
+		let mut out = self.ty.solidity_type();
+		if self.ty.is_reference_type() {
+			out.push_str(" memory");
+		}
+		if !self.name.is_empty() {
+			out.push(' ');
+			out.push_str(&self.name);
+		}
+		out
+	}
+
+	fn to_json(&self, indexed: Option<bool>) -> String {
+		let mut out = format!(
+			"{{\"name\":{},\"type\":{}",
+			json_string(&self.name),
+			json_string(&self.ty.json_type())
+		);
+		if let Some(components) = self.ty.components() {
+			let components: Vec<_> = components.iter().map(|c| c.to_json(None)).collect();
+			out.push_str(&format!(",\"components\":[{}]", components.join(",")));
+		}
+		if let Some(indexed) = indexed {
+			out.push_str(&format!(",\"indexed\":{}", indexed));
+		}
+		out.push('}');
+		out
+	}
+}
+
+/// A public function of a precompile. Functions having multiple selectors are described once
+/// per selector.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Function {
+	pub name: String,
+	pub selector: u32,
+	pub inputs: Vec<Param>,
+	pub outputs: Vec<Param>,
+	pub modifier: FunctionModifier,
+}
+
+impl Function {
+	/// Signature used to compute the selector, such as `transfer(address,uint256)`.
+	pub fn signature(&self) -> String {
+		let inputs: Vec<_> = self.inputs.iter().map(|p| p.ty.signature()).collect();
+		format!("{}({})", self.name, inputs.join(","))
+	}
+
+	fn state_mutability(&self) -> &'static str {
+		match self.modifier {
+			FunctionModifier::View => "view",
+			FunctionModifier::NonPayable => "nonpayable",
+			FunctionModifier::Payable => "payable",
+		}
+	}
+
+	fn to_solidity(&self) -> String {
+		let inputs: Vec<_> = self.inputs.iter().map(Param::to_solidity).collect();
+		let mut out = format!(
+			"    /// @custom:selector {:08x}\n    function {}({}) external",
+			self.selector,
+			self.name,
+			inputs.join(", ")
+		);
+		match self.modifier {
+			FunctionModifier::View => out.push_str(" view"),
+			FunctionModifier::Payable => out.push_str(" payable"),
+			FunctionModifier::NonPayable => (),
+		}
+		if !self.outputs.is_empty() {
+			let outputs: Vec<_> = self.outputs.iter().map(Param::to_solidity).collect();
+			out.push_str(&format!(" returns ({})", outputs.join(", ")));
+		}
+		out.push(';');
+		out
+	}
+
+	fn to_json(&self) -> String {
+		let inputs: Vec<_> = self.inputs.iter().map(|p| p.to_json(None)).collect();
+		let outputs: Vec<_> = self.outputs.iter().map(|p| p.to_json(None)).collect();
+		format!(
+			"{{\"type\":\"function\",\"name\":{},\"inputs\":[{}],\"outputs\":[{}],\
+			\"stateMutability\":\"{}\"}}",
+			json_string(&self.name),
+			inputs.join(","),
+			outputs.join(","),
+			self.state_mutability()
+		)
+	}
+}
+
+/// A parameter of an event.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventParam {
+	pub param: Param,
+	pub indexed: bool,
+}
+
+/// An event emitted by a precompile.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Event {
+	pub name: String,
+	pub inputs: Vec<EventParam>,
+}
+
+impl Event {
+	/// Signature used to compute the topic, such as `Transfer(address,address,uint256)`.
+	pub fn signature(&self) -> String {
+		let inputs: Vec<_> = self.inputs.iter().map(|p| p.param.ty.signature()).collect();
+		format!("{}({})", self.name, inputs.join(","))
+	}
+
+	fn to_solidity(&self) -> String {
+		let inputs: Vec<_> = self
+			.inputs
+			.iter()
+			.map(|p| {
+				let mut out = p.param.ty.solidity_type();
+				if p.indexed {
+					out.push_str(" indexed");
+				}
+				if !p.param.name.is_empty() {
+					out.push(' ');
+					out.push_str(&p.param.name);
+				}
+				out
+			})
+			.collect();
+		format!(
+			"    /// @custom:selector {}\n    event {}({});",
+			to_hex(&sp_io::hashing::keccak_256(self.signature().as_bytes())),
+			self.name,
+			inputs.join(", ")
+		)
+	}
+
+	fn to_json(&self) -> String {
+		let inputs: Vec<_> = self
+			.inputs
+			.iter()
+			.map(|p| p.param.to_json(Some(p.indexed)))
+			.collect();
+		format!(
+			"{{\"type\":\"event\",\"name\":{},\"inputs\":[{}],\"anonymous\":false}}",
+			json_string(&self.name),
+			inputs.join(",")
+		)
+	}
+}
+
+/// Implemented by the `#[precompile]` macro to describe the Solidity interface of a precompile.
+/// Precompiles that are not declared with the macro implement it without items, as they have no
+/// Solidity interface.
+pub trait SolidityInterface {
+	/// Public functions of the precompile.
+	fn solidity_functions() -> Vec<Function> {
+		Vec::new()
+	}
+
+	/// Events emitted by the precompile.
+	fn solidity_events() -> Vec<Event> {
+		Vec::new()
+	}
+}
+
+#[cfg(feature = "frontier-precompiles")]
+mod frontier_precompiles {
+	use super::SolidityInterface;
+
+	impl SolidityInterface for pallet_evm_precompile_blake2::Blake2F {}
+	impl SolidityInterface for pallet_evm_precompile_bn128::Bn128Add {}
+	impl SolidityInterface for pallet_evm_precompile_bn128::Bn128Mul {}
+	impl SolidityInterface for pallet_evm_precompile_bn128::Bn128Pairing {}
+	impl SolidityInterface for pallet_evm_precompile_modexp::Modexp {}
+	impl SolidityInterface for pallet_evm_precompile_sha3fips::Sha3FIPS256 {}
+	impl SolidityInterface for pallet_evm_precompile_simple::ECRecover {}
+	impl SolidityInterface for pallet_evm_precompile_simple::ECRecoverPublicKey {}
+	impl SolidityInterface for pallet_evm_precompile_simple::Identity {}
+	impl SolidityInterface for pallet_evm_precompile_simple::Ripemd160 {}
+	impl SolidityInterface for pallet_evm_precompile_simple::Sha256 {}
+}
+
+/// Solidity interface of a precompile.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Interface {
+	pub name: String,
+	pub address: Option<H160>,
+	pub functions: Vec<Function>,
+	pub events: Vec<Event>,
+}
+
+impl Interface {
+	/// Interface of precompile `P`, deployed at the given address if any.
+	pub fn of<P: SolidityInterface>(name: &str, address: Option<H160>) -> Self {
+		Self {
+			name: name.to_owned(),
+			address,
+			functions: P::solidity_functions(),
+			events: P::solidity_events(),
+		}
+	}
+
+	/// Add an event declared with `#[derive(solidity::Event)]` to the interface.
+	pub fn with_event<E: crate::solidity::event::Event>(mut self) -> Self {
+		self.events.push(E::abi_event());
+		self
+	}
+
+	/// Generate the Solidity interface, with structs declared inside the interface.
+	pub fn to_solidity(&self) -> String {
+		let mut structs = Vec::new();
+		for function in &self.functions {
+			for param in function.inputs.iter().chain(function.outputs.iter()) {
+				param.ty.collect_structs(&mut structs);
+			}
+		}
+		for event in &self.events {
+			for input in &event.inputs {
+				input.param.ty.collect_structs(&mut structs);
+			}
+		}
+
+		let mut items: Vec<String> = structs
+			.into_iter()
+			.map(|(name, components)| {
+				let fields: Vec<_> = components
+					.iter()
+					.map(|c| format!("        {} {};\n", c.ty.solidity_type(), c.name))
+					.collect();
+				format!("    struct {} {{\n{}    }}", name, fields.concat())
+			})
+			.collect();
+		items.extend(self.functions.iter().map(Function::to_solidity));
+		items.extend(self.events.iter().map(Event::to_solidity));
+
+		let mut out = String::from("// SPDX-License-Identifier: GPL-3.0-only\n");
+		out.push_str("pragma solidity >=0.8.3;\n\n");
+		if let Some(address) = self.address {
+			out.push_str(&format!(
+				"/// @custom:address 0x{}\n",
+				to_hex(address.as_bytes())
+			));
+		}
+		out.push_str(&format!(
+			"interface {} {{\n{}\n}}\n",
+			self.name,
+			items.join("\n\n")
+		));
+		out
+	}
+
+	/// Generate the ABI JSON.
+	pub fn to_abi_json(&self) -> String {
+		let entries: Vec<_> = self
+			.functions
+			.iter()
+			.map(Function::to_json)
+			.chain(self.events.iter().map(Event::to_json))
+			.collect();
+		format!("[{}]", entries.join(","))
+	}
+}
+
+fn json_string(value: &str) -> String {
+	let mut out = String::from("\"");
+	for c in value.chars() {
+		match c {
+			'"' => out.push_str("\\\""),
+			'\\' => out.push_str("\\\\"),
+			c => out.push(c),
+		}
+	}
+	out.push('"');
+	out
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+	bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
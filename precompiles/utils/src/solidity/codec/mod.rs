@@ -23,7 +23,10 @@ pub mod native;
 #[cfg(any(feature = "codec-xcm", test))]
 pub mod xcm;
 
-use crate::solidity::revert::{MayRevert, RevertReason};
+use crate::solidity::{
+	abi::AbiType,
+	revert::{MayRevert, RevertReason},
+};
 use core::{marker::PhantomData, ops::Range};
 use sp_core::{H256, U256};
 use sp_std::{convert::TryInto, vec, vec::Vec};
@@ -44,6 +47,10 @@ pub trait Codec: Sized {
 	fn is_explicit_tuple() -> bool {
 		false
 	}
+	/// Type used to describe this value in a Solidity interface or ABI JSON.
+	fn abi_type() -> AbiType {
+		AbiType::Elementary(Self::signature())
+	}
 }
 
 /// Encode the value into its Solidity ABI format.
@@ -366,4 +373,8 @@ where
 	fn signature() -> String {
 		P::signature()
 	}
+
+	fn abi_type() -> AbiType {
+		P::abi_type()
+	}
 }
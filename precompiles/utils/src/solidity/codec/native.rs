@@ -15,7 +15,7 @@
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 
 use super::*;
-use crate::solidity::revert::InjectBacktrace;
+use crate::solidity::{abi::Param, revert::InjectBacktrace};
 use impl_trait_for_tuples::impl_for_tuples;
 use sp_core::{ConstU32, Get, H160};
 
@@ -33,6 +33,13 @@ impl Codec for () {
 	fn signature() -> String {
 		String::from("()")
 	}
+
+	fn abi_type() -> AbiType {
+		AbiType::Tuple {
+			name: None,
+			components: Vec::new(),
+		}
+	}
 }
 
 #[impl_for_tuples(1, 18)]
@@ -79,6 +86,15 @@ impl Codec for Tuple {
 	fn is_explicit_tuple() -> bool {
 		true
 	}
+
+	fn abi_type() -> AbiType {
+		let mut components = Vec::new();
+		for_tuples!( #( components.push(Param::new("", Tuple::abi_type())); )* );
+		AbiType::Tuple {
+			name: None,
+			components,
+		}
+	}
 }
 
 impl Codec for H256 {
@@ -271,6 +287,10 @@ impl<T: Codec> Codec for Vec<T> {
 	fn signature() -> String {
 		alloc::format!("{}[]", T::signature())
 	}
+
+	fn abi_type() -> AbiType {
+		AbiType::array(T::abi_type())
+	}
 }
 
 /// Wrapper around a Vec that provides a max length bound on read.
@@ -344,6 +364,10 @@ impl<T: Codec, S: Get<u32>> Codec for BoundedVec<T, S> {
 	fn signature() -> String {
 		alloc::format!("{}[]", T::signature())
 	}
+
+	fn abi_type() -> AbiType {
+		AbiType::array(T::abi_type())
+	}
 }
 
 impl<T, S> From<Vec<T>> for BoundedVec<T, S> {
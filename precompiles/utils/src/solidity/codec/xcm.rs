@@ -18,13 +18,14 @@
 
 use {
 	crate::solidity::{
+		abi::{AbiType, Param},
 		codec::{bytes::*, Codec, Reader, Writer},
 		revert::{BacktraceExt, InjectBacktrace, MayRevert, RevertReason},
 	},
 	alloc::string::String,
 	frame_support::{ensure, traits::ConstU32},
 	sp_core::H256,
-	sp_std::vec::Vec,
+	sp_std::{vec, vec::Vec},
 	sp_weights::Weight,
 	xcm::latest::{Junction, Junctions, MultiLocation, NetworkId},
 };
@@ -346,6 +347,10 @@ impl Codec for Junctions {
 	fn signature() -> String {
 		Vec::<Junction>::signature()
 	}
+
+	fn abi_type() -> AbiType {
+		Vec::<Junction>::abi_type()
+	}
 }
 
 // Cannot used derive macro since it is a foreign struct.
@@ -368,6 +373,16 @@ impl Codec for MultiLocation {
 	fn signature() -> String {
 		<(u8, Junctions)>::signature()
 	}
+
+	fn abi_type() -> AbiType {
+		AbiType::named_struct(
+			"Multilocation",
+			vec![
+				Param::new("parents", u8::abi_type()),
+				Param::new("interior", Junctions::abi_type()),
+			],
+		)
+	}
 }
 
 impl Codec for Weight {
@@ -389,4 +404,14 @@ impl Codec for Weight {
 	fn signature() -> String {
 		<(u64, u64)>::signature()
 	}
+
+	fn abi_type() -> AbiType {
+		AbiType::named_struct(
+			"Weight",
+			vec![
+				Param::new("refTime", u64::abi_type()),
+				Param::new("proofSize", u64::abi_type()),
+			],
+		)
+	}
 }
@@ -0,0 +1,126 @@
+// Copyright 2019-2023 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Solidity events, declared as Rust structs with `#[derive(solidity::Event)]`.
+//!
+//! ```rust,ignore
+//! #[derive(solidity::Event)]
+//! pub struct Transfer {
+//! 	#[indexed]
+//! 	pub from: Address,
+//! 	#[indexed]
+//! 	pub to: Address,
+//! 	pub value: U256,
+//! }
+//!
+//! Transfer { from, to, value }.emit(handle)?;
+//! ```
+//!
+//! The event name is the name of the struct, and its signature is computed from the
+//! `solidity::Codec` implementation of the fields. Up to 3 fields can be `#[indexed]`.
+
+use {
+	crate::{
+		evm::{handle::PrecompileHandleExt, logs::LogExt},
+		solidity::{
+			abi,
+			codec::{bytes::BoundedBytesString, Address},
+		},
+		EvmResult,
+	},
+	alloc::string::String,
+	fp_evm::{Log, PrecompileHandle},
+	sp_core::{Get, H160, H256, U256},
+};
+
+// derive macro
+pub use precompile_utils_macro::Event;
+
+/// Solidity event emitted by a precompile.
+pub trait Event: Sized {
+	/// Signature of the event, such as `Transfer(address,address,uint256)`.
+	fn signature() -> String;
+
+	/// Description of the event used in the Solidity interface and ABI JSON of the precompile.
+	fn abi_event() -> abi::Event;
+
+	/// Build the log of this event emitted by the provided address.
+	fn into_log(self, address: impl Into<H160>) -> Log;
+
+	/// First topic of the event, which is the hash of its signature.
+	fn topic0() -> H256 {
+		H256(sp_io::hashing::keccak_256(Self::signature().as_bytes()))
+	}
+
+	/// Charge the cost of the log then emit it from the address of the precompile.
+	fn emit(self, handle: &mut impl PrecompileHandle) -> EvmResult {
+		let log = self.into_log(handle.context().address);
+		handle.record_log_costs(&[&log])?;
+		log.record(handle)
+	}
+}
+
+/// Type that can be used as an `#[indexed]` field of an event.
+/// Value types are stored as is in the topic, while bytes and strings are hashed.
+pub trait EventTopic {
+	fn to_topic(self) -> H256;
+}
+
+impl EventTopic for H256 {
+	fn to_topic(self) -> H256 {
+		self
+	}
+}
+
+impl EventTopic for Address {
+	fn to_topic(self) -> H256 {
+		self.0.into()
+	}
+}
+
+impl EventTopic for U256 {
+	fn to_topic(self) -> H256 {
+		let mut topic = H256::zero();
+		self.to_big_endian(topic.as_bytes_mut());
+		topic
+	}
+}
+
+impl EventTopic for bool {
+	fn to_topic(self) -> H256 {
+		H256::from_low_u64_be(self as u64)
+	}
+}
+
+macro_rules! impl_event_topic_for_uints {
+	($($uint:ty),*) => {
+		$(
+			impl EventTopic for $uint {
+				fn to_topic(self) -> H256 {
+					U256::from(self).to_topic()
+				}
+			}
+		)*
+	};
+}
+
+impl_event_topic_for_uints!(u8, u16, u32, u64, u128);
+
+impl<K, S: Get<u32>> EventTopic for BoundedBytesString<K, S> {
+	fn to_topic(self) -> H256 {
+		H256(sp_io::hashing::keccak_256(self.as_bytes()))
+	}
+}
@@ -16,7 +16,9 @@
 
 //! Provides utilities for compatibility with Solidity tooling.
 
+pub mod abi;
 pub mod codec;
+pub mod event;
 pub mod modifier;
 pub mod revert;
 
@@ -24,3 +26,4 @@ pub use codec::{
 	decode_arguments, decode_event_data, decode_return_value, encode_arguments, encode_event_data,
 	encode_return_value, encode_with_selector, Codec,
 };
+pub use event::Event;
@@ -23,12 +23,13 @@ use {
 };
 
 /// Represents modifiers a Solidity function can be annotated with.
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub enum FunctionModifier {
 	/// Function that doesn't modify the state.
 	View,
 	/// Function that modifies the state but refuse receiving funds.
 	/// Correspond to a Solidity function with no modifiers.
+	#[default]
 	NonPayable,
 	/// Function that modifies the state and accept funds.
 	Payable,
@@ -17,8 +17,15 @@
 //! Utilities to work with revert messages with support for backtraces and
 //! consistent formatting.
 
-use crate::solidity::{self, codec::bytes::UnboundedBytes};
-use alloc::string::{String, ToString};
+use crate::solidity::{
+	self,
+	abi::AbiType,
+	codec::{bytes::UnboundedBytes, Codec},
+};
+use alloc::{
+	format,
+	string::{String, ToString},
+};
 use fp_evm::{ExitRevert, PrecompileFailure};
 use sp_std::vec::Vec;
 
@@ -37,6 +44,13 @@ pub fn revert_as_bytes(msg: impl Into<String>) -> Vec<u8> {
 	Revert::new(RevertReason::custom(msg)).to_encoded_bytes()
 }
 
+/// Generate a revert with a Solidity custom error, such as
+/// `error InsufficientBond(uint256 required, uint256 actual)`, from its name and arguments.
+/// Returns a `PrecompileFailure` that fits in an `EvmResult::Err`.
+pub fn custom_error<T: Codec>(name: &str, args: T) -> PrecompileFailure {
+	RevertReason::custom_error(name, args).into()
+}
+
 /// Generic error to build abi-encoded revert output.
 /// See: https://docs.soliditylang.org/en/latest/control-structures.html?highlight=revert#revert
 pub const ERROR_SELECTOR: u32 = 0x08c379a0;
@@ -111,6 +125,15 @@ pub enum RevertReason {
 	/// Used by a check that the input contains at least N static arguments.
 	/// Often use to return early if the input is too short.
 	ExpectedAtLeastNArguments(usize),
+	/// A Solidity custom error. Unlike other variants it is not encoded as an `Error(string)`
+	/// but as the error selector followed by its ABI encoded arguments, and the backtrace is
+	/// discarded.
+	CustomError {
+		/// Signature of the error, such as `InsufficientBond(uint256,uint256)`.
+		signature: String,
+		/// ABI encoded arguments of the error.
+		data: Vec<u8>,
+	},
 }
 
 impl RevertReason {
@@ -131,6 +154,26 @@ impl RevertReason {
 	pub fn value_is_too_large(what: impl Into<String>) -> Self {
 		RevertReason::ValueIsTooLarge { what: what.into() }
 	}
+
+	/// Create a `RevertReason::CustomError` from the name of the error and its arguments.
+	/// A tuple is encoded as multiple arguments, while any other type is a single argument.
+	pub fn custom_error<T: Codec>(name: &str, args: T) -> Self {
+		let signature = match T::abi_type() {
+			ty @ AbiType::Tuple { name: None, .. } => format!("{name}{}", ty.signature()),
+			ty => format!("{name}({})", ty.signature()),
+		};
+
+		RevertReason::CustomError {
+			signature,
+			data: solidity::encode_arguments(args),
+		}
+	}
+}
+
+/// Selector of a Solidity custom error from its signature.
+pub fn custom_error_selector(signature: &str) -> u32 {
+	let hash = sp_io::hashing::keccak_256(signature.as_bytes());
+	u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]])
 }
 
 impl core::fmt::Display for RevertReason {
@@ -147,6 +190,7 @@ impl core::fmt::Display for RevertReason {
 			RevertReason::ExpectedAtLeastNArguments(n) => {
 				write!(f, "Expected at least {n} arguments")
 			}
+			RevertReason::CustomError { signature, .. } => write!(f, "{signature}"),
 		}
 	}
 }
@@ -188,8 +232,15 @@ impl Revert {
 		self
 	}
 
-	/// Transforms the revert into its bytes representation (from a String).
+	/// Transforms the revert into its bytes representation (from a String), or into the
+	/// selector and arguments of the custom error.
 	pub fn to_encoded_bytes(self) -> Vec<u8> {
+		if let RevertReason::CustomError { signature, data } = &self.reason {
+			let mut bytes = custom_error_selector(signature).to_be_bytes().to_vec();
+			bytes.extend_from_slice(data);
+			return bytes;
+		}
+
 		let bytes: Vec<u8> = self.into();
 		solidity::encode_with_selector(ERROR_SELECTOR, UnboundedBytes::from(bytes))
 	}
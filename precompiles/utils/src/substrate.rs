@@ -18,10 +18,16 @@
 //! - Substrate call dispatch.
 //! - Substrate DB read and write costs
 
-use sp_runtime::traits::Dispatchable;
+use sp_runtime::{traits::Dispatchable, ModuleError};
 
 use {
-	crate::{evm::handle::using_precompile_handle, solidity::revert::revert},
+	crate::{
+		evm::handle::using_precompile_handle,
+		solidity::{
+			codec::UnboundedString,
+			revert::{revert, MayRevert, RevertReason},
+		},
+	},
 	core::marker::PhantomData,
 	fp_evm::{ExitError, PrecompileFailure, PrecompileHandle},
 	frame_support::{
@@ -36,12 +42,47 @@ use {
 /// Blake2_128Concat (16) + AccountId (20) + AccountInfo (4 + 12 + AccountData (4* 16)) = 148
 pub const SYSTEM_ACCOUNT_SIZE: u64 = 148;
 
+/// Name of the Solidity custom error used by `TryDispatchError::into_custom_error`:
+/// `error DispatchError(uint8 module, uint8 index, string message)`.
+pub const DISPATCH_ERROR_NAME: &str = "DispatchError";
+
 #[derive(Debug)]
 pub enum TryDispatchError {
 	Evm(ExitError),
 	Substrate(DispatchError),
 }
 
+impl TryDispatchError {
+	/// Convert into a `PrecompileFailure` in which pallet errors revert with the Solidity custom
+	/// error `DispatchError(uint8 module, uint8 index, string message)`, with the index of the
+	/// pallet in the runtime, the index of the error in the pallet and the name of the error.
+	/// This allows contracts to branch on specific pallet errors. Other errors are converted
+	/// like the `From` implementation does.
+	///
+	/// This is opt-in: a precompile maps the errors of `RuntimeHelper::try_dispatch` with this
+	/// function (as the parachain staking precompile does) and declares the custom error in its
+	/// Solidity interface. Precompiles relying on `?` keep reverting with an `Error(string)`
+	/// message, which existing callers of the ERC-20 precompiles expect.
+	pub fn into_custom_error(self) -> PrecompileFailure {
+		match self {
+			TryDispatchError::Substrate(DispatchError::Module(ModuleError {
+				index,
+				error,
+				message,
+			})) => RevertReason::custom_error(
+				DISPATCH_ERROR_NAME,
+				(
+					index,
+					error[0],
+					UnboundedString::from(message.unwrap_or_default()),
+				),
+			)
+			.into(),
+			other => other.into(),
+		}
+	}
+}
+
 impl From<TryDispatchError> for PrecompileFailure {
 	fn from(f: TryDispatchError) -> PrecompileFailure {
 		match f {
@@ -104,6 +145,8 @@ where
 	/// Try to dispatch a Substrate call.
 	/// Return an error if there are not enough gas, or if the call fails.
 	/// If successful returns the used gas using the Runtime GasWeightMapping.
+	/// Map the error with `TryDispatchError::into_custom_error` to revert with the
+	/// `DispatchError` custom error instead of an `Error(string)` message.
 	pub fn try_dispatch<Call>(
 		handle: &mut impl PrecompileHandle,
 		origin: <Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin,
@@ -156,3 +199,11 @@ where
 		)
 	}
 }
+
+/// Convert a value into another type sharing its SCALE encoding.
+/// This allows to read or build runtime types whose fields are not public, through a mirror of
+/// their definition which must be kept in sync with the original type.
+pub fn transcode<T: Decode>(value: &impl Encode) -> MayRevert<T> {
+	T::decode(&mut value.encode().as_slice())
+		.map_err(|_| RevertReason::custom("Failed to transcode value").into())
+}
@@ -16,7 +16,11 @@
 
 use {
 	crate::{
-		solidity::codec::Codec,
+		solidity::{
+			codec::Codec,
+			event::Event,
+			revert::{Revert, RevertReason},
+		},
 		testing::{decode_revert_message, MockHandle, PrettyLog, SubcallHandle, SubcallTrait},
 	},
 	fp_evm::{
@@ -36,6 +40,9 @@ pub struct PrecompilesTester<'p, P> {
 	subcall_handle: Option<SubcallHandle>,
 
 	expected_cost: Option<u64>,
+	expected_ref_time: Option<u64>,
+	expected_proof_size: Option<u64>,
+	expected_storage_growth: Option<u64>,
 	expected_logs: Option<Vec<PrettyLog>>,
 	static_call: bool,
 }
@@ -67,6 +74,9 @@ impl<'p, P: PrecompileSet> PrecompilesTester<'p, P> {
 			subcall_handle: None,
 
 			expected_cost: None,
+			expected_ref_time: None,
+			expected_proof_size: None,
+			expected_storage_growth: None,
 			expected_logs: None,
 			static_call: false,
 		}
@@ -92,11 +102,51 @@ impl<'p, P: PrecompileSet> PrecompilesTester<'p, P> {
 		self
 	}
 
+	pub fn with_ref_time_limit(mut self, limit: u64) -> Self {
+		self.handle.ref_time_limit = limit;
+		self
+	}
+
+	pub fn with_proof_size_limit(mut self, limit: u64) -> Self {
+		self.handle.proof_size_limit = limit;
+		self
+	}
+
+	pub fn with_storage_growth_limit(mut self, limit: u64) -> Self {
+		self.handle.storage_growth_limit = limit;
+		self
+	}
+
 	pub fn expect_cost(mut self, cost: u64) -> Self {
 		self.expected_cost = Some(cost);
 		self
 	}
 
+	/// Expect the ref time recorded as external cost, after refunds.
+	pub fn expect_ref_time(mut self, ref_time: u64) -> Self {
+		self.expected_ref_time = Some(ref_time);
+		self
+	}
+
+	/// Expect the proof size recorded as external cost, after refunds.
+	pub fn expect_proof_size(mut self, proof_size: u64) -> Self {
+		self.expected_proof_size = Some(proof_size);
+		self
+	}
+
+	/// Expect the storage growth recorded as external cost.
+	pub fn expect_storage_growth(mut self, storage_growth: u64) -> Self {
+		self.expected_storage_growth = Some(storage_growth);
+		self
+	}
+
+	/// Expect all the external costs at once.
+	pub fn expect_external_cost(self, ref_time: u64, proof_size: u64, storage_growth: u64) -> Self {
+		self.expect_ref_time(ref_time)
+			.expect_proof_size(proof_size)
+			.expect_storage_growth(storage_growth)
+	}
+
 	pub fn expect_no_logs(mut self) -> Self {
 		self.expected_logs = Some(vec![]);
 		self
@@ -111,11 +161,35 @@ impl<'p, P: PrecompileSet> PrecompilesTester<'p, P> {
 		self
 	}
 
+	/// Expect a log of the provided event, emitted by the precompile.
+	pub fn expect_event(self, event: impl Event) -> Self {
+		let address = self.handle.context.address;
+		self.expect_log(event.into_log(address))
+	}
+
 	fn assert_optionals(&self) {
 		if let Some(cost) = &self.expected_cost {
 			assert_eq!(&self.handle.gas_used, cost);
 		}
 
+		if let Some(ref_time) = &self.expected_ref_time {
+			assert_eq!(&self.handle.ref_time_used, ref_time, "unexpected ref time");
+		}
+
+		if let Some(proof_size) = &self.expected_proof_size {
+			assert_eq!(
+				&self.handle.proof_size_used, proof_size,
+				"unexpected proof size"
+			);
+		}
+
+		if let Some(storage_growth) = &self.expected_storage_growth {
+			assert_eq!(
+				&self.handle.storage_growth_used, storage_growth,
+				"unexpected storage growth"
+			);
+		}
+
 		if let Some(logs) = &self.expected_logs {
 			similar_asserts::assert_eq!(&self.handle.logs, logs);
 		}
@@ -222,6 +296,33 @@ impl<'p, P: PrecompileSet> PrecompilesTester<'p, P> {
 		self.assert_optionals();
 	}
 
+	/// Execute the precompile set and check it reverts with the provided Solidity custom error,
+	/// whose revert data is the error selector followed by its ABI encoded arguments instead of
+	/// an `Error(string)` message.
+	pub fn execute_reverts_with_custom_error<T: Codec>(mut self, name: &str, args: T) {
+		let expected = Revert::new(RevertReason::custom_error(name, args)).to_encoded_bytes();
+		let res = self.execute();
+
+		match res {
+			Some(Err(PrecompileFailure::Revert { output, .. })) => {
+				if output != expected {
+					eprintln!(
+						"Revert data (bytes): {:?}",
+						sp_core::hexdisplay::HexDisplay::from(&output)
+					);
+					eprintln!(
+						"Expected revert data (bytes): {:?}",
+						sp_core::hexdisplay::HexDisplay::from(&expected)
+					);
+					panic!("Revert data doesn't match !");
+				}
+			}
+			other => panic!("Didn't revert, instead returned {:?}", other),
+		}
+
+		self.assert_optionals();
+	}
+
 	/// Execute the precompile set and check it returns provided output.
 	pub fn execute_error(mut self, error: ExitError) {
 		let res = self.execute();
@@ -79,6 +79,12 @@ pub type SubcallHandle = Box<dyn SubcallTrait>;
 pub struct MockHandle {
 	pub gas_limit: u64,
 	pub gas_used: u64,
+	pub ref_time_limit: u64,
+	pub ref_time_used: u64,
+	pub proof_size_limit: u64,
+	pub proof_size_used: u64,
+	pub storage_growth_limit: u64,
+	pub storage_growth_used: u64,
 	pub logs: Vec<PrettyLog>,
 	pub subcall_handle: Option<SubcallHandle>,
 	pub code_address: H160,
@@ -92,6 +98,12 @@ impl MockHandle {
 		Self {
 			gas_limit: u64::MAX,
 			gas_used: 0,
+			ref_time_limit: u64::MAX,
+			ref_time_used: 0,
+			proof_size_limit: u64::MAX,
+			proof_size_used: 0,
+			storage_growth_limit: u64::MAX,
+			storage_growth_used: 0,
 			logs: vec![],
 			subcall_handle: None,
 			code_address,
@@ -203,14 +215,35 @@ impl PrecompileHandle for MockHandle {
 		Some(self.gas_limit)
 	}
 
+	/// Record external costs against their limits. Nothing is recorded if any of the limits is
+	/// exceeded.
 	fn record_external_cost(
 		&mut self,
-		_ref_time: Option<u64>,
-		_proof_size: Option<u64>,
-		_storage_growth: Option<u64>,
+		ref_time: Option<u64>,
+		proof_size: Option<u64>,
+		storage_growth: Option<u64>,
 	) -> Result<(), ExitError> {
+		let ref_time_used = self.ref_time_used.saturating_add(ref_time.unwrap_or(0));
+		let proof_size_used = self.proof_size_used.saturating_add(proof_size.unwrap_or(0));
+		let storage_growth_used = self
+			.storage_growth_used
+			.saturating_add(storage_growth.unwrap_or(0));
+
+		if ref_time_used > self.ref_time_limit
+			|| proof_size_used > self.proof_size_limit
+			|| storage_growth_used > self.storage_growth_limit
+		{
+			return Err(ExitError::OutOfGas);
+		}
+
+		self.ref_time_used = ref_time_used;
+		self.proof_size_used = proof_size_used;
+		self.storage_growth_used = storage_growth_used;
 		Ok(())
 	}
 
-	fn refund_external_cost(&mut self, _ref_time: Option<u64>, _proof_size: Option<u64>) {}
+	fn refund_external_cost(&mut self, ref_time: Option<u64>, proof_size: Option<u64>) {
+		self.ref_time_used = self.ref_time_used.saturating_sub(ref_time.unwrap_or(0));
+		self.proof_size_used = self.proof_size_used.saturating_sub(proof_size.unwrap_or(0));
+	}
 }
@@ -16,6 +16,10 @@
 
 //! Utility module to interact with solidity file.
 
+use crate::solidity::{
+	abi::{Interface, SolidityInterface},
+	modifier::FunctionModifier,
+};
 use sp_io::hashing::keccak_256;
 use std::{
 	collections::HashMap,
@@ -51,6 +55,132 @@ pub fn check_precompile_implements_solidity_interfaces<F>(
 	}
 }
 
+/// Check that the Solidity interface generated from the `#[precompile]` definition of `P` matches
+/// the functions of the given Solidity files and their modifiers, as well as their events if `P`
+/// declares its events, and that the generated interface is itself a valid description of the
+/// precompile selectors.
+/// Every generated function must be listed in the files, except the deprecated snake_case
+/// selectors of the functions listed in camelCase.
+pub fn check_precompile_generated_interface<P: SolidityInterface>(files: &[&'static str]) {
+	let functions = P::solidity_functions();
+	let declared: Vec<_> = files.iter().flat_map(|file| get_selectors(file)).collect();
+
+	for file in files {
+		for solidity_fn in get_selectors(file) {
+			let selector = solidity_fn.compute_selector();
+			let generated = functions
+				.iter()
+				.find(|f| f.selector == selector)
+				.unwrap_or_else(|| {
+					panic!(
+						"function '{}' listed in file {file} is missing from the generated \
+						interface",
+						solidity_fn.signature(),
+					)
+				});
+
+			assert_eq!(
+				generated.signature(),
+				solidity_fn.signature(),
+				"generated signature doesn't match the one in file '{}'",
+				file,
+			);
+			assert_eq!(
+				generated.modifier,
+				solidity_fn.modifier,
+				"generated modifier of '{}' doesn't match the one in file '{}'",
+				solidity_fn.signature(),
+				file,
+			);
+		}
+	}
+
+	for function in &functions {
+		let signature = function.signature();
+		let camel_case_signature = to_camel_case(&signature);
+		assert!(
+			declared.iter().any(|solidity_fn| {
+				let declared_signature = solidity_fn.signature();
+				declared_signature == signature
+					|| (signature.contains('_') && declared_signature == camel_case_signature)
+			}),
+			"generated function '{signature}' is not listed in files {files:?}",
+		);
+	}
+
+	// Precompiles declaring their events must declare all the events of the Solidity files.
+	let events = P::solidity_events();
+	if !events.is_empty() {
+		let declared: Vec<_> = files.iter().flat_map(|file| get_events(file)).collect();
+
+		for solidity_event in &declared {
+			let generated = events
+				.iter()
+				.find(|e| e.name == solidity_event.name)
+				.unwrap_or_else(|| {
+					panic!(
+						"event '{}' listed in files {files:?} is missing from the generated \
+						interface",
+						solidity_event.signature(),
+					)
+				});
+
+			assert_eq!(
+				generated.signature(),
+				solidity_event.signature(),
+				"generated event signature doesn't match the one in files {:?}",
+				files,
+			);
+			assert_eq!(
+				generated
+					.inputs
+					.iter()
+					.map(|p| p.indexed)
+					.collect::<Vec<_>>(),
+				solidity_event.indexed,
+				"generated indexed fields of '{}' don't match the ones in files {:?}",
+				solidity_event.signature(),
+				files,
+			);
+		}
+
+		for event in &events {
+			assert!(
+				declared.iter().any(|e| e.name == event.name),
+				"generated event '{}' is not listed in files {files:?}",
+				event.signature(),
+			);
+		}
+	}
+
+	let generated = Interface::of::<P>("Generated", None).to_solidity();
+	for solidity_fn in get_selectors_from_reader(generated.as_bytes()) {
+		assert_eq!(
+			solidity_fn.compute_selector_hex(),
+			solidity_fn.docs_selector,
+			"generated selector for '{}' doesn't match its signature",
+			solidity_fn.signature(),
+		);
+	}
+}
+
+/// Convert the snake_case name of a function signature to camelCase.
+fn to_camel_case(signature: &str) -> String {
+	let (name, args) = signature.split_once('(').unwrap_or((signature, ""));
+	let mut out = String::new();
+	for (i, word) in name.split('_').enumerate() {
+		let mut chars = word.chars();
+		match chars.next() {
+			Some(first) if i > 0 => {
+				out.extend(first.to_uppercase());
+				out.push_str(chars.as_str());
+			}
+			_ => out.push_str(word),
+		}
+	}
+	format!("{out}({args}")
+}
+
 /// Represents a declared custom type struct within a solidity file
 #[derive(Clone, Default, Debug)]
 pub struct SolidityStruct {
@@ -82,6 +212,8 @@ pub struct SolidityFunction {
 	pub args: Vec<String>,
 	/// The declared selector in the file
 	pub docs_selector: String,
+	/// The declared modifier, `pure` functions being `view`
+	pub modifier: FunctionModifier,
 }
 
 impl SolidityFunction {
@@ -101,6 +233,24 @@ impl SolidityFunction {
 	}
 }
 
+/// Represents a declared event within a solidity file
+#[derive(Clone, Default, Debug)]
+pub struct SolidityEvent {
+	/// Event name
+	pub name: String,
+	/// List of event parameter types
+	pub args: Vec<String>,
+	/// Whether each parameter is indexed
+	pub indexed: Vec<bool>,
+}
+
+impl SolidityEvent {
+	/// Returns the representative signature for the solidity event
+	pub fn signature(&self) -> String {
+		format!("{}({})", self.name, self.args.join(","))
+	}
+}
+
 /// Computes a solidity selector from a given string
 pub fn compute_selector(v: &str) -> u32 {
 	let output = keccak_256(v.as_bytes());
@@ -134,6 +284,43 @@ fn try_lookup_custom_type(word: &str, custom_types: &HashMap<String, SolidityStr
 	word.to_string()
 }
 
+/// Returns a list of [SolidityEvent] defined in a solidity file
+pub fn get_events(filename: &str) -> Vec<SolidityEvent> {
+	let file = File::open(filename)
+		.unwrap_or_else(|e| panic!("failed opening file '{}': {}", filename, e));
+	get_events_from_reader(file)
+}
+
+fn get_events_from_reader<R: Read>(reader: R) -> Vec<SolidityEvent> {
+	let code: Vec<_> = BufReader::new(reader)
+		.lines()
+		.map(|line| line.expect("failed unwrapping line").trim().to_string())
+		.filter(|line| !line.starts_with("//"))
+		.collect();
+
+	code.join(" ")
+		.split(';')
+		.filter_map(|statement| {
+			let (_, declaration) = format!(" {statement}")
+				.split_once(" event ")
+				.map(|(before, after)| (before.to_string(), after.to_string()))?;
+			let (name, params) = declaration.split_once('(')?;
+			let params = params.rsplit_once(')')?.0;
+
+			let mut event = SolidityEvent {
+				name: name.trim().to_string(),
+				..Default::default()
+			};
+			for param in params.split(',').filter(|param| !param.trim().is_empty()) {
+				let mut words = param.split_whitespace();
+				event.args.push(words.next()?.to_string());
+				event.indexed.push(words.any(|word| word == "indexed"));
+			}
+			Some(event)
+		})
+		.collect()
+}
+
 fn get_selectors_from_reader<R: Read>(reader: R) -> Vec<SolidityFunction> {
 	#[derive(Clone, Copy)]
 	enum Stage {
@@ -143,6 +330,7 @@ fn get_selectors_from_reader<R: Read>(reader: R) -> Vec<SolidityFunction> {
 		StructParams,
 		FnName,
 		Args,
+		Modifiers,
 	}
 	#[derive(Clone, Copy)]
 	enum Pair {
@@ -169,7 +357,9 @@ fn get_selectors_from_reader<R: Read>(reader: R) -> Vec<SolidityFunction> {
 	for line in reader.lines() {
 		let line = line.expect("failed unwrapping line").trim().to_string();
 		// identify declared selector
-		if line.starts_with("/// @custom:selector ") && matches!(stage, Stage::Start) {
+		if line.starts_with("/// @custom:selector ")
+			&& matches!(stage, Stage::Start | Stage::Modifiers)
+		{
 			solidity_fn.docs_selector = line.replace("/// @custom:selector ", "").to_string();
 		}
 
@@ -183,6 +373,24 @@ fn get_selectors_from_reader<R: Read>(reader: R) -> Vec<SolidityFunction> {
 			if word.trim().is_empty() {
 				continue;
 			}
+			// parse the modifiers following "external", until any other word
+			if let Stage::Modifiers = stage {
+				let modifier = match word {
+					"view" | "pure" => FunctionModifier::View,
+					"payable" => FunctionModifier::Payable,
+					_ => {
+						stage = Stage::Start;
+						pair = Pair::First;
+						FunctionModifier::NonPayable
+					}
+				};
+				if let Stage::Modifiers = stage {
+					if let Some(solidity_fn) = functions.last_mut() {
+						solidity_fn.modifier = modifier;
+					}
+					continue;
+				}
+			}
 			match (stage, pair, word) {
 				// parse custom type enums
 				(Stage::Start, Pair::First, "enum") => {
@@ -239,7 +447,7 @@ fn get_selectors_from_reader<R: Read>(reader: R) -> Vec<SolidityFunction> {
 				}
 				(Stage::Args, Pair::First, "external") => {
 					functions.push(solidity_fn);
-					stage = Stage::Start;
+					stage = Stage::Modifiers;
 					pair = Pair::First;
 					solidity_fn = SolidityFunction::default()
 				}
@@ -369,4 +577,82 @@ mod tests {
 
 		assert_eq!(expected, actual);
 	}
+
+	#[test]
+	fn test_modifiers_are_parsed() {
+		let code = "
+			interface Test {
+				/// @custom:selector 00000001
+				function fnView() external view returns (uint256);
+				/// @custom:selector 00000002
+				function fnPure(uint256 a) external pure returns (uint256);
+				function fnPayable() external payable;
+				function fnNonPayable() external returns (bool);
+				function fnNoModifiers() external;
+			}
+		";
+		let actual = get_selectors_from_reader(code.as_bytes())
+			.into_iter()
+			.map(|sol_fn| (sol_fn.signature(), sol_fn.docs_selector, sol_fn.modifier))
+			.collect::<Vec<_>>();
+		let expected = vec![
+			(
+				String::from("fnView()"),
+				String::from("00000001"),
+				FunctionModifier::View,
+			),
+			(
+				String::from("fnPure(uint256)"),
+				String::from("00000002"),
+				FunctionModifier::View,
+			),
+			(
+				String::from("fnPayable()"),
+				String::new(),
+				FunctionModifier::Payable,
+			),
+			(
+				String::from("fnNonPayable()"),
+				String::new(),
+				FunctionModifier::NonPayable,
+			),
+			(
+				String::from("fnNoModifiers()"),
+				String::new(),
+				FunctionModifier::NonPayable,
+			),
+		];
+
+		assert_eq!(expected, actual);
+	}
+
+	#[test]
+	fn test_camel_case_signatures() {
+		assert_eq!(
+			to_camel_case("delegator_bond_more(address,uint256)"),
+			"delegatorBondMore(address,uint256)"
+		);
+		assert_eq!(to_camel_case("minDelegation()"), "minDelegation()");
+	}
+
+	#[test]
+	fn test_events_are_parsed() {
+		let actual = get_events("tests/solidity_test.sol")
+			.into_iter()
+			.map(|event| (event.signature(), event.indexed))
+			.collect::<Vec<_>>();
+		let expected = vec![
+			(String::from("EventNoArgs()"), vec![]),
+			(
+				String::from("EventTwoArgs(address,uint256)"),
+				vec![true, false],
+			),
+			(
+				String::from("EventSplitArgs(address,address,bytes32)"),
+				vec![true, true, false],
+			),
+		];
+
+		assert_eq!(expected, actual);
+	}
 }
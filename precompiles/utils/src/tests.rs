@@ -1119,3 +1119,247 @@ fn evm_data_solidity_types() {
 	// Struct encode like tuples
 	assert_eq!(MultiLocation::signature(), "(uint8,bytes[])");
 }
+
+#[test]
+fn abi_type_of_structs_and_tuples() {
+	use crate::solidity::abi::{AbiType, Param};
+
+	assert_eq!(
+		MultiLocation::abi_type(),
+		AbiType::named_struct(
+			"MultiLocation",
+			vec![
+				Param::new("parents", AbiType::elementary("uint8")),
+				Param::new("interior", AbiType::array(AbiType::elementary("bytes"))),
+			]
+		)
+	);
+	assert_eq!(
+		MultiLocation::abi_type().signature(),
+		MultiLocation::signature()
+	);
+	assert_eq!(
+		Vec::<(bool, Vec<Address>)>::abi_type().signature(),
+		Vec::<(bool, Vec<Address>)>::signature()
+	);
+
+	// A returned tuple describes multiple return values, while a struct is a single value.
+	assert_eq!(Param::outputs(<()>::abi_type()), vec![]);
+	assert_eq!(
+		Param::outputs(<(bool, U256)>::abi_type()),
+		vec![
+			Param::new("", AbiType::elementary("bool")),
+			Param::new("", AbiType::elementary("uint256")),
+		]
+	);
+	assert_eq!(
+		Param::outputs(MultiLocation::abi_type()),
+		vec![Param::new("", MultiLocation::abi_type())]
+	);
+}
+
+#[test]
+fn generate_solidity_interface_and_abi_json() {
+	use crate::solidity::abi::{Event, EventParam, Function, Interface, Param};
+
+	let interface = Interface {
+		name: String::from("Example"),
+		address: Some(H160::from_low_u64_be(0x0808)),
+		functions: vec![
+			Function {
+				name: String::from("send"),
+				selector: 0x82a5f2d9,
+				inputs: vec![
+					Param::new("destination", MultiLocation::abi_type()),
+					Param::new("amount", U256::abi_type()),
+				],
+				outputs: vec![],
+				modifier: FunctionModifier::Payable,
+			},
+			Function {
+				name: String::from("balances"),
+				selector: 0xec36c849,
+				inputs: vec![Param::new("who", Vec::<Address>::abi_type())],
+				outputs: Param::outputs(<(Vec<U256>, bool)>::abi_type()),
+				modifier: FunctionModifier::View,
+			},
+		],
+		events: vec![Event {
+			name: String::from("Sent"),
+			inputs: vec![
+				EventParam {
+					param: Param::new("from", Address::abi_type()),
+					indexed: true,
+				},
+				EventParam {
+					param: Param::new("amount", U256::abi_type()),
+					indexed: false,
+				},
+			],
+		}],
+	};
+
+	assert_eq!(
+		interface.functions[0].signature(),
+		"send((uint8,bytes[]),uint256)"
+	);
+	assert_eq!(interface.events[0].signature(), "Sent(address,uint256)");
+
+	let expected_solidity = "\
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @custom:address 0x0000000000000000000000000000000000000808
+interface Example {
+    struct MultiLocation {
+        uint8 parents;
+        bytes[] interior;
+    }
+
+    /// @custom:selector 82a5f2d9
+    function send(MultiLocation memory destination, uint256 amount) external payable;
+
+    /// @custom:selector ec36c849
+    function balances(address[] memory who) external view returns (uint256[] memory, bool);
+
+    /// @custom:selector 510ffb4dcab972ae9d2007a58e13f1b0881776d23cd8f5cc32f8c5be2dbf70d2
+    event Sent(address indexed from, uint256 amount);
+}
+";
+	assert_eq!(interface.to_solidity(), expected_solidity);
+
+	assert_eq!(
+		interface.to_abi_json(),
+		"[{\"type\":\"function\",\"name\":\"send\",\"inputs\":[{\"name\":\"destination\",\
+		\"type\":\"tuple\",\"components\":[{\"name\":\"parents\",\"type\":\"uint8\"},\
+		{\"name\":\"interior\",\"type\":\"bytes[]\"}]},{\"name\":\"amount\",\"type\":\"uint256\"}],\
+		\"outputs\":[],\"stateMutability\":\"payable\"},{\"type\":\"function\",\"name\":\"balances\",\
+		\"inputs\":[{\"name\":\"who\",\"type\":\"address[]\"}],\"outputs\":[{\"name\":\"\",\
+		\"type\":\"uint256[]\"},{\"name\":\"\",\"type\":\"bool\"}],\"stateMutability\":\"view\"},\
+		{\"type\":\"event\",\"name\":\"Sent\",\"inputs\":[{\"name\":\"from\",\"type\":\"address\",\
+		\"indexed\":true},{\"name\":\"amount\",\"type\":\"uint256\",\"indexed\":false}],\
+		\"anonymous\":false}]"
+	);
+}
+
+#[test]
+fn custom_errors_are_encoded_with_their_selector() {
+	use {
+		crate::solidity::revert::custom_error_selector,
+		fp_evm::{ExitRevert, PrecompileFailure},
+	};
+
+	let reason = RevertReason::custom_error("InsufficientBond", (U256::from(10), U256::from(5)));
+	assert_eq!(reason.to_string(), "InsufficientBond(uint256,uint256)");
+	assert_eq!(
+		Revert::new(reason).in_field("amount").to_encoded_bytes(),
+		Writer::new_with_selector(0x44d4caf7u32)
+			.write(U256::from(10))
+			.write(U256::from(5))
+			.build()
+	);
+
+	// A single argument is not a tuple, and no argument is an empty tuple.
+	assert_eq!(
+		RevertReason::custom_error("Unauthorized", Address(H160::repeat_byte(0x11))).to_string(),
+		"Unauthorized(address)"
+	);
+	assert_eq!(custom_error_selector("Unauthorized(address)"), 0x8e4a23d6);
+	assert_eq!(
+		custom_error("NotFound", ()),
+		PrecompileFailure::Revert {
+			exit_status: ExitRevert::Reverted,
+			output: 0xc5723b51u32.to_be_bytes().to_vec(),
+		}
+	);
+}
+
+#[derive(Debug, solidity::Event)]
+struct Transfer {
+	#[indexed]
+	from: Address,
+	#[indexed]
+	to: Address,
+	value: U256,
+}
+
+#[derive(solidity::Event)]
+struct Named {
+	#[indexed]
+	name: UnboundedString,
+	account_id: H256,
+	flag: bool,
+}
+
+#[test]
+fn derive_event_builds_logs() {
+	use crate::solidity::{
+		abi::{EventParam, Param},
+		Event,
+	};
+
+	assert_eq!(Transfer::signature(), "Transfer(address,address,uint256)");
+	assert_eq!(
+		Transfer::topic0(),
+		H256(hex!(
+			"ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+		))
+	);
+
+	let log = Transfer {
+		from: Address(H160::repeat_byte(0x11)),
+		to: Address(H160::repeat_byte(0x22)),
+		value: U256::from(42),
+	}
+	.into_log(H160::repeat_byte(0x33));
+	assert_eq!(
+		log,
+		log3(
+			H160::repeat_byte(0x33),
+			Transfer::topic0(),
+			H160::repeat_byte(0x11),
+			H160::repeat_byte(0x22),
+			solidity::encode_event_data(U256::from(42)),
+		)
+	);
+
+	// Indexed bytes and strings are hashed.
+	assert_eq!(Named::signature(), "Named(string,bytes32,bool)");
+	let log = Named {
+		name: UnboundedString::from("hello"),
+		account_id: H256::repeat_byte(0x44),
+		flag: true,
+	}
+	.into_log(H160::repeat_byte(0x33));
+	assert_eq!(
+		log,
+		log2(
+			H160::repeat_byte(0x33),
+			H256(hex!(
+				"0cfeb42b6418057c010206d9bb22cdf81aff282b28db01d17135dface6d8421e"
+			)),
+			H256(hex!(
+				"1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8"
+			)),
+			solidity::encode_event_data((H256::repeat_byte(0x44), true)),
+		)
+	);
+
+	assert_eq!(
+		Named::abi_event().inputs,
+		vec![
+			EventParam {
+				param: Param::new("name", UnboundedString::abi_type()),
+				indexed: true,
+			},
+			EventParam {
+				param: Param::new("accountId", H256::abi_type()),
+				indexed: false,
+			},
+			EventParam {
+				param: Param::new("flag", bool::abi_type()),
+				indexed: false,
+			},
+		]
+	);
+}
@@ -20,13 +20,13 @@ mod tests {
 	use std::rc::Rc;
 
 	use evm::Context;
-	use fp_evm::{ExitReason, ExitRevert, PrecompileFailure, PrecompileHandle};
+	use fp_evm::{ExitError, ExitReason, ExitRevert, PrecompileFailure, PrecompileHandle};
 	use frame_support::traits::Everything;
 	use frame_support::{construct_runtime, parameter_types, weights::Weight};
 	use pallet_evm::{EnsureAddressNever, EnsureAddressRoot};
 	use precompile_utils::{
 		precompile_set::*,
-		solidity::{codec::Writer, revert::revert},
+		solidity::{abi::Interface, codec::Writer, revert::revert},
 		testing::*,
 		EvmResult,
 	};
@@ -450,4 +450,85 @@ mod tests {
 			);
 		})
 	}
+
+	#[test]
+	fn precompile_macro_describes_solidity_functions() {
+		use precompile_utils::solidity::abi::{Interface, SolidityInterface};
+
+		let functions = MockPrecompile::solidity_functions();
+		assert_eq!(functions.len(), 2);
+		assert_eq!(functions[0].signature(), "subcall()");
+		assert_eq!(functions[0].selector, 0xa3cab0dd);
+		assert_eq!(functions[1].signature(), "success()");
+		assert_eq!(functions[1].selector, 0x0b93381b);
+
+		assert_eq!(
+			Interface::of::<MockPrecompile>("Mock", None).to_abi_json(),
+			"[{\"type\":\"function\",\"name\":\"subcall\",\"inputs\":[],\"outputs\":[],\
+			\"stateMutability\":\"nonpayable\"},{\"type\":\"function\",\"name\":\"success\",\
+			\"inputs\":[],\"outputs\":[],\"stateMutability\":\"nonpayable\"}]"
+		);
+	}
+
+	#[test]
+	fn mock_handle_records_external_costs_against_limits() {
+		let mut handle = MockHandle::new(
+			H160::repeat_byte(0x11),
+			Context {
+				address: H160::repeat_byte(0x11),
+				caller: H160::repeat_byte(0x22),
+				apparent_value: U256::zero(),
+			},
+		);
+		handle.ref_time_limit = 1_000;
+		handle.proof_size_limit = 100;
+		handle.storage_growth_limit = 10;
+
+		assert_eq!(
+			handle.record_external_cost(Some(500), Some(60), Some(10)),
+			Ok(())
+		);
+		handle.refund_external_cost(Some(100), Some(20));
+		assert_eq!(handle.ref_time_used, 400);
+		assert_eq!(handle.proof_size_used, 40);
+		assert_eq!(handle.storage_growth_used, 10);
+
+		// Exceeding any limit fails without recording anything.
+		assert_eq!(
+			handle.record_external_cost(Some(100), Some(61), None),
+			Err(ExitError::OutOfGas)
+		);
+		assert_eq!(
+			handle.record_external_cost(None, None, Some(1)),
+			Err(ExitError::OutOfGas)
+		);
+		assert_eq!(handle.ref_time_used, 400);
+		assert_eq!(handle.proof_size_used, 40);
+		assert_eq!(handle.storage_growth_used, 10);
+
+		assert_eq!(
+			handle.record_external_cost(Some(600), Some(60), None),
+			Ok(())
+		);
+		assert_eq!(handle.ref_time_used, 1_000);
+		assert_eq!(handle.proof_size_used, 100);
+	}
+
+	#[test]
+	fn precompile_set_interfaces_are_generated() {
+		let interfaces = Precompiles::<Runtime>::solidity_interfaces();
+
+		assert_eq!(
+			interfaces,
+			(1..=4)
+				.map(|address| Interface::of::<MockPrecompile>(
+					"MockPrecompile",
+					Some(H160::from_low_u64_be(address))
+				))
+				.collect::<Vec<_>>()
+		);
+		assert!(interfaces[0]
+			.to_solidity()
+			.contains("/// @custom:address 0x0000000000000000000000000000000000000001"));
+	}
 }
@@ -120,4 +120,19 @@ interface SolidityTest {
     /// @custom:selector 97baa040
     function fnCustomComposedArrayArg(CustomArg2[] memory arg0, uint64 arg1)
         external;
+
+    /// @dev Event without params
+    event EventNoArgs();
+
+    /// @dev Event info
+    /// @param arg0 Arg0 Description
+    /// @param arg1 Arg1 Description
+    event EventTwoArgs(address indexed arg0, uint256 arg1);
+
+    /// @dev Event split over multiple lines
+    event EventSplitArgs(
+        address indexed arg0,
+        address indexed arg1,
+        bytes32 arg2
+    );
 }
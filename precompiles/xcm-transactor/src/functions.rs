@@ -23,11 +23,12 @@ use frame_support::{
 };
 use pallet_evm::AddressMapping;
 use pallet_xcm_transactor::{
-	Currency, CurrencyPayment, RemoteTransactInfoWithMaxWeight, TransactWeights,
+	Currency, CurrencyPayment, RemoteTransactInfoWithMaxWeight, TransactReport, TransactWeights,
 };
+use parity_scale_codec::Encode;
 use precompile_utils::prelude::*;
 use sp_core::{MaxEncodedLen, H160, U256};
-use sp_runtime::traits::Dispatchable;
+use sp_runtime::{traits::Dispatchable, DispatchError};
 use sp_std::{
 	boxed::Box,
 	convert::{TryFrom, TryInto},
@@ -50,6 +51,10 @@ pub type CurrencyIdOf<Runtime> = <Runtime as pallet_xcm_transactor::Config>::Cur
 pub const CALL_DATA_LIMIT: u32 = 2u32.pow(16);
 pub type GetDataLimit = ConstU32<CALL_DATA_LIMIT>;
 
+/// Max encoded length of the response stored in a transact status query:
+/// Option(1) + Response::DispatchResult(1) + MaybeErrorCode(1) + BoundedVec(2 + 128)
+const TRANSACT_STATUS_RESPONSE_LEN: usize = 133;
+
 impl<Runtime> XcmTransactorWrapper<Runtime>
 where
 	Runtime: pallet_xcm_transactor::Config + pallet_evm::Config + frame_system::Config,
@@ -137,8 +142,10 @@ where
 		// fetch data from pallet
 		// storage item: AssetTypeUnitsPerSecond: Blake2_128(16) + MultiLocation + u128(16)
 		handle.record_db_read::<Runtime>(32 + MultiLocation::max_encoded_len())?;
+		// The fee per second provider is queried first, we assume it involves a similar read
+		handle.record_db_read::<Runtime>(32 + MultiLocation::max_encoded_len())?;
 		let fee_per_second: u128 =
-			pallet_xcm_transactor::Pallet::<Runtime>::dest_asset_fee_per_second(multilocation)
+			pallet_xcm_transactor::Pallet::<Runtime>::fee_per_second(&multilocation)
 				.ok_or(revert("Fee Per Second not set"))?;
 
 		Ok(fee_per_second.into())
@@ -567,6 +574,37 @@ where
 		))
 	}
 
+	pub(crate) fn transact_status(
+		handle: &mut impl PrecompileHandle,
+		query_id: u64,
+	) -> EvmResult<(bool, bool, UnboundedBytes)> {
+		// fetch data from pallet
+		// storage item: TransactStatusQueries: Blake2_128(16) + QueryId(8) + TransactStatusQuery
+		// (AccountId(20) + MultiLocation + TransactReport(1) + BlockNumber(4) + Option<Response>)
+		handle.record_db_read::<Runtime>(
+			16 + 8 + 20 + MultiLocation::max_encoded_len() + 1 + 4 + TRANSACT_STATUS_RESPONSE_LEN,
+		)?;
+
+		let query = pallet_xcm_transactor::Pallet::<Runtime>::transact_status(query_id)
+			.ok_or(revert("Unknown query"))?;
+
+		// The error data is the raw dispatch error for ReportTransactStatus, and the scale
+		// encoded (instruction index, xcm error) for ReportError
+		let (responded, success, error) = match query.response {
+			None => (false, false, Vec::new()),
+			Some(Response::DispatchResult(MaybeErrorCode::Success)) => (true, true, Vec::new()),
+			Some(Response::DispatchResult(MaybeErrorCode::Error(error)))
+			| Some(Response::DispatchResult(MaybeErrorCode::TruncatedError(error))) => {
+				(true, false, error.into_inner())
+			}
+			Some(Response::ExecutionResult(None)) => (true, true, Vec::new()),
+			Some(Response::ExecutionResult(Some(error))) => (true, false, error.encode()),
+			Some(_) => (true, false, Vec::new()),
+		};
+
+		Ok((responded, success, error.into()))
+	}
+
 	pub(crate) fn transact_through_derivative_multilocation_v3(
 		handle: &mut impl PrecompileHandle,
 		transactor: u8,
@@ -761,4 +799,152 @@ where
 
 		Ok(())
 	}
+
+	pub(crate) fn transact_through_derivative_multilocation_with_report(
+		handle: &mut impl PrecompileHandle,
+		transactor: u8,
+		index: u16,
+		fee_asset: MultiLocation,
+		weight: Weight,
+		inner_call: BoundedBytes<GetDataLimit>,
+		fee_amount: u128,
+		overall_weight: Weight,
+		refund: bool,
+		report: u8,
+	) -> EvmResult<u64> {
+		let transactor: TransactorOf<Runtime> = transactor
+			.try_into()
+			.map_err(|_| RevertReason::custom("Non-existent transactor").in_field("transactor"))?;
+		let report = Self::transact_report(report)?;
+
+		let inner_call: Vec<_> = inner_call.into();
+
+		let overall_weight_limit = match overall_weight.ref_time() {
+			u64::MAX => Unlimited,
+			_ => Limited(overall_weight),
+		};
+
+		// Depending on the Runtime, this might involve a DB read. This is not the case in
+		// moonbeam, as we are using IdentityMapping
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let fee = CurrencyPayment {
+			currency: Currency::AsMultiLocation(Box::new(xcm::VersionedMultiLocation::V3(
+				fee_asset,
+			))),
+			fee_amount: Some(fee_amount),
+		};
+		let weight_info = TransactWeights {
+			transact_required_weight_at_most: weight,
+			overall_weight: Some(overall_weight_limit),
+		};
+		let call =
+			pallet_xcm_transactor::Call::<Runtime>::transact_through_derivative_with_report {
+				dest: transactor.clone(),
+				index,
+				fee: fee.clone(),
+				inner_call: inner_call.clone(),
+				weight_info: weight_info.clone(),
+				refund,
+				report,
+			};
+
+		Self::transact_with_report(handle, call, || {
+			pallet_xcm_transactor::Pallet::<Runtime>::do_transact_through_derivative(
+				origin,
+				transactor,
+				index,
+				fee,
+				inner_call,
+				weight_info,
+				refund,
+				Some(report),
+			)
+		})
+	}
+
+	pub(crate) fn transact_through_signed_multilocation_with_report(
+		handle: &mut impl PrecompileHandle,
+		dest: MultiLocation,
+		fee_asset: MultiLocation,
+		weight: Weight,
+		call: BoundedBytes<GetDataLimit>,
+		fee_amount: u128,
+		overall_weight: Weight,
+		refund: bool,
+		report: u8,
+	) -> EvmResult<u64> {
+		let report = Self::transact_report(report)?;
+
+		let call: Vec<_> = call.into();
+
+		let overall_weight_limit = match overall_weight.ref_time() {
+			u64::MAX => Unlimited,
+			_ => Limited(overall_weight),
+		};
+
+		// Depending on the Runtime, this might involve a DB read. This is not the case in
+		// moonbeam, as we are using IdentityMapping
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let dest = Box::new(xcm::VersionedMultiLocation::V3(dest));
+		let fee = CurrencyPayment {
+			currency: Currency::AsMultiLocation(Box::new(xcm::VersionedMultiLocation::V3(
+				fee_asset,
+			))),
+			fee_amount: Some(fee_amount),
+		};
+		let weight_info = TransactWeights {
+			transact_required_weight_at_most: weight,
+			overall_weight: Some(overall_weight_limit),
+		};
+		let dispatch_call =
+			pallet_xcm_transactor::Call::<Runtime>::transact_through_signed_with_report {
+				dest: dest.clone(),
+				fee: fee.clone(),
+				call: call.clone(),
+				weight_info: weight_info.clone(),
+				refund,
+				report,
+			};
+
+		Self::transact_with_report(handle, dispatch_call, || {
+			pallet_xcm_transactor::Pallet::<Runtime>::do_transact_through_signed(
+				origin,
+				dest,
+				fee,
+				call,
+				weight_info,
+				refund,
+				Some(report),
+			)
+		})
+	}
+
+	fn transact_report(report: u8) -> EvmResult<TransactReport> {
+		match report {
+			0 => Ok(TransactReport::TransactStatus),
+			1 => Ok(TransactReport::ExecutionResult),
+			_ => Err(RevertReason::custom("Unknown report kind")
+				.in_field("report")
+				.into()),
+		}
+	}
+
+	/// Run a transact requesting a report and return the id of the registered transact status
+	/// query. The pallet is called directly instead of dispatching `call` so the query id can be
+	/// returned, but the cost charged is the weight of `call`.
+	fn transact_with_report(
+		handle: &mut impl PrecompileHandle,
+		call: pallet_xcm_transactor::Call<Runtime>,
+		transact: impl FnOnce() -> Result<Option<QueryId>, DispatchError>,
+	) -> EvmResult<u64> {
+		let weight = call.get_dispatch_info().weight;
+		RuntimeHelper::<Runtime>::reocrd_external_cost(handle, weight, 0)?;
+
+		let query_id = frame_support::storage::with_storage_layer(transact)
+			.map_err(TryDispatchError::Substrate)?;
+
+		RuntimeHelper::<Runtime>::refund_weight_v2_cost(handle, weight, None)?;
+
+		query_id.ok_or_else(|| revert("No transact status query registered"))
+	}
 }
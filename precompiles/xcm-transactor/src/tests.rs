@@ -14,15 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 use crate::mock::{
-	AssetAddress, ExtBuilder, PCallV1, PCallV2, PCallV3, Precompiles, PrecompilesValue, Runtime,
-	RuntimeOrigin, TransactorV1, TransactorV2, TransactorV3, XcmTransactor,
+	AssetAddress, ExtBuilder, MaxTransactStatusQueries, PCallV1, PCallV2, PCallV3, Precompiles,
+	PrecompilesValue, Runtime, RuntimeOrigin, TransactorV1, TransactorV2, TransactorV3,
+	XcmTransactor,
 };
 
 use frame_support::{assert_ok, weights::Weight};
 use precompile_utils::{prelude::*, testing::*};
 use sp_core::H160;
 use sp_std::boxed::Box;
-use xcm::latest::MultiLocation;
+use std::str::from_utf8;
+use xcm::latest::{MaybeErrorCode, MultiLocation, Response};
 
 fn precompiles() -> Precompiles<Runtime> {
 	PrecompilesValue::get()
@@ -54,6 +56,15 @@ fn selectors() {
 	assert!(PCallV3::transact_through_derivative_selectors().contains(&0xca8c82d8));
 	assert!(PCallV3::transact_through_signed_multilocation_selectors().contains(&0x27b1d492));
 	assert!(PCallV3::transact_through_signed_selectors().contains(&0xb18270cf));
+	assert!(PCallV3::transact_status_selectors().contains(&0x30015ade));
+	assert!(
+		PCallV3::transact_through_derivative_multilocation_with_report_selectors()
+			.contains(&0x1cab7237)
+	);
+	assert!(
+		PCallV3::transact_through_signed_multilocation_with_report_selectors()
+			.contains(&0x32390bab)
+	);
 }
 
 #[test]
@@ -234,7 +245,7 @@ fn take_fee_per_second() {
 			));
 			precompiles()
 				.prepare_test(Alice, TransactorV1, input)
-				.expect_cost(1)
+				.expect_cost(2)
 				.expect_no_logs()
 				.execute_returns(1u64);
 		});
@@ -371,6 +382,50 @@ fn take_transact_info_with_signed_v3() {
 		});
 }
 
+#[test]
+fn take_transact_status_v3() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			let input: Vec<_> = PCallV3::transact_status { query_id: 0 }.into();
+
+			// Assert that errors since no query is registered
+			precompiles()
+				.prepare_test(Alice, TransactorV3, input.clone())
+				.execute_reverts(|output| output == b"Unknown query");
+
+			pallet_xcm_transactor::TransactStatusQueries::<Runtime>::insert(
+				0,
+				pallet_xcm_transactor::TransactStatusQuery {
+					account: Alice.into(),
+					dest: MultiLocation::parent(),
+					report: pallet_xcm_transactor::TransactReport::TransactStatus,
+					timeout: 100,
+					response: None,
+				},
+			);
+
+			// Not answered yet
+			precompiles()
+				.prepare_test(Alice, TransactorV3, input.clone())
+				.expect_no_logs()
+				.execute_returns((false, false, UnboundedBytes::from(&[][..])));
+
+			// The relay reports the dispatch error of the transact
+			assert_ok!(XcmTransactor::notify_transact_status(
+				RuntimeOrigin::root(),
+				0,
+				Response::DispatchResult(MaybeErrorCode::Error(vec![1u8, 2u8].try_into().unwrap()))
+			));
+
+			precompiles()
+				.prepare_test(Alice, TransactorV3, input)
+				.expect_no_logs()
+				.execute_returns((true, false, UnboundedBytes::from(&[1u8, 2u8][..])));
+		});
+}
+
 #[test]
 fn test_transact_derivative_multilocation() {
 	ExtBuilder::default()
@@ -792,6 +847,94 @@ fn test_transact_through_signed_multilocation_v3() {
 		});
 }
 
+#[test]
+fn test_transact_derivative_multilocation_with_report_v3() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			// register index
+			assert_ok!(XcmTransactor::register(
+				RuntimeOrigin::root(),
+				Alice.into(),
+				0
+			));
+
+			let total_weight = Weight::from_parts(1_000_000_000u64, 82_000u64);
+			let require_weight_at_most = Weight::from_parts(4_000_000u64, 82_000u64);
+			precompiles()
+				.prepare_test(
+					Alice,
+					TransactorV3,
+					PCallV3::transact_through_derivative_multilocation_with_report {
+						transactor: 0,
+						index: 0,
+						fee_asset: MultiLocation::parent(),
+						weight: require_weight_at_most,
+						inner_call: vec![1u8, 2u8, 3u8].into(),
+						fee_amount: u128::from(total_weight.ref_time()).into(),
+						overall_weight: total_weight,
+						refund: false,
+						report: 0,
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(0u64);
+
+			let query = XcmTransactor::transact_status(0).expect("query to be registered");
+			assert_eq!(query.account, Alice.into());
+			assert_eq!(
+				query.report,
+				pallet_xcm_transactor::TransactReport::TransactStatus
+			);
+		});
+}
+
+#[test]
+fn test_transact_through_signed_multilocation_with_report_v3() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			let total_weight = Weight::from_parts(1_000_000_000u64, 82_000u64);
+			let require_weight_at_most = Weight::from_parts(4_000_000u64, 82_000u64);
+			let call = |report| PCallV3::transact_through_signed_multilocation_with_report {
+				dest: MultiLocation::parent(),
+				fee_asset: MultiLocation::parent(),
+				weight: require_weight_at_most,
+				call: vec![1u8, 2u8, 3u8].into(),
+				fee_amount: u128::from(total_weight.ref_time()).into(),
+				overall_weight: total_weight,
+				refund: false,
+				report,
+			};
+
+			precompiles()
+				.prepare_test(Alice, TransactorV3, call(2))
+				.execute_reverts(|output| output.ends_with(b"Unknown report kind"));
+
+			// Each transact registers a new query, up to MaxTransactStatusQueries per account
+			for query_id in 0..MaxTransactStatusQueries::get() {
+				precompiles()
+					.prepare_test(Alice, TransactorV3, call(1))
+					.expect_no_logs()
+					.execute_returns(u64::from(query_id));
+			}
+			assert_eq!(
+				XcmTransactor::transact_status(0).map(|query| query.report),
+				Some(pallet_xcm_transactor::TransactReport::ExecutionResult)
+			);
+
+			precompiles()
+				.prepare_test(Alice, TransactorV3, call(1))
+				.execute_reverts(|output| {
+					from_utf8(output)
+						.unwrap()
+						.contains("TooManyTransactStatusQueries")
+				});
+		});
+}
+
 #[test]
 fn test_solidity_interface_has_all_function_selectors_documented_and_implemented_v1() {
 	check_precompile_implements_solidity_interfaces(
@@ -800,6 +943,13 @@ fn test_solidity_interface_has_all_function_selectors_documented_and_implemented
 	)
 }
 
+#[test]
+fn test_generated_interface_matches_solidity_interface_v1() {
+	check_precompile_generated_interface::<crate::v1::XcmTransactorPrecompileV1<Runtime>>(&[
+		"src/v1/XcmTransactorV1.sol",
+	])
+}
+
 #[test]
 fn test_solidity_interface_has_all_function_selectors_documented_and_implemented_v2() {
 	check_precompile_implements_solidity_interfaces(
@@ -808,6 +958,13 @@ fn test_solidity_interface_has_all_function_selectors_documented_and_implemented
 	)
 }
 
+#[test]
+fn test_generated_interface_matches_solidity_interface_v2() {
+	check_precompile_generated_interface::<crate::v2::XcmTransactorPrecompileV2<Runtime>>(&[
+		"src/v2/XcmTransactorV2.sol",
+	])
+}
+
 #[test]
 fn test_solidity_interface_has_all_function_selectors_documented_and_implemented_v3() {
 	check_precompile_implements_solidity_interfaces(
@@ -816,6 +973,13 @@ fn test_solidity_interface_has_all_function_selectors_documented_and_implemented
 	)
 }
 
+#[test]
+fn test_generated_interface_matches_solidity_interface_v3() {
+	check_precompile_generated_interface::<crate::v3::XcmTransactorPrecompileV3<Runtime>>(&[
+		"src/v3/XcmTransactorV3.sol",
+	])
+}
+
 #[test]
 fn test_deprecated_solidity_selectors_are_supported() {
 	for deprecated_function in [
@@ -59,6 +59,22 @@ interface XcmTransactorV3 {
         view
         returns (uint256 feePerSecond);
 
+    /// Get the status of a transact sent with a ReportTransactStatus or ReportError appendix
+    /// @custom:selector 30015ade
+    /// @param queryId The id of the query registered when sending the transact
+    /// @return responded Whether the destination chain already reported the result
+    /// @return success Whether the transact was successfully executed in the destination chain
+    /// @return error The error reported by the destination chain, if any
+    ///
+    function transactStatus(uint64 queryId)
+        external
+        view
+        returns (
+            bool responded,
+            bool success,
+            bytes memory error
+        );
+
     /// Transact through XCM using fee based on its multilocation
     /// @custom:selector bdacc26b
     /// @dev The token transfer burns/transfers the corresponding amount before sending
@@ -155,6 +171,63 @@ interface XcmTransactorV3 {
         bool refund
     ) external;
 
+    /// Transact through XCM using fee based on its multilocation, reporting the result back
+    /// @custom:selector 1cab7237
+    /// @dev The token transfer burns/transfers the corresponding amount before sending
+    /// @param transactor The transactor to be used
+    /// @param index The index to be used
+    /// @param feeAsset The asset in which we want to pay fees.
+    /// It has to be a reserve of the destination chain
+    /// @param transactRequiredWeightAtMost The weight we want to buy in the destination chain
+    /// @param innerCall The inner call to be executed in the destination chain
+    /// @param feeAmount Amount to be used as fee.
+    /// @param overallWeight Overall weight to be used for the xcm message. If uint64:MAX is passed 
+    /// through refTime field, Unlimited variant will be used. 
+    /// @param refund Indicates if RefundSurplus instruction will be appended
+    /// @param report What to report: 0 for the transact dispatch result, 1 for the execution
+    /// result of the whole message
+    /// @return queryId The id of the query to pass to transactStatus
+    function transactThroughDerivativeMultilocationWithReport(
+        uint8 transactor,
+        uint16 index,
+        Multilocation memory feeAsset,
+        Weight memory transactRequiredWeightAtMost,
+        bytes memory innerCall,
+        uint256 feeAmount,
+        Weight memory overallWeight,
+        bool refund,
+        uint8 report
+    ) external returns (uint64 queryId);
+
+    /// Transact through XCM using fee based on its multilocation through signed origins,
+    /// reporting the result back
+    /// @custom:selector 32390bab
+    /// @dev No token is burnt before sending the message. The caller must ensure the destination
+    /// is able to undertand the DescendOrigin message, and create a unique account from which
+    /// dispatch the call
+    /// @param dest The destination chain (as multilocation) where to send the message
+    /// @param feeLocation The asset multilocation that indentifies the fee payment currency
+    /// It has to be a reserve of the destination chain
+    /// @param transactRequiredWeightAtMost The weight we want to buy in the destination chain for the call to be made
+    /// @param call The call to be executed in the destination chain
+    /// @param feeAmount Amount to be used as fee.
+    /// @param overallWeight Overall weight to be used for the xcm message. If uint64:MAX is passed 
+    /// through refTime field, Unlimited variant will be used. 
+    /// @param refund Indicates if RefundSurplus instruction will be appended
+    /// @param report What to report: 0 for the transact dispatch result, 1 for the execution
+    /// result of the whole message
+    /// @return queryId The id of the query to pass to transactStatus
+    function transactThroughSignedMultilocationWithReport(
+        Multilocation memory dest,
+        Multilocation memory feeLocation,
+        Weight memory transactRequiredWeightAtMost,
+        bytes memory call,
+        uint256 feeAmount,
+        Weight memory overallWeight,
+        bool refund,
+        uint8 report
+    ) external returns (uint64 queryId);
+
     /// @dev Encode 'utility.as_derivative' relay call
     /// @custom:selector ff86378d
     /// @param transactor The transactor to be used
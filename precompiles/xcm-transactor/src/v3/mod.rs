@@ -65,6 +65,15 @@ where
 		XcmTransactorWrapper::<Runtime>::fee_per_second(handle, multilocation)
 	}
 
+	#[precompile::public("transactStatus(uint64)")]
+	#[precompile::view]
+	fn transact_status(
+		handle: &mut impl PrecompileHandle,
+		query_id: u64,
+	) -> EvmResult<(bool, bool, UnboundedBytes)> {
+		XcmTransactorWrapper::<Runtime>::transact_status(handle, query_id)
+	}
+
 	#[precompile::public(
 		"transactThroughDerivativeMultilocation(\
 		uint8,\
@@ -199,6 +208,79 @@ where
 		)
 	}
 
+	#[precompile::public(
+		"transactThroughDerivativeMultilocationWithReport(\
+		uint8,\
+		uint16,\
+		(uint8,bytes[]),\
+		(uint64,uint64),\
+		bytes,\
+		uint256,\
+		(uint64,uint64),\
+		bool,\
+		uint8)"
+	)]
+	fn transact_through_derivative_multilocation_with_report(
+		handle: &mut impl PrecompileHandle,
+		transactor: u8,
+		index: u16,
+		fee_asset: MultiLocation,
+		weight: Weight,
+		inner_call: BoundedBytes<GetDataLimit>,
+		fee_amount: Convert<U256, u128>,
+		overall_weight: Weight,
+		refund: bool,
+		report: u8,
+	) -> EvmResult<u64> {
+		XcmTransactorWrapper::<Runtime>::transact_through_derivative_multilocation_with_report(
+			handle,
+			transactor,
+			index,
+			fee_asset,
+			weight,
+			inner_call,
+			fee_amount.converted(),
+			overall_weight,
+			refund,
+			report,
+		)
+	}
+
+	#[precompile::public(
+		"transactThroughSignedMultilocationWithReport(\
+		(uint8,bytes[]),\
+		(uint8,bytes[]),\
+		(uint64,uint64),\
+		bytes,\
+		uint256,\
+		(uint64,uint64),\
+		bool,\
+		uint8)"
+	)]
+	fn transact_through_signed_multilocation_with_report(
+		handle: &mut impl PrecompileHandle,
+		dest: MultiLocation,
+		fee_asset: MultiLocation,
+		weight: Weight,
+		call: BoundedBytes<GetDataLimit>,
+		fee_amount: Convert<U256, u128>,
+		overall_weight: Weight,
+		refund: bool,
+		report: u8,
+	) -> EvmResult<u64> {
+		XcmTransactorWrapper::<Runtime>::transact_through_signed_multilocation_with_report(
+			handle,
+			dest,
+			fee_asset,
+			weight,
+			call,
+			fee_amount.converted(),
+			overall_weight,
+			refund,
+			report,
+		)
+	}
+
 	#[precompile::public("encodeUtilityAsDerivative(uint8,uint16,bytes)")]
 	#[precompile::public("encode_utility_as_derivative(uint8,uint16,bytes)")]
 	#[precompile::view]
@@ -18,6 +18,12 @@ interface XcmUtils {
         bytes[] interior;
     }
 
+    // A fungible asset, identified by its multilocation
+    struct XcmAsset {
+        Multilocation location;
+        uint256 amount;
+    }
+
     /// Get retrieve the account associated to a given MultiLocation
     /// @custom:selector 343b3e00
     /// @param multilocation The multilocation that we want to know to which account maps to
@@ -43,6 +49,38 @@ interface XcmUtils {
         view
         returns (uint256 unitsPerSecond);
 
+    /// Estimate the fees needed to send a message and have it executed at its destination
+    /// @custom:selector 64b9bfff
+    /// @param dest The destination chain to which the message would be sent
+    /// @param message The versioned message to be sent scale-encoded
+    /// @param feeAsset The asset in which the destination would charge execution
+    /// @return deliveryFees The assets charged to deliver the message to the destination
+    /// @return executionFee The amount of feeAsset charged to execute the message
+    function estimateXcmFees(
+        Multilocation memory dest,
+        bytes memory message,
+        Multilocation memory feeAsset
+    )
+        external
+        view
+        returns (XcmAsset[] memory deliveryFees, uint256 executionFee);
+
+    /// Execute custom xcm message without persisting any of its effects
+    /// @dev Meant to be used with eth_call. Messages moving erc20 tokens are refused
+    /// @custom:selector 06ee6567
+    /// @param message The versioned message to be executed scale encoded
+    /// @param maxWeight The maximum weight to be consumed
+    /// @return success Whether the whole message was executed
+    /// @return weightUsed The weight consumed by the execution
+    /// @return trappedAssets The assets left in holding at the end of the execution
+    function dryRunXcmExecute(bytes memory message, uint64 maxWeight)
+        external
+        returns (
+            bool success,
+            uint64 weightUsed,
+            XcmAsset[] memory trappedAssets
+        );
+
     /// Execute custom xcm message
     /// @dev This function CANNOT be called from a smart contract
     /// @custom:selector 34334a02
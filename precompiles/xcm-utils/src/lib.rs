@@ -19,28 +19,31 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use fp_evm::PrecompileHandle;
+use frame_support::storage::with_transaction;
 use frame_support::traits::ConstU32;
+use frame_support::weights::constants::WEIGHT_REF_TIME_PER_SECOND;
 use frame_support::{
 	dispatch::{GetDispatchInfo, PostDispatchInfo},
-	traits::OriginTrait,
+	traits::{Contains, EnsureOrigin, OriginTrait},
 };
 use pallet_evm::AddressMapping;
-use parity_scale_codec::{Decode, DecodeLimit, MaxEncodedLen};
+use parity_scale_codec::{Decode, DecodeLimit, Encode, MaxEncodedLen};
 use precompile_utils::precompile_set::SelectorFilter;
 use precompile_utils::prelude::*;
 use sp_core::{H160, U256};
 use sp_runtime::traits::Dispatchable;
+use sp_runtime::{DispatchError, TransactionOutcome};
 use sp_std::boxed::Box;
 use sp_std::marker::PhantomData;
 use sp_std::vec;
 use sp_std::vec::Vec;
 use sp_weights::Weight;
-use xcm::{latest::prelude::*, VersionedXcm, MAX_XCM_DECODE_DEPTH};
+use xcm::{latest::prelude::*, latest::validate_send, VersionedXcm, MAX_XCM_DECODE_DEPTH};
 use xcm_executor::traits::ConvertOrigin;
 use xcm_executor::traits::WeightBounds;
 use xcm_executor::traits::WeightTrader;
 
-use xcm_primitives::DEFAULT_PROOF_SIZE;
+use xcm_primitives::{UnitsToWeightRatio, DEFAULT_PROOF_SIZE};
 
 pub type XcmOriginOf<XcmConfig> =
 	<<XcmConfig as xcm_executor::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin;
@@ -57,12 +60,68 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+/// A fungible asset identified by its location
+#[derive(Debug, Eq, PartialEq, solidity::Codec)]
+pub struct XcmAsset {
+	pub location: MultiLocation,
+	pub amount: U256,
+}
+
+/// The fungible assets among `assets`, other assets can't be represented as an `XcmAsset`
+fn fungible_assets(assets: MultiAssets) -> Vec<XcmAsset> {
+	assets
+		.into_inner()
+		.into_iter()
+		.filter_map(|asset| match asset {
+			MultiAsset {
+				id: Concrete(location),
+				fun: Fungible(amount),
+			} => Some(XcmAsset {
+				location,
+				amount: amount.into(),
+			}),
+			_ => None,
+		})
+		.collect()
+}
+
+/// Whether the message moves erc20 tokens of the erc20 xcm bridge, or brings them into the
+/// holding register, including from its error handler and appendix.
+fn moves_erc20_assets<Runtime, Call>(xcm: &Xcm<Call>) -> bool
+where
+	Runtime: pallet_erc20_xcm_bridge::Config,
+{
+	let has_erc20 = |assets: &MultiAssets| {
+		assets
+			.inner()
+			.iter()
+			.any(|asset| pallet_erc20_xcm_bridge::Pallet::<Runtime>::is_erc20_asset(asset))
+	};
+	xcm.0.iter().any(|instruction| match instruction {
+		WithdrawAsset(assets)
+		| ReserveAssetDeposited(assets)
+		| ReceiveTeleportedAsset(assets)
+		| ClaimAsset { assets, .. }
+		| TransferAsset { assets, .. }
+		| TransferReserveAsset { assets, .. }
+		| ExchangeAsset { want: assets, .. } => has_erc20(assets),
+		SetErrorHandler(xcm) | SetAppendix(xcm) => moves_erc20_assets::<Runtime, _>(xcm),
+		_ => false,
+	})
+}
+
 #[derive(Debug)]
-pub struct AllExceptXcmExecute<Runtime, XcmConfig>(PhantomData<(Runtime, XcmConfig)>);
+pub struct AllExceptXcmExecute<Runtime, XcmConfig, DestinationFeePerSecond>(
+	PhantomData<(Runtime, XcmConfig, DestinationFeePerSecond)>,
+);
 
-impl<Runtime, XcmConfig> SelectorFilter for AllExceptXcmExecute<Runtime, XcmConfig>
+impl<Runtime, XcmConfig, DestinationFeePerSecond> SelectorFilter
+	for AllExceptXcmExecute<Runtime, XcmConfig, DestinationFeePerSecond>
 where
-	Runtime: pallet_evm::Config + frame_system::Config + pallet_xcm::Config,
+	Runtime: pallet_evm::Config
+		+ frame_system::Config
+		+ pallet_xcm::Config
+		+ pallet_erc20_xcm_bridge::Config,
 	XcmOriginOf<XcmConfig>: OriginTrait,
 	XcmAccountIdOf<XcmConfig>: Into<H160>,
 	XcmConfig: xcm_executor::Config,
@@ -70,12 +129,14 @@ where
 	<<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
 		From<Option<Runtime::AccountId>>,
 	<Runtime as frame_system::Config>::RuntimeCall: From<pallet_xcm::Call<Runtime>>,
+	<Runtime as frame_system::Config>::RuntimeEvent: TryInto<pallet_xcm::Event<Runtime>>,
+	DestinationFeePerSecond: UnitsToWeightRatio<MultiLocation>,
 {
 	fn is_allowed(_caller: H160, selector: Option<u32>) -> bool {
 		match selector {
 			None => true,
 			Some(selector) => {
-				!XcmUtilsPrecompileCall::<Runtime, XcmConfig>::xcm_execute_selectors()
+				!XcmUtilsPrecompileCall::<Runtime, XcmConfig, DestinationFeePerSecond>::xcm_execute_selectors()
 					.contains(&selector)
 			}
 		}
@@ -86,13 +147,20 @@ where
 	}
 }
 
-/// A precompile to wrap the functionality from xcm-utils
-pub struct XcmUtilsPrecompile<Runtime, XcmConfig>(PhantomData<(Runtime, XcmConfig)>);
+/// A precompile to wrap the functionality from xcm-utils. `DestinationFeePerSecond` provides the
+/// fee per second charged by the reserve chain of a fee asset, used to estimate execution fees.
+pub struct XcmUtilsPrecompile<Runtime, XcmConfig, DestinationFeePerSecond>(
+	PhantomData<(Runtime, XcmConfig, DestinationFeePerSecond)>,
+);
 
 #[precompile_utils::precompile]
-impl<Runtime, XcmConfig> XcmUtilsPrecompile<Runtime, XcmConfig>
+impl<Runtime, XcmConfig, DestinationFeePerSecond>
+	XcmUtilsPrecompile<Runtime, XcmConfig, DestinationFeePerSecond>
 where
-	Runtime: pallet_evm::Config + frame_system::Config + pallet_xcm::Config,
+	Runtime: pallet_evm::Config
+		+ frame_system::Config
+		+ pallet_xcm::Config
+		+ pallet_erc20_xcm_bridge::Config,
 	XcmOriginOf<XcmConfig>: OriginTrait,
 	XcmAccountIdOf<XcmConfig>: Into<H160>,
 	XcmConfig: xcm_executor::Config,
@@ -100,6 +168,8 @@ where
 	<<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
 		From<Option<Runtime::AccountId>>,
 	<Runtime as frame_system::Config>::RuntimeCall: From<pallet_xcm::Call<Runtime>>,
+	<Runtime as frame_system::Config>::RuntimeEvent: TryInto<pallet_xcm::Event<Runtime>>,
+	DestinationFeePerSecond: UnitsToWeightRatio<MultiLocation>,
 {
 	#[precompile::public("multilocationToAddress((uint8,bytes[]))")]
 	#[precompile::view]
@@ -182,23 +252,155 @@ where
 	) -> EvmResult<u64> {
 		let message: Vec<u8> = message.into();
 
-		let msg =
-			VersionedXcm::<<XcmConfig as xcm_executor::Config>::RuntimeCall>::decode_all_with_depth_limit(
-				MAX_XCM_DECODE_DEPTH,
-				&mut message.as_slice(),
-			)
-			.map(Xcm::<<XcmConfig as xcm_executor::Config>::RuntimeCall>::try_from);
+		Ok(Self::weigh_message(&message)?.ref_time())
+	}
 
-		let result = match msg {
-			Ok(Ok(mut x)) => {
-				XcmConfig::Weigher::weight(&mut x).map_err(|_| revert("failed weighting"))
+	#[precompile::public("estimateXcmFees((uint8,bytes[]),bytes,(uint8,bytes[]))")]
+	#[precompile::view]
+	fn estimate_xcm_fees(
+		handle: &mut impl PrecompileHandle,
+		dest: MultiLocation,
+		message: BoundedBytes<GetXcmSizeLimit>,
+		fee_asset: MultiLocation,
+	) -> EvmResult<(Vec<XcmAsset>, U256)> {
+		// storage items: DestinationAssetFeePerSecond, then AssetTypeUnitsPerSecond
+		// max encoded len: hash (16) + Multilocation + u128 (16)
+		handle.record_db_read::<Runtime>(32 + MultiLocation::max_encoded_len())?;
+		handle.record_db_read::<Runtime>(32 + MultiLocation::max_encoded_len())?;
+
+		// Storage items read by the router to validate the delivery:
+		// - SupportedVersion: Twox64Concat (8) + u32 (4) + Blake2_128Concat (16)
+		//   + VersionedMultiLocation (1 + Multilocation) + u32 (4)
+		handle.record_db_read::<Runtime>(33 + MultiLocation::max_encoded_len())?;
+		// - SafeXcmVersion: u32 (4)
+		handle.record_db_read::<Runtime>(4)?;
+		// - DeliveryFeeFactor: Twox64Concat (8) + ParaId (4) + FixedU128 (16), the
+		//   UpwardDeliveryFeeFactor of the parent being smaller
+		handle.record_db_read::<Runtime>(28)?;
+		// - RelevantMessagingState: dmq_mqc_head (32) + relay_dispatch_queue_remaining_capacity (8)
+		//   + ingress and egress channels, Vec<(ParaId (4), AbridgedHrmpChannel (53))> each, the
+		//   HostConfiguration read for the parent being smaller
+		// TODO channels are unbounded, we account for a theoretical 30 channels of each kind
+		handle.record_db_read::<Runtime>(40 + 2 * (5 + 30 * 57))?;
+
+		let message: Vec<u8> = message.into();
+
+		let xcm = VersionedXcm::<()>::decode_all_with_depth_limit(
+			MAX_XCM_DECODE_DEPTH,
+			&mut message.as_slice(),
+		)
+		.ok()
+		.and_then(|xcm| Xcm::<()>::try_from(xcm).ok())
+		.ok_or(RevertReason::custom("Failed decoding").in_field("message"))?;
+
+		// The router prices the delivery of the message to the destination
+		let (_, delivery_fees) = validate_send::<XcmConfig::XcmSender>(dest, xcm)
+			.map_err(|_| revert("Message cannot be delivered to the destination"))?;
+
+		// The message is weighted with our own weigher, as an approximation of the one of the
+		// destination
+		let weight = Self::weigh_message(&message)?;
+		let fee_per_second = DestinationFeePerSecond::get_units_per_second(fee_asset).ok_or(
+			RevertReason::custom("Asset not supported as fee payment").in_field("feeAsset"),
+		)?;
+
+		// Round up the fractional part, as the destination does when charging fees
+		let execution_fee = U256::from(fee_per_second)
+			.saturating_mul(weight.ref_time().into())
+			.saturating_add((WEIGHT_REF_TIME_PER_SECOND - 1).into())
+			/ U256::from(WEIGHT_REF_TIME_PER_SECOND);
+
+		Ok((fungible_assets(delivery_fees), execution_fee))
+	}
+
+	#[precompile::public("dryRunXcmExecute(bytes,uint64)")]
+	fn dry_run_xcm_execute(
+		handle: &mut impl PrecompileHandle,
+		message: BoundedBytes<GetXcmSizeLimit>,
+		weight: u64,
+	) -> EvmResult<(bool, u64, Vec<XcmAsset>)> {
+		let message: Vec<u8> = message.into();
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+
+		let xcm = xcm::VersionedXcm::<SystemCallOf<Runtime>>::decode_all_with_depth_limit(
+			xcm::MAX_XCM_DECODE_DEPTH,
+			&mut message.as_slice(),
+		)
+		.ok()
+		.and_then(|xcm| Xcm::<SystemCallOf<Runtime>>::try_from(xcm).ok())
+		.ok_or(RevertReason::custom("Failed xcm decoding").in_field("message"))?;
+
+		// Transfers of erc20 tokens are EVM calls, which can't be made from the dry-run: through
+		// the precompile handle their logs and gas would outlive the rolled back execution, and
+		// the EVM can't be reentered otherwise.
+		if moves_erc20_assets::<Runtime, _>(&xcm) {
+			return Err(RevertReason::custom("Erc20 assets are not supported").in_field("message"));
+		}
+
+		// Charge the weight of `xcmExecute`, the unused part is refunded after the dry-run
+		let max_weight = Weight::from_parts(weight, DEFAULT_PROOF_SIZE);
+		let execute_weight =
+			<<Runtime as pallet_xcm::Config>::WeightInfo as pallet_xcm::WeightInfo>::execute();
+		let dispatch_weight = max_weight.saturating_add(execute_weight);
+		RuntimeHelper::<Runtime>::reocrd_external_cost(handle, dispatch_weight, 0)?;
+
+		let origin_location = <Runtime as pallet_xcm::Config>::ExecuteXcmOrigin::ensure_origin(
+			frame_system::RawOrigin::Signed(origin).into(),
+		)
+		.map_err(|_| revert("Origin cannot execute XCM"))?;
+		if !<Runtime as pallet_xcm::Config>::XcmExecuteFilter::contains(&(
+			origin_location,
+			xcm.clone(),
+		)) {
+			return Err(revert("XCM execution is filtered"));
+		}
+		let hash = xcm.using_encoded(sp_core::hashing::blake2_256);
+
+		// Execute the message as `xcmExecute` would, then revert all of its changes. Only the
+		// events deposited by the execution are looked at, the events of the block being read
+		// back from storage once.
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let event_count = frame_system::Pallet::<Runtime>::event_count();
+		let (outcome, events) = with_transaction(|| {
+			let outcome = <Runtime as pallet_xcm::Config>::XcmExecutor::execute_xcm_in_credit(
+				origin_location,
+				xcm,
+				hash,
+				max_weight,
+				max_weight,
+			);
+			let events = frame_system::Pallet::<Runtime>::read_events_no_consensus()
+				.skip(event_count as usize)
+				.map(|record| record.event)
+				.collect::<Vec<_>>();
+			TransactionOutcome::Rollback(Ok::<_, DispatchError>((outcome, events)))
+		})
+		.map_err(|_| revert("Failed dry-run"))?;
+
+		RuntimeHelper::<Runtime>::refund_weight_v2_cost(
+			handle,
+			dispatch_weight,
+			Some(outcome.weight_used().saturating_add(execute_weight)),
+		)?;
+
+		let mut trapped_assets = Vec::new();
+		for event in events {
+			if let Ok(pallet_xcm::Event::<Runtime>::AssetsTrapped { assets, .. }) = event.try_into()
+			{
+				if let Ok(assets) = MultiAssets::try_from(assets) {
+					trapped_assets.extend(fungible_assets(assets));
+				}
 			}
-			_ => Err(RevertReason::custom("Failed decoding")
-				.in_field("message")
-				.into()),
+		}
+
+		let (success, weight_used) = match outcome {
+			Outcome::Complete(weight_used) => (true, weight_used.ref_time()),
+			Outcome::Incomplete(weight_used, _) => (false, weight_used.ref_time()),
+			Outcome::Error(_) => (false, 0),
 		};
 
-		Ok(result?.ref_time())
+		Ok((success, weight_used, trapped_assets))
 	}
 
 	#[precompile::public("xcmExecute(bytes,uint64)")]
@@ -254,4 +456,22 @@ where
 
 		Ok(())
 	}
+
+	fn weigh_message(message: &[u8]) -> EvmResult<Weight> {
+		let msg =
+			VersionedXcm::<<XcmConfig as xcm_executor::Config>::RuntimeCall>::decode_all_with_depth_limit(
+				MAX_XCM_DECODE_DEPTH,
+				&mut &message[..],
+			)
+			.map(Xcm::<<XcmConfig as xcm_executor::Config>::RuntimeCall>::try_from);
+
+		match msg {
+			Ok(Ok(mut x)) => {
+				XcmConfig::Weigher::weight(&mut x).map_err(|_| revert("failed weighting"))
+			}
+			_ => Err(RevertReason::custom("Failed decoding")
+				.in_field("message")
+				.into()),
+		}
+	}
 }
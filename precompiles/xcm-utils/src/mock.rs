@@ -56,6 +56,7 @@ construct_runtime!(
 		Evm: pallet_evm,
 		Timestamp: pallet_timestamp,
 		PolkadotXcm: pallet_xcm,
+		Erc20XcmBridge: pallet_erc20_xcm_bridge,
 	}
 );
 
@@ -240,13 +241,13 @@ pub type Precompiles<R> = PrecompileSetBuilder<
 	(
 		PrecompileAt<
 			AddressU64<1>,
-			XcmUtilsPrecompile<R, XcmConfig>,
-			CallableByContract<AllExceptXcmExecute<R, XcmConfig>>,
+			XcmUtilsPrecompile<R, XcmConfig, MockDestinationFeePerSecond>,
+			CallableByContract<AllExceptXcmExecute<R, XcmConfig, MockDestinationFeePerSecond>>,
 		>,
 	),
 >;
 
-pub type PCall = XcmUtilsPrecompileCall<Runtime, XcmConfig>;
+pub type PCall = XcmUtilsPrecompileCall<Runtime, XcmConfig, MockDestinationFeePerSecond>;
 
 const MAX_POV_SIZE: u64 = 5 * 1024 * 1024;
 /// Block storage limit in bytes. Set to 40 KB.
@@ -311,6 +312,28 @@ impl pallet_timestamp::Config for Runtime {
 	type MinimumPeriod = MinimumPeriod;
 	type WeightInfo = ();
 }
+
+parameter_types! {
+	pub Erc20XcmBridgePalletLocation: MultiLocation = MultiLocation::new(
+		0,
+		Junctions::X1(PalletInstance(
+			<Runtime as frame_system::Config>::PalletInfo::index::<Erc20XcmBridge>().unwrap() as u8
+		)),
+	);
+	pub const Erc20XcmBridgeTransferGasLimit: u64 = 200_000;
+	pub const Erc20XcmBridgeMaxTransferGasLimit: u64 = 400_000;
+}
+impl pallet_erc20_xcm_bridge::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AccountIdConverter = ();
+	type Erc20MultilocationPrefix = Erc20XcmBridgePalletLocation;
+	type Erc20TransferGasLimit = Erc20XcmBridgeTransferGasLimit;
+	type Erc20MaxTransferGasLimit = Erc20XcmBridgeMaxTransferGasLimit;
+	type Erc20RegistryOrigin = frame_system::EnsureRoot<AccountId>;
+	type EvmRunner = pallet_evm::runner::stack::Runner<Self>;
+	type WeightInfo = ();
+}
+
 pub type Barrier = AllowUnpaidExecutionFrom<Everything>;
 
 pub struct ConvertOriginToLocal;
@@ -336,26 +359,49 @@ thread_local! {
 pub fn sent_xcm() -> Vec<(MultiLocation, opaque::Xcm)> {
 	SENT_XCM.with(|q| (*q.borrow()).clone())
 }
+/// Fee charged in the relay asset to deliver a message to a sibling parachain
+pub const SIBLING_DELIVERY_FEE: u128 = 100;
+
 pub struct TestSendXcm;
 impl SendXcm for TestSendXcm {
-	type Ticket = ();
+	type Ticket = (MultiLocation, opaque::Xcm);
 
 	fn validate(
 		destination: &mut Option<MultiLocation>,
 		message: &mut Option<opaque::Xcm>,
 	) -> SendResult<Self::Ticket> {
-		SENT_XCM.with(|q| {
-			q.borrow_mut()
-				.push((destination.clone().unwrap(), message.clone().unwrap()))
-		});
-		Ok(((), MultiAssets::new()))
+		let destination = destination.take().ok_or(SendError::MissingArgument)?;
+		let message = message.take().ok_or(SendError::MissingArgument)?;
+		let price = match destination {
+			MultiLocation {
+				parents: 1,
+				interior: X1(Parachain(_)),
+			} => (MultiLocation::parent(), SIBLING_DELIVERY_FEE).into(),
+			_ => MultiAssets::new(),
+		};
+		Ok(((destination, message), price))
 	}
 
-	fn deliver(_: Self::Ticket) -> Result<XcmHash, SendError> {
+	fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+		SENT_XCM.with(|q| q.borrow_mut().push(ticket));
 		Ok(XcmHash::default())
 	}
 }
 
+/// Fee per second charged by the relay for its own asset
+pub const PARENT_FEE_PER_SECOND: u128 = 1_500_000_000;
+
+pub struct MockDestinationFeePerSecond;
+impl UnitsToWeightRatio<MultiLocation> for MockDestinationFeePerSecond {
+	fn payment_is_supported(asset_type: MultiLocation) -> bool {
+		asset_type == MultiLocation::parent()
+	}
+
+	fn get_units_per_second(asset_type: MultiLocation) -> Option<u128> {
+		Self::payment_is_supported(asset_type).then_some(PARENT_FEE_PER_SECOND)
+	}
+}
+
 pub struct DummyAssetTransactor;
 impl TransactAsset for DummyAssetTransactor {
 	fn deposit_asset(_what: &MultiAsset, _who: &MultiLocation, _context: &XcmContext) -> XcmResult {
@@ -435,7 +481,7 @@ impl xcm_executor::Config for XcmConfig {
 	type Trader = DummyWeightTrader;
 	type ResponseHandler = ();
 	type SubscriptionService = ();
-	type AssetTrap = ();
+	type AssetTrap = PolkadotXcm;
 	type AssetClaims = ();
 	type CallDispatcher = RuntimeCall;
 	type AssetLocker = ();
@@ -15,9 +15,10 @@
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::mock::{
-	sent_xcm, AccountId, Balances, ExtBuilder, PCall, ParentAccount, Precompiles, PrecompilesValue,
-	Runtime, SiblingParachainAccount, System,
+	sent_xcm, AccountId, Balances, Erc20XcmBridge, ExtBuilder, PCall, ParentAccount, Precompiles,
+	PrecompilesValue, Runtime, SiblingParachainAccount, System, SIBLING_DELIVERY_FEE,
 };
+use crate::XcmAsset;
 use frame_support::{traits::PalletInfo, weights::Weight};
 use parity_scale_codec::Encode;
 use precompile_utils::{prelude::*, testing::*};
@@ -33,6 +34,8 @@ fn test_selector_enum() {
 	assert!(PCall::multilocation_to_address_selectors().contains(&0x343b3e00));
 	assert!(PCall::weight_message_selectors().contains(&0x25d54154));
 	assert!(PCall::get_units_per_second_selectors().contains(&0x3f0f65db));
+	assert!(PCall::estimate_xcm_fees_selectors().contains(&0x64b9bfff));
+	assert!(PCall::dry_run_xcm_execute_selectors().contains(&0x06ee6567));
 }
 
 #[test]
@@ -43,6 +46,8 @@ fn modifiers() {
 		tester.test_view_modifier(PCall::multilocation_to_address_selectors());
 		tester.test_view_modifier(PCall::weight_message_selectors());
 		tester.test_view_modifier(PCall::get_units_per_second_selectors());
+		tester.test_view_modifier(PCall::estimate_xcm_fees_selectors());
+		tester.test_default_modifier(PCall::dry_run_xcm_execute_selectors());
 	});
 }
 
@@ -115,6 +120,194 @@ fn test_get_units_per_second() {
 	});
 }
 
+#[test]
+fn test_estimate_xcm_fees() {
+	ExtBuilder::default().build().execute_with(|| {
+		let message = VersionedXcm::<()>::V3(Xcm(vec![ClearOrigin])).encode();
+
+		let input = PCall::estimate_xcm_fees {
+			dest: MultiLocation::new(1, Junctions::X1(Junction::Parachain(2000u32))),
+			message: message.into(),
+			fee_asset: MultiLocation::parent(),
+		};
+
+		// 1000 weight at 1_500_000_000 units per second is 1.5 units, rounded up
+		precompiles()
+			.prepare_test(Alice, Precompile1, input)
+			.expect_cost(6)
+			.expect_no_logs()
+			.execute_returns((
+				vec![XcmAsset {
+					location: MultiLocation::parent(),
+					amount: SIBLING_DELIVERY_FEE.into(),
+				}],
+				U256::from(2u32),
+			));
+
+		// Estimating must not send anything
+		assert!(sent_xcm().is_empty());
+	});
+}
+
+#[test]
+fn test_estimate_xcm_fees_without_delivery_fees() {
+	ExtBuilder::default().build().execute_with(|| {
+		let message = VersionedXcm::<()>::V3(Xcm(vec![ClearOrigin, ClearOrigin])).encode();
+
+		let input = PCall::estimate_xcm_fees {
+			dest: MultiLocation::parent(),
+			message: message.into(),
+			fee_asset: MultiLocation::parent(),
+		};
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, input)
+			.expect_cost(6)
+			.expect_no_logs()
+			.execute_returns((Vec::<XcmAsset>::new(), U256::from(3u32)));
+	});
+}
+
+#[test]
+fn test_estimate_xcm_fees_fails_if_fee_asset_is_not_supported() {
+	ExtBuilder::default().build().execute_with(|| {
+		let message = VersionedXcm::<()>::V3(Xcm(vec![ClearOrigin])).encode();
+
+		let input = PCall::estimate_xcm_fees {
+			dest: MultiLocation::parent(),
+			message: message.into(),
+			fee_asset: MultiLocation::here(),
+		};
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, input)
+			.execute_reverts(|output| output == b"feeAsset: Asset not supported as fee payment");
+	});
+}
+
+#[test]
+fn test_dry_run_traps_leftover_assets() {
+	ExtBuilder::default().build().execute_with(|| {
+		let withdrawn_asset: MultiAsset = (MultiLocation::parent(), 100u128).into();
+		let xcm_to_execute =
+			VersionedXcm::<()>::V3(Xcm(vec![WithdrawAsset(vec![withdrawn_asset].into())])).encode();
+
+		let input = PCall::dry_run_xcm_execute {
+			message: xcm_to_execute.into(),
+			weight: 10000u64,
+		};
+
+		let event_count = System::event_count();
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, input)
+			.expect_no_logs()
+			.execute_returns((
+				true,
+				1000u64,
+				vec![XcmAsset {
+					location: MultiLocation::parent(),
+					amount: 100u32.into(),
+				}],
+			));
+
+		// Nothing executed during the dry-run is kept
+		assert_eq!(System::event_count(), event_count);
+	});
+}
+
+#[test]
+fn test_dry_run_ignores_and_keeps_previous_events() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Assets trapped earlier in the block are not reported by the dry-run
+		let trapped_asset: MultiAsset = (MultiLocation::parent(), 50u128).into();
+		System::deposit_event(pallet_xcm::Event::<Runtime>::AssetsTrapped {
+			hash: Default::default(),
+			origin: MultiLocation::parent(),
+			assets: MultiAssets::from(vec![trapped_asset]).into(),
+		});
+		let events = System::events();
+
+		let xcm_to_execute = VersionedXcm::<()>::V3(Xcm(vec![ClearOrigin])).encode();
+
+		let input = PCall::dry_run_xcm_execute {
+			message: xcm_to_execute.into(),
+			weight: 10000u64,
+		};
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, input)
+			.expect_no_logs()
+			.execute_returns((true, 1000u64, Vec::<XcmAsset>::new()));
+
+		assert_eq!(System::events(), events);
+	});
+}
+
+#[test]
+fn test_dry_run_reports_failed_execution() {
+	ExtBuilder::default().build().execute_with(|| {
+		let withdrawn_asset: MultiAsset = (MultiLocation::parent(), 100u128).into();
+		let xcm_to_execute = VersionedXcm::<()>::V3(Xcm(vec![
+			WithdrawAsset(vec![withdrawn_asset].into()),
+			Trap(5),
+		]))
+		.encode();
+
+		let input = PCall::dry_run_xcm_execute {
+			message: xcm_to_execute.into(),
+			weight: 10000u64,
+		};
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, input)
+			.expect_no_logs()
+			.execute_returns((
+				false,
+				2000u64,
+				vec![XcmAsset {
+					location: MultiLocation::parent(),
+					amount: 100u32.into(),
+				}],
+			));
+	});
+}
+
+#[test]
+fn test_dry_run_refuses_erc20_assets() {
+	ExtBuilder::default().build().execute_with(|| {
+		let erc20_location = MultiLocation::new(
+			0,
+			Junctions::X2(
+				PalletInstance(
+					<Runtime as frame_system::Config>::PalletInfo::index::<Erc20XcmBridge>()
+						.unwrap() as u8,
+				),
+				AccountKey20 {
+					network: None,
+					key: [1u8; 20],
+				},
+			),
+		);
+		let withdrawn_asset: MultiAsset = (erc20_location, 100u128).into();
+		let xcm_to_execute =
+			VersionedXcm::<()>::V3(Xcm(vec![SetAppendix(Xcm(vec![WithdrawAsset(
+				vec![withdrawn_asset].into(),
+			)]))]))
+			.encode();
+
+		let input = PCall::dry_run_xcm_execute {
+			message: xcm_to_execute.into(),
+			weight: 10000u64,
+		};
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, input)
+			.expect_no_logs()
+			.execute_reverts(|output| output == b"message: Erc20 assets are not supported");
+	});
+}
+
 #[test]
 fn test_executor_clear_origin() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -261,3 +454,14 @@ fn execute_fails_if_called_by_smart_contract() {
 fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
 	check_precompile_implements_solidity_interfaces(&["XcmUtils.sol"], PCall::supports_selector)
 }
+
+#[test]
+fn test_generated_interface_matches_solidity_interface() {
+	check_precompile_generated_interface::<
+		crate::XcmUtilsPrecompile<
+			Runtime,
+			crate::mock::XcmConfig,
+			crate::mock::MockDestinationFeePerSecond,
+		>,
+	>(&["XcmUtils.sol"])
+}
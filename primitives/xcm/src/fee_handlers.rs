@@ -193,6 +193,40 @@ pub trait UnitsToWeightRatio<AssetType> {
 	fn set_units_per_second(_asset_type: AssetType, _fee_per_second: u128) {}
 }
 
+/// No asset is supported
+impl<AssetType> UnitsToWeightRatio<AssetType> for () {
+	fn payment_is_supported(_asset_type: AssetType) -> bool {
+		false
+	}
+	fn get_units_per_second(_asset_type: AssetType) -> Option<u128> {
+		None
+	}
+}
+
+/// Units per second source that prefers the rates of `Preferred` (e.g. derived from a price
+/// oracle), and falls back to the rates of `Fallback` (e.g. set by governance) when `Preferred`
+/// has none for the asset, for instance because its price is stale.
+/// Whether an asset can be used for fee payment is only decided by `Fallback`.
+pub struct UnitsToWeightRatioWithFallback<Preferred, Fallback>(PhantomData<(Preferred, Fallback)>);
+impl<
+		AssetType: Clone,
+		Preferred: UnitsToWeightRatio<AssetType>,
+		Fallback: UnitsToWeightRatio<AssetType>,
+	> UnitsToWeightRatio<AssetType> for UnitsToWeightRatioWithFallback<Preferred, Fallback>
+{
+	fn payment_is_supported(asset_type: AssetType) -> bool {
+		Fallback::payment_is_supported(asset_type)
+	}
+	fn get_units_per_second(asset_type: AssetType) -> Option<u128> {
+		Preferred::get_units_per_second(asset_type.clone())
+			.or_else(|| Fallback::get_units_per_second(asset_type))
+	}
+	#[cfg(feature = "runtime-benchmarks")]
+	fn set_units_per_second(asset_type: AssetType, fee_per_second: u128) {
+		Fallback::set_units_per_second(asset_type, fee_per_second)
+	}
+}
+
 #[cfg(test)]
 mod test {
 	use super::*;
@@ -206,7 +240,8 @@ mod test {
 	};
 	const ARBITRARY_ID: AssetId = AssetId::Concrete(ARBITRARY_ML);
 
-	impl UnitsToWeightRatio<MultiLocation> for () {
+	struct TestUnitsToWeightRatio;
+	impl UnitsToWeightRatio<MultiLocation> for TestUnitsToWeightRatio {
 		fn payment_is_supported(_asset_type: MultiLocation) -> bool {
 			true
 		}
@@ -231,7 +266,8 @@ mod test {
 			fun: Fungibility::Fungible(amount),
 		});
 
-		let mut trader: FirstAssetTrader<MultiLocation, (), ()> = FirstAssetTrader::new();
+		let mut trader: FirstAssetTrader<MultiLocation, TestUnitsToWeightRatio, ()> =
+			FirstAssetTrader::new();
 		let ctx = XcmContext {
 			origin: Some(multilocation),
 			message_id: XcmHash::default(),
@@ -246,7 +282,8 @@ mod test {
 
 	#[test]
 	fn cant_call_buy_weight_twice() {
-		let mut trader: FirstAssetTrader<MultiLocation, (), ()> = FirstAssetTrader::new();
+		let mut trader: FirstAssetTrader<MultiLocation, TestUnitsToWeightRatio, ()> =
+			FirstAssetTrader::new();
 
 		// should be able to buy once
 		let mut asset_one_payment = Assets::new();
@@ -325,7 +362,8 @@ mod test {
 			fun: Fungibility::Fungible(amount),
 		});
 
-		let mut trader: FirstAssetTrader<MultiLocation, (), ()> = FirstAssetTrader::new();
+		let mut trader: FirstAssetTrader<MultiLocation, TestUnitsToWeightRatio, ()> =
+			FirstAssetTrader::new();
 		let ctx = XcmContext {
 			origin: Some(ARBITRARY_ML),
 			message_id: XcmHash::default(),
@@ -356,7 +394,8 @@ mod test {
 			fun: Fungibility::Fungible(amount),
 		});
 
-		let mut trader: FirstAssetTrader<MultiLocation, (), ()> = FirstAssetTrader::new();
+		let mut trader: FirstAssetTrader<MultiLocation, TestUnitsToWeightRatio, ()> =
+			FirstAssetTrader::new();
 		let ctx = XcmContext {
 			origin: Some(ARBITRARY_ML),
 			message_id: XcmHash::default(),
@@ -408,7 +447,8 @@ mod test {
 			message_id: XcmHash::default(),
 			topic: None,
 		};
-		let mut trader: FirstAssetTrader<MultiLocation, (), ()> = FirstAssetTrader::new();
+		let mut trader: FirstAssetTrader<MultiLocation, TestUnitsToWeightRatio, ()> =
+			FirstAssetTrader::new();
 		let unused = trader
 			.buy_weight((amount as u64).into(), payment.clone(), &ctx)
 			.expect("can buy weight once");
@@ -441,7 +481,8 @@ mod test {
 			message_id: XcmHash::default(),
 			topic: None,
 		};
-		let mut trader: FirstAssetTrader<MultiLocation, (), ()> = FirstAssetTrader::new();
+		let mut trader: FirstAssetTrader<MultiLocation, TestUnitsToWeightRatio, ()> =
+			FirstAssetTrader::new();
 		let unused = trader
 			.buy_weight((amount as u64).into(), payment.clone(), &ctx)
 			.expect("can buy weight once");
@@ -462,4 +503,35 @@ mod test {
 		);
 		assert_eq!(trader.0, 500u64.into()); // still thinks we have unreturned weight
 	}
+
+	struct NoUnitsToWeightRatio;
+	impl UnitsToWeightRatio<MultiLocation> for NoUnitsToWeightRatio {
+		fn payment_is_supported(_asset_type: MultiLocation) -> bool {
+			false
+		}
+		fn get_units_per_second(_asset_type: MultiLocation) -> Option<u128> {
+			Some(1)
+		}
+	}
+
+	#[test]
+	fn fallback_units_per_second_are_used_if_preferred_has_none() {
+		type Rates = UnitsToWeightRatioWithFallback<(), TestUnitsToWeightRatio>;
+		assert!(Rates::payment_is_supported(ARBITRARY_ML));
+		assert_eq!(
+			Rates::get_units_per_second(ARBITRARY_ML),
+			Some(WEIGHT_REF_TIME_PER_SECOND as u128)
+		);
+	}
+
+	#[test]
+	fn preferred_units_per_second_are_used_first() {
+		type Rates = UnitsToWeightRatioWithFallback<TestUnitsToWeightRatio, NoUnitsToWeightRatio>;
+		// Support is decided by the fallback
+		assert!(!Rates::payment_is_supported(ARBITRARY_ML));
+		assert_eq!(
+			Rates::get_units_per_second(ARBITRARY_ML),
+			Some(WEIGHT_REF_TIME_PER_SECOND as u128)
+		);
+	}
 }
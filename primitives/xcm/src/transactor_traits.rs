@@ -17,6 +17,7 @@ use cumulus_primitives_core::{
 	relay_chain::{self, HrmpChannelId},
 	ParaId,
 };
+use parity_scale_codec::{Decode, Encode, Error as CodecError, Input, Output};
 use sp_std::vec::Vec;
 use xcm::latest::{Error as XcmError, MultiLocation};
 
@@ -86,3 +87,127 @@ pub trait StakeEncodeCall {
 	/// Encode call from the relay.
 	fn encode_call(call: AvailableStakeCalls) -> Vec<u8>;
 }
+
+/// Source of the extra funds bonded into a relay nomination pool.
+/// Mirrors `pallet_nomination_pools::BondExtra`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+pub enum PoolBondExtra {
+	FreeBalance(relay_chain::Balance),
+	Rewards,
+}
+
+pub enum AvailableNominationPoolsCalls {
+	Join(relay_chain::Balance, u32),
+	BondExtra(PoolBondExtra),
+	Unbond(relay_chain::AccountId, relay_chain::Balance),
+	ClaimPayout,
+}
+
+pub trait NominationPoolsEncodeCall {
+	/// Encode nomination pools call from the relay.
+	fn nomination_pools_encode_call(call: AvailableNominationPoolsCalls) -> Vec<u8>;
+}
+
+/// Conviction multiplier of a relay OpenGov vote.
+/// Mirrors `pallet_conviction_voting::Conviction`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+pub enum RelayConviction {
+	None,
+	Locked1x,
+	Locked2x,
+	Locked3x,
+	Locked4x,
+	Locked5x,
+	Locked6x,
+}
+
+impl TryFrom<u8> for RelayConviction {
+	type Error = ();
+
+	fn try_from(value: u8) -> Result<Self, Self::Error> {
+		Ok(match value {
+			0 => RelayConviction::None,
+			1 => RelayConviction::Locked1x,
+			2 => RelayConviction::Locked2x,
+			3 => RelayConviction::Locked3x,
+			4 => RelayConviction::Locked4x,
+			5 => RelayConviction::Locked5x,
+			6 => RelayConviction::Locked6x,
+			_ => return Err(()),
+		})
+	}
+}
+
+/// A relay OpenGov vote, packed into a single byte as the relay does:
+/// the highest bit is set for aye, the lower bits hold the conviction.
+/// Mirrors `pallet_conviction_voting::Vote`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RelayVote {
+	pub aye: bool,
+	pub conviction: RelayConviction,
+}
+
+impl Encode for RelayVote {
+	fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+		dest.push_byte(self.conviction as u8 | if self.aye { 0b1000_0000 } else { 0 });
+	}
+}
+
+impl Decode for RelayVote {
+	fn decode<I: Input>(input: &mut I) -> Result<Self, CodecError> {
+		let byte = input.read_byte()?;
+		Ok(RelayVote {
+			aye: (byte & 0b1000_0000) == 0b1000_0000,
+			conviction: RelayConviction::try_from(byte & 0b0111_1111)
+				.map_err(|_| CodecError::from("Invalid conviction"))?,
+		})
+	}
+}
+
+/// Mirrors `pallet_conviction_voting::AccountVote`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+pub enum RelayAccountVote {
+	Standard {
+		vote: RelayVote,
+		balance: relay_chain::Balance,
+	},
+	Split {
+		aye: relay_chain::Balance,
+		nay: relay_chain::Balance,
+	},
+	SplitAbstain {
+		aye: relay_chain::Balance,
+		nay: relay_chain::Balance,
+		abstain: relay_chain::Balance,
+	},
+}
+
+pub enum AvailableConvictionVotingCalls {
+	Vote(u32, RelayAccountVote),
+	RemoveVote(Option<u16>, u32),
+	Delegate(
+		u16,
+		relay_chain::AccountId,
+		RelayConviction,
+		relay_chain::Balance,
+	),
+}
+
+pub trait ConvictionVotingEncodeCall {
+	/// Encode conviction voting call from the relay.
+	fn conviction_voting_encode_call(call: AvailableConvictionVotingCalls) -> Vec<u8>;
+}
+
+// The proxy type is kept as its raw relay index, as each relay defines its own enum
+pub enum AvailableProxyCalls {
+	Proxy(relay_chain::AccountId, Option<u8>, Vec<u8>),
+	AddProxy(relay_chain::AccountId, u8, u32),
+	RemoveProxy(relay_chain::AccountId, u8, u32),
+}
+
+// As for the utility calls, the user only controls the call inside the proxy extrinsic,
+// which can only be dispatched by the relay account on behalf of the proxied account
+pub trait ProxyEncodeCall {
+	/// Encode proxy call from the relay.
+	fn proxy_encode_call(call: AvailableProxyCalls) -> Vec<u8>;
+}
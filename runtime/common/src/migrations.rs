@@ -116,6 +116,33 @@ impl<T: pallet_xcm_transactor::Config> Migration for PopulateRelayIndices<T> {
 	}
 }
 
+pub struct XcmTransactorExtendRelayIndices<T, NewIndices>(pub PhantomData<(T, NewIndices)>);
+impl<T, NewIndices> Migration for XcmTransactorExtendRelayIndices<T, NewIndices>
+where
+	T: pallet_xcm_transactor::Config,
+	NewIndices: Get<RelayChainIndices>,
+{
+	fn friendly_name(&self) -> &str {
+		"MM_XcmTransactorExtendRelayIndices"
+	}
+
+	fn migrate(&self, _available_weight: Weight) -> Weight {
+		pallet_xcm_transactor::migrations::ExtendRelayIndices::<T, NewIndices>::on_runtime_upgrade()
+	}
+
+	/// Run a standard pre-runtime test. This works the same way as in a normal runtime upgrade.
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade(&self) -> Result<Vec<u8>, sp_runtime::DispatchError> {
+		pallet_xcm_transactor::migrations::ExtendRelayIndices::<T, NewIndices>::pre_upgrade()
+	}
+
+	/// Run a standard post-runtime test. This works the same way as in a normal runtime upgrade.
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(&self, state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+		pallet_xcm_transactor::migrations::ExtendRelayIndices::<T, NewIndices>::post_upgrade(state)
+	}
+}
+
 pub struct RemoveMinBondForOrbiterCollators<T>(pub PhantomData<T>);
 impl<T> Migration for RemoveMinBondForOrbiterCollators<T>
 where
@@ -153,6 +180,32 @@ where
 	}
 }
 
+pub struct ParachainStakingMigrateInflationDistributionConfig<T>(pub PhantomData<T>);
+impl<T> Migration for ParachainStakingMigrateInflationDistributionConfig<T>
+where
+	T: pallet_parachain_staking::Config,
+{
+	fn friendly_name(&self) -> &str {
+		"MM_ParachainStakingMigrateInflationDistributionConfig"
+	}
+
+	fn migrate(&self, _available_weight: Weight) -> Weight {
+		pallet_parachain_staking::migrations::MigrateParachainBondConfig::<T>::on_runtime_upgrade()
+	}
+
+	/// Run a standard pre-runtime test. This works the same way as in a normal runtime upgrade.
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade(&self) -> Result<Vec<u8>, sp_runtime::DispatchError> {
+		pallet_parachain_staking::migrations::MigrateParachainBondConfig::<T>::pre_upgrade()
+	}
+
+	/// Run a standard post-runtime test. This works the same way as in a normal runtime upgrade.
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(&self, state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+		pallet_parachain_staking::migrations::MigrateParachainBondConfig::<T>::post_upgrade(state)
+	}
+}
+
 pub struct MissingBalancesMigrations<T>(PhantomData<T>);
 impl<T> Migration for MissingBalancesMigrations<T>
 where
@@ -338,6 +391,8 @@ where
 		let missing_balances_migrations = MissingBalancesMigrations::<Runtime>(Default::default());
 		let fix_pallet_versions =
 			FixIncorrectPalletVersions::<Runtime, Treasury, OpenTech>(Default::default());
+		let parachain_staking_inflation_distribution_config =
+			ParachainStakingMigrateInflationDistributionConfig::<Runtime>(Default::default());
 
 		vec![
 			// completed in runtime 800
@@ -388,6 +443,7 @@ where
 			Box::new(remove_min_bond_for_old_orbiter_collators),
 			Box::new(missing_balances_migrations),
 			Box::new(fix_pallet_versions),
+			Box::new(parachain_staking_inflation_distribution_config),
 		]
 	}
 }
@@ -28,6 +28,8 @@ pub mod pallet_collective;
 pub mod pallet_conviction_voting;
 pub mod pallet_crowdloan_rewards;
 pub mod pallet_democracy;
+pub mod pallet_erc20_transfer_logs;
+pub mod pallet_erc20_xcm_bridge;
 pub mod pallet_evm;
 pub mod pallet_identity;
 pub mod pallet_moonbeam_orbiters;
@@ -44,4 +46,6 @@ pub mod pallet_treasury;
 pub mod pallet_utility;
 pub mod pallet_whitelist;
 pub mod pallet_xcm;
+pub mod pallet_xcm_fee_oracle;
+pub mod pallet_xcm_rate_limiter;
 pub mod pallet_xcm_transactor;
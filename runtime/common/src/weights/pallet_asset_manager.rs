@@ -165,4 +165,48 @@ impl<T: frame_system::Config> pallet_asset_manager::WeightInfo for WeightInfo<T>
 			.saturating_add(T::DbWeight::get().writes(4))
 			.saturating_add(Weight::from_parts(0, 10).saturating_mul(x.into()))
 	}
+	/// Storage: AssetManager AssetIdType (r:1 w:0)
+	/// Proof Skipped: AssetManager AssetIdType (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(174), added: 2649, mode: MaxEncodedLen)
+	/// Storage: Assets Metadata (r:1 w:1)
+	/// Proof: Assets Metadata (max_values: None, max_size: Some(152), added: 2627, mode: MaxEncodedLen)
+	fn update_foreign_asset_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `520`
+		//  Estimated: `3979`
+		// Minimum execution time: 29_314_000 picoseconds.
+		Weight::from_parts(29_314_000, 0)
+			.saturating_add(Weight::from_parts(0, 3979))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: AssetManager AssetIdType (r:1 w:0)
+	/// Proof Skipped: AssetManager AssetIdType (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetTypeId (r:1 w:0)
+	/// Proof Skipped: AssetManager AssetTypeId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetTypeAliases (r:1 w:1)
+	/// Proof Skipped: AssetManager AssetTypeAliases (max_values: None, max_size: None, mode: Measured)
+	fn add_asset_type_alias() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `335`
+		//  Estimated: `3800`
+		// Minimum execution time: 18_102_000 picoseconds.
+		Weight::from_parts(18_102_000, 0)
+			.saturating_add(Weight::from_parts(0, 3800))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: AssetManager AssetTypeAliases (r:1 w:1)
+	/// Proof Skipped: AssetManager AssetTypeAliases (max_values: None, max_size: None, mode: Measured)
+	fn remove_asset_type_alias() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `196`
+		//  Estimated: `3661`
+		// Minimum execution time: 12_871_000 picoseconds.
+		Weight::from_parts(12_871_000, 0)
+			.saturating_add(Weight::from_parts(0, 3661))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
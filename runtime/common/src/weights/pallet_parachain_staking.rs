@@ -69,8 +69,8 @@ impl<T: frame_system::Config> pallet_parachain_staking::WeightInfo for WeightInf
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: ParachainStaking ParachainBondInfo (r:1 w:1)
-	/// Proof Skipped: ParachainStaking ParachainBondInfo (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking InflationDistributionInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking InflationDistributionInfo (max_values: Some(1), max_size: None, mode: Measured)
 	fn set_parachain_bond_account() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `6`
@@ -81,8 +81,8 @@ impl<T: frame_system::Config> pallet_parachain_staking::WeightInfo for WeightInf
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: ParachainStaking ParachainBondInfo (r:1 w:1)
-	/// Proof Skipped: ParachainStaking ParachainBondInfo (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking InflationDistributionInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking InflationDistributionInfo (max_values: Some(1), max_size: None, mode: Measured)
 	fn set_parachain_bond_reserve_percent() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `6`
@@ -615,9 +615,9 @@ impl<T: frame_system::Config> pallet_parachain_staking::WeightInfo for WeightInf
 	/// Proof Skipped: ParachainStaking Staked (max_values: None, max_size: None, mode: Measured)
 	/// Storage: ParachainStaking InflationConfig (r:1 w:0)
 	/// Proof Skipped: ParachainStaking InflationConfig (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: ParachainStaking ParachainBondInfo (r:1 w:0)
-	/// Proof Skipped: ParachainStaking ParachainBondInfo (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: System Account (r:1 w:1)
+	/// Storage: ParachainStaking InflationDistributionInfo (r:1 w:0)
+	/// Proof Skipped: ParachainStaking InflationDistributionInfo (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: System Account (r:8 w:8)
 	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
 	/// Storage: ParachainStaking CollatorCommission (r:1 w:0)
 	/// Proof Skipped: ParachainStaking CollatorCommission (max_values: Some(1), max_size: None, mode: Measured)
@@ -625,13 +625,13 @@ impl<T: frame_system::Config> pallet_parachain_staking::WeightInfo for WeightInf
 	/// Proof Skipped: ParachainStaking DelayedPayouts (max_values: None, max_size: None, mode: Measured)
 	fn prepare_staking_payouts() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `380`
-		//  Estimated: `3845`
-		// Minimum execution time: 21_509_000 picoseconds.
-		Weight::from_parts(22_210_000, 0)
-			.saturating_add(Weight::from_parts(0, 3845))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(3))
+		//  Measured:  `1203`
+		//  Estimated: `21982`
+		// Minimum execution time: 58_147_000 picoseconds.
+		Weight::from_parts(60_044_000, 0)
+			.saturating_add(Weight::from_parts(0, 21982))
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(10))
 	}
 	/// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:0)
 	/// Proof Skipped: ParachainStaking DelegationScheduledRequests (max_values: None, max_size: None, mode: Measured)
@@ -736,6 +736,8 @@ impl<T: frame_system::Config> pallet_parachain_staking::WeightInfo for WeightInf
 	/// Proof Skipped: ParachainStaking AtStake (max_values: None, max_size: None, mode: Measured)
 	/// Storage: ParachainStaking AwardedPts (r:1 w:1)
 	/// Proof Skipped: ParachainStaking AwardedPts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking RewardMode (r:1 w:0)
+	/// Proof Skipped: ParachainStaking RewardMode (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:0)
 	/// Proof Skipped: ParachainStaking DelegationScheduledRequests (max_values: None, max_size: None, mode: Measured)
 	/// Storage: MoonbeamOrbiters OrbiterPerRound (r:1 w:0)
@@ -752,12 +754,44 @@ impl<T: frame_system::Config> pallet_parachain_staking::WeightInfo for WeightInf
 			.saturating_add(Weight::from_parts(0, 6978))
 			// Standard Error: 4_122
 			.saturating_add(Weight::from_parts(9_113_953, 0).saturating_mul(y.into()))
-			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().reads(9))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(y.into())))
 			.saturating_add(T::DbWeight::get().writes(3))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(y.into())))
 			.saturating_add(Weight::from_parts(0, 2591).saturating_mul(y.into()))
 	}
+	/// Storage: ParachainStaking DelayedPayouts (r:1 w:0)
+	/// Proof Skipped: ParachainStaking DelayedPayouts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Points (r:1 w:0)
+	/// Proof Skipped: ParachainStaking Points (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking AtStake (r:2 w:1)
+	/// Proof Skipped: ParachainStaking AtStake (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking AwardedPts (r:1 w:1)
+	/// Proof Skipped: ParachainStaking AwardedPts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking RewardMode (r:1 w:0)
+	/// Proof Skipped: ParachainStaking RewardMode (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:0)
+	/// Proof Skipped: ParachainStaking DelegationScheduledRequests (max_values: None, max_size: None, mode: Measured)
+	/// Storage: MoonbeamOrbiters OrbiterPerRound (r:1 w:0)
+	/// Proof Skipped: MoonbeamOrbiters OrbiterPerRound (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking DelegatorRewardPools (r:0 w:1)
+	/// Proof Skipped: ParachainStaking DelegatorRewardPools (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `y` is `[0, 300]`.
+	fn pay_one_collator_reward_claim(y: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1243 + y * (160 ±0)`
+		//  Estimated: `7013 + y * (160 ±0)`
+		// Minimum execution time: 33_871_000 picoseconds.
+		Weight::from_parts(34_702_518, 0)
+			.saturating_add(Weight::from_parts(0, 7013))
+			// Standard Error: 1_147
+			.saturating_add(Weight::from_parts(231_406, 0).saturating_mul(y.into()))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(Weight::from_parts(0, 160).saturating_mul(y.into()))
+	}
 	fn base_on_initialize() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
@@ -900,4 +934,92 @@ impl<T: frame_system::Config> pallet_parachain_staking::WeightInfo for WeightInf
 			.saturating_add(T::DbWeight::get().reads(10_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: ParachainStaking InflationDistributionInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking InflationDistributionInfo (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_inflation_distribution_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `1491`
+		// Minimum execution time: 15_094_000 picoseconds.
+		Weight::from_parts(15_094_000, 1491)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: ParachainStaking DelegatorRewardPools (r:201 w:100)
+	/// Proof Skipped: ParachainStaking DelegatorRewardPools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking AutoCompoundingDelegations (r:100 w:0)
+	/// Proof Skipped: ParachainStaking AutoCompoundingDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:100 w:100)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking DelegatorState (r:100 w:100)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidateInfo (r:100 w:100)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking TopDelegations (r:100 w:100)
+	/// Proof Skipped: ParachainStaking TopDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	/// Proof Skipped: ParachainStaking CandidatePool (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1287), added: 3762, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:0)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking Total (r:1 w:1)
+	/// Proof Skipped: ParachainStaking Total (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `x` is `[0, 200]`.
+	/// The range of component `y` is `[1, 300]`.
+	/// The range of component `z` is `[1, 100]`.
+	fn claim_rewards(x: u32, y: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1862 + x * (96 ±0) + x * y * (36 ±0) + z * (1402 ±0) + y * z * (36 ±0)`
+		//  Estimated: `7802 + x * (2571 ±0) + x * y * (36 ±0) + z * (14318 ±0) + y * z * (36 ±0)`
+		// Minimum execution time: 98_710_000 picoseconds.
+		Weight::from_parts(52_381_000, 7802)
+			// Standard Error: 9_412
+			.saturating_add(Weight::from_parts(6_125_408, 0).saturating_mul(x.into()))
+			// Standard Error: 31
+			.saturating_add(Weight::from_parts(61_233, 0).saturating_mul(
+				(x as u64).saturating_mul(y as u64),
+			))
+			// Standard Error: 61_930
+			.saturating_add(Weight::from_parts(61_046_112, 0).saturating_mul(z.into()))
+			// Standard Error: 214
+			.saturating_add(Weight::from_parts(88_473, 0).saturating_mul(
+				(y as u64).saturating_mul(z as u64),
+			))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(z.into())))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(z.into())))
+			.saturating_add(Weight::from_parts(0, 2571).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 36).saturating_mul(
+				(x as u64).saturating_mul(y as u64),
+			))
+			.saturating_add(Weight::from_parts(0, 14318).saturating_mul(z.into()))
+			.saturating_add(Weight::from_parts(0, 36).saturating_mul(
+				(y as u64).saturating_mul(z as u64),
+			))
+	}
+	/// Storage: ParachainStaking RewardMode (r:1 w:1)
+	/// Proof Skipped: ParachainStaking RewardMode (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_reward_payment_mode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `1491`
+		// Minimum execution time: 14_310_000 picoseconds.
+		Weight::from_parts(14_310_000, 1491)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: ParachainStaking InflationDistributionInfo (r:1 w:1)
+	/// Proof Skipped: ParachainStaking InflationDistributionInfo (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_inflation_distribution_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `1491`
+		// Minimum execution time: 15_094_000 picoseconds.
+		Weight::from_parts(15_094_000, 1491)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
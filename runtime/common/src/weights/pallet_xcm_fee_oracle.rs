@@ -0,0 +1,108 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+//! Autogenerated weights for `pallet_xcm_fee_oracle`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-11-20, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `ip-10-0-0-176`, CPU: `Intel(R) Xeon(R) Platinum 8375C CPU @ 2.90GHz`
+//! EXECUTION: None, WASM-EXECUTION: Compiled, CHAIN: Some("moonbase-dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/moonbeam
+// benchmark
+// pallet
+// --chain=moonbase-dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_xcm_fee_oracle
+// --extrinsic=*
+// --wasm-execution=compiled
+// --header=./file_header.txt
+// --output=./runtime/common/src/weights/
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_xcm_fee_oracle`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_xcm_fee_oracle::WeightInfo for WeightInfo<T> {
+	/// Storage: XcmFeeOracle PriceFeeders (r:1 w:1)
+	/// Proof: XcmFeeOracle PriceFeeders (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn add_feeder() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3501`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3501))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: XcmFeeOracle PriceFeeders (r:1 w:1)
+	/// Proof: XcmFeeOracle PriceFeeders (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn remove_feeder() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `98`
+		//  Estimated: `3501`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3501))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: XcmFeeOracle PriceFeeders (r:1 w:0)
+	/// Proof: XcmFeeOracle PriceFeeders (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: XcmFeeOracle RelativePrices (r:1 w:1)
+	/// Proof: XcmFeeOracle RelativePrices (max_values: None, max_size: Some(591), added: 3066, mode: MaxEncodedLen)
+	fn set_relative_price() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `194`
+		//  Estimated: `4056`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4056))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: XcmFeeOracle RelativePrices (r:1 w:1)
+	/// Proof: XcmFeeOracle RelativePrices (max_values: None, max_size: Some(591), added: 3066, mode: MaxEncodedLen)
+	fn remove_relative_price() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `4056`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4056))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: XcmFeeOracle RelativePrices (r:0 w:1)
+	/// Proof: XcmFeeOracle RelativePrices (max_values: None, max_size: Some(591), added: 3066, mode: MaxEncodedLen)
+	fn force_set_relative_price() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
@@ -0,0 +1,127 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+//! Autogenerated weights for `pallet_xcm_rate_limiter`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-11-27, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `ip-10-0-0-176`, CPU: `Intel(R) Xeon(R) Platinum 8375C CPU @ 2.90GHz`
+//! EXECUTION: None, WASM-EXECUTION: Compiled, CHAIN: Some("moonbase-dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/moonbeam
+// benchmark
+// pallet
+// --chain=moonbase-dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_xcm_rate_limiter
+// --extrinsic=*
+// --wasm-execution=compiled
+// --header=./file_header.txt
+// --output=./runtime/common/src/weights/
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_xcm_rate_limiter`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_xcm_rate_limiter::WeightInfo for WeightInfo<T> {
+	/// Storage: XcmRateLimiter RateLimits (r:0 w:1)
+	/// Proof: XcmRateLimiter RateLimits (max_values: None, max_size: Some(614), added: 3089, mode: MaxEncodedLen)
+	fn set_rate_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `1494`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 1494))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: XcmRateLimiter RateLimits (r:1 w:1)
+	/// Proof: XcmRateLimiter RateLimits (max_values: None, max_size: Some(614), added: 3089, mode: MaxEncodedLen)
+	/// Storage: XcmRateLimiter Inflows (r:0 w:1)
+	/// Proof: XcmRateLimiter Inflows (max_values: None, max_size: Some(614), added: 3089, mode: MaxEncodedLen)
+	fn remove_rate_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `155`
+		//  Estimated: `4079`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4079))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: XcmRateLimiter PausedOrigins (r:1 w:1)
+	/// Proof: XcmRateLimiter PausedOrigins (max_values: None, max_size: Some(590), added: 3065, mode: MaxEncodedLen)
+	fn pause_origin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `4055`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4055))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: XcmRateLimiter PausedOrigins (r:1 w:1)
+	/// Proof: XcmRateLimiter PausedOrigins (max_values: None, max_size: Some(590), added: 3065, mode: MaxEncodedLen)
+	fn unpause_origin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `131`
+		//  Estimated: `4055`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4055))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: XcmRateLimiter QuarantinedDeposits (r:1 w:1)
+	/// Proof: XcmRateLimiter QuarantinedDeposits (max_values: None, max_size: Some(1749), added: 4224, mode: MaxEncodedLen)
+	/// Storage: XcmRateLimiter CounterForQuarantinedDeposits (r:1 w:1)
+	/// Proof: XcmRateLimiter CounterForQuarantinedDeposits (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	fn release_quarantined_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `368`
+		//  Estimated: `5214`
+		// Minimum execution time: 41_000_000 picoseconds.
+		Weight::from_parts(41_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5214))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: XcmRateLimiter QuarantinedDeposits (r:1 w:1)
+	/// Proof: XcmRateLimiter QuarantinedDeposits (max_values: None, max_size: Some(1749), added: 4224, mode: MaxEncodedLen)
+	/// Storage: XcmRateLimiter CounterForQuarantinedDeposits (r:1 w:1)
+	/// Proof: XcmRateLimiter CounterForQuarantinedDeposits (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn reject_quarantined_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `257`
+		//  Estimated: `5214`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5214))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+}
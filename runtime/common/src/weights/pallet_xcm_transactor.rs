@@ -193,4 +193,48 @@ impl<T: frame_system::Config> pallet_xcm_transactor::WeightInfo for WeightInfo<T
 			.saturating_add(T::DbWeight::get().reads(8))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	/// Proof Skipped: PolkadotXcm QueryCounter (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PolkadotXcm Queries (r:0 w:1)
+	/// Proof Skipped: PolkadotXcm Queries (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor TransactStatusQueryCount (r:1 w:1)
+	/// Proof Skipped: XcmTransactor TransactStatusQueryCount (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor TransactStatusQueries (r:0 w:1)
+	/// Proof Skipped: XcmTransactor TransactStatusQueries (max_values: None, max_size: None, mode: Measured)
+	fn register_transact_status_query() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `139`
+		//  Estimated: `3604`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(27_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3604))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: XcmTransactor TransactStatusQueries (r:1 w:1)
+	/// Proof Skipped: XcmTransactor TransactStatusQueries (max_values: None, max_size: None, mode: Measured)
+	fn notify_transact_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `245`
+		//  Estimated: `3710`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3710))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: XcmTransactor TransactStatusQueries (r:1 w:1)
+	/// Proof Skipped: XcmTransactor TransactStatusQueries (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmTransactor TransactStatusQueryCount (r:1 w:1)
+	/// Proof Skipped: XcmTransactor TransactStatusQueryCount (max_values: None, max_size: None, mode: Measured)
+	fn remove_transact_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `272`
+		//  Estimated: `3737`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3737))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
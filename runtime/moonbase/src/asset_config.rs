@@ -214,6 +214,21 @@ impl pallet_asset_manager::AssetRegistrar<Runtime> for AssetRegistrar {
 		Ok(())
 	}
 
+	#[transactional]
+	fn update_foreign_asset_metadata(
+		asset: AssetId,
+		metadata: AssetRegistrarMetadata,
+	) -> DispatchResult {
+		Assets::force_set_metadata(
+			RuntimeOrigin::root(),
+			asset.into(),
+			metadata.name,
+			metadata.symbol,
+			metadata.decimals,
+			metadata.is_frozen,
+		)
+	}
+
 	#[transactional]
 	fn destroy_foreign_asset(asset: AssetId) -> DispatchResult {
 		// First destroy the asset
@@ -307,6 +322,7 @@ impl pallet_asset_manager::Config for Runtime {
 	type AssetRegistrar = AssetRegistrar;
 	type ForeignAssetModifierOrigin = ForeignAssetModifierOrigin;
 	type LocalAssetModifierOrigin = LocalAssetModifierOrigin;
+	type UnregisteredFeeAssets = xcm_config::Erc20FeeAssets;
 	type LocalAssetIdCreator = LocalAssetIdCreator;
 	type Currency = Balances;
 	type LocalAssetDeposit = AssetDeposit;
@@ -1042,6 +1042,12 @@ impl pallet_proxy::Config for Runtime {
 }
 
 use pallet_migrations::{GetMigrations, Migration};
+
+parameter_types! {
+	pub const RelayIndicesToExtend: pallet_xcm_transactor::relay_indices::RelayChainIndices =
+		moonbeam_relay_encoder::westend::WESTEND_RELAY_INDICES;
+}
+
 pub struct TransactorRelayIndexMigration<Runtime>(sp_std::marker::PhantomData<Runtime>);
 
 impl<Runtime> GetMigrations for TransactorRelayIndexMigration<Runtime>
@@ -1049,12 +1055,20 @@ where
 	Runtime: pallet_xcm_transactor::Config,
 {
 	fn get_migrations() -> Vec<Box<dyn Migration>> {
-		vec![Box::new(
-			moonbeam_runtime_common::migrations::PopulateRelayIndices::<Runtime>(
+		vec![
+			Box::new(moonbeam_runtime_common::migrations::PopulateRelayIndices::<
+				Runtime,
+			>(
 				moonbeam_relay_encoder::westend::WESTEND_RELAY_INDICES,
 				Default::default(),
+			)),
+			Box::new(
+				moonbeam_runtime_common::migrations::XcmTransactorExtendRelayIndices::<
+					Runtime,
+					RelayIndicesToExtend,
+				>(Default::default()),
 			),
-		)]
+		]
 	}
 }
 
@@ -1414,8 +1428,11 @@ construct_runtime! {
 		OpenTechCommitteeCollective:
 			pallet_collective::<Instance4>::{Pallet, Call, Storage, Event<T>, Origin<T>, Config<T>} = 46,
 		RootTesting: pallet_root_testing::{Pallet, Call, Storage} = 47,
-		Erc20XcmBridge: pallet_erc20_xcm_bridge::{Pallet} = 48,
+		Erc20XcmBridge: pallet_erc20_xcm_bridge::{Pallet, Call, Storage, Event<T>} = 48,
 		Multisig: pallet_multisig::{Pallet, Call, Storage, Event<T>} = 49,
+		XcmFeeOracle: pallet_xcm_fee_oracle::{Pallet, Call, Storage, Event<T>} = 50,
+		XcmRateLimiter: pallet_xcm_rate_limiter::{Pallet, Call, Storage, Event<T>} = 51,
+		Erc20TransferLogs: pallet_erc20_transfer_logs::{Pallet, Call, Storage, Event<T>} = 52,
 	}
 }
 
@@ -1480,6 +1497,10 @@ mod benches {
 		[cumulus_pallet_xcmp_queue, XcmpQueue]
 		[pallet_xcm, PolkadotXcm]
 		[pallet_asset_manager, AssetManager]
+		[pallet_erc20_xcm_bridge, Erc20XcmBridge]
+		[pallet_xcm_fee_oracle, XcmFeeOracle]
+		[pallet_xcm_rate_limiter, XcmRateLimiter]
+		[pallet_erc20_transfer_logs, Erc20TransferLogs]
 		[pallet_xcm_transactor, XcmTransactor]
 		[pallet_moonbeam_orbiters, MoonbeamOrbiters]
 		[pallet_randomness, Randomness]
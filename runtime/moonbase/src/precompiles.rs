@@ -16,23 +16,31 @@
 
 use crate::{
 	asset_config::{ForeignAssetInstance, LocalAssetInstance},
-	xcm_config::XcmExecutorConfig,
+	xcm_config::{Erc20TransferLogsSource, XcmDestinationFeePerSecond, XcmExecutorConfig},
 	CouncilInstance, OpenTechCommitteeInstance, TechCommitteeInstance, TreasuryCouncilInstance,
 };
 use frame_support::parameter_types;
+use pallet_evm_precompile_asset_registry::AssetRegistryPrecompile;
 use pallet_evm_precompile_author_mapping::AuthorMappingPrecompile;
 use pallet_evm_precompile_balances_erc20::{Erc20BalancesPrecompile, Erc20Metadata};
 use pallet_evm_precompile_batch::BatchPrecompile;
 use pallet_evm_precompile_blake2::Blake2F;
+use pallet_evm_precompile_bls12381::{
+	Bls12381G1Add, Bls12381G1Msm, Bls12381G2Add, Bls12381G2Msm, Bls12381MapFp2ToG2,
+	Bls12381MapFpToG1, Bls12381Pairing,
+};
 use pallet_evm_precompile_bn128::{Bn128Add, Bn128Mul, Bn128Pairing};
 use pallet_evm_precompile_call_permit::CallPermitPrecompile;
 use pallet_evm_precompile_collective::CollectivePrecompile;
 use pallet_evm_precompile_conviction_voting::ConvictionVotingPrecompile;
 use pallet_evm_precompile_crowdloan_rewards::CrowdloanRewardsPrecompile;
 use pallet_evm_precompile_democracy::DemocracyPrecompile;
+use pallet_evm_precompile_erc20_transfer_logs::Erc20TransferLogsPrecompile;
 use pallet_evm_precompile_gmp::GmpPrecompile;
 use pallet_evm_precompile_identity::IdentityPrecompile;
 use pallet_evm_precompile_modexp::Modexp;
+use pallet_evm_precompile_multisig::MultisigPrecompile;
+use pallet_evm_precompile_p256verify::P256Verify;
 use pallet_evm_precompile_parachain_staking::ParachainStakingPrecompile;
 use pallet_evm_precompile_preimage::PreimagePrecompile;
 use pallet_evm_precompile_proxy::{OnlyIsProxyAndProxy, ProxyPrecompile};
@@ -40,8 +48,10 @@ use pallet_evm_precompile_randomness::RandomnessPrecompile;
 use pallet_evm_precompile_referenda::ReferendaPrecompile;
 use pallet_evm_precompile_registry::PrecompileRegistry;
 use pallet_evm_precompile_relay_encoder::RelayEncoderPrecompile;
+use pallet_evm_precompile_scheduler::SchedulerPrecompile;
 use pallet_evm_precompile_sha3fips::Sha3FIPS256;
 use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
+use pallet_evm_precompile_treasury::TreasuryPrecompile;
 use pallet_evm_precompile_xcm_transactor::{
 	v1::XcmTransactorPrecompileV1, v2::XcmTransactorPrecompileV2, v3::XcmTransactorPrecompileV3,
 };
@@ -103,6 +113,16 @@ type MoonbasePrecompilesAt<R> = (
 	PrecompileAt<AddressU64<7>, Bn128Mul, EthereumPrecompilesChecks>,
 	PrecompileAt<AddressU64<8>, Bn128Pairing, EthereumPrecompilesChecks>,
 	PrecompileAt<AddressU64<9>, Blake2F, EthereumPrecompilesChecks>,
+	// EIP-2537 BLS12-381 precompiles:
+	PrecompileAt<AddressU64<11>, Bls12381G1Add, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<12>, Bls12381G1Msm, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<13>, Bls12381G2Add, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<14>, Bls12381G2Msm, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<15>, Bls12381Pairing, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<16>, Bls12381MapFpToG1, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<17>, Bls12381MapFp2ToG2, EthereumPrecompilesChecks>,
+	// RIP-7212 secp256r1 signature verification:
+	PrecompileAt<AddressU64<256>, P256Verify, EthereumPrecompilesChecks>,
 	// Non-Moonbeam specific nor Ethereum precompiles :
 	PrecompileAt<AddressU64<1024>, Sha3FIPS256, (CallableByContract, CallableByPrecompile)>,
 	RemovedPrecompileAt<AddressU64<1025>>, // Dispatch<R>
@@ -183,8 +203,8 @@ type MoonbasePrecompilesAt<R> = (
 	>,
 	PrecompileAt<
 		AddressU64<2060>,
-		XcmUtilsPrecompile<R, XcmExecutorConfig>,
-		CallableByContract<AllExceptXcmExecute<R, XcmExecutorConfig>>,
+		XcmUtilsPrecompile<R, XcmExecutorConfig, XcmDestinationFeePerSecond>,
+		CallableByContract<AllExceptXcmExecute<R, XcmExecutorConfig, XcmDestinationFeePerSecond>>,
 	>,
 	PrecompileAt<
 		AddressU64<2061>,
@@ -242,6 +262,23 @@ type MoonbasePrecompilesAt<R> = (
 		IdentityPrecompile<R>,
 		(CallableByContract, CallableByPrecompile),
 	>,
+	PrecompileAt<
+		AddressU64<2073>,
+		MultisigPrecompile<R>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<2074>,
+		TreasuryPrecompile<R>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<AddressU64<2075>, SchedulerPrecompile<R>, CallableByContract>,
+	PrecompileAt<AddressU64<2076>, Erc20TransferLogsPrecompile<R, Erc20TransferLogsSource>>,
+	PrecompileAt<
+		AddressU64<2077>,
+		AssetRegistryPrecompile<R, ForeignAssetPrefix>,
+		(CallableByContract, CallableByPrecompile),
+	>,
 );
 
 /// The PrecompileSet installed in the Moonbase runtime.
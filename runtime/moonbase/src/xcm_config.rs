@@ -18,15 +18,17 @@
 //!
 
 use super::{
-	governance, AccountId, AssetId, AssetManager, Assets, Balance, Balances, DealWithFees,
-	Erc20XcmBridge, LocalAssets, ParachainInfo, ParachainSystem, PolkadotXcm, Runtime, RuntimeCall,
-	RuntimeEvent, RuntimeOrigin, Treasury, XcmpQueue, FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX,
+	governance, AccountId, AssetId, AssetManager, Assets, Balance, Balances, BlockNumber,
+	DealWithFees, Erc20TransferLogs, Erc20XcmBridge, LocalAssets, ParachainInfo, ParachainSystem,
+	PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, Treasury, XcmFeeOracle,
+	XcmRateLimiter, XcmpQueue, DAYS, FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX,
 };
 use moonbeam_runtime_common::weights as moonbeam_weights;
+use pallet_erc20_transfer_logs::{ObservedEvent, TransferLog};
 use pallet_evm_precompileset_assets_erc20::AccountIdAssetIdConversion;
 use sp_runtime::{
 	traits::{Hash as THash, MaybeEquivalence, PostDispatchInfoOf},
-	DispatchErrorWithPostInfo,
+	DispatchErrorWithPostInfo, Perbill,
 };
 
 use frame_support::{
@@ -52,7 +54,8 @@ use xcm_executor::traits::{CallDispatcher, ConvertLocation, JustTry};
 use orml_xcm_support::MultiNativeAsset;
 use xcm_primitives::{
 	AbsoluteAndRelativeReserve, AccountIdToCurrencyId, AccountIdToMultiLocation, AsAssetType,
-	FirstAssetTrader, SignedToAccountId20, UtilityAvailableCalls, UtilityEncodeCall, XcmTransact,
+	FirstAssetTrader, SignedToAccountId20, UnitsToWeightRatio, UnitsToWeightRatioWithFallback,
+	UtilityAvailableCalls, UtilityEncodeCall, XcmTransact,
 };
 
 use parity_scale_codec::{Decode, Encode};
@@ -87,6 +90,9 @@ parameter_types! {
 			PalletInstance(<Balances as PalletInfoAccess>::index() as u8)
 		)
 	};
+	// Address of the balances erc20 precompile, which is the ERC-20 token of the self-reserve
+	// currency
+	pub NativeErc20Address: H160 = H160::from_low_u64_be(2050);
 
 	// This is the relative view of our local assets.
 	// Indentified by thix prefix + generalIndex(assetId)
@@ -256,6 +262,8 @@ pub type XcmBarrier = (
 parameter_types! {
 	/// Xcm fees will go to the treasury account
 	pub XcmFeesAccount: AccountId = Treasury::account_id();
+	/// Xcm fees paid with erc20 tokens will also go to the treasury account
+	pub Erc20XcmFeesAccount: H160 = XcmFeesAccount::get().into();
 }
 
 /// This is the struct that will handle the revenue from xcm fees
@@ -302,7 +310,8 @@ impl xcm_executor::Config for XcmExecutorConfig {
 	type RuntimeCall = RuntimeCall;
 	type XcmSender = XcmRouter;
 	// How to withdraw and deposit an asset.
-	type AssetTransactor = AssetTransactors;
+	// Deposits coming from other chains are rate limited
+	type AssetTransactor = XcmRateLimiter;
 	type OriginConverter = XcmOriginToTransactDispatchOrigin;
 	// Filter to the reserve withdraw operations
 	// Whenever the reserve matches the relative or absolute value
@@ -312,10 +321,12 @@ impl xcm_executor::Config for XcmExecutorConfig {
 	type UniversalLocation = UniversalLocation;
 	type Barrier = XcmBarrier;
 	type Weigher = XcmWeigher;
-	// We use two traders
+	// We use three traders
 	// When we receive the relative representation of the self-reserve asset,
 	// we use UsingComponents and the local way of handling fees
-	// When we receive a non-reserve asset, we use AssetManager to fetch how many
+	// When we receive a bridged erc20 token, we use Erc20Trader, which fetches the units per
+	// second from XcmFeeRates and pays the fees with erc20 transfers
+	// When we receive a non-reserve asset, we use XcmFeeRates to fetch how many
 	// units per second we should charge
 	type Trader = (
 		UsingComponents<
@@ -325,7 +336,8 @@ impl xcm_executor::Config for XcmExecutorConfig {
 			Balances,
 			DealWithFees<Runtime>,
 		>,
-		FirstAssetTrader<AssetType, AssetManager, XcmFeesToAccount>,
+		pallet_erc20_xcm_bridge::Erc20Trader<Runtime, AssetType, XcmFeeRates, Erc20XcmFeesAccount>,
+		FirstAssetTrader<AssetType, XcmFeeRates, XcmFeesToAccount>,
 	);
 	type ResponseHandler = PolkadotXcm;
 	type SubscriptionService = PolkadotXcm;
@@ -355,9 +367,14 @@ pub type XcmRouter = (
 	XcmpQueue,
 );
 
+// The rate limiter wrapper records the origin of the executed message, so that deposits made
+// after a ClearOrigin are still rate limited
 type XcmExecutor = pallet_erc20_xcm_bridge::XcmExecutorWrapper<
 	RuntimeCall,
-	xcm_executor::XcmExecutor<XcmExecutorConfig>,
+	pallet_xcm_rate_limiter::XcmExecutorWrapper<
+		RuntimeCall,
+		xcm_executor::XcmExecutor<XcmExecutorConfig>,
+	>,
 >;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -474,7 +491,7 @@ impl AccountIdToCurrencyId<AccountId, CurrencyId> for Runtime {
 	fn account_to_currency_id(account: AccountId) -> Option<CurrencyId> {
 		Some(match account {
 			// the self-reserve currency is identified by the pallet-balances address
-			a if a == H160::from_low_u64_be(2050).into() => CurrencyId::SelfReserve,
+			a if a == NativeErc20Address::get().into() => CurrencyId::SelfReserve,
 			// the rest of the currencies, by their corresponding erc20 address
 			_ => match Runtime::account_to_asset_id(account) {
 				// We distinguish by prefix, and depending on it we create either
@@ -628,6 +645,29 @@ impl XcmTransact for Transactors {
 pub type DerivativeAddressRegistrationOrigin =
 	EitherOfDiverse<EnsureRoot<AccountId>, governance::custom_origins::GeneralAdmin>;
 
+parameter_types! {
+	// Transact status queries that were not answered can be removed after a day
+	pub const TransactStatusQueryTimeout: BlockNumber = DAYS;
+	// Transact status queries an account can have stored at once, until they are removed
+	pub const MaxTransactStatusQueries: u32 = 100;
+}
+
+/// Registers the transact status queries of pallet-xcm-transactor in pallet-xcm, which will
+/// dispatch `notify_transact_status` once the response is received
+pub struct TransactStatusQueryRegistrar;
+impl pallet_xcm_transactor::NotifyQueryRegistrar<pallet_xcm_transactor::Call<Runtime>, BlockNumber>
+	for TransactStatusQueryRegistrar
+{
+	fn new_notify_query(
+		responder: MultiLocation,
+		notify: pallet_xcm_transactor::Call<Runtime>,
+		timeout: BlockNumber,
+		match_querier: MultiLocation,
+	) -> QueryId {
+		PolkadotXcm::new_notify_query(responder, notify, timeout, match_querier)
+	}
+}
+
 impl pallet_xcm_transactor::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
@@ -648,6 +688,11 @@ impl pallet_xcm_transactor::Config for Runtime {
 	type WeightInfo = moonbeam_weights::pallet_xcm_transactor::WeightInfo<Runtime>;
 	type HrmpManipulatorOrigin = GeneralAdminOrRoot;
 	type MaxHrmpFee = xcm_builder::Case<MaxHrmpRelayFee>;
+	type QueryRegistrar = TransactStatusQueryRegistrar;
+	type ResponseOrigin = pallet_xcm::EnsureResponse<Everything>;
+	type TransactStatusQueryTimeout = TransactStatusQueryTimeout;
+	type MaxTransactStatusQueries = MaxTransactStatusQueries;
+	type FeePerSecondProvider = XcmFeeOracle;
 }
 
 parameter_types! {
@@ -664,13 +709,186 @@ parameter_types! {
 	// To be able to support almost all erc20 implementations,
 	// we provide a sufficiently hight gas limit.
 	pub Erc20XcmBridgeTransferGasLimit: u64 = 200_000;
+	// Gas limit an erc20 transfer can request through the multilocation of the token
+	pub Erc20XcmBridgeMaxTransferGasLimit: u64 = 400_000;
 }
 
 impl pallet_erc20_xcm_bridge::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type AccountIdConverter = LocationToH160;
 	type Erc20MultilocationPrefix = Erc20XcmBridgePalletLocation;
 	type Erc20TransferGasLimit = Erc20XcmBridgeTransferGasLimit;
+	type Erc20MaxTransferGasLimit = Erc20XcmBridgeMaxTransferGasLimit;
+	type Erc20RegistryOrigin = GeneralAdminOrRoot;
 	type EvmRunner = EvmRunnerPrecompileOrEthXcm<MoonbeamCall, Self>;
+	type WeightInfo = moonbeam_weights::pallet_erc20_xcm_bridge::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	// Units of native currency charged per second of execution
+	pub NativeUnitsPerSecond: u128 = crate::currency::WEIGHT_FEE
+		.saturating_mul(frame_support::weights::constants::WEIGHT_REF_TIME_PER_SECOND as u128);
+	// Relative prices older than a day are ignored, and the static rates are used instead
+	pub const XcmFeeOracleMaxPriceAge: BlockNumber = DAYS;
+	// Price feeders can move a relative price by at most 10% away from the one set by governance
+	pub const XcmFeeOracleMaxPriceDeviation: Perbill = Perbill::from_percent(10);
+}
+
+impl pallet_xcm_fee_oracle::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type FeederManagerOrigin = GeneralAdminOrRoot;
+	type NativeUnitsPerSecond = NativeUnitsPerSecond;
+	type MaxPriceAge = XcmFeeOracleMaxPriceAge;
+	type MaxPriceDeviation = XcmFeeOracleMaxPriceDeviation;
+	type WeightInfo = moonbeam_weights::pallet_xcm_fee_oracle::WeightInfo<Runtime>;
+}
+
+/// Units per second charged for the assets used to pay xcm fees: the rates derived from the
+/// relative prices of XcmFeeOracle, falling back to the static rates of AssetManager
+pub type XcmFeeRates = UnitsToWeightRatioWithFallback<XcmFeeOracle, AssetManager>;
+
+/// Units per second charged by a destination chain for an asset: the rate pallet-xcm-transactor
+/// pays remote executions with, falling back to the rate at which we charge the asset ourselves
+pub struct XcmDestinationFeePerSecond;
+impl UnitsToWeightRatio<MultiLocation> for XcmDestinationFeePerSecond {
+	fn payment_is_supported(asset_location: MultiLocation) -> bool {
+		Self::get_units_per_second(asset_location).is_some()
+	}
+	fn get_units_per_second(asset_location: MultiLocation) -> Option<u128> {
+		XcmTransactor::fee_per_second(&asset_location).or_else(|| {
+			<AssetManager as UnitsToWeightRatio<AssetType>>::get_units_per_second(
+				asset_location.into(),
+			)
+		})
+	}
+}
+
+parameter_types! {
+	pub const XcmRateLimiterMaxQuarantinedDeposits: u32 = 100;
+}
+
+impl pallet_xcm_rate_limiter::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	// Xcm deposits and withdrawals are noted for the erc20 transfer logs
+	type AssetTransactor = Erc20TransferLogs;
+	type ManagerOrigin = GeneralAdminOrRoot;
+	// Deposits can be paused in an emergency by the technical committee
+	type PauseOrigin = EitherOfDiverse<
+		GeneralAdminOrRoot,
+		pallet_collective::EnsureProportionAtLeast<
+			AccountId,
+			governance::councils::TechCommitteeInstance,
+			2,
+			3,
+		>,
+	>;
+	type MaxQuarantinedDeposits = XcmRateLimiterMaxQuarantinedDeposits;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkReleasableAsset = SelfReserve;
+	type WeightInfo = moonbeam_weights::pallet_xcm_rate_limiter::WeightInfo<Runtime>;
+}
+
+/// Classify the events reported as ERC-20 `Transfer` logs: native token transfers are logged
+/// from the balances erc20 precompile and foreign asset transfers from the asset precompile
+pub struct Erc20TransferLogsClassifier;
+impl pallet_erc20_transfer_logs::ClassifyEvent<RuntimeEvent> for Erc20TransferLogsClassifier {
+	fn classify(event: &RuntimeEvent) -> ObservedEvent {
+		let native_token = NativeErc20Address::get();
+		match event {
+			RuntimeEvent::Balances(pallet_balances::Event::Transfer { from, to, amount }) => {
+				ObservedEvent::Transfer(TransferLog {
+					token: native_token,
+					from: (*from).into(),
+					to: (*to).into(),
+					value: (*amount).into(),
+				})
+			}
+			RuntimeEvent::ParachainStaking(pallet_parachain_staking::Event::Rewarded {
+				account,
+				rewards,
+			}) => ObservedEvent::Transfer(TransferLog {
+				token: native_token,
+				from: H160::zero(),
+				to: (*account).into(),
+				value: (*rewards).into(),
+			}),
+			RuntimeEvent::ParachainStaking(
+				pallet_parachain_staking::Event::InflationDistributed { account, value, .. },
+			) => ObservedEvent::Transfer(TransferLog {
+				token: native_token,
+				from: H160::zero(),
+				to: (*account).into(),
+				value: (*value).into(),
+			}),
+			RuntimeEvent::Assets(pallet_assets::Event::Transferred {
+				asset_id,
+				from,
+				to,
+				amount,
+			}) => ObservedEvent::Transfer(TransferLog {
+				token: Runtime::asset_id_to_account(
+					FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX,
+					*asset_id,
+				)
+				.into(),
+				from: (*from).into(),
+				to: (*to).into(),
+				value: (*amount).into(),
+			}),
+			RuntimeEvent::EVM(pallet_evm::Event::Log { log }) => TransferLog::from_evm_log(log)
+				.map_or(ObservedEvent::Other, ObservedEvent::EvmTransfer),
+			_ => ObservedEvent::Other,
+		}
+	}
+}
+
+/// Address of the ERC-20 token of an asset moved through xcm, if it has one
+pub struct Erc20OfAsset;
+impl sp_runtime::traits::Convert<MultiLocation, Option<H160>> for Erc20OfAsset {
+	fn convert(location: MultiLocation) -> Option<H160> {
+		if location == SelfReserve::get() {
+			return Some(NativeErc20Address::get());
+		}
+		AsAssetType::<AssetId, AssetType, AssetManager>::convert_location(&location).map(
+			|asset_id| {
+				Runtime::asset_id_to_account(FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX, asset_id)
+					.into()
+			},
+		)
+	}
+}
+
+parameter_types! {
+	/// Keyless account sending the transactions carrying the transfer logs
+	pub Erc20TransferLogsSource: H160 =
+		H160::from_slice(&sp_io::hashing::keccak_256(b"erc20-transfer-logs/source")[12..]);
+	pub Erc20TransferLogsEmitter: H160 = H160::from_low_u64_be(2076);
+	pub const Erc20TransferLogsMaxTransfersPerTransaction: u32 = 100;
+}
+
+impl pallet_erc20_transfer_logs::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type EventClassifier = Erc20TransferLogsClassifier;
+	type AssetTransactor = AssetTransactors;
+	type AccountIdConverter = LocationToH160;
+	type Erc20OfAsset = Erc20OfAsset;
+	type ValidatedTransaction = pallet_ethereum::ValidatedTransaction<Self>;
+	type LogsSource = Erc20TransferLogsSource;
+	type LogsEmitter = Erc20TransferLogsEmitter;
+	type MaxTransfersPerTransaction = Erc20TransferLogsMaxTransfersPerTransaction;
+	type ControllerOrigin = GeneralAdminOrRoot;
+	type WeightInfo = moonbeam_weights::pallet_erc20_transfer_logs::WeightInfo<Runtime>;
+}
+
+/// Bridged erc20 tokens can be used for xcm fee payment without being registered
+/// as foreign assets
+pub struct Erc20FeeAssets;
+impl frame_support::traits::Contains<AssetType> for Erc20FeeAssets {
+	fn contains(asset_type: &AssetType) -> bool {
+		match asset_type {
+			AssetType::Xcm(location) => Erc20XcmBridge::is_erc20_location(location),
+		}
+	}
 }
 
 #[cfg(feature = "runtime-benchmarks")]
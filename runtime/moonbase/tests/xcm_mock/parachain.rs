@@ -859,6 +859,17 @@ impl pallet_asset_manager::AssetRegistrar<Runtime> for AssetRegistrar {
 		Ok(())
 	}
 
+	fn update_foreign_asset_metadata(asset: AssetId, metadata: AssetMetadata) -> DispatchResult {
+		Assets::force_set_metadata(
+			RuntimeOrigin::root(),
+			asset,
+			metadata.name,
+			metadata.symbol,
+			metadata.decimals,
+			false,
+		)
+	}
+
 	fn destroy_foreign_asset(asset: AssetId) -> DispatchResult {
 		// Mark the asset as destroying
 		Assets::start_destroy(RuntimeOrigin::root(), asset.into())?;
@@ -913,6 +924,7 @@ impl pallet_asset_manager::Config for Runtime {
 	type AssetRegistrar = AssetRegistrar;
 	type ForeignAssetModifierOrigin = EnsureRoot<AccountId>;
 	type LocalAssetModifierOrigin = EnsureRoot<AccountId>;
+	type UnregisteredFeeAssets = Nothing;
 	type LocalAssetIdCreator = LocalAssetIdCreator;
 	type Currency = Balances;
 	type LocalAssetDeposit = AssetDeposit;
@@ -924,6 +936,25 @@ parameter_types! {
 	pub MaxHrmpRelayFee: MultiAsset = (MultiLocation::parent(), 1_000_000_000_000u128).into();
 }
 
+parameter_types! {
+	pub const TransactStatusQueryTimeout: BlockNumber = 100;
+	pub const MaxTransactStatusQueries: u32 = 3;
+}
+
+pub struct TransactStatusQueryRegistrar;
+impl pallet_xcm_transactor::NotifyQueryRegistrar<pallet_xcm_transactor::Call<Runtime>, BlockNumber>
+	for TransactStatusQueryRegistrar
+{
+	fn new_notify_query(
+		responder: MultiLocation,
+		notify: pallet_xcm_transactor::Call<Runtime>,
+		timeout: BlockNumber,
+		match_querier: MultiLocation,
+	) -> QueryId {
+		PolkadotXcm::new_notify_query(responder, notify, timeout, match_querier)
+	}
+}
+
 impl pallet_xcm_transactor::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
@@ -944,6 +975,11 @@ impl pallet_xcm_transactor::Config for Runtime {
 	type WeightInfo = ();
 	type HrmpManipulatorOrigin = EnsureRoot<AccountId>;
 	type MaxHrmpFee = xcm_builder::Case<MaxHrmpRelayFee>;
+	type QueryRegistrar = TransactStatusQueryRegistrar;
+	type ResponseOrigin = pallet_xcm::EnsureResponse<Everything>;
+	type TransactStatusQueryTimeout = TransactStatusQueryTimeout;
+	type MaxTransactStatusQueries = MaxTransactStatusQueries;
+	type FeePerSecondProvider = ();
 }
 
 parameter_types! {
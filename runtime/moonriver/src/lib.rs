@@ -1057,6 +1057,12 @@ impl pallet_proxy::Config for Runtime {
 }
 
 use pallet_migrations::{GetMigrations, Migration};
+
+parameter_types! {
+	pub const RelayIndicesToExtend: pallet_xcm_transactor::relay_indices::RelayChainIndices =
+		moonbeam_relay_encoder::kusama::KUSAMA_RELAY_INDICES;
+}
+
 pub struct TransactorRelayIndexMigration<Runtime>(sp_std::marker::PhantomData<Runtime>);
 
 impl<Runtime> GetMigrations for TransactorRelayIndexMigration<Runtime>
@@ -1064,12 +1070,20 @@ where
 	Runtime: pallet_xcm_transactor::Config,
 {
 	fn get_migrations() -> Vec<Box<dyn Migration>> {
-		vec![Box::new(
-			moonbeam_runtime_common::migrations::PopulateRelayIndices::<Runtime>(
+		vec![
+			Box::new(moonbeam_runtime_common::migrations::PopulateRelayIndices::<
+				Runtime,
+			>(
 				moonbeam_relay_encoder::kusama::KUSAMA_RELAY_INDICES,
 				Default::default(),
+			)),
+			Box::new(
+				moonbeam_runtime_common::migrations::XcmTransactorExtendRelayIndices::<
+					Runtime,
+					RelayIndicesToExtend,
+				>(Default::default()),
 			),
-		)]
+		]
 	}
 }
 
@@ -1451,7 +1465,10 @@ construct_runtime! {
 		XcmTransactor: pallet_xcm_transactor::{Pallet, Call, Storage, Event<T>} = 107,
 		LocalAssets: pallet_assets::<Instance1>::{Pallet, Call, Storage, Event<T>} = 108,
 		EthereumXcm: pallet_ethereum_xcm::{Pallet, Call, Storage, Origin} = 109,
-		Erc20XcmBridge: pallet_erc20_xcm_bridge::{Pallet} = 110,
+		Erc20XcmBridge: pallet_erc20_xcm_bridge::{Pallet, Call, Storage, Event<T>} = 110,
+		XcmFeeOracle: pallet_xcm_fee_oracle::{Pallet, Call, Storage, Event<T>} = 111,
+		XcmRateLimiter: pallet_xcm_rate_limiter::{Pallet, Call, Storage, Event<T>} = 112,
+		Erc20TransferLogs: pallet_erc20_transfer_logs::{Pallet, Call, Storage, Event<T>} = 113,
 
 		// Randomness
 		Randomness: pallet_randomness::{Pallet, Call, Storage, Event<T>, Inherent} = 120,
@@ -1485,6 +1502,10 @@ mod benches {
 		[cumulus_pallet_xcmp_queue, XcmpQueue]
 		[pallet_xcm, PolkadotXcm]
 		[pallet_asset_manager, AssetManager]
+		[pallet_erc20_xcm_bridge, Erc20XcmBridge]
+		[pallet_xcm_fee_oracle, XcmFeeOracle]
+		[pallet_xcm_rate_limiter, XcmRateLimiter]
+		[pallet_erc20_transfer_logs, Erc20TransferLogs]
 		[pallet_xcm_transactor, XcmTransactor]
 		[pallet_moonbeam_orbiters, MoonbeamOrbiters]
 		[pallet_randomness, Randomness]
@@ -50,7 +50,7 @@ use pallet_xcm_transactor::{Currency, CurrencyPayment, TransactWeights};
 use parity_scale_codec::Encode;
 use polkadot_parachain::primitives::Sibling;
 use precompile_utils::{
-	precompile_set::{is_precompile_or_fail, IsActivePrecompile},
+	precompile_set::{is_precompile_or_fail, IsActivePrecompile, PrecompileSetInterfaces},
 	prelude::*,
 	testing::*,
 };
@@ -2423,9 +2423,10 @@ fn precompile_existence() {
 	ExtBuilder::default().build().execute_with(|| {
 		let precompiles = Precompiles::new();
 		let precompile_addresses: std::collections::BTreeSet<_> = vec![
-			1, 2, 3, 4, 5, 6, 7, 8, 9, 1024, 1025, 1026, 2048, 2049, 2050, 2051, 2052, 2053, 2054,
-			2055, 2056, 2057, 2058, 2059, 2060, 2061, 2062, 2063, 2064, 2065, 2066, 2067, 2068,
-			2069, 2070, 2071, 2072,
+			1, 2, 3, 4, 5, 6, 7, 8, 9, 11, 12, 13, 14, 15, 16, 17, 256, 1024, 1025, 1026, 2048,
+			2049, 2050, 2051, 2052, 2053, 2054, 2055, 2056, 2057, 2058, 2059, 2060, 2061, 2062,
+			2063, 2064, 2065, 2066, 2067, 2068, 2069, 2070, 2071, 2072, 2073, 2074, 2075, 2076,
+			2077,
 		]
 		.into_iter()
 		.map(H160::from_low_u64_be)
@@ -2481,6 +2482,40 @@ fn precompile_existence() {
 	});
 }
 
+#[test]
+fn precompile_interfaces_are_generated() {
+	let interfaces = Precompiles::solidity_interfaces();
+
+	// Only the precompiles declared with the `#[precompile]` macro have an interface, the
+	// Ethereum ones and the removed ones are skipped.
+	let addresses: std::collections::BTreeSet<_> = interfaces
+		.iter()
+		.filter_map(|interface| interface.address)
+		.collect();
+	let expected: std::collections::BTreeSet<_> =
+		(2048..=2077).map(H160::from_low_u64_be).collect();
+	assert_eq!(addresses, expected);
+
+	for interface in &interfaces {
+		assert!(
+			!interface.functions.is_empty(),
+			"{} should have functions",
+			interface.name
+		);
+
+		for function in &interface.functions {
+			let hash = Keccak256::digest(function.signature().as_bytes());
+			assert_eq!(
+				function.selector,
+				u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]]),
+				"{}: wrong selector for {}",
+				interface.name,
+				function.signature()
+			);
+		}
+	}
+}
+
 #[test]
 fn removed_precompiles() {
 	ExtBuilder::default().build().execute_with(|| {
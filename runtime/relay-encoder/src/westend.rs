@@ -35,6 +35,15 @@ pub enum RelayCall {
 	#[codec(index = 51u8)]
 	// the index should match the position of the module in `construct_runtime!`
 	Hrmp(HrmpCall),
+	#[codec(index = 29u8)]
+	// the index should match the position of the module in `construct_runtime!`
+	NominationPools(NominationPoolsCall),
+	#[codec(index = 38u8)]
+	// the index should match the position of the module in `construct_runtime!`
+	ConvictionVoting(ConvictionVotingCall),
+	#[codec(index = 22u8)]
+	// the index should match the position of the module in `construct_runtime!`
+	Proxy(ProxyCall),
 }
 
 #[derive(Encode, Decode)]
@@ -85,6 +94,63 @@ pub enum HrmpCall {
 	CancelOpenRequest(HrmpChannelId, u32),
 }
 
+// Nomination pools call encoding, needed for xcm transactor pallet
+#[derive(Encode, Decode)]
+pub enum NominationPoolsCall {
+	#[codec(index = 0u8)]
+	Join(
+		#[codec(compact)] cumulus_primitives_core::relay_chain::Balance,
+		u32,
+	),
+	#[codec(index = 1u8)]
+	BondExtra(xcm_primitives::PoolBondExtra),
+	#[codec(index = 2u8)]
+	ClaimPayout,
+	#[codec(index = 3u8)]
+	Unbond(
+		<AccountIdLookup<AccountId32, ()> as StaticLookup>::Source,
+		#[codec(compact)] cumulus_primitives_core::relay_chain::Balance,
+	),
+}
+
+// Conviction voting call encoding, needed for xcm transactor pallet
+#[derive(Encode, Decode)]
+pub enum ConvictionVotingCall {
+	#[codec(index = 0u8)]
+	Vote(#[codec(compact)] u32, xcm_primitives::RelayAccountVote),
+	#[codec(index = 1u8)]
+	Delegate(
+		u16,
+		<AccountIdLookup<AccountId32, ()> as StaticLookup>::Source,
+		xcm_primitives::RelayConviction,
+		cumulus_primitives_core::relay_chain::Balance,
+	),
+	#[codec(index = 4u8)]
+	RemoveVote(Option<u16>, u32),
+}
+
+// Proxy call encoding, needed for xcm transactor pallet
+#[derive(Encode, Decode)]
+pub enum ProxyCall {
+	#[codec(index = 0u8)]
+	Proxy(
+		<AccountIdLookup<AccountId32, ()> as StaticLookup>::Source,
+		Option<u8>,
+	),
+	#[codec(index = 1u8)]
+	AddProxy(
+		<AccountIdLookup<AccountId32, ()> as StaticLookup>::Source,
+		u8,
+		u32,
+	),
+	#[codec(index = 2u8)]
+	RemoveProxy(
+		<AccountIdLookup<AccountId32, ()> as StaticLookup>::Source,
+		u8,
+		u32,
+	),
+}
+
 pub struct WestendEncoder;
 
 impl xcm_primitives::UtilityEncodeCall for WestendEncoder {
@@ -171,6 +237,73 @@ impl xcm_primitives::StakeEncodeCall for WestendEncoder {
 	}
 }
 
+impl xcm_primitives::NominationPoolsEncodeCall for WestendEncoder {
+	fn nomination_pools_encode_call(
+		call: xcm_primitives::AvailableNominationPoolsCalls,
+	) -> Vec<u8> {
+		match call {
+			xcm_primitives::AvailableNominationPoolsCalls::Join(a, b) => {
+				RelayCall::NominationPools(NominationPoolsCall::Join(a, b)).encode()
+			}
+
+			xcm_primitives::AvailableNominationPoolsCalls::BondExtra(a) => {
+				RelayCall::NominationPools(NominationPoolsCall::BondExtra(a)).encode()
+			}
+
+			xcm_primitives::AvailableNominationPoolsCalls::Unbond(a, b) => {
+				RelayCall::NominationPools(NominationPoolsCall::Unbond(a.into(), b)).encode()
+			}
+
+			xcm_primitives::AvailableNominationPoolsCalls::ClaimPayout => {
+				RelayCall::NominationPools(NominationPoolsCall::ClaimPayout).encode()
+			}
+		}
+	}
+}
+
+impl xcm_primitives::ConvictionVotingEncodeCall for WestendEncoder {
+	fn conviction_voting_encode_call(
+		call: xcm_primitives::AvailableConvictionVotingCalls,
+	) -> Vec<u8> {
+		match call {
+			xcm_primitives::AvailableConvictionVotingCalls::Vote(a, b) => {
+				RelayCall::ConvictionVoting(ConvictionVotingCall::Vote(a, b)).encode()
+			}
+
+			xcm_primitives::AvailableConvictionVotingCalls::RemoveVote(a, b) => {
+				RelayCall::ConvictionVoting(ConvictionVotingCall::RemoveVote(a, b)).encode()
+			}
+
+			xcm_primitives::AvailableConvictionVotingCalls::Delegate(a, b, c, d) => {
+				RelayCall::ConvictionVoting(ConvictionVotingCall::Delegate(a, b.into(), c, d))
+					.encode()
+			}
+		}
+	}
+}
+
+impl xcm_primitives::ProxyEncodeCall for WestendEncoder {
+	fn proxy_encode_call(call: xcm_primitives::AvailableProxyCalls) -> Vec<u8> {
+		match call {
+			xcm_primitives::AvailableProxyCalls::Proxy(a, b, mut c) => {
+				let mut call = RelayCall::Proxy(ProxyCall::Proxy(a.into(), b)).encode();
+				// If we encode directly we inject the call length,
+				// so we just append the inner call after encoding the outer
+				call.append(&mut c);
+				call
+			}
+
+			xcm_primitives::AvailableProxyCalls::AddProxy(a, b, c) => {
+				RelayCall::Proxy(ProxyCall::AddProxy(a.into(), b, c)).encode()
+			}
+
+			xcm_primitives::AvailableProxyCalls::RemoveProxy(a, b, c) => {
+				RelayCall::Proxy(ProxyCall::RemoveProxy(a.into(), b, c)).encode()
+			}
+		}
+	}
+}
+
 /// Westend pallet and extrinsic indices
 pub const WESTEND_RELAY_INDICES: RelayChainIndices = RelayChainIndices {
 	staking: 6u8,
@@ -191,6 +324,19 @@ pub const WESTEND_RELAY_INDICES: RelayChainIndices = RelayChainIndices {
 	accept_open_channel: 1u8,
 	close_channel: 2u8,
 	cancel_open_request: 6u8,
+	nomination_pools: 29u8,
+	conviction_voting: 38u8,
+	proxy: 22u8,
+	pool_join: 0u8,
+	pool_bond_extra: 1u8,
+	pool_unbond: 3u8,
+	pool_claim_payout: 2u8,
+	vote: 0u8,
+	remove_vote: 4u8,
+	delegate: 1u8,
+	proxy_call: 0u8,
+	add_proxy: 1u8,
+	remove_proxy: 2u8,
 };
 
 #[cfg(test)]
@@ -845,4 +991,150 @@ mod tests {
 			);
 		});
 	}
+
+	#[test]
+	fn test_pool_join() {
+		let mut expected_encoded: Vec<u8> = Vec::new();
+
+		let index = <westend_runtime::Runtime as frame_system::Config>::PalletInfo::index::<
+			westend_runtime::NominationPools,
+		>()
+		.unwrap() as u8;
+		expected_encoded.push(index);
+
+		let mut expected = pallet_nomination_pools::Call::<westend_runtime::Runtime>::join {
+			amount: 100u32.into(),
+			pool_id: 1u32,
+		}
+		.encode();
+		expected_encoded.append(&mut expected);
+
+		assert_eq!(
+			<WestendEncoder as xcm_primitives::NominationPoolsEncodeCall>::nomination_pools_encode_call(
+				xcm_primitives::AvailableNominationPoolsCalls::Join(100u32.into(), 1u32)
+			),
+			expected_encoded.clone()
+		);
+		sp_io::TestExternalities::default().execute_with(|| {
+			// Pallet-xcm-transactor default encoder returns same result
+			// insert storage item as per migration to set the storage item
+			pallet_xcm_transactor::RelayIndices::<moonbase_runtime::Runtime>::put(
+				WESTEND_RELAY_INDICES,
+			);
+			assert_eq!(
+				<pallet_xcm_transactor::Pallet::<
+					moonbase_runtime::Runtime> as xcm_primitives::NominationPoolsEncodeCall
+				>::nomination_pools_encode_call(
+					xcm_primitives::AvailableNominationPoolsCalls::Join(100u32.into(), 1u32)
+				),
+				expected_encoded
+			);
+		});
+	}
+
+	#[test]
+	fn test_conviction_vote() {
+		let mut expected_encoded: Vec<u8> = Vec::new();
+
+		let index = <westend_runtime::Runtime as frame_system::Config>::PalletInfo::index::<
+			westend_runtime::ConvictionVoting,
+		>()
+		.unwrap() as u8;
+		expected_encoded.push(index);
+
+		let mut expected = pallet_conviction_voting::Call::<westend_runtime::Runtime>::vote {
+			poll_index: 1u32,
+			vote: pallet_conviction_voting::AccountVote::Standard {
+				vote: pallet_conviction_voting::Vote {
+					aye: true,
+					conviction: pallet_conviction_voting::Conviction::Locked2x,
+				},
+				balance: 100u32.into(),
+			},
+		}
+		.encode();
+		expected_encoded.append(&mut expected);
+
+		let vote = xcm_primitives::RelayAccountVote::Standard {
+			vote: xcm_primitives::RelayVote {
+				aye: true,
+				conviction: xcm_primitives::RelayConviction::Locked2x,
+			},
+			balance: 100u32.into(),
+		};
+
+		assert_eq!(
+			<WestendEncoder as xcm_primitives::ConvictionVotingEncodeCall>::conviction_voting_encode_call(
+				xcm_primitives::AvailableConvictionVotingCalls::Vote(1u32, vote)
+			),
+			expected_encoded.clone()
+		);
+		sp_io::TestExternalities::default().execute_with(|| {
+			// Pallet-xcm-transactor default encoder returns same result
+			// insert storage item as per migration to set the storage item
+			pallet_xcm_transactor::RelayIndices::<moonbase_runtime::Runtime>::put(
+				WESTEND_RELAY_INDICES,
+			);
+			assert_eq!(
+				<pallet_xcm_transactor::Pallet::<
+					moonbase_runtime::Runtime> as xcm_primitives::ConvictionVotingEncodeCall
+				>::conviction_voting_encode_call(
+					xcm_primitives::AvailableConvictionVotingCalls::Vote(1u32, vote)
+				),
+				expected_encoded
+			);
+		});
+	}
+
+	#[test]
+	fn test_add_proxy() {
+		let mut expected_encoded: Vec<u8> = Vec::new();
+
+		let index = <westend_runtime::Runtime as frame_system::Config>::PalletInfo::index::<
+			westend_runtime::Proxy,
+		>()
+		.unwrap() as u8;
+		expected_encoded.push(index);
+
+		let delegate = AccountId32::from([1u8; 32]);
+		let proxy_type = westend_runtime::ProxyType::Staking;
+		let mut expected = pallet_proxy::Call::<westend_runtime::Runtime>::add_proxy {
+			delegate: delegate.clone().into(),
+			proxy_type,
+			delay: 0u32,
+		}
+		.encode();
+		expected_encoded.append(&mut expected);
+
+		let proxy_type_index = proxy_type.encode()[0];
+		assert_eq!(
+			<WestendEncoder as xcm_primitives::ProxyEncodeCall>::proxy_encode_call(
+				xcm_primitives::AvailableProxyCalls::AddProxy(
+					delegate.clone(),
+					proxy_type_index,
+					0u32
+				)
+			),
+			expected_encoded.clone()
+		);
+		sp_io::TestExternalities::default().execute_with(|| {
+			// Pallet-xcm-transactor default encoder returns same result
+			// insert storage item as per migration to set the storage item
+			pallet_xcm_transactor::RelayIndices::<moonbase_runtime::Runtime>::put(
+				WESTEND_RELAY_INDICES,
+			);
+			assert_eq!(
+				<pallet_xcm_transactor::Pallet::<
+					moonbase_runtime::Runtime> as xcm_primitives::ProxyEncodeCall
+				>::proxy_encode_call(
+					xcm_primitives::AvailableProxyCalls::AddProxy(
+						delegate,
+						proxy_type_index,
+						0u32
+					)
+				),
+				expected_encoded
+			);
+		});
+	}
 }
@@ -53,10 +53,10 @@ describeSuite({
         expect(finishedReferendum.isFinished).to.be.true;
         expect(finishedReferendum.asFinished.approved.isTrue).to.be.true;
 
-        const parachainBondInfo = await context
+        const [parachainBondReserve] = await context
           .polkadotJs()
-          .query.parachainStaking.parachainBondInfo();
-        expect(parachainBondInfo.account.toString()).to.equal(randomAddress);
+          .query.parachainStaking.inflationDistributionInfo();
+        expect(parachainBondReserve.account.toString()).to.equal(randomAddress);
       },
     });
 
@@ -90,10 +90,10 @@ describeSuite({
         expect(finishedReferendum.isFinished).to.be.true;
         expect(finishedReferendum.asFinished.approved.isFalse).to.be.true;
 
-        const parachainBondInfo = await context
+        const [parachainBondReserve] = await context
           .polkadotJs()
-          .query.parachainStaking.parachainBondInfo();
-        expect(parachainBondInfo.account.toString()).not.toBe(randomAddress);
+          .query.parachainStaking.inflationDistributionInfo();
+        expect(parachainBondReserve.account.toString()).not.toBe(randomAddress);
       },
     });
 
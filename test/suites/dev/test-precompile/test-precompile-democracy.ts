@@ -26,10 +26,10 @@ describeSuite({
       id: "T02",
       title: "should check initial state - 0x0 ParachainBondAccount",
       test: async function () {
-        const parachainBondInfo = await context
+        const [parachainBondReserve] = await context
           .polkadotJs()
-          .query.parachainStaking.parachainBondInfo();
-        expect(parachainBondInfo.account.toString()).to.equal(ZERO_ADDRESS);
+          .query.parachainStaking.inflationDistributionInfo();
+        expect(parachainBondReserve.account.toString()).to.equal(ZERO_ADDRESS);
       },
     });
 
@@ -1,6 +1,7 @@
 import "@moonbeam-network/api-augment";
-import { describeSuite, expect } from "@moonwall/cli";
+import { describeSuite, expect, fetchCompiledContract } from "@moonwall/cli";
 import { ALITH_ADDRESS, MIN_GLMR_STAKING } from "@moonwall/util";
+import { BaseError, ContractFunctionRevertedError, parseAbiItem } from "viem";
 
 //     // We delegate our target collator with all the tokens provided
 //     staking.delegate(target, msg.value);
@@ -47,18 +48,30 @@ describeSuite({
           "balance dif should only be tx fee, not MIN_GLMR_STAKING"
         ).toBeLessThan(MIN_GLMR_STAKING);
 
-        expect(
-          async () =>
-            await context.writeContract!({
-              contractAddress,
-              contractName: "StakingAttacker",
-              functionName: "score_a_free_delegation",
-              value: MIN_GLMR_STAKING,
-            })
-        ).rejects.toThrowError(
-          "Module(ModuleError { index: 12, error: [10, 0, 0, 0], " +
-            'message: Some("DelegationBelowMin") })'
-        );
+        // The staking precompile reverts with the custom error
+        // `DispatchError(uint8 module, uint8 index, string message)`, which is not part of the
+        // attacker ABI, so it is added to decode the revert data.
+        const { abi } = fetchCompiledContract("StakingAttacker");
+        const error = await context
+          .viem()
+          .simulateContract({
+            account: ALITH_ADDRESS,
+            address: contractAddress,
+            abi: [
+              ...abi,
+              parseAbiItem("error DispatchError(uint8 module, uint8 index, string message)"),
+            ],
+            functionName: "score_a_free_delegation",
+            value: MIN_GLMR_STAKING,
+          })
+          .catch((e: BaseError) => e);
+        expect(error).toBeInstanceOf(BaseError);
+
+        const revertError = (error as BaseError).walk(
+          (e) => e instanceof ContractFunctionRevertedError
+        ) as ContractFunctionRevertedError | null;
+        expect(revertError?.data?.errorName).toBe("DispatchError");
+        expect(revertError?.data?.args).toStrictEqual([12, 10, "DelegationBelowMin"]);
       },
     });
   },
@@ -13,11 +13,11 @@ describeSuite({
       id: "T01",
       title: "should be initialized at address zero",
       test: async function () {
-        const parachainBondInfo = await context
+        const [parachainBondReserve] = await context
           .polkadotJs()
-          .query.parachainStaking.parachainBondInfo();
-        expect(parachainBondInfo.account.toString()).to.equal(ZERO_ADDRESS);
-        expect(parachainBondInfo.percent.toNumber()).to.equal(30);
+          .query.parachainStaking.inflationDistributionInfo();
+        expect(parachainBondReserve.account.toString()).to.equal(ZERO_ADDRESS);
+        expect(parachainBondReserve.percent.toNumber()).to.equal(30);
       },
     });
 
@@ -35,11 +35,11 @@ describeSuite({
         );
         expect(result!.successful).to.be.true;
 
-        const parachainBondInfo = await context
+        const [parachainBondReserve] = await context
           .polkadotJs()
-          .query.parachainStaking.parachainBondInfo();
-        expect(parachainBondInfo.account.toString()).to.equal(alith.address);
-        expect(parachainBondInfo.percent.toNumber()).to.equal(30);
+          .query.parachainStaking.inflationDistributionInfo();
+        expect(parachainBondReserve.account.toString()).to.equal(alith.address);
+        expect(parachainBondReserve.percent.toNumber()).to.equal(30);
       },
     });
 
@@ -72,10 +72,10 @@ describeSuite({
         );
         expect(result!.successful).to.be.true;
 
-        const parachainBondInfo = await context
+        const [parachainBondReserve] = await context
           .polkadotJs()
-          .query.parachainStaking.parachainBondInfo();
-        expect(parachainBondInfo.percent.toBigInt()).to.equal(20n);
+          .query.parachainStaking.inflationDistributionInfo();
+        expect(parachainBondReserve.percent.toBigInt()).to.equal(20n);
       },
     });
 
@@ -34,6 +34,13 @@ describeSuite({
         });
         expect(status).eq("success");
 
+        // Allow the erc20 token to be transacted through xcm
+        await context.createBlock(
+          context
+            .polkadotJs()
+            .tx.sudo.sudo(context.polkadotJs().tx.erc20XcmBridge.registerToken(contractAddress, null))
+        );
+
         // Send some native tokens to the sovereign account of paraId (to pay fees)
         await polkadotJs.tx.balances.transfer(paraSovereign, parseEther("1")).signAndSend(alith);
         await context.createBlock();
@@ -100,6 +107,13 @@ describeSuite({
         });
         expect(status).eq("success");
 
+        // Allow the erc20 token to be transacted through xcm
+        await context.createBlock(
+          context
+            .polkadotJs()
+            .tx.sudo.sudo(context.polkadotJs().tx.erc20XcmBridge.registerToken(contractAddress, null))
+        );
+
         // Send some native tokens to the sovereign account of paraId (to pay fees)
         await polkadotJs.tx.balances.transfer(paraSovereign, parseEther("1")).signAndSend(alith);
         await context.createBlock();
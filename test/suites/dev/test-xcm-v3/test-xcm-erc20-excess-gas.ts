@@ -46,6 +46,13 @@ describeSuite({
         });
         expect(status).eq("success");
 
+        // Allow the erc20 token to be transacted through xcm
+        await context.createBlock(
+          context
+            .polkadotJs()
+            .tx.sudo.sudo(context.polkadotJs().tx.erc20XcmBridge.registerToken(contractAddress, null))
+        );
+
         const balanceBefore = (
           await polkadotJs.query.system.account(ALITH_ADDRESS)
         ).data.free.toBigInt();
@@ -105,6 +112,13 @@ describeSuite({
         });
         expect(status).eq("success");
 
+        // Allow the erc20 token to be transacted through xcm
+        await context.createBlock(
+          context
+            .polkadotJs()
+            .tx.sudo.sudo(context.polkadotJs().tx.erc20XcmBridge.registerToken(contractAddress, null))
+        );
+
         // Get pallet indices
         const metadata = await polkadotJs.rpc.state.getMetadata();
         const balancesPalletIndex = metadata.asLatest.pallets
@@ -191,6 +205,13 @@ describeSuite({
         });
         expect(status).eq("success");
 
+        // Allow the erc20 token to be transacted through xcm
+        await context.createBlock(
+          context
+            .polkadotJs()
+            .tx.sudo.sudo(context.polkadotJs().tx.erc20XcmBridge.registerToken(contractAddress, null))
+        );
+
         // Get pallet indices
         const metadata = await polkadotJs.rpc.state.getMetadata();
         const balancesPalletIndex = metadata.asLatest.pallets
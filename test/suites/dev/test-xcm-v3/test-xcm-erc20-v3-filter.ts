@@ -28,6 +28,13 @@ describeSuite({
       });
       erc20ContractAddress = contractAddress;
       expect(status).eq("success");
+
+      // Allow the erc20 token to be transacted through xcm
+      await context.createBlock(
+        context
+          .polkadotJs()
+          .tx.sudo.sudo(context.polkadotJs().tx.erc20XcmBridge.registerToken(contractAddress, null))
+      );
     });
 
     it({
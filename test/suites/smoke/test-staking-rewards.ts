@@ -495,8 +495,8 @@ describeSuite({
       );
 
       // calculate reward amounts
-      const parachainBondInfo = await apiAtPriorRewarded.query.parachainStaking.parachainBondInfo();
-      const parachainBondPercent = new Percent(parachainBondInfo.percent);
+      const inflationDistributionInfo =
+        await apiAtPriorRewarded.query.parachainStaking.inflationDistributionInfo();
       const totalStaked = await apiAtPriorRewarded.query.parachainStaking.staked(
         originalRoundNumber
       );
@@ -529,33 +529,28 @@ describeSuite({
 
       // calculate total staking reward
       const firstBlockRewardedEvents = await apiAtRewarded.query.system.events();
-      let reservedForParachainBond = new BN(0);
+      let reservedForInflationDistribution = new BN(0);
       for (const { phase, event } of firstBlockRewardedEvents) {
         if (!phase.isInitialization) {
           continue;
         }
-        // only deduct parachainBondReward if it was transferred (event must exist)
-        if (apiAtRewarded.events.parachainStaking.ReservedForParachainBond.is(event)) {
-          reservedForParachainBond = event.data[1] as any;
-          break;
-        }
-      }
-
-      // total expected staking reward minus the amount reserved for parachain bond
-      const totalStakingReward = (function () {
-        const parachainBondReward = parachainBondPercent.of(totalRoundIssuance);
-        if (!reservedForParachainBond.isZero()) {
+        // only deduct the reserves that were transferred (event must exist)
+        if (apiAtRewarded.events.parachainStaking.InflationDistributed.is(event)) {
+          const [index, account, value] = event.data as any;
+          const reserve = inflationDistributionInfo[index.toNumber()];
+          const expectedReserve = new Percent(reserve.percent).of(totalRoundIssuance);
           expect(
-            parachainBondReward.eq(reservedForParachainBond),
-            `parachain bond amount does not match \
-              ${parachainBondReward.toString()} != ${reservedForParachainBond.toString()} \
+            expectedReserve.eq(value),
+            `inflation distribution amount for ${account.toString()} does not match \
+              ${expectedReserve.toString()} != ${value.toString()} \
               for round ${originalRoundNumber.toString()}`
           ).to.be.true;
-          return totalRoundIssuance.sub(parachainBondReward);
+          reservedForInflationDistribution = reservedForInflationDistribution.add(value);
         }
+      }
 
-        return totalRoundIssuance;
-      })();
+      // total expected staking reward minus the amounts reserved for inflation distribution
+      const totalStakingReward = totalRoundIssuance.sub(reservedForInflationDistribution);
       const totalBondReward = totalStakingReward.sub(totalCollatorCommissionReward);
 
       const delayedPayout = (